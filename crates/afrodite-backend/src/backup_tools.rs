@@ -7,6 +7,8 @@ use config::args::BackupMode;
 use server_common::backup_encryption::decrypt;
 use sha2::{Digest, Sha256};
 
+mod restore;
+
 struct Sha256Finder {
     stack: Vec<std::fs::ReadDir>,
 }
@@ -135,6 +137,40 @@ fn verify_backup_dir(dir: &Path) -> Result<u64, String> {
     }
 }
 
+fn append_to_file_name(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_os_string();
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+/// Decrypt backup file and write the plaintext to `out_path`.
+fn decrypt_file(data_path: &Path, key: &[u8; 16], out_path: &Path) -> Result<(), String> {
+    let mut out_file = std::fs::File::create(out_path)
+        .map_err(|e| format!("Failed to create {}: {e}", out_path.display()))?;
+
+    let mut stream = decrypt::DecryptBackupDataStream::open(data_path, *key)
+        .map_err(|e| format!("Failed to open {}: {e}", data_path.display()))?;
+
+    loop {
+        match stream.decrypt_next_chunk() {
+            Ok(Some(chunk)) => std::io::Write::write_all(&mut out_file, &chunk)
+                .map_err(|e| format!("IO error writing {}: {e}", out_path.display()))?,
+            Ok(None) => break,
+            Err(decrypt::DecryptError::Io(e)) => {
+                return Err(format!("IO error decrypting {}: {e}", data_path.display()));
+            }
+            Err(decrypt::DecryptError::DecryptionFailed)
+            | Err(decrypt::DecryptError::NotEnoughData) => {
+                return Err(format!("Decryption failed for {}", data_path.display()));
+            }
+        }
+    }
+
+    out_file
+        .sync_all()
+        .map_err(|e| format!("Failed to sync {}: {e}", out_path.display()))
+}
+
 /// Find all `.sha256` files under `root`, read the paired data file,
 /// decrypt it with the given key, and write the plaintext alongside
 /// the encrypted file with a `.decrypted` suffix.
@@ -160,47 +196,20 @@ fn decrypt_backup_subdir(
             }
 
             let out_path = data_path.with_extension("decrypted");
-            let mut out_file = match std::fs::File::create(&out_path) {
-                Ok(f) => f,
+            match decrypt_file(&data_path, key, &out_path) {
+                Ok(()) => {
+                    *decrypted += 1;
+                    println!(
+                        "Decrypted: {} -> {}",
+                        data_path.display(),
+                        out_path.display()
+                    );
+                }
                 Err(e) => {
                     *errors += 1;
-                    eprintln!("ERROR: failed to create {}: {e}", out_path.display());
-                    continue;
-                }
-            };
-
-            let mut stream = decrypt::DecryptBackupDataStream::open(&data_path, *key)
-                .map_err(|e| format!("Failed to open {}: {e}", data_path.display()))?;
-
-            loop {
-                match stream.decrypt_next_chunk() {
-                    Ok(Some(chunk)) => {
-                        if let Err(e) = std::io::Write::write_all(&mut out_file, &chunk) {
-                            *errors += 1;
-                            eprintln!("ERROR: IO error writing {}: {e}", out_path.display());
-                            break;
-                        }
-                    }
-                    Ok(None) => break,
-                    Err(decrypt::DecryptError::Io(e)) => {
-                        *errors += 1;
-                        eprintln!("ERROR: IO error decrypting {}: {e}", data_path.display());
-                        break;
-                    }
-                    Err(decrypt::DecryptError::DecryptionFailed)
-                    | Err(decrypt::DecryptError::NotEnoughData) => {
-                        *errors += 1;
-                        eprintln!("ERROR: decryption failed for {}", data_path.display());
-                        break;
-                    }
+                    eprintln!("ERROR: {e}");
                 }
             }
-            *decrypted += 1;
-            println!(
-                "Decrypted: {} -> {}",
-                data_path.display(),
-                out_path.display()
-            );
         }
     }
 
@@ -278,5 +287,38 @@ pub fn handle_backup_mode(mode: BackupMode) -> ExitCode {
                 }
             }
         }
+        BackupMode::Restore {
+            dir,
+            key_file,
+            target_dir,
+            snapshot,
+            force,
+        } => {
+            if !dir.exists() {
+                eprintln!("Directory not found: {}", dir.display());
+                return ExitCode::FAILURE;
+            }
+            let key = match read_key_file(&key_file) {
+                Ok(k) => k,
+                Err(e) => {
+                    eprintln!("{e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let settings = restore::RestoreSettings {
+                backup_dir: &dir,
+                key,
+                target_dir: &target_dir,
+                snapshot: snapshot.as_deref(),
+                force,
+            };
+            match restore::restore_backup(settings) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(e) => {
+                    eprintln!("{e}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
     }
 }
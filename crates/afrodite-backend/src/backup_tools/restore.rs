@@ -0,0 +1,313 @@
+//! Restore backup created by the manager backup link target
+
+use std::path::{Path, PathBuf};
+
+use model::ContentFileVariant;
+use server_data::file::utils::CONTENT_DIR_NAME;
+use simple_backend_config::Database;
+use simple_backend_database::data::{FILE_DIR_NAME, SQLITE_DIR_NAME};
+
+use super::{append_to_file_name, decrypt_file, read_sha256_file, verify_file_checksum};
+
+const BACKUP_FILES_DIR_NAME: &str = "files";
+const BACKUP_CONTENT_DIR_NAME: &str = "content";
+const BACKUP_FILE_NAME_PREFIX: &str = "backup_";
+
+pub struct RestoreSettings<'a> {
+    pub backup_dir: &'a Path,
+    pub key: [u8; 16],
+    pub target_dir: &'a Path,
+    pub snapshot: Option<&'a str>,
+    pub force: bool,
+}
+
+/// Encrypted backup file and the path where the decrypted file is written.
+struct RestoreFile {
+    backup: PathBuf,
+    target: PathBuf,
+}
+
+pub fn restore_backup(settings: RestoreSettings) -> Result<(), String> {
+    check_target_dir(settings.target_dir, settings.force)?;
+
+    let mut files = vec![];
+    for db in [Database::Current, Database::History] {
+        let backup = find_database_backup(settings.backup_dir, db, settings.snapshot)?;
+        println!("Selected database backup: {}", backup.display());
+        let name = db.sqlite_name();
+        files.push(RestoreFile {
+            backup,
+            target: settings
+                .target_dir
+                .join(SQLITE_DIR_NAME)
+                .join(name)
+                .join(format!("{name}.db")),
+        });
+    }
+    find_content_backups(settings.backup_dir, settings.target_dir, &mut files)?;
+
+    // Verify everything before writing anything to the target directory
+    for f in &files {
+        verify_with_sha256_file(&f.backup)?;
+    }
+    println!("Verified {} files", files.len());
+
+    for f in &files {
+        if let Some(parent) = f.target.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {e}", parent.display()))?;
+        }
+        let tmp = append_to_file_name(&f.target, ".tmp");
+        decrypt_file(&f.backup, &settings.key, &tmp)?;
+        std::fs::rename(&tmp, &f.target)
+            .map_err(|e| format!("Failed to rename {}: {e}", tmp.display()))?;
+    }
+
+    // SQLite would apply old WAL files to the restored databases
+    for db in [Database::Current, Database::History] {
+        let name = db.sqlite_name();
+        let db_dir = settings.target_dir.join(SQLITE_DIR_NAME).join(name);
+        for suffix in ["-wal", "-shm"] {
+            let path = db_dir.join(format!("{name}.db{suffix}"));
+            if path.exists() {
+                std::fs::remove_file(&path)
+                    .map_err(|e| format!("Failed to remove {}: {e}", path.display()))?;
+            }
+        }
+    }
+
+    println!(
+        "Restored {} files to {}",
+        files.len(),
+        settings.target_dir.display()
+    );
+
+    Ok(())
+}
+
+fn check_target_dir(target_dir: &Path, force: bool) -> Result<(), String> {
+    if !target_dir.exists() {
+        return std::fs::create_dir_all(target_dir)
+            .map_err(|e| format!("Failed to create {}: {e}", target_dir.display()));
+    }
+
+    let mut iter = std::fs::read_dir(target_dir)
+        .map_err(|e| format!("Failed to read {}: {e}", target_dir.display()))?;
+    if iter.next().is_some() && !force {
+        return Err(format!(
+            "Target directory {} is not empty. Use --force to restore anyway.",
+            target_dir.display()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Find latest database backup. If `snapshot` is set, the backup
+/// creation time must start with it.
+fn find_database_backup(
+    backup_dir: &Path,
+    db: Database,
+    snapshot: Option<&str>,
+) -> Result<PathBuf, String> {
+    let files_dir = backup_dir.join(BACKUP_FILES_DIR_NAME);
+    let prefix = format!("{BACKUP_FILE_NAME_PREFIX}{}_", db.sqlite_name());
+    let iter = std::fs::read_dir(&files_dir)
+        .map_err(|e| format!("Failed to read {}: {e}", files_dir.display()))?;
+
+    let mut selected: Option<(String, PathBuf)> = None;
+    for entry in iter {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        let Some(name) = path.file_name().and_then(|v| v.to_str()) else {
+            continue;
+        };
+        // Skip checksum and decrypted files
+        if name.contains('.') {
+            continue;
+        }
+        let Some(time) = name.strip_prefix(&prefix) else {
+            continue;
+        };
+        if snapshot.is_some_and(|s| !time.starts_with(s)) {
+            continue;
+        }
+        // Time format is sortable
+        if selected.as_ref().is_none_or(|(t, _)| time > t.as_str()) {
+            selected = Some((time.to_string(), path.clone()));
+        }
+    }
+
+    selected.map(|(_, path)| path).ok_or_else(|| {
+        format!(
+            "Database backup for {} not found from {}",
+            db.sqlite_name(),
+            files_dir.display()
+        )
+    })
+}
+
+fn find_content_backups(
+    backup_dir: &Path,
+    target_dir: &Path,
+    files: &mut Vec<RestoreFile>,
+) -> Result<(), String> {
+    let content_dir = backup_dir.join(BACKUP_CONTENT_DIR_NAME);
+    if !content_dir.exists() {
+        return Ok(());
+    }
+
+    let accounts = std::fs::read_dir(&content_dir)
+        .map_err(|e| format!("Failed to read {}: {e}", content_dir.display()))?;
+    for account in accounts {
+        let account_dir = account.map_err(|e| e.to_string())?.path();
+        if !account_dir.is_dir() {
+            continue;
+        }
+        let Some(account_id) = account_dir.file_name() else {
+            continue;
+        };
+        let target_content_dir = target_dir
+            .join(FILE_DIR_NAME)
+            .join(account_id)
+            .join(CONTENT_DIR_NAME);
+
+        let content = std::fs::read_dir(&account_dir)
+            .map_err(|e| format!("Failed to read {}: {e}", account_dir.display()))?;
+        for c in content {
+            let path = c.map_err(|e| e.to_string())?.path();
+            let Some(name) = path.file_name().and_then(|v| v.to_str()) else {
+                continue;
+            };
+            if ContentFileVariant::parse_file_name(name).is_none() {
+                continue;
+            }
+            files.push(RestoreFile {
+                target: target_content_dir.join(name),
+                backup: path,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn verify_with_sha256_file(data_path: &Path) -> Result<(), String> {
+    let sha256_path = append_to_file_name(data_path, ".sha256");
+    let file_name = data_path
+        .file_name()
+        .and_then(|v| v.to_str())
+        .ok_or_else(|| format!("Invalid file name {}", data_path.display()))?;
+    let entries = read_sha256_file(&sha256_path)?;
+    let (hash, _) = entries
+        .iter()
+        .find(|(_, name)| name == file_name)
+        .ok_or_else(|| format!("Checksum not found for {}", data_path.display()))?;
+    verify_file_checksum(data_path, hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use server_common::backup_encryption::encrypt_backup_data_stream;
+    use sha2::{Digest, Sha256};
+
+    use super::*;
+
+    const KEY: [u8; 16] = [0x5A; 16];
+
+    fn write_backup_file(dir: &Path, name: &str, plaintext: &[u8]) -> PathBuf {
+        std::fs::create_dir_all(dir).unwrap();
+        let path = dir.join(name);
+        let encrypted = encrypt_backup_data_stream(&KEY, plaintext, true);
+        let hash = base16ct::lower::encode_string(&Sha256::digest(&encrypted));
+        std::fs::write(&path, &encrypted).unwrap();
+        std::fs::write(
+            append_to_file_name(&path, ".sha256"),
+            format!("{hash} *{name}\n"),
+        )
+        .unwrap();
+        path
+    }
+
+    /// Returns backup directory and target directory
+    fn create_backup(test_name: &str) -> (PathBuf, PathBuf) {
+        let root = std::env::temp_dir().join(test_name);
+        let _ = std::fs::remove_dir_all(&root);
+        let backup_dir = root.join("backup");
+        let files_dir = backup_dir.join(BACKUP_FILES_DIR_NAME);
+        write_backup_file(&files_dir, "backup_current_2024-01-01", b"old current");
+        write_backup_file(&files_dir, "backup_current_2024-01-02", b"current");
+        write_backup_file(&files_dir, "backup_history_2024-01-02", b"history");
+        write_backup_file(
+            &backup_dir.join(BACKUP_CONTENT_DIR_NAME).join("account"),
+            "content6",
+            b"image",
+        );
+        (backup_dir, root.join("target"))
+    }
+
+    fn restore(backup_dir: &Path, target_dir: &Path) -> Result<(), String> {
+        restore_backup(RestoreSettings {
+            backup_dir,
+            key: KEY,
+            target_dir,
+            snapshot: None,
+            force: false,
+        })
+    }
+
+    #[test]
+    fn restore_latest_backup() {
+        let (backup_dir, target_dir) = create_backup("backup_restore_latest_backup");
+
+        restore(&backup_dir, &target_dir).unwrap();
+
+        let sqlite_dir = target_dir.join(SQLITE_DIR_NAME);
+        assert_eq!(
+            std::fs::read(sqlite_dir.join("current").join("current.db")).unwrap(),
+            b"current"
+        );
+        assert_eq!(
+            std::fs::read(sqlite_dir.join("history").join("history.db")).unwrap(),
+            b"history"
+        );
+        let content = target_dir
+            .join(FILE_DIR_NAME)
+            .join("account")
+            .join(CONTENT_DIR_NAME)
+            .join("content6");
+        assert_eq!(std::fs::read(content).unwrap(), b"image");
+
+        let _ = std::fs::remove_dir_all(backup_dir.parent().unwrap());
+    }
+
+    #[test]
+    fn restore_fails_if_checksum_does_not_match() {
+        let (backup_dir, target_dir) = create_backup("backup_restore_checksum_mismatch");
+        let modified = backup_dir
+            .join(BACKUP_CONTENT_DIR_NAME)
+            .join("account")
+            .join("content6");
+        let mut data = std::fs::read(&modified).unwrap();
+        let last = data.len() - 1;
+        data[last] ^= 0xFF;
+        std::fs::write(&modified, data).unwrap();
+
+        let error = restore(&backup_dir, &target_dir).unwrap_err();
+        assert!(error.contains("Checksum mismatch"), "{error}");
+        // Nothing is written if verification fails
+        assert!(!target_dir.join(SQLITE_DIR_NAME).exists());
+
+        let _ = std::fs::remove_dir_all(backup_dir.parent().unwrap());
+    }
+
+    #[test]
+    fn restore_to_non_empty_target_requires_force() {
+        let (backup_dir, target_dir) = create_backup("backup_restore_non_empty_target");
+        std::fs::create_dir_all(&target_dir).unwrap();
+        std::fs::write(target_dir.join("file"), b"data").unwrap();
+
+        assert!(restore(&backup_dir, &target_dir).is_err());
+
+        let _ = std::fs::remove_dir_all(backup_dir.parent().unwrap());
+    }
+}
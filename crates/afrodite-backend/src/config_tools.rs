@@ -38,6 +38,19 @@ fn handle_check_and_view_server(dir: PathBuf, print: bool) -> Result<(), GetConf
         println!("{:#?}", c.parsed_files())
     } else {
         println!("Server config loaded correctly");
+        if let Err(e) = c.email_content().render_all_with_sample_data() {
+            println!("Email content rendering failed: {e:?}");
+            return Err(GetConfigError::InvalidConfiguration);
+        }
+        println!("Email content rendered correctly");
+        for region in c.location_regions() {
+            println!(
+                "Region {}: {}",
+                region.name,
+                LocationIndexInfoCreator::new(region.location.clone())
+                    .create_one(region.location.index_cell_square_km)
+            );
+        }
     }
 
     Ok(())
@@ -86,10 +99,12 @@ fn handle_index_info(dir: PathBuf) -> Result<(), GetConfigError> {
     )
     .unwrap();
 
-    println!(
-        "{}",
-        LocationIndexInfoCreator::new(config.location().clone()).create_all()
-    );
+    for region in config.location_regions() {
+        let info = LocationIndexInfoCreator::new(region.location);
+        println!("Region {}", region.name);
+        println!("Supported zoom levels\n{}", info.create_all());
+        println!("\nConfigured location index\n{}\n", info.create_rows());
+    }
 
     Ok(())
 }
@@ -240,6 +240,16 @@ pub enum PostSaveInfoBannersError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`post_search_accounts`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PostSearchAccountsError {
+    Status400(),
+    Status401(),
+    Status500(),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`post_send_custom_email_draft_to_my_email_address`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -1217,6 +1227,47 @@ pub async fn post_save_info_banners(configuration: &configuration::Configuration
     }
 }
 
+/// Email and profile name filters find case-insensitive partial matches. Page numbers start from 0.  # Access  Permission [model_account::Permissions::admin_find_account_by_email_address] is required.  Additionally IP address filter requires permission [model_account::Permissions::admin_view_account_ip_address_usage] and permissions filter requires permission [model_account::Permissions::admin_view_permissions].
+pub async fn post_search_accounts(configuration: &configuration::Configuration, admin_account_search_query: models::AdminAccountSearchQuery) -> Result<models::AdminAccountSearchResult, Error<PostSearchAccountsError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_body_admin_account_search_query = admin_account_search_query;
+
+    let uri_str = format!("{}/account_api/search_accounts", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_admin_account_search_query);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::AdminAccountSearchResult`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::AdminAccountSearchResult`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<PostSearchAccountsError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
 pub async fn post_send_custom_email_draft_to_my_email_address(configuration: &configuration::Configuration, send_custom_email: models::SendCustomEmail) -> Result<(), Error<PostSendCustomEmailDraftToMyEmailAddressError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_body_send_custom_email = send_custom_email;
@@ -1366,8 +1417,8 @@ pub async fn post_set_news_publicity(configuration: &configuration::Configuratio
     }
 }
 
-/// # Access  Permission [model_account::Permissions::admin_edit_permissions] is required.
-pub async fn post_set_permissions(configuration: &configuration::Configuration, aid: &str, permissions: models::Permissions) -> Result<(), Error<PostSetPermissionsError>> {
+/// If the server requires TOTP for admin permissions, permissions can be granted only to accounts which have TOTP enabled.  # Access  Permission [model_account::Permissions::admin_edit_permissions] is required.
+pub async fn post_set_permissions(configuration: &configuration::Configuration, aid: &str, permissions: models::Permissions) -> Result<models::SetPermissionsResult, Error<PostSetPermissionsError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_aid = aid;
     let p_body_permissions = permissions;
@@ -1387,9 +1438,20 @@ pub async fn post_set_permissions(configuration: &configuration::Configuration,
     let resp = configuration.client.execute(req).await?;
 
     let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
 
     if !status.is_client_error() && !status.is_server_error() {
-        Ok(())
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::SetPermissionsResult`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::SetPermissionsResult`")))),
+        }
     } else {
         let content = resp.text().await?;
         let entity: Option<PostSetPermissionsError> = serde_json::from_str(&content).ok();
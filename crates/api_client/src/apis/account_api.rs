@@ -114,6 +114,15 @@ pub enum GetSignInWithInfoError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`get_totp_state`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetTotpStateError {
+    Status401(),
+    Status500(),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`get_verify_email`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -188,6 +197,16 @@ pub enum PostCompleteSetupError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`post_confirm_totp_enrollment`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PostConfirmTotpEnrollmentError {
+    Status401(),
+    Status429(),
+    Status500(),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`post_custom_report_empty`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -239,6 +258,16 @@ pub enum PostDemoAccountRegisterAccountError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`post_disable_totp`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PostDisableTotpError {
+    Status401(),
+    Status429(),
+    Status500(),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`post_email_login_with_token`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -319,6 +348,16 @@ pub enum PostLogoutError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`post_regenerate_totp_recovery_codes`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PostRegenerateTotpRecoveryCodesError {
+    Status401(),
+    Status429(),
+    Status500(),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`post_request_email_login_token`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -371,6 +410,15 @@ pub enum PostSignInWithLoginError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`post_start_totp_enrollment`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PostStartTotpEnrollmentError {
+    Status401(),
+    Status500(),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`post_verify_email`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -389,6 +437,16 @@ pub enum PostVerifyNewEmailError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`post_verify_totp`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PostVerifyTotpError {
+    Status401(),
+    Status429(),
+    Status500(),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`put_setting_profile_visiblity`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -426,6 +484,15 @@ pub enum PutSignInWithGoogleError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`put_sign_in_with_oidc`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PutSignInWithOidcError {
+    Status401(),
+    Status500(),
+    UnknownValue(serde_json::Value),
+}
+
 
 pub async fn delete_association_membership(configuration: &configuration::Configuration, ) -> Result<(), Error<DeleteAssociationMembershipError>> {
 
@@ -838,6 +905,43 @@ pub async fn get_sign_in_with_info(configuration: &configuration::Configuration,
     }
 }
 
+pub async fn get_totp_state(configuration: &configuration::Configuration, ) -> Result<models::TotpState, Error<GetTotpStateError>> {
+
+    let uri_str = format!("{}/account_api/totp_state", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::TotpState`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::TotpState`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<GetTotpStateError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
 pub async fn get_verify_email(configuration: &configuration::Configuration, ) -> Result<(), Error<GetVerifyEmailError>> {
 
     let uri_str = format!("{}/verify_email", configuration.base_path);
@@ -1077,6 +1181,47 @@ pub async fn post_complete_setup(configuration: &configuration::Configuration, )
     }
 }
 
+/// If the code is valid, TOTP is enabled, the current login session is marked as verified and recovery codes are returned.
+pub async fn post_confirm_totp_enrollment(configuration: &configuration::Configuration, totp_code: models::TotpCode) -> Result<models::TotpCodeResult, Error<PostConfirmTotpEnrollmentError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_body_totp_code = totp_code;
+
+    let uri_str = format!("{}/account_api/confirm_totp_enrollment", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_totp_code);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::TotpCodeResult`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::TotpCodeResult`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<PostConfirmTotpEnrollmentError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
 pub async fn post_custom_report_empty(configuration: &configuration::Configuration, update_custom_report_empty: models::UpdateCustomReportEmpty) -> Result<models::UpdateReportResult, Error<PostCustomReportEmptyError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_body_update_custom_report_empty = update_custom_report_empty;
@@ -1293,6 +1438,46 @@ pub async fn post_demo_account_register_account(configuration: &configuration::C
     }
 }
 
+pub async fn post_disable_totp(configuration: &configuration::Configuration, totp_code: models::TotpCode) -> Result<models::TotpCodeResult, Error<PostDisableTotpError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_body_totp_code = totp_code;
+
+    let uri_str = format!("{}/account_api/disable_totp", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_totp_code);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::TotpCodeResult`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::TotpCodeResult`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<PostDisableTotpError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
 /// The route always takes at least 5 seconds to complete to make token guessing slower.
 pub async fn post_email_login_with_token(configuration: &configuration::Configuration, email_login: models::EmailLogin) -> Result<models::LoginResult, Error<PostEmailLoginWithTokenError>> {
     // add a prefix to parameters to efficiently prevent name collisions
@@ -1627,6 +1812,47 @@ pub async fn post_logout(configuration: &configuration::Configuration, ) -> Resu
     }
 }
 
+/// Requires a TOTP code or recovery code.
+pub async fn post_regenerate_totp_recovery_codes(configuration: &configuration::Configuration, totp_code: models::TotpCode) -> Result<models::TotpCodeResult, Error<PostRegenerateTotpRecoveryCodesError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_body_totp_code = totp_code;
+
+    let uri_str = format!("{}/account_api/regenerate_totp_recovery_codes", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_totp_code);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::TotpCodeResult`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::TotpCodeResult`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<PostRegenerateTotpRecoveryCodesError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
 /// The route always takes at least 5 seconds to complete to prevent timing attacks that could be used to enumerate existing email addresses.
 pub async fn post_request_email_login_token(configuration: &configuration::Configuration, request_email_login_token: models::RequestEmailLoginToken) -> Result<models::RequestEmailLoginTokenResult, Error<PostRequestEmailLoginTokenError>> {
     // add a prefix to parameters to efficiently prevent name collisions
@@ -1838,6 +2064,44 @@ pub async fn post_sign_in_with_login(configuration: &configuration::Configuratio
     }
 }
 
+/// Creates a new TOTP secret which is enabled after confirming it with a valid code. Possible previous unconfirmed secret is replaced.  If the server requires TOTP for admin permissions, enrollment is not possible when the account has admin permissions.
+pub async fn post_start_totp_enrollment(configuration: &configuration::Configuration, ) -> Result<models::TotpEnrollmentResult, Error<PostStartTotpEnrollmentError>> {
+
+    let uri_str = format!("{}/account_api/start_totp_enrollment", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::TotpEnrollmentResult`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::TotpEnrollmentResult`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<PostStartTotpEnrollmentError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
 pub async fn post_verify_email(configuration: &configuration::Configuration, ) -> Result<(), Error<PostVerifyEmailError>> {
 
     let uri_str = format!("{}/verify_email", configuration.base_path);
@@ -1884,6 +2148,47 @@ pub async fn post_verify_new_email(configuration: &configuration::Configuration,
     }
 }
 
+/// Admin permissions are usable only from verified login sessions if the server requires that. Verification is valid for a server configured time and new login removes the verification.
+pub async fn post_verify_totp(configuration: &configuration::Configuration, totp_code: models::TotpCode) -> Result<models::TotpCodeResult, Error<PostVerifyTotpError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_body_totp_code = totp_code;
+
+    let uri_str = format!("{}/account_api/verify_totp", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_totp_code);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::TotpCodeResult`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::TotpCodeResult`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<PostVerifyTotpError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
 /// NOTE: Client uses this in initial setup.  # Limits - When [AccountState::Banned], the visiblity can only be set to private.
 pub async fn put_setting_profile_visiblity(configuration: &configuration::Configuration, boolean_setting: models::BooleanSetting) -> Result<(), Error<PutSettingProfileVisiblityError>> {
     // add a prefix to parameters to efficiently prevent name collisions
@@ -2023,3 +2328,44 @@ pub async fn put_sign_in_with_google(configuration: &configuration::Configuratio
     }
 }
 
+/// Only one OpenID Connect provider can be associated with the account.
+pub async fn put_sign_in_with_oidc(configuration: &configuration::Configuration, put_sign_in_with_oidc: models::PutSignInWithOidc) -> Result<models::PutSignInWithResult, Error<PutSignInWithOidcError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_body_put_sign_in_with_oidc = put_sign_in_with_oidc;
+
+    let uri_str = format!("{}/account_api/sign_in_with_oidc", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::PUT, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_put_sign_in_with_oidc);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::PutSignInWithResult`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::PutSignInWithResult`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<PutSignInWithOidcError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
@@ -80,6 +80,16 @@ pub enum GetLatestPublicKeyIdError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`get_message_attachment`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetMessageAttachmentError {
+    Status401(),
+    Status404(),
+    Status500(),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`get_message_delivery_info`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -361,6 +371,16 @@ pub enum PostUnblockProfileError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`post_upload_message_attachment`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PostUploadMessageAttachmentError {
+    Status401(),
+    Status413(),
+    Status500(),
+    UnknownValue(serde_json::Value),
+}
+
 
 pub async fn get_chat_app_notification_settings(configuration: &configuration::Configuration, ) -> Result<models::ChatAppNotificationSettings, Error<GetChatAppNotificationSettingsError>> {
 
@@ -627,6 +647,38 @@ pub async fn get_latest_public_key_id(configuration: &configuration::Configurati
 }
 
 /// This endpoint returns delivery information (delivered/seen status) for all messages sent by the authenticated user.
+/// The attachment is available until both sender and recipient have acknowledged the message. Sender and recipient must be a match and no blocks must exist.
+pub async fn get_message_attachment(configuration: &configuration::Configuration, sender: &str, message_id: &str) -> Result<reqwest::Response, Error<GetMessageAttachmentError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_query_sender = sender;
+    let p_query_message_id = message_id;
+
+    let uri_str = format!("{}/chat_api/message_attachment", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    req_builder = req_builder.query(&[("sender", &p_query_sender.to_string())]);
+    req_builder = req_builder.query(&[("message_id", &p_query_message_id.to_string())]);
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+
+    if !status.is_client_error() && !status.is_server_error() {
+        Ok(resp)
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<GetMessageAttachmentError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
 pub async fn get_message_delivery_info(configuration: &configuration::Configuration, ) -> Result<models::MessageDeliveryInfoList, Error<GetMessageDeliveryInfoError>> {
 
     let uri_str = format!("{}/chat_api/message_delivery_info", configuration.base_path);
@@ -1633,7 +1685,7 @@ pub async fn post_send_like(configuration: &configuration::Configuration, send_l
     }
 }
 
-/// Server config file defines max count for conversation pending messages. Max message size is u16::MAX.  Sending will fail if one or two way block exists.  Only the latest public key for sender and recipient can be used when sending a message.
+/// Server config file defines max count for conversation pending messages. Max message size is u16::MAX.  Sending will fail if one or two way block exists.  Only the latest public key for sender and recipient can be used when sending a message.  Connected recipient receives the message with WebSocket `PendingMessage` message. The same functionality is also available using WebSocket `SendMessage` message.
 pub async fn post_send_message(configuration: &configuration::Configuration, sender_public_key_id: i64, recipient: &str, recipient_public_key_id: i64, message_id: &str, body: Vec<u8>) -> Result<models::SendMessageResult, Error<PostSendMessageError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_query_sender_public_key_id = sender_public_key_id;
@@ -1713,3 +1765,48 @@ pub async fn post_unblock_profile(configuration: &configuration::Configuration,
     }
 }
 
+/// The message must be sent before uploading the attachment and sender acknowledgement for the message must be done only after the attachment upload. Upload fails if the recipient has already acknowledged the message. Only one attachment per message is possible.  The attachment is removed from server when both sender and recipient have acknowledged the message.  Server config file defines max size for an attachment and max count for attachments waiting for message acknowledgements. Attachment bytes are counted to the yearly chat backup transfer budget.
+pub async fn post_upload_message_attachment(configuration: &configuration::Configuration, message_id: &str, body: Vec<u8>) -> Result<models::UploadMessageAttachmentResult, Error<PostUploadMessageAttachmentError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_query_message_id = message_id;
+    let p_body_body = body;
+
+    let uri_str = format!("{}/chat_api/upload_message_attachment", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+
+    req_builder = req_builder.query(&[("message_id", &p_query_message_id.to_string())]);
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    let file = std::io::Cursor::new(p_body_body);
+    let stream = FramedRead::new(file, BytesCodec::new());
+    req_builder = req_builder.body(reqwest::Body::wrap_stream(stream));
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::UploadMessageAttachmentResult`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::UploadMessageAttachmentResult`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<PostUploadMessageAttachmentError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
@@ -13,6 +13,7 @@ use reqwest;
 use serde::{Deserialize, Serialize, de::Error as _};
 use crate::{apis::ResponseContent, models};
 use super::{Error, configuration, ContentType};
+use tokio_util::codec::{BytesCodec, FramedRead};
 
 
 /// struct for typed errors of method [`get_admin_notification_settings`]
@@ -160,6 +161,15 @@ pub enum PostEditMaintenanceNotificationError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`post_get_admin_audit_log_page`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PostGetAdminAuditLogPageError {
+    Status401(),
+    Status500(),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`post_get_api_usage_data`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -223,6 +233,16 @@ pub enum PostGetReportQueuePageError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`post_import_account_data`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PostImportAccountDataError {
+    Status401(),
+    Status413(),
+    Status500(),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`post_process_reports`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -881,6 +901,47 @@ pub async fn post_edit_maintenance_notification(configuration: &configuration::C
     }
 }
 
+/// HTTP method is POST because JSON request body requires it.  # Permissions Requires [Permissions::admin_view_admin_audit_log].
+pub async fn post_get_admin_audit_log_page(configuration: &configuration::Configuration, admin_audit_log_query: models::AdminAuditLogQuery) -> Result<models::AdminAuditLogPage, Error<PostGetAdminAuditLogPageError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_body_admin_audit_log_query = admin_audit_log_query;
+
+    let uri_str = format!("{}/common_api/admin_audit_log_page", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_admin_audit_log_query);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::AdminAuditLogPage`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::AdminAuditLogPage`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<PostGetAdminAuditLogPageError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
 /// HTTP method is POST because JSON request body requires it.  # Permissions Requires [Permissions::admin_view_account_api_usage].
 pub async fn post_get_api_usage_data(configuration: &configuration::Configuration, get_api_usage_statistics_settings: models::GetApiUsageStatisticsSettings) -> Result<models::GetApiUsageStatisticsResult, Error<PostGetApiUsageDataError>> {
     // add a prefix to parameters to efficiently prevent name collisions
@@ -1166,6 +1227,49 @@ pub async fn post_get_report_queue_page(configuration: &configuration::Configura
     }
 }
 
+/// The archive must be created using user data export. The imported data is profile, profile images, security content, settings and favorites. Favorites are imported only if the favorite account exists on this server. Other data which refers to other accounts is not imported. The account ID from the archive is used if it is not already used on this server.  Profile images are processed using content processing, so this might take a while. Images which fail the processing are not imported.  The imported account does not have any sign in with login methods, so email login must be used.  Max archive size is 200 MiB.  # Permissions Requires [Permissions::admin_import_account_data].
+pub async fn post_import_account_data(configuration: &configuration::Configuration, body: Vec<u8>) -> Result<models::PostImportAccountDataResult, Error<PostImportAccountDataError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_body_body = body;
+
+    let uri_str = format!("{}/common_api/import_account_data", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    let file = std::io::Cursor::new(p_body_body);
+    let stream = FramedRead::new(file, BytesCodec::new());
+    req_builder = req_builder.body(reqwest::Body::wrap_stream(stream));
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::PostImportAccountDataResult`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::PostImportAccountDataResult`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<PostImportAccountDataError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
 pub async fn post_process_reports(configuration: &configuration::Configuration, process_reports: models::ProcessReports) -> Result<(), Error<PostProcessReportsError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_body_process_reports = process_reports;
@@ -0,0 +1,61 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// AdminAccountSearchQuery : Admin account search query.  All set filters must match.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AdminAccountSearchQuery {
+    /// Account creation time is equal or after this time
+    #[serde(rename = "created_after", skip_serializing_if = "Option::is_none")]
+    pub created_after: Option<models::UnixTime>,
+    /// Account creation time is equal or before this time
+    #[serde(rename = "created_before", skip_serializing_if = "Option::is_none")]
+    pub created_before: Option<models::UnixTime>,
+    /// Case-insensitive partial email address match
+    #[serde(rename = "email", skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// IP address from IP address usage history.  Requires permission [model::Permissions::admin_view_account_ip_address_usage].
+    #[serde(rename = "ip_address", skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
+    /// Page number starting from 0
+    #[serde(rename = "page")]
+    pub page: i64,
+    /// Account has all permissions which are enabled in this value. If no permissions are enabled, account has at least one permission.  Requires permission [model::Permissions::admin_view_permissions].
+    #[serde(rename = "permissions", skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<models::Permissions>,
+    /// Case-insensitive partial profile name match
+    #[serde(rename = "profile_name", skip_serializing_if = "Option::is_none")]
+    pub profile_name: Option<String>,
+    /// Sign in with Apple, Google or OpenID Connect account ID
+    #[serde(rename = "sign_in_with_account_id", skip_serializing_if = "Option::is_none")]
+    pub sign_in_with_account_id: Option<String>,
+    #[serde(rename = "state", skip_serializing_if = "Option::is_none")]
+    pub state: Option<models::AdminAccountSearchState>,
+}
+
+impl AdminAccountSearchQuery {
+    /// Admin account search query.  All set filters must match.
+    pub fn new(page: i64) -> AdminAccountSearchQuery {
+        AdminAccountSearchQuery {
+            created_after: None,
+            created_before: None,
+            email: None,
+            ip_address: None,
+            page,
+            permissions: None,
+            profile_name: None,
+            sign_in_with_account_id: None,
+            state: None,
+        }
+    }
+}
+
@@ -0,0 +1,27 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AdminAccountSearchResult {
+    #[serde(rename = "entries")]
+    pub entries: Vec<models::AdminAccountSearchResultEntry>,
+}
+
+impl AdminAccountSearchResult {
+    pub fn new(entries: Vec<models::AdminAccountSearchResultEntry>) -> AdminAccountSearchResult {
+        AdminAccountSearchResult {
+            entries,
+        }
+    }
+}
+
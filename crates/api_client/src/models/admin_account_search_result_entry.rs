@@ -0,0 +1,39 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AdminAccountSearchResultEntry {
+    #[serde(rename = "account_created_unix_time")]
+    pub account_created_unix_time: models::UnixTime,
+    #[serde(rename = "aid")]
+    pub aid: models::AccountId,
+    #[serde(rename = "email", skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(rename = "profile_name", skip_serializing_if = "Option::is_none")]
+    pub profile_name: Option<String>,
+    #[serde(rename = "state")]
+    pub state: models::AccountStateContainer,
+}
+
+impl AdminAccountSearchResultEntry {
+    pub fn new(account_created_unix_time: models::UnixTime, aid: models::AccountId, state: models::AccountStateContainer) -> AdminAccountSearchResultEntry {
+        AdminAccountSearchResultEntry {
+            account_created_unix_time,
+            aid,
+            email: None,
+            profile_name: None,
+            state,
+        }
+    }
+}
+
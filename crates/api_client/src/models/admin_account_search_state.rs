@@ -0,0 +1,41 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum AdminAccountSearchState {
+    #[serde(rename = "InitialSetup")]
+    InitialSetup,
+    #[serde(rename = "Banned")]
+    Banned,
+    #[serde(rename = "PendingDeletion")]
+    PendingDeletion,
+
+}
+
+impl std::fmt::Display for AdminAccountSearchState {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::InitialSetup => write!(f, "InitialSetup"),
+            Self::Banned => write!(f, "Banned"),
+            Self::PendingDeletion => write!(f, "PendingDeletion"),
+        }
+    }
+}
+
+impl Default for AdminAccountSearchState {
+    fn default() -> AdminAccountSearchState {
+        Self::InitialSetup
+    }
+}
+
@@ -0,0 +1,179 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum AdminAuditLogAction {
+    #[serde(rename = "EditMaintenanceNotification")]
+    EditMaintenanceNotification,
+    #[serde(rename = "EditBotConfig")]
+    EditBotConfig,
+    #[serde(rename = "EditServerConfig")]
+    EditServerConfig,
+    #[serde(rename = "ProcessReport")]
+    ProcessReport,
+    #[serde(rename = "TriggerSoftwareUpdateDownload")]
+    TriggerSoftwareUpdateDownload,
+    #[serde(rename = "TriggerSoftwareUpdateInstall")]
+    TriggerSoftwareUpdateInstall,
+    #[serde(rename = "TriggerServerDataReset")]
+    TriggerServerDataReset,
+    #[serde(rename = "TriggerServerRestart")]
+    TriggerServerRestart,
+    #[serde(rename = "TriggerSystemReboot")]
+    TriggerSystemReboot,
+    #[serde(rename = "TriggerSystemShutdown")]
+    TriggerSystemShutdown,
+    #[serde(rename = "ScheduleTask")]
+    ScheduleTask,
+    #[serde(rename = "UnscheduleTask")]
+    UnscheduleTask,
+    #[serde(rename = "ImportAccountData")]
+    ImportAccountData,
+    #[serde(rename = "StartDataExport")]
+    StartDataExport,
+    #[serde(rename = "SetBanState")]
+    SetBanState,
+    #[serde(rename = "DeleteAccount")]
+    DeleteAccount,
+    #[serde(rename = "SetPermissions")]
+    SetPermissions,
+    #[serde(rename = "SetAccountLockedState")]
+    SetAccountLockedState,
+    #[serde(rename = "Logout")]
+    Logout,
+    #[serde(rename = "CancelEmailChange")]
+    CancelEmailChange,
+    #[serde(rename = "InitEmailChange")]
+    InitEmailChange,
+    #[serde(rename = "ManualAssociationMembershipRegistry")]
+    ManualAssociationMembershipRegistry,
+    #[serde(rename = "DeleteAssociationMembership")]
+    DeleteAssociationMembership,
+    #[serde(rename = "UpdateAssociationMembershipType")]
+    UpdateAssociationMembershipType,
+    #[serde(rename = "RemoveAccountVerificationQueueItem")]
+    RemoveAccountVerificationQueueItem,
+    #[serde(rename = "SaveInfoBanners")]
+    SaveInfoBanners,
+    #[serde(rename = "CreateNewsItem")]
+    CreateNewsItem,
+    #[serde(rename = "DeleteNewsItem")]
+    DeleteNewsItem,
+    #[serde(rename = "UpdateNewsTranslation")]
+    UpdateNewsTranslation,
+    #[serde(rename = "DeleteNewsTranslation")]
+    DeleteNewsTranslation,
+    #[serde(rename = "SetNewsPublicity")]
+    SetNewsPublicity,
+    #[serde(rename = "CreateCustomEmail")]
+    CreateCustomEmail,
+    #[serde(rename = "UpdateCustomEmail")]
+    UpdateCustomEmail,
+    #[serde(rename = "SendCustomEmailToAllAccounts")]
+    SendCustomEmailToAllAccounts,
+    #[serde(rename = "SetAccountDeletionRequestState")]
+    SetAccountDeletionRequestState,
+    #[serde(rename = "GetAccountDeletionRequestState")]
+    GetAccountDeletionRequestState,
+    #[serde(rename = "SetEmailLoginEnabled")]
+    SetEmailLoginEnabled,
+    #[serde(rename = "GetAccountBanTime")]
+    GetAccountBanTime,
+    #[serde(rename = "SetSecurityContentVerifiedValue")]
+    SetSecurityContentVerifiedValue,
+    #[serde(rename = "SetMediaContentFaceDetectedValue")]
+    SetMediaContentFaceDetectedValue,
+    #[serde(rename = "SetMediaContentFaceVerifiedValue")]
+    SetMediaContentFaceVerifiedValue,
+    #[serde(rename = "ModerateMediaContent")]
+    ModerateMediaContent,
+    #[serde(rename = "EditImageProcessingConfig")]
+    EditImageProcessingConfig,
+    #[serde(rename = "DeleteMediaContent")]
+    DeleteMediaContent,
+    #[serde(rename = "SetProfileAgeRangeVerifiedValue")]
+    SetProfileAgeRangeVerifiedValue,
+    #[serde(rename = "SetProfileNameVerifiedValue")]
+    SetProfileNameVerifiedValue,
+    #[serde(rename = "ModerateProfileString")]
+    ModerateProfileString,
+    #[serde(rename = "SetProfileName")]
+    SetProfileName,
+    #[serde(rename = "EditProfileAttributesSchema")]
+    EditProfileAttributesSchema,
+
+}
+
+impl std::fmt::Display for AdminAuditLogAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::EditMaintenanceNotification => write!(f, "EditMaintenanceNotification"),
+            Self::EditBotConfig => write!(f, "EditBotConfig"),
+            Self::EditServerConfig => write!(f, "EditServerConfig"),
+            Self::ProcessReport => write!(f, "ProcessReport"),
+            Self::TriggerSoftwareUpdateDownload => write!(f, "TriggerSoftwareUpdateDownload"),
+            Self::TriggerSoftwareUpdateInstall => write!(f, "TriggerSoftwareUpdateInstall"),
+            Self::TriggerServerDataReset => write!(f, "TriggerServerDataReset"),
+            Self::TriggerServerRestart => write!(f, "TriggerServerRestart"),
+            Self::TriggerSystemReboot => write!(f, "TriggerSystemReboot"),
+            Self::TriggerSystemShutdown => write!(f, "TriggerSystemShutdown"),
+            Self::ScheduleTask => write!(f, "ScheduleTask"),
+            Self::UnscheduleTask => write!(f, "UnscheduleTask"),
+            Self::ImportAccountData => write!(f, "ImportAccountData"),
+            Self::StartDataExport => write!(f, "StartDataExport"),
+            Self::SetBanState => write!(f, "SetBanState"),
+            Self::DeleteAccount => write!(f, "DeleteAccount"),
+            Self::SetPermissions => write!(f, "SetPermissions"),
+            Self::SetAccountLockedState => write!(f, "SetAccountLockedState"),
+            Self::Logout => write!(f, "Logout"),
+            Self::CancelEmailChange => write!(f, "CancelEmailChange"),
+            Self::InitEmailChange => write!(f, "InitEmailChange"),
+            Self::ManualAssociationMembershipRegistry => write!(f, "ManualAssociationMembershipRegistry"),
+            Self::DeleteAssociationMembership => write!(f, "DeleteAssociationMembership"),
+            Self::UpdateAssociationMembershipType => write!(f, "UpdateAssociationMembershipType"),
+            Self::RemoveAccountVerificationQueueItem => write!(f, "RemoveAccountVerificationQueueItem"),
+            Self::SaveInfoBanners => write!(f, "SaveInfoBanners"),
+            Self::CreateNewsItem => write!(f, "CreateNewsItem"),
+            Self::DeleteNewsItem => write!(f, "DeleteNewsItem"),
+            Self::UpdateNewsTranslation => write!(f, "UpdateNewsTranslation"),
+            Self::DeleteNewsTranslation => write!(f, "DeleteNewsTranslation"),
+            Self::SetNewsPublicity => write!(f, "SetNewsPublicity"),
+            Self::CreateCustomEmail => write!(f, "CreateCustomEmail"),
+            Self::UpdateCustomEmail => write!(f, "UpdateCustomEmail"),
+            Self::SendCustomEmailToAllAccounts => write!(f, "SendCustomEmailToAllAccounts"),
+            Self::SetAccountDeletionRequestState => write!(f, "SetAccountDeletionRequestState"),
+            Self::GetAccountDeletionRequestState => write!(f, "GetAccountDeletionRequestState"),
+            Self::SetEmailLoginEnabled => write!(f, "SetEmailLoginEnabled"),
+            Self::GetAccountBanTime => write!(f, "GetAccountBanTime"),
+            Self::SetSecurityContentVerifiedValue => write!(f, "SetSecurityContentVerifiedValue"),
+            Self::SetMediaContentFaceDetectedValue => write!(f, "SetMediaContentFaceDetectedValue"),
+            Self::SetMediaContentFaceVerifiedValue => write!(f, "SetMediaContentFaceVerifiedValue"),
+            Self::ModerateMediaContent => write!(f, "ModerateMediaContent"),
+            Self::EditImageProcessingConfig => write!(f, "EditImageProcessingConfig"),
+            Self::DeleteMediaContent => write!(f, "DeleteMediaContent"),
+            Self::SetProfileAgeRangeVerifiedValue => write!(f, "SetProfileAgeRangeVerifiedValue"),
+            Self::SetProfileNameVerifiedValue => write!(f, "SetProfileNameVerifiedValue"),
+            Self::ModerateProfileString => write!(f, "ModerateProfileString"),
+            Self::SetProfileName => write!(f, "SetProfileName"),
+            Self::EditProfileAttributesSchema => write!(f, "EditProfileAttributesSchema"),
+        }
+    }
+}
+
+impl Default for AdminAuditLogAction {
+    fn default() -> AdminAuditLogAction {
+        Self::EditMaintenanceNotification
+    }
+}
+
@@ -0,0 +1,46 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AdminAuditLogEntry {
+    #[serde(rename = "action")]
+    pub action: models::AdminAuditLogAction,
+    #[serde(rename = "admin")]
+    pub admin: models::AccountId,
+    #[serde(rename = "ip_address")]
+    pub ip_address: String,
+    /// Action parameters as JSON
+    #[serde(rename = "parameters", skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<String>,
+    #[serde(rename = "target_account", skip_serializing_if = "Option::is_none")]
+    pub target_account: Option<models::AccountId>,
+    #[serde(rename = "target_content", skip_serializing_if = "Option::is_none")]
+    pub target_content: Option<models::ContentId>,
+    #[serde(rename = "time")]
+    pub time: models::UnixTime,
+}
+
+impl AdminAuditLogEntry {
+    pub fn new(action: models::AdminAuditLogAction, admin: models::AccountId, ip_address: String, time: models::UnixTime) -> AdminAuditLogEntry {
+        AdminAuditLogEntry {
+            action,
+            admin,
+            ip_address,
+            parameters: None,
+            target_account: None,
+            target_content: None,
+            time,
+        }
+    }
+}
+
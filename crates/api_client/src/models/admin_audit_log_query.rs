@@ -0,0 +1,47 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AdminAuditLogQuery {
+    /// Filter by action
+    #[serde(rename = "action", skip_serializing_if = "Option::is_none")]
+    pub action: Option<models::AdminAuditLogAction>,
+    /// Filter by admin account
+    #[serde(rename = "admin", skip_serializing_if = "Option::is_none")]
+    pub admin: Option<models::AccountId>,
+    #[serde(rename = "page")]
+    pub page: i64,
+    /// Only entries created at this time or before are returned.
+    #[serde(rename = "start_position")]
+    pub start_position: models::UnixTime,
+    /// Filter by target account
+    #[serde(rename = "target_account", skip_serializing_if = "Option::is_none")]
+    pub target_account: Option<models::AccountId>,
+    /// Filter by target content
+    #[serde(rename = "target_content", skip_serializing_if = "Option::is_none")]
+    pub target_content: Option<models::ContentId>,
+}
+
+impl AdminAuditLogQuery {
+    pub fn new(page: i64, start_position: models::UnixTime) -> AdminAuditLogQuery {
+        AdminAuditLogQuery {
+            action: None,
+            admin: None,
+            page,
+            start_position,
+            target_account: None,
+            target_content: None,
+        }
+    }
+}
+
@@ -35,6 +35,9 @@ pub struct Attribute {
     /// Default name for the attribute if translated value is not available.
     #[serde(rename = "name")]
     pub name: String,
+    /// Value range for [AttributeMode::Numeric] attribute.
+    #[serde(rename = "numeric", skip_serializing_if = "Option::is_none")]
+    pub numeric: Option<models::AttributeNumericRange>,
     /// Attribute order number.
     #[serde(rename = "order_number")]
     pub order_number: i32,
@@ -47,16 +50,16 @@ pub struct Attribute {
     /// Attribute value ordering mode for client to determine in what order the values should be displayed.
     #[serde(rename = "value_order")]
     pub value_order: models::AttributeValueOrderMode,
-    /// Top level values for the attribute.  Values are sorted by AttributeValue ID. Indexing with it is not possible as ID might be a bitflag value.
-    #[serde(rename = "values")]
-    pub values: Vec<models::AttributeValue>,
+    /// Top level values for the attribute.  Values are sorted by AttributeValue ID. Indexing with it is not possible as ID might be a bitflag value.  Empty for [AttributeMode::Numeric] attribute.
+    #[serde(rename = "values", skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<models::AttributeValue>>,
     /// Client should show this attribute when viewing a profile.
     #[serde(rename = "visible", skip_serializing_if = "Option::is_none")]
     pub visible: Option<bool>,
 }
 
 impl Attribute {
-    pub fn new(id: i32, key: String, mode: models::AttributeMode, name: String, order_number: i32, value_order: models::AttributeValueOrderMode, values: Vec<models::AttributeValue>) -> Attribute {
+    pub fn new(id: i32, key: String, mode: models::AttributeMode, name: String, order_number: i32, value_order: models::AttributeValueOrderMode) -> Attribute {
         Attribute {
             editable: None,
             icon: None,
@@ -66,11 +69,12 @@ impl Attribute {
             max_selected: None,
             mode,
             name,
+            numeric: None,
             order_number,
             required: None,
             translations: None,
             value_order,
-            values,
+            values: None,
             visible: None,
         }
     }
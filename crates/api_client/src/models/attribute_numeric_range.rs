@@ -0,0 +1,36 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AttributeNumericRange {
+    /// Max value (inclusive)
+    #[serde(rename = "max")]
+    pub max: i32,
+    /// Min value (inclusive)
+    #[serde(rename = "min")]
+    pub min: i32,
+    /// Valid values are `min + n * step` where `n` is 0 or greater integer.
+    #[serde(rename = "step")]
+    pub step: i32,
+}
+
+impl AttributeNumericRange {
+    pub fn new(max: i32, min: i32, step: i32) -> AttributeNumericRange {
+        AttributeNumericRange {
+            max,
+            min,
+            step,
+        }
+    }
+}
+
@@ -0,0 +1,42 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// BanEvasionEvidence : Signals which link the report target to the banned report creator.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BanEvasionEvidence {
+    /// Target's media content which is identical to some media content of the creator.
+    #[serde(rename = "identical_media_content", skip_serializing_if = "Option::is_none")]
+    pub identical_media_content: Option<Vec<models::ContentId>>,
+    /// IP addresses which both accounts have used
+    #[serde(rename = "shared_ip_addresses", skip_serializing_if = "Option::is_none")]
+    pub shared_ip_addresses: Option<Vec<String>>,
+    /// Names of IP lists which contain IP addresses of both accounts
+    #[serde(rename = "shared_ip_lists", skip_serializing_if = "Option::is_none")]
+    pub shared_ip_lists: Option<Vec<String>>,
+    /// Both accounts have the same Apple, Google or OIDC account
+    #[serde(rename = "shared_sign_in_with_account", skip_serializing_if = "Option::is_none")]
+    pub shared_sign_in_with_account: Option<bool>,
+}
+
+impl BanEvasionEvidence {
+    /// Signals which link the report target to the banned report creator.
+    pub fn new() -> BanEvasionEvidence {
+        BanEvasionEvidence {
+            identical_media_content: None,
+            shared_ip_addresses: None,
+            shared_ip_lists: None,
+            shared_sign_in_with_account: None,
+        }
+    }
+}
+
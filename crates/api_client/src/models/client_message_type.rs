@@ -11,8 +11,8 @@
 use crate::models;
 use serde::{Deserialize, Serialize};
 
-/// ClientMessageType : First byte of websocket binary protocol messages sent from client to server.  Remaining bytes are message payload. Payload format depends on the message type value: - `SyncVersionList` (0): payload contains list of current data sync versions.   Each byte in the payload is a sync version for a data type. The position   of the byte defines the data type (see `SyncCheckDataType`). If client   does not have any version of the data, version number must be `255`. - `ClearMaintenanceStatusIfPossible` (1): payload is empty. - `RequestResetProfilePaging` (60): payload format:   - request id byte (u8) - `RequestGetNextProfilePage` (61): payload format:   - request id byte (u8)   - profile iterator session id as minimal i64 - `RequestAutomaticProfileSearchResetProfilePaging` (62): payload format:   - request id byte (u8) - `RequestAutomaticProfileSearchGetNextProfilePage` (63): payload format:   - request id byte (u8)   - automatic profile search iterator session id as minimal i64 - `TypingStart` (120): payload is exactly 16 bytes account UUID in big-endian   byte order. - `TypingStop` (121): payload is empty. - `CheckOnlineStatus` (122): payload is 16 bytes account UUID. Optional   17th byte can be included for online status hint (0 = false, non-zero = true).  # Data formats  Data types used in payload definitions: - minimal i64:   - i64 byte count (u8, values: 1, 2, 3, 4, 5, 6, 7, 8)   - i64 bytes (little-endian byte order) - optional values in payloads are omitted when they are not present
-/// First byte of websocket binary protocol messages sent from client to server.  Remaining bytes are message payload. Payload format depends on the message type value: - `SyncVersionList` (0): payload contains list of current data sync versions.   Each byte in the payload is a sync version for a data type. The position   of the byte defines the data type (see `SyncCheckDataType`). If client   does not have any version of the data, version number must be `255`. - `ClearMaintenanceStatusIfPossible` (1): payload is empty. - `RequestResetProfilePaging` (60): payload format:   - request id byte (u8) - `RequestGetNextProfilePage` (61): payload format:   - request id byte (u8)   - profile iterator session id as minimal i64 - `RequestAutomaticProfileSearchResetProfilePaging` (62): payload format:   - request id byte (u8) - `RequestAutomaticProfileSearchGetNextProfilePage` (63): payload format:   - request id byte (u8)   - automatic profile search iterator session id as minimal i64 - `TypingStart` (120): payload is exactly 16 bytes account UUID in big-endian   byte order. - `TypingStop` (121): payload is empty. - `CheckOnlineStatus` (122): payload is 16 bytes account UUID. Optional   17th byte can be included for online status hint (0 = false, non-zero = true).  # Data formats  Data types used in payload definitions: - minimal i64:   - i64 byte count (u8, values: 1, 2, 3, 4, 5, 6, 7, 8)   - i64 bytes (little-endian byte order) - optional values in payloads are omitted when they are not present
+/// ClientMessageType : First byte of websocket binary protocol messages sent from client to server.  Remaining bytes are message payload. Payload format depends on the message type value: - `SyncVersionList` (0): payload contains list of current data sync versions.   Each byte in the payload is a sync version for a data type. The position   of the byte defines the data type (see `SyncCheckDataType`). If client   does not have any version of the data, version number must be `255`. - `ClearMaintenanceStatusIfPossible` (1): payload is empty. - `RequestResetProfilePaging` (60): payload format:   - request id byte (u8) - `RequestGetNextProfilePage` (61): payload format:   - request id byte (u8)   - profile iterator session id as minimal i64 - `RequestAutomaticProfileSearchResetProfilePaging` (62): payload format:   - request id byte (u8) - `RequestAutomaticProfileSearchGetNextProfilePage` (63): payload format:   - request id byte (u8)   - automatic profile search iterator session id as minimal i64 - `TypingStart` (120): payload is exactly 16 bytes account UUID in big-endian   byte order. - `TypingStop` (121): payload is empty. - `CheckOnlineStatus` (122): payload is 16 bytes account UUID. Optional   17th byte can be included for online status hint (0 = false, non-zero = true). - `SendMessage` (123): server responds with `ResponseSendMessage`.   Payload format:   - request id byte (u8)   - recipient account UUID (16 bytes, big-endian byte order)   - message UUID (16 bytes, big-endian byte order)   - sender public key ID as minimal i64   - recipient public key ID as minimal i64   - message data until payload ends (max size is u16::MAX) - `AcknowledgeReceivedMessages` (124): server responds with   `ResponseAcknowledgeMessages`. Payload format:   - request id byte (u8)   - delivery failed (u8, 0 = false, non-zero = true)   - repeated entries until payload ends:     - sender account UUID (16 bytes, big-endian byte order)     - message UUID (16 bytes, big-endian byte order) - `AcknowledgeSentMessages` (125): server responds with   `ResponseAcknowledgeMessages`. Payload format:   - request id byte (u8)   - repeated message UUIDs (16 bytes, big-endian byte order) until     payload ends  # Data formats  Data types used in payload definitions: - minimal i64:   - i64 byte count (u8, values: 1, 2, 3, 4, 5, 6, 7, 8)   - i64 bytes (little-endian byte order) - optional values in payloads are omitted when they are not present
+/// First byte of websocket binary protocol messages sent from client to server.  Remaining bytes are message payload. Payload format depends on the message type value: - `SyncVersionList` (0): payload contains list of current data sync versions.   Each byte in the payload is a sync version for a data type. The position   of the byte defines the data type (see `SyncCheckDataType`). If client   does not have any version of the data, version number must be `255`. - `ClearMaintenanceStatusIfPossible` (1): payload is empty. - `RequestResetProfilePaging` (60): payload format:   - request id byte (u8) - `RequestGetNextProfilePage` (61): payload format:   - request id byte (u8)   - profile iterator session id as minimal i64 - `RequestAutomaticProfileSearchResetProfilePaging` (62): payload format:   - request id byte (u8) - `RequestAutomaticProfileSearchGetNextProfilePage` (63): payload format:   - request id byte (u8)   - automatic profile search iterator session id as minimal i64 - `TypingStart` (120): payload is exactly 16 bytes account UUID in big-endian   byte order. - `TypingStop` (121): payload is empty. - `CheckOnlineStatus` (122): payload is 16 bytes account UUID. Optional   17th byte can be included for online status hint (0 = false, non-zero = true). - `SendMessage` (123): server responds with `ResponseSendMessage`.   Payload format:   - request id byte (u8)   - recipient account UUID (16 bytes, big-endian byte order)   - message UUID (16 bytes, big-endian byte order)   - sender public key ID as minimal i64   - recipient public key ID as minimal i64   - message data until payload ends (max size is u16::MAX) - `AcknowledgeReceivedMessages` (124): server responds with   `ResponseAcknowledgeMessages`. Payload format:   - request id byte (u8)   - delivery failed (u8, 0 = false, non-zero = true)   - repeated entries until payload ends:     - sender account UUID (16 bytes, big-endian byte order)     - message UUID (16 bytes, big-endian byte order) - `AcknowledgeSentMessages` (125): server responds with   `ResponseAcknowledgeMessages`. Payload format:   - request id byte (u8)   - repeated message UUIDs (16 bytes, big-endian byte order) until     payload ends  # Data formats  Data types used in payload definitions: - minimal i64:   - i64 byte count (u8, values: 1, 2, 3, 4, 5, 6, 7, 8)   - i64 bytes (little-endian byte order) - optional values in payloads are omitted when they are not present
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum ClientMessageType {
     #[serde(rename = "SyncVersionList")]
@@ -33,6 +33,12 @@ pub enum ClientMessageType {
     TypingStop,
     #[serde(rename = "CheckOnlineStatus")]
     CheckOnlineStatus,
+    #[serde(rename = "SendMessage")]
+    SendMessage,
+    #[serde(rename = "AcknowledgeReceivedMessages")]
+    AcknowledgeReceivedMessages,
+    #[serde(rename = "AcknowledgeSentMessages")]
+    AcknowledgeSentMessages,
 
 }
 
@@ -48,6 +54,9 @@ impl std::fmt::Display for ClientMessageType {
             Self::TypingStart => write!(f, "TypingStart"),
             Self::TypingStop => write!(f, "TypingStop"),
             Self::CheckOnlineStatus => write!(f, "CheckOnlineStatus"),
+            Self::SendMessage => write!(f, "SendMessage"),
+            Self::AcknowledgeReceivedMessages => write!(f, "AcknowledgeReceivedMessages"),
+            Self::AcknowledgeSentMessages => write!(f, "AcknowledgeSentMessages"),
         }
     }
 }
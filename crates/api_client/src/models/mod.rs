@@ -52,10 +52,26 @@ pub mod add_favorite_profile_result;
 pub use self::add_favorite_profile_result::AddFavoriteProfileResult;
 pub mod add_public_key_result;
 pub use self::add_public_key_result::AddPublicKeyResult;
+pub mod admin_account_search_query;
+pub use self::admin_account_search_query::AdminAccountSearchQuery;
+pub mod admin_account_search_result;
+pub use self::admin_account_search_result::AdminAccountSearchResult;
+pub mod admin_account_search_result_entry;
+pub use self::admin_account_search_result_entry::AdminAccountSearchResultEntry;
+pub mod admin_account_search_state;
+pub use self::admin_account_search_state::AdminAccountSearchState;
 pub mod admin_bot_account_verification_config;
 pub use self::admin_bot_account_verification_config::AdminBotAccountVerificationConfig;
 pub mod admin_bot_base_llm_config;
 pub use self::admin_bot_base_llm_config::AdminBotBaseLlmConfig;
+pub mod admin_audit_log_action;
+pub use self::admin_audit_log_action::AdminAuditLogAction;
+pub mod admin_audit_log_entry;
+pub use self::admin_audit_log_entry::AdminAuditLogEntry;
+pub mod admin_audit_log_page;
+pub use self::admin_audit_log_page::AdminAuditLogPage;
+pub mod admin_audit_log_query;
+pub use self::admin_audit_log_query::AdminAuditLogQuery;
 pub mod admin_bot_config;
 pub use self::admin_bot_config::AdminBotConfig;
 pub mod admin_bot_content_moderation_config;
@@ -128,6 +144,8 @@ pub mod attribute_hash;
 pub use self::attribute_hash::AttributeHash;
 pub mod attribute_mode;
 pub use self::attribute_mode::AttributeMode;
+pub mod attribute_numeric_range;
+pub use self::attribute_numeric_range::AttributeNumericRange;
 pub mod attribute_order_mode;
 pub use self::attribute_order_mode::AttributeOrderMode;
 pub mod attribute_value;
@@ -154,6 +172,8 @@ pub mod backup_transfer_initial_message;
 pub use self::backup_transfer_initial_message::BackupTransferInitialMessage;
 pub mod backup_transfer_target_data;
 pub use self::backup_transfer_target_data::BackupTransferTargetData;
+pub mod ban_evasion_evidence;
+pub use self::ban_evasion_evidence::BanEvasionEvidence;
 pub mod banner_platform;
 pub use self::banner_platform::BannerPlatform;
 pub mod banner_visibility;
@@ -566,6 +586,8 @@ pub mod post_age_verification;
 pub use self::post_age_verification::PostAgeVerification;
 pub mod post_age_verification_result;
 pub use self::post_age_verification_result::PostAgeVerificationResult;
+pub mod post_import_account_data_result;
+pub use self::post_import_account_data_result::PostImportAccountDataResult;
 pub mod post_media_content_face_detected_value;
 pub use self::post_media_content_face_detected_value::PostMediaContentFaceDetectedValue;
 pub mod post_media_content_face_verified_value;
@@ -696,6 +718,8 @@ pub mod put_sign_in_with_apple;
 pub use self::put_sign_in_with_apple::PutSignInWithApple;
 pub mod put_sign_in_with_google;
 pub use self::put_sign_in_with_google::PutSignInWithGoogle;
+pub mod put_sign_in_with_oidc;
+pub use self::put_sign_in_with_oidc::PutSignInWithOidc;
 pub mod put_sign_in_with_result;
 pub use self::put_sign_in_with_result::PutSignInWithResult;
 pub mod received_like_id;
@@ -788,6 +812,8 @@ pub mod set_email_login_enabled;
 pub use self::set_email_login_enabled::SetEmailLoginEnabled;
 pub mod set_initial_email;
 pub use self::set_initial_email::SetInitialEmail;
+pub mod set_permissions_result;
+pub use self::set_permissions_result::SetPermissionsResult;
 pub mod set_profile_content;
 pub use self::set_profile_content::SetProfileContent;
 pub mod set_profile_name;
@@ -798,10 +824,16 @@ pub mod sign_in_with_google_info;
 pub use self::sign_in_with_google_info::SignInWithGoogleInfo;
 pub mod sign_in_with_login_info;
 pub use self::sign_in_with_login_info::SignInWithLoginInfo;
+pub mod sign_in_with_oidc_info;
+pub use self::sign_in_with_oidc_info::SignInWithOidcInfo;
 pub mod sign_in_with_state;
 pub use self::sign_in_with_state::SignInWithState;
 pub mod software_info;
 pub use self::software_info::SoftwareInfo;
+pub mod software_rollback;
+pub use self::software_rollback::SoftwareRollback;
+pub mod software_update_error;
+pub use self::software_update_error::SoftwareUpdateError;
 pub mod software_update_state;
 pub use self::software_update_state::SoftwareUpdateState;
 pub mod software_update_status;
@@ -818,6 +850,16 @@ pub mod text_info_banner;
 pub use self::text_info_banner::TextInfoBanner;
 pub mod time_granularity;
 pub use self::time_granularity::TimeGranularity;
+pub mod totp_code;
+pub use self::totp_code::TotpCode;
+pub mod totp_code_result;
+pub use self::totp_code_result::TotpCodeResult;
+pub mod totp_enrollment;
+pub use self::totp_enrollment::TotpEnrollment;
+pub mod totp_enrollment_result;
+pub use self::totp_enrollment_result::TotpEnrollmentResult;
+pub mod totp_state;
+pub use self::totp_state::TotpState;
 pub mod translation;
 pub use self::translation::Translation;
 pub mod typing_indicator_config;
@@ -854,6 +896,8 @@ pub mod update_profile_text_report;
 pub use self::update_profile_text_report::UpdateProfileTextReport;
 pub mod update_report_result;
 pub use self::update_report_result::UpdateReportResult;
+pub mod upload_message_attachment_result;
+pub use self::upload_message_attachment_result::UploadMessageAttachmentResult;
 pub mod vapid_public_key;
 pub use self::vapid_public_key::VapidPublicKey;
 pub mod verification_method;
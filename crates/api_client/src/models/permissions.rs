@@ -50,6 +50,8 @@ pub struct Permissions {
     pub admin_export_data: Option<bool>,
     #[serde(rename = "admin_find_account_by_email_address", skip_serializing_if = "Option::is_none")]
     pub admin_find_account_by_email_address: Option<bool>,
+    #[serde(rename = "admin_import_account_data", skip_serializing_if = "Option::is_none")]
+    pub admin_import_account_data: Option<bool>,
     #[serde(rename = "admin_moderate_media_content", skip_serializing_if = "Option::is_none")]
     pub admin_moderate_media_content: Option<bool>,
     #[serde(rename = "admin_moderate_profile_names", skip_serializing_if = "Option::is_none")]
@@ -103,6 +105,8 @@ pub struct Permissions {
     pub admin_subscribe_admin_notifications: Option<bool>,
     #[serde(rename = "admin_verify_account", skip_serializing_if = "Option::is_none")]
     pub admin_verify_account: Option<bool>,
+    #[serde(rename = "admin_view_admin_audit_log", skip_serializing_if = "Option::is_none")]
+    pub admin_view_admin_audit_log: Option<bool>,
     #[serde(rename = "admin_view_account_api_usage", skip_serializing_if = "Option::is_none")]
     pub admin_view_account_api_usage: Option<bool>,
     #[serde(rename = "admin_view_account_ip_address_usage", skip_serializing_if = "Option::is_none")]
@@ -143,6 +147,7 @@ impl Permissions {
             admin_edit_security_content_verified_value: None,
             admin_export_data: None,
             admin_find_account_by_email_address: None,
+            admin_import_account_data: None,
             admin_moderate_media_content: None,
             admin_moderate_profile_names: None,
             admin_moderate_profile_texts: None,
@@ -169,6 +174,7 @@ impl Permissions {
             admin_server_view_server_config: None,
             admin_subscribe_admin_notifications: None,
             admin_verify_account: None,
+            admin_view_admin_audit_log: None,
             admin_view_account_api_usage: None,
             admin_view_account_ip_address_usage: None,
             admin_view_account_state: None,
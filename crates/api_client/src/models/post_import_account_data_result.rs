@@ -0,0 +1,43 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PostImportAccountDataResult {
+    /// Account ID of the imported account.
+    #[serde(rename = "aid", skip_serializing_if = "Option::is_none")]
+    pub aid: Option<models::AccountId>,
+    #[serde(rename = "error", skip_serializing_if = "Option::is_none")]
+    pub error: Option<bool>,
+    /// Some other account uses the email address from the archive.
+    #[serde(rename = "error_email_already_used", skip_serializing_if = "Option::is_none")]
+    pub error_email_already_used: Option<bool>,
+    /// The archive is not a user data export archive or it contains invalid data.
+    #[serde(rename = "error_invalid_archive", skip_serializing_if = "Option::is_none")]
+    pub error_invalid_archive: Option<bool>,
+    /// Number of images which were not imported because image processing failed.
+    #[serde(rename = "failed_image_count", skip_serializing_if = "Option::is_none")]
+    pub failed_image_count: Option<i64>,
+}
+
+impl PostImportAccountDataResult {
+    pub fn new() -> PostImportAccountDataResult {
+        PostImportAccountDataResult {
+            aid: None,
+            error: None,
+            error_email_already_used: None,
+            error_invalid_archive: None,
+            failed_image_count: None,
+        }
+    }
+}
+
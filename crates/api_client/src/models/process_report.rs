@@ -13,7 +13,7 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ProcessReport {
-    /// True marks the report as Accepted, false marks it as Rejected.
+    /// True marks the report as Accepted, false marks it as Rejected.  Accepting a ban evasion report bans the report target until the report creator's ban ends. That requires the `admin_ban_account` permission.
     #[serde(rename = "accepted")]
     pub accepted: bool,
     #[serde(rename = "content")]
@@ -18,6 +18,12 @@ pub struct ProfileAttributeFilterValue {
     /// Attribute ID
     #[serde(rename = "id")]
     pub id: i32,
+    /// Max value (inclusive) for numeric attribute.
+    #[serde(rename = "max", skip_serializing_if = "Option::is_none")]
+    pub max: Option<i32>,
+    /// Min value (inclusive) for numeric attribute.
+    #[serde(rename = "min", skip_serializing_if = "Option::is_none")]
+    pub min: Option<i32>,
     /// Same as [Self::wanted] but for unwanted values.  The unwanted values are checked always with AND operator.
     #[serde(rename = "unwanted")]
     pub unwanted: Vec<i32>,
@@ -33,6 +39,8 @@ impl ProfileAttributeFilterValue {
         ProfileAttributeFilterValue {
             accept_missing_attribute,
             id,
+            max: None,
+            min: None,
             unwanted,
             use_logical_operator_and,
             wanted,
@@ -22,6 +22,12 @@ pub struct ProfileAttributeFilterValueUpdate {
     /// Attribute ID
     #[serde(rename = "id")]
     pub id: i32,
+    /// Max value (inclusive) for numeric attribute.
+    #[serde(rename = "max", skip_serializing_if = "Option::is_none")]
+    pub max: Option<i32>,
+    /// Min value (inclusive) for numeric attribute.  Numeric attributes support only min and max filters.
+    #[serde(rename = "min", skip_serializing_if = "Option::is_none")]
+    pub min: Option<i32>,
     /// Same as [Self::wanted] but for unwanted values.  The unwanted values are checked always with AND operator.
     #[serde(rename = "unwanted", skip_serializing_if = "Option::is_none")]
     pub unwanted: Option<Vec<i32>>,
@@ -39,6 +45,8 @@ impl ProfileAttributeFilterValueUpdate {
             accept_missing_attribute: None,
             enabled: None,
             id,
+            max: None,
+            min: None,
             unwanted: None,
             use_logical_operator_and: None,
             wanted: None,
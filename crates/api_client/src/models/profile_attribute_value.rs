@@ -16,7 +16,7 @@ pub struct ProfileAttributeValue {
     /// Attribute ID
     #[serde(rename = "id")]
     pub id: i32,
-    /// For bitflag filters the list only has one u16 value.  For one level attributes the values are u16 attribute value IDs.  For two level attributes the values are u32 values with most significant u16 containing attribute value ID and least significant u16 containing group value ID.  Values are in ascending order.
+    /// For bitflag filters the list only has one u16 value.  For one level attributes the values are u16 attribute value IDs.  For two level attributes the values are u32 values with most significant u16 containing attribute value ID and least significant u16 containing group value ID.  For numeric attributes the list only has one u32 value.  Values are in ascending order.
     #[serde(rename = "v")]
     pub v: Vec<i32>,
 }
@@ -0,0 +1,27 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PutSignInWithOidc {
+    #[serde(rename = "oidc", skip_serializing_if = "Option::is_none")]
+    pub oidc: Option<models::SignInWithOidcInfo>,
+}
+
+impl PutSignInWithOidc {
+    pub fn new() -> PutSignInWithOidc {
+        PutSignInWithOidc {
+            oidc: None,
+        }
+    }
+}
+
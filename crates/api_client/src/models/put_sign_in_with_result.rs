@@ -11,7 +11,7 @@
 use crate::models;
 use serde::{Deserialize, Serialize};
 
-/// PutSignInWithResult : Result of a sign in with Apple or Google association change.
+/// PutSignInWithResult : Result of a sign in with Apple, Google or OpenID Connect association change.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PutSignInWithResult {
     #[serde(rename = "error", skip_serializing_if = "Option::is_none")]
@@ -21,7 +21,7 @@ pub struct PutSignInWithResult {
 }
 
 impl PutSignInWithResult {
-    /// Result of a sign in with Apple or Google association change.
+    /// Result of a sign in with Apple, Google or OpenID Connect association change.
     pub fn new() -> PutSignInWithResult {
         PutSignInWithResult {
             error: None,
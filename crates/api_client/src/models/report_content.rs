@@ -13,6 +13,8 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ReportContent {
+    #[serde(rename = "ban_evasion", skip_serializing_if = "Option::is_none")]
+    pub ban_evasion: Option<models::BanEvasionEvidence>,
     #[serde(rename = "chat_message", skip_serializing_if = "Option::is_none")]
     pub chat_message: Option<models::ChatMessageReport>,
     #[serde(rename = "profile_content", skip_serializing_if = "Option::is_none")]
@@ -28,6 +30,7 @@ pub struct ReportContent {
 impl ReportContent {
     pub fn new() -> ReportContent {
         ReportContent {
+            ban_evasion: None,
             chat_message: None,
             profile_content: None,
             profile_name: None,
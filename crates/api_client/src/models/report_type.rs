@@ -11,7 +11,7 @@
 use crate::models;
 use serde::{Deserialize, Serialize};
 
-/// ReportType : Values:  * ProfileName = 0 * ProfileText = 1 * ProfileContent = 2 * ChatMessage = 3 * CustomReport = values from 64 to 127
+/// ReportType : Values:  * ProfileName = 0 * ProfileText = 1 * ProfileContent = 2 * ChatMessage = 3 * BanEvasion = 4 * CustomReport = values from 64 to 127
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ReportType {
     /// This is i8 so that max value is 127. That makes SQLite to store the value using single byte.
@@ -20,7 +20,7 @@ pub struct ReportType {
 }
 
 impl ReportType {
-    /// Values:  * ProfileName = 0 * ProfileText = 1 * ProfileContent = 2 * ChatMessage = 3 * CustomReport = values from 64 to 127
+    /// Values:  * ProfileName = 0 * ProfileText = 1 * ProfileContent = 2 * ChatMessage = 3 * BanEvasion = 4 * CustomReport = values from 64 to 127
     pub fn new(t: i32) -> ReportType {
         ReportType {
             t,
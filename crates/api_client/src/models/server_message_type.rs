@@ -11,8 +11,8 @@
 use crate::models;
 use serde::{Deserialize, Serialize};
 
-/// ServerMessageType : First byte of websocket binary protocol messages sent from server to client.  # Message types and payloads  - `PendingAppNotificationsChanged` (0): payload is empty. - `ClientConfigChanged` (1): payload is empty. - `NewsCountChanged` (2): payload is empty. - `ScheduledMaintenanceStatus` (3): payload format:   - admin bot offline (u8, 0 or 1)   - maintenance start as optional minimal i64   - if start exists, maintenance end as optional minimal i64 - `AdminBotNotification` (4): payload is unsigned integer with   little-endian byte order for `AdminBotNotificationTypes` bitflags.   (1 byte = u8, 2 bytes = u16 etc.) - `PushNotificationInfoChanged` (5): payload is empty. - `WebSocketConnectionAttemptsRemaining` (6): payload format:   - remaining daily websocket connection attempts as u8 - `AppUpdateAvailable` (7): payload is currently empty.   - Client must accept both empty and non-empty payload to support     forward-compatible protocol changes. - `AccountStateChanged` (30): payload is empty. - `AccountVerificationQueuePositionChanged` (31): payload format:   - optional queue position as 1 byte (empty payload means `None`) - `EmailAddressStateChanged` (32): payload is empty. - `ProfileChanged` (60): payload is empty. - `ResponseResetProfilePaging` (61): payload format:   - request id byte (u8)   - status byte:     - 0: success     - 1: rate limited     - 2: internal server error   - if status is 0:     - profile iterator session id as minimal i64 - `ResponseNextProfilePage` (62): payload format:   - request id byte (u8)   - status byte:     - 0: success     - 1: invalid iterator session id     - 2: rate limited     - 3: internal server error   - if status is 0:     - repeated profile entries until payload ends:       - account id as 16-byte big-endian UUID       - profile version as 16-byte big-endian UUID       - profile content version as 16-byte big-endian UUID       - null last seen time (0 byte) or last seen time as minimal i64 - `ResponseAutomaticProfileSearchResetProfilePaging` (63): payload format:   - request id byte (u8)   - status byte:     - 0: success     - 1: rate limited     - 2: internal server error   - if status is 0:     - automatic profile search iterator session id as minimal i64 - `ResponseAutomaticProfileSearchNextProfilePage` (64): payload format:   - request id byte (u8)   - status byte:     - 0: success     - 1: invalid iterator session id     - 2: rate limited     - 3: internal server error   - if status is 0:     - repeated profile entries until payload ends:       - account id as 16-byte big-endian UUID       - profile version as 16-byte big-endian UUID       - profile content version as 16-byte big-endian UUID       - null last seen time (0 byte) or last seen time as minimal i64 - `ContentProcessingStateChanged` (90): payload format:   - client-provided processing id byte (u8)   - content processing state byte:     - 0: InQueue     - 1: Processing     - 2: Completed     - 3: Failed     - 4: NsfwDetected   - state specific data:     - InQueue: queue number as minimal i64     - Completed:       - content ID as 16 byte big-endian UUID (16 bytes)       - face detection bool (1 byte, 0 or 1) - `MediaContentChanged` (91): payload is empty. - `NewMessageReceived` (120): payload is empty. - `PendingChatNotificationsChanged` (121): payload is empty. - `ReceivedLikesChanged` (122): payload is empty. - `DailyLikesLeftChanged` (123): payload is empty. - `TypingStart` (124): payload is exactly 16 bytes account UUID in   big-endian byte order. - `TypingStop` (125): payload is exactly 16 bytes account UUID in   big-endian byte order. - `OnlineStatusUpdated` (126): payload is 16 bytes account UUID,   followed by null last seen time (0 byte) or last seen time as minimal i64. - `MessageDeliveryInfoChanged` (127): payload is empty. - `LatestSeenMessageChanged` (128): payload is empty.  # Data formats  Data types used in payload definitions: - minimal i64:   - i64 byte count (u8, values: 1, 2, 3, 4, 5, 6, 7, 8)   - i64 bytes (little-endian byte order) - optional values in payloads are omitted when they are not present
-/// First byte of websocket binary protocol messages sent from server to client.  # Message types and payloads  - `PendingAppNotificationsChanged` (0): payload is empty. - `ClientConfigChanged` (1): payload is empty. - `NewsCountChanged` (2): payload is empty. - `ScheduledMaintenanceStatus` (3): payload format:   - admin bot offline (u8, 0 or 1)   - maintenance start as optional minimal i64   - if start exists, maintenance end as optional minimal i64 - `AdminBotNotification` (4): payload is unsigned integer with   little-endian byte order for `AdminBotNotificationTypes` bitflags.   (1 byte = u8, 2 bytes = u16 etc.) - `PushNotificationInfoChanged` (5): payload is empty. - `WebSocketConnectionAttemptsRemaining` (6): payload format:   - remaining daily websocket connection attempts as u8 - `AppUpdateAvailable` (7): payload is currently empty.   - Client must accept both empty and non-empty payload to support     forward-compatible protocol changes. - `AccountStateChanged` (30): payload is empty. - `AccountVerificationQueuePositionChanged` (31): payload format:   - optional queue position as 1 byte (empty payload means `None`) - `EmailAddressStateChanged` (32): payload is empty. - `ProfileChanged` (60): payload is empty. - `ResponseResetProfilePaging` (61): payload format:   - request id byte (u8)   - status byte:     - 0: success     - 1: rate limited     - 2: internal server error   - if status is 0:     - profile iterator session id as minimal i64 - `ResponseNextProfilePage` (62): payload format:   - request id byte (u8)   - status byte:     - 0: success     - 1: invalid iterator session id     - 2: rate limited     - 3: internal server error   - if status is 0:     - repeated profile entries until payload ends:       - account id as 16-byte big-endian UUID       - profile version as 16-byte big-endian UUID       - profile content version as 16-byte big-endian UUID       - null last seen time (0 byte) or last seen time as minimal i64 - `ResponseAutomaticProfileSearchResetProfilePaging` (63): payload format:   - request id byte (u8)   - status byte:     - 0: success     - 1: rate limited     - 2: internal server error   - if status is 0:     - automatic profile search iterator session id as minimal i64 - `ResponseAutomaticProfileSearchNextProfilePage` (64): payload format:   - request id byte (u8)   - status byte:     - 0: success     - 1: invalid iterator session id     - 2: rate limited     - 3: internal server error   - if status is 0:     - repeated profile entries until payload ends:       - account id as 16-byte big-endian UUID       - profile version as 16-byte big-endian UUID       - profile content version as 16-byte big-endian UUID       - null last seen time (0 byte) or last seen time as minimal i64 - `ContentProcessingStateChanged` (90): payload format:   - client-provided processing id byte (u8)   - content processing state byte:     - 0: InQueue     - 1: Processing     - 2: Completed     - 3: Failed     - 4: NsfwDetected   - state specific data:     - InQueue: queue number as minimal i64     - Completed:       - content ID as 16 byte big-endian UUID (16 bytes)       - face detection bool (1 byte, 0 or 1) - `MediaContentChanged` (91): payload is empty. - `NewMessageReceived` (120): payload is empty. - `PendingChatNotificationsChanged` (121): payload is empty. - `ReceivedLikesChanged` (122): payload is empty. - `DailyLikesLeftChanged` (123): payload is empty. - `TypingStart` (124): payload is exactly 16 bytes account UUID in   big-endian byte order. - `TypingStop` (125): payload is exactly 16 bytes account UUID in   big-endian byte order. - `OnlineStatusUpdated` (126): payload is 16 bytes account UUID,   followed by null last seen time (0 byte) or last seen time as minimal i64. - `MessageDeliveryInfoChanged` (127): payload is empty. - `LatestSeenMessageChanged` (128): payload is empty.  # Data formats  Data types used in payload definitions: - minimal i64:   - i64 byte count (u8, values: 1, 2, 3, 4, 5, 6, 7, 8)   - i64 bytes (little-endian byte order) - optional values in payloads are omitted when they are not present
+/// ServerMessageType : First byte of websocket binary protocol messages sent from server to client.  # Message types and payloads  - `PendingAppNotificationsChanged` (0): payload is empty. - `ClientConfigChanged` (1): payload is empty. - `NewsCountChanged` (2): payload is empty. - `ScheduledMaintenanceStatus` (3): payload format:   - admin bot offline (u8, 0 or 1)   - maintenance start as optional minimal i64   - if start exists, maintenance end as optional minimal i64 - `AdminBotNotification` (4): payload is unsigned integer with   little-endian byte order for `AdminBotNotificationTypes` bitflags.   (1 byte = u8, 2 bytes = u16 etc.) - `PushNotificationInfoChanged` (5): payload is empty. - `WebSocketConnectionAttemptsRemaining` (6): payload format:   - remaining daily websocket connection attempts as u8 - `AppUpdateAvailable` (7): payload is currently empty.   - Client must accept both empty and non-empty payload to support     forward-compatible protocol changes. - `AccountStateChanged` (30): payload is empty. - `AccountVerificationQueuePositionChanged` (31): payload format:   - optional queue position as 1 byte (empty payload means `None`) - `EmailAddressStateChanged` (32): payload is empty. - `ProfileChanged` (60): payload is empty. - `ResponseResetProfilePaging` (61): payload format:   - request id byte (u8)   - status byte:     - 0: success     - 1: rate limited     - 2: internal server error   - if status is 0:     - profile iterator session id as minimal i64 - `ResponseNextProfilePage` (62): payload format:   - request id byte (u8)   - status byte:     - 0: success     - 1: invalid iterator session id     - 2: rate limited     - 3: internal server error   - if status is 0:     - repeated profile entries until payload ends:       - account id as 16-byte big-endian UUID       - profile version as 16-byte big-endian UUID       - profile content version as 16-byte big-endian UUID       - null last seen time (0 byte) or last seen time as minimal i64 - `ResponseAutomaticProfileSearchResetProfilePaging` (63): payload format:   - request id byte (u8)   - status byte:     - 0: success     - 1: rate limited     - 2: internal server error   - if status is 0:     - automatic profile search iterator session id as minimal i64 - `ResponseAutomaticProfileSearchNextProfilePage` (64): payload format:   - request id byte (u8)   - status byte:     - 0: success     - 1: invalid iterator session id     - 2: rate limited     - 3: internal server error   - if status is 0:     - repeated profile entries until payload ends:       - account id as 16-byte big-endian UUID       - profile version as 16-byte big-endian UUID       - profile content version as 16-byte big-endian UUID       - null last seen time (0 byte) or last seen time as minimal i64 - `ContentProcessingStateChanged` (90): payload format:   - client-provided processing id byte (u8)   - content processing state byte:     - 0: InQueue     - 1: Processing     - 2: Completed     - 3: Failed     - 4: NsfwDetected   - state specific data:     - InQueue: queue number as minimal i64     - Completed:       - content ID as 16 byte big-endian UUID (16 bytes)       - face detection bool (1 byte, 0 or 1) - `MediaContentChanged` (91): payload is empty. - `NewMessageReceived` (120): payload is empty. - `PendingChatNotificationsChanged` (121): payload is empty. - `ReceivedLikesChanged` (122): payload is empty. - `DailyLikesLeftChanged` (123): payload is empty. - `TypingStart` (124): payload is exactly 16 bytes account UUID in   big-endian byte order. - `TypingStop` (125): payload is exactly 16 bytes account UUID in   big-endian byte order. - `OnlineStatusUpdated` (126): payload is 16 bytes account UUID,   followed by null last seen time (0 byte) or last seen time as minimal i64. - `MessageDeliveryInfoChanged` (127): payload is empty. - `LatestSeenMessageChanged` (128): payload is empty. - `ResponseSendMessage` (129): payload format:   - request id byte (u8)   - status byte:     - 0: success     - 1: rate limited     - 2: internal server error     - 3: too many recipient acknowledgements missing     - 4: too many sender acknowledgements missing     - 5: sender public key outdated     - 6: recipient public key outdated     - 7: recipient blocked sender or recipient not found     - 8: too many pending delivery infos exists   - null remaining daily messages (0 byte) or remaining daily messages     as minimal i64. The value is sent only if there is 50 or less     messages left.   - if status is 0:     - null remaining conversation messages (0 byte) or remaining       conversation messages as minimal i64. The value is sent only if       there is 5 or less messages left.     - backend signed PGP message containing `SignedMessageData` until       payload ends - `PendingMessage` (130): payload is backend signed PGP message   containing `SignedMessageData`. Message is sent to message recipient   when new message is received while WebSocket connection is open.   `NewMessageReceived` is also sent at the same time for clients which   fetch pending messages using HTTP API. When WebSocket connection is   created and there is pending messages, only `NewMessageReceived` is   sent. - `ResponseAcknowledgeMessages` (131): payload format:   - request id byte (u8)   - status byte:     - 0: success     - 1: internal server error  # Data formats  Data types used in payload definitions: - minimal i64:   - i64 byte count (u8, values: 1, 2, 3, 4, 5, 6, 7, 8)   - i64 bytes (little-endian byte order) - optional values in payloads are omitted when they are not present
+/// First byte of websocket binary protocol messages sent from server to client.  # Message types and payloads  - `PendingAppNotificationsChanged` (0): payload is empty. - `ClientConfigChanged` (1): payload is empty. - `NewsCountChanged` (2): payload is empty. - `ScheduledMaintenanceStatus` (3): payload format:   - admin bot offline (u8, 0 or 1)   - maintenance start as optional minimal i64   - if start exists, maintenance end as optional minimal i64 - `AdminBotNotification` (4): payload is unsigned integer with   little-endian byte order for `AdminBotNotificationTypes` bitflags.   (1 byte = u8, 2 bytes = u16 etc.) - `PushNotificationInfoChanged` (5): payload is empty. - `WebSocketConnectionAttemptsRemaining` (6): payload format:   - remaining daily websocket connection attempts as u8 - `AppUpdateAvailable` (7): payload is currently empty.   - Client must accept both empty and non-empty payload to support     forward-compatible protocol changes. - `AccountStateChanged` (30): payload is empty. - `AccountVerificationQueuePositionChanged` (31): payload format:   - optional queue position as 1 byte (empty payload means `None`) - `EmailAddressStateChanged` (32): payload is empty. - `ProfileChanged` (60): payload is empty. - `ResponseResetProfilePaging` (61): payload format:   - request id byte (u8)   - status byte:     - 0: success     - 1: rate limited     - 2: internal server error   - if status is 0:     - profile iterator session id as minimal i64 - `ResponseNextProfilePage` (62): payload format:   - request id byte (u8)   - status byte:     - 0: success     - 1: invalid iterator session id     - 2: rate limited     - 3: internal server error   - if status is 0:     - repeated profile entries until payload ends:       - account id as 16-byte big-endian UUID       - profile version as 16-byte big-endian UUID       - profile content version as 16-byte big-endian UUID       - null last seen time (0 byte) or last seen time as minimal i64 - `ResponseAutomaticProfileSearchResetProfilePaging` (63): payload format:   - request id byte (u8)   - status byte:     - 0: success     - 1: rate limited     - 2: internal server error   - if status is 0:     - automatic profile search iterator session id as minimal i64 - `ResponseAutomaticProfileSearchNextProfilePage` (64): payload format:   - request id byte (u8)   - status byte:     - 0: success     - 1: invalid iterator session id     - 2: rate limited     - 3: internal server error   - if status is 0:     - repeated profile entries until payload ends:       - account id as 16-byte big-endian UUID       - profile version as 16-byte big-endian UUID       - profile content version as 16-byte big-endian UUID       - null last seen time (0 byte) or last seen time as minimal i64 - `ContentProcessingStateChanged` (90): payload format:   - client-provided processing id byte (u8)   - content processing state byte:     - 0: InQueue     - 1: Processing     - 2: Completed     - 3: Failed     - 4: NsfwDetected   - state specific data:     - InQueue: queue number as minimal i64     - Completed:       - content ID as 16 byte big-endian UUID (16 bytes)       - face detection bool (1 byte, 0 or 1) - `MediaContentChanged` (91): payload is empty. - `NewMessageReceived` (120): payload is empty. - `PendingChatNotificationsChanged` (121): payload is empty. - `ReceivedLikesChanged` (122): payload is empty. - `DailyLikesLeftChanged` (123): payload is empty. - `TypingStart` (124): payload is exactly 16 bytes account UUID in   big-endian byte order. - `TypingStop` (125): payload is exactly 16 bytes account UUID in   big-endian byte order. - `OnlineStatusUpdated` (126): payload is 16 bytes account UUID,   followed by null last seen time (0 byte) or last seen time as minimal i64. - `MessageDeliveryInfoChanged` (127): payload is empty. - `LatestSeenMessageChanged` (128): payload is empty. - `ResponseSendMessage` (129): payload format:   - request id byte (u8)   - status byte:     - 0: success     - 1: rate limited     - 2: internal server error     - 3: too many recipient acknowledgements missing     - 4: too many sender acknowledgements missing     - 5: sender public key outdated     - 6: recipient public key outdated     - 7: recipient blocked sender or recipient not found     - 8: too many pending delivery infos exists   - null remaining daily messages (0 byte) or remaining daily messages     as minimal i64. The value is sent only if there is 50 or less     messages left.   - if status is 0:     - null remaining conversation messages (0 byte) or remaining       conversation messages as minimal i64. The value is sent only if       there is 5 or less messages left.     - backend signed PGP message containing `SignedMessageData` until       payload ends - `PendingMessage` (130): payload is backend signed PGP message   containing `SignedMessageData`. Message is sent to message recipient   when new message is received while WebSocket connection is open.   `NewMessageReceived` is also sent at the same time for clients which   fetch pending messages using HTTP API. When WebSocket connection is   created and there is pending messages, only `NewMessageReceived` is   sent. - `ResponseAcknowledgeMessages` (131): payload format:   - request id byte (u8)   - status byte:     - 0: success     - 1: internal server error  # Data formats  Data types used in payload definitions: - minimal i64:   - i64 byte count (u8, values: 1, 2, 3, 4, 5, 6, 7, 8)   - i64 bytes (little-endian byte order) - optional values in payloads are omitted when they are not present
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum ServerMessageType {
     #[serde(rename = "PendingAppNotificationsChanged")]
@@ -69,6 +69,12 @@ pub enum ServerMessageType {
     MessageDeliveryInfoChanged,
     #[serde(rename = "LatestSeenMessageChanged")]
     LatestSeenMessageChanged,
+    #[serde(rename = "ResponseSendMessage")]
+    ResponseSendMessage,
+    #[serde(rename = "PendingMessage")]
+    PendingMessage,
+    #[serde(rename = "ResponseAcknowledgeMessages")]
+    ResponseAcknowledgeMessages,
 
 }
 
@@ -102,6 +108,9 @@ impl std::fmt::Display for ServerMessageType {
             Self::OnlineStatusUpdated => write!(f, "OnlineStatusUpdated"),
             Self::MessageDeliveryInfoChanged => write!(f, "MessageDeliveryInfoChanged"),
             Self::LatestSeenMessageChanged => write!(f, "LatestSeenMessageChanged"),
+            Self::ResponseSendMessage => write!(f, "ResponseSendMessage"),
+            Self::PendingMessage => write!(f, "PendingMessage"),
+            Self::ResponseAcknowledgeMessages => write!(f, "ResponseAcknowledgeMessages"),
         }
     }
 }
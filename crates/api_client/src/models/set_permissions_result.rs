@@ -0,0 +1,28 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SetPermissionsResult {
+    /// Server requires TOTP for admin permissions and the account does not have TOTP enabled. Permissions were not changed.
+    #[serde(rename = "error_totp_not_enabled", skip_serializing_if = "Option::is_none")]
+    pub error_totp_not_enabled: Option<bool>,
+}
+
+impl SetPermissionsResult {
+    pub fn new() -> SetPermissionsResult {
+        SetPermissionsResult {
+            error_totp_not_enabled: None,
+        }
+    }
+}
+
@@ -19,6 +19,8 @@ pub struct SignInWithLoginInfo {
     pub client_info: models::ClientInfo,
     #[serde(rename = "google", skip_serializing_if = "Option::is_none")]
     pub google: Option<models::SignInWithGoogleInfo>,
+    #[serde(rename = "oidc", skip_serializing_if = "Option::is_none")]
+    pub oidc: Option<models::SignInWithOidcInfo>,
 }
 
 impl SignInWithLoginInfo {
@@ -27,6 +29,7 @@ impl SignInWithLoginInfo {
             apple: None,
             client_info,
             google: None,
+            oidc: None,
         }
     }
 }
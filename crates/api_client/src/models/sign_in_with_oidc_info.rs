@@ -0,0 +1,36 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SignInWithOidcInfo {
+    /// Base64 URL (with possible padding) encoded nonce.  The token contains Base64 URL (with possible padding) encoded SHA-256 of the nonce.
+    #[serde(rename = "nonce")]
+    pub nonce: String,
+    /// OpenID Connect provider name from server config
+    #[serde(rename = "provider")]
+    pub provider: String,
+    /// ID token
+    #[serde(rename = "token")]
+    pub token: String,
+}
+
+impl SignInWithOidcInfo {
+    pub fn new(nonce: String, provider: String, token: String) -> SignInWithOidcInfo {
+        SignInWithOidcInfo {
+            nonce,
+            provider,
+            token,
+        }
+    }
+}
+
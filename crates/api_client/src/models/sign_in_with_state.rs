@@ -17,6 +17,9 @@ pub struct SignInWithState {
     pub apple: bool,
     #[serde(rename = "google")]
     pub google: bool,
+    /// OpenID Connect provider name if OpenID Connect sign in is associated with the account.
+    #[serde(rename = "oidc", skip_serializing_if = "Option::is_none")]
+    pub oidc: Option<String>,
 }
 
 impl SignInWithState {
@@ -24,6 +27,7 @@ impl SignInWithState {
         SignInWithState {
             apple,
             google,
+            oidc: None,
         }
     }
 }
@@ -0,0 +1,35 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SoftwareRollback {
+    /// Software which failed health checks
+    #[serde(rename = "failed")]
+    pub failed: Box<models::SoftwareInfo>,
+    /// Software which was restored
+    #[serde(rename = "restored", skip_serializing_if = "Option::is_none")]
+    pub restored: Option<models::SoftwareInfo>,
+    #[serde(rename = "time")]
+    pub time: models::UnixTime,
+}
+
+impl SoftwareRollback {
+    pub fn new(failed: models::SoftwareInfo, time: models::UnixTime) -> SoftwareRollback {
+        SoftwareRollback {
+            failed: Box::new(failed),
+            restored: None,
+            time,
+        }
+    }
+}
+
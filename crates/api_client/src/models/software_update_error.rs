@@ -0,0 +1,44 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum SoftwareUpdateError {
+    #[serde(rename = "DownloadFailed")]
+    DownloadFailed,
+    #[serde(rename = "InstallFailed")]
+    InstallFailed,
+    #[serde(rename = "SignatureVerificationFailed")]
+    SignatureVerificationFailed,
+    #[serde(rename = "HealthCheckFailedAndRolledBack")]
+    HealthCheckFailedAndRolledBack,
+
+}
+
+impl std::fmt::Display for SoftwareUpdateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::DownloadFailed => write!(f, "DownloadFailed"),
+            Self::InstallFailed => write!(f, "InstallFailed"),
+            Self::SignatureVerificationFailed => write!(f, "SignatureVerificationFailed"),
+            Self::HealthCheckFailedAndRolledBack => write!(f, "HealthCheckFailedAndRolledBack"),
+        }
+    }
+}
+
+impl Default for SoftwareUpdateError {
+    fn default() -> SoftwareUpdateError {
+        Self::DownloadFailed
+    }
+}
+
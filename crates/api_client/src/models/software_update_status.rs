@@ -17,6 +17,12 @@ pub struct SoftwareUpdateStatus {
     pub downloaded: Option<models::SoftwareInfo>,
     #[serde(rename = "installed", skip_serializing_if = "Option::is_none")]
     pub installed: Option<models::SoftwareInfo>,
+    /// Error from the latest download or install task.
+    #[serde(rename = "last_error", skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<models::SoftwareUpdateError>,
+    /// Latest automatic rollback since manager start.
+    #[serde(rename = "last_rollback", skip_serializing_if = "Option::is_none")]
+    pub last_rollback: Option<models::SoftwareRollback>,
     #[serde(rename = "state")]
     pub state: models::SoftwareUpdateState,
 }
@@ -26,6 +32,8 @@ impl SoftwareUpdateStatus {
         SoftwareUpdateStatus {
             downloaded: None,
             installed: None,
+            last_error: None,
+            last_rollback: None,
             state,
         }
     }
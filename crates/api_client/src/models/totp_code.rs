@@ -0,0 +1,29 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// TotpCode : TOTP code or recovery code
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TotpCode {
+    #[serde(rename = "code")]
+    pub code: String,
+}
+
+impl TotpCode {
+    /// TOTP code or recovery code
+    pub fn new(code: String) -> TotpCode {
+        TotpCode {
+            code,
+        }
+    }
+}
+
@@ -0,0 +1,31 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TotpCodeResult {
+    #[serde(rename = "error_invalid_code", skip_serializing_if = "Option::is_none")]
+    pub error_invalid_code: Option<bool>,
+    /// New recovery codes. Server does not store the codes, so the client should show the codes to the user.
+    #[serde(rename = "recovery_codes", skip_serializing_if = "Option::is_none")]
+    pub recovery_codes: Option<Vec<String>>,
+}
+
+impl TotpCodeResult {
+    pub fn new() -> TotpCodeResult {
+        TotpCodeResult {
+            error_invalid_code: None,
+            recovery_codes: None,
+        }
+    }
+}
+
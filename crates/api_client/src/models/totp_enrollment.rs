@@ -0,0 +1,30 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// TotpEnrollment : TOTP secret for authenticator apps.  Codes have 6 digits and they are generated with SHA1 and 30 second time step.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TotpEnrollment {
+    /// Base32 without padding
+    #[serde(rename = "secret")]
+    pub secret: String,
+}
+
+impl TotpEnrollment {
+    /// TOTP secret for authenticator apps.  Codes have 6 digits and they are generated with SHA1 and 30 second time step.
+    pub fn new(secret: String) -> TotpEnrollment {
+        TotpEnrollment {
+            secret,
+        }
+    }
+}
+
@@ -0,0 +1,34 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TotpEnrollmentResult {
+    #[serde(rename = "error_already_enabled", skip_serializing_if = "Option::is_none")]
+    pub error_already_enabled: Option<bool>,
+    /// Server requires TOTP for admin permissions and the account has admin permissions. Enrollment is possible after the permissions are removed.
+    #[serde(rename = "error_admin_permissions", skip_serializing_if = "Option::is_none")]
+    pub error_admin_permissions: Option<bool>,
+    #[serde(rename = "enrollment", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub enrollment: Option<Option<Box<models::TotpEnrollment>>>,
+}
+
+impl TotpEnrollmentResult {
+    pub fn new() -> TotpEnrollmentResult {
+        TotpEnrollmentResult {
+            error_already_enabled: None,
+            error_admin_permissions: None,
+            enrollment: None,
+        }
+    }
+}
+
@@ -0,0 +1,39 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TotpState {
+    /// TOTP enrollment is confirmed.
+    #[serde(rename = "enabled")]
+    pub enabled: bool,
+    #[serde(rename = "recovery_codes_left")]
+    pub recovery_codes_left: i64,
+    /// Admin permissions are available only after TOTP verification.
+    #[serde(rename = "verification_required")]
+    pub verification_required: bool,
+    /// Current login session has valid TOTP verification.
+    #[serde(rename = "verified")]
+    pub verified: bool,
+}
+
+impl TotpState {
+    pub fn new(enabled: bool, recovery_codes_left: i64, verification_required: bool, verified: bool) -> TotpState {
+        TotpState {
+            enabled,
+            recovery_codes_left,
+            verification_required,
+            verified,
+        }
+    }
+}
+
@@ -0,0 +1,40 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UploadMessageAttachmentResult {
+    #[serde(rename = "error", skip_serializing_if = "Option::is_none")]
+    pub error: Option<bool>,
+    #[serde(rename = "error_attachment_already_exists", skip_serializing_if = "Option::is_none")]
+    pub error_attachment_already_exists: Option<bool>,
+    /// Message is not pending, sender or recipient has already acknowledged the message or messaging is not possible anymore because of a block or unmatching.
+    #[serde(rename = "error_message_not_found", skip_serializing_if = "Option::is_none")]
+    pub error_message_not_found: Option<bool>,
+    #[serde(rename = "error_too_many_pending_attachments", skip_serializing_if = "Option::is_none")]
+    pub error_too_many_pending_attachments: Option<bool>,
+    #[serde(rename = "error_transfer_budget_exceeded", skip_serializing_if = "Option::is_none")]
+    pub error_transfer_budget_exceeded: Option<bool>,
+}
+
+impl UploadMessageAttachmentResult {
+    pub fn new() -> UploadMessageAttachmentResult {
+        UploadMessageAttachmentResult {
+            error: None,
+            error_attachment_already_exists: None,
+            error_message_not_found: None,
+            error_too_many_pending_attachments: None,
+            error_transfer_budget_exceeded: None,
+        }
+    }
+}
+
@@ -168,6 +168,7 @@ impl TestMode {
             }
             TestModeSubMode::Benchmark(c) => match c.benchmark {
                 SelectedBenchmark::GetProfileList => c.tasks + 1,
+                SelectedBenchmark::ChatMessages | SelectedBenchmark::TypingIndicator => c.tasks * 2,
                 _ => c.tasks,
             },
             TestModeSubMode::Qa(_) => panic!("QA test runner does not call this method"),
@@ -202,6 +203,15 @@ impl TestMode {
         }
     }
 
+    /// Metrics API port for reading server side performance counters
+    /// during benchmarks. Not available when servers are not started.
+    pub fn benchmark_metrics_api_port(&self) -> Option<u16> {
+        match &self.mode {
+            TestModeSubMode::Benchmark(c) if !self.no_servers => Some(c.metrics_api_port),
+            _ => None,
+        }
+    }
+
     pub fn overridden_index_cell_size(&self) -> Option<NonZeroU8> {
         match &self.mode {
             TestModeSubMode::Benchmark(c) => c.index_cell_square_km,
@@ -238,7 +248,7 @@ pub struct ServerConfig {
 
 #[derive(Args, Debug, Clone)]
 pub struct BenchmarkConfig {
-    /// Task count
+    /// Task count. Bot pair count for chat and typing indicator benchmarks.
     #[arg(short, long, default_value = "1", value_name = "COUNT")]
     pub tasks: u32,
 
@@ -249,6 +259,11 @@ pub struct BenchmarkConfig {
     /// Override index cell size value
     #[arg(long)]
     pub index_cell_square_km: Option<NonZeroU8>,
+
+    /// Server metrics API port which is used for printing
+    /// performance counter changes
+    #[arg(long, default_value = "3002", value_name = "PORT")]
+    pub metrics_api_port: u16,
 }
 
 #[derive(Args, Debug, Clone)]
@@ -279,6 +294,14 @@ pub enum SelectedBenchmark {
     GetProfileList,
     PostProfile,
     PostProfileToDatabase,
+    /// Tasks:
+    ///  - Bot pairs (tasks flag) which match and then send messages
+    ///    to each other and acknowledge received messages
+    ChatMessages,
+    /// Tasks:
+    ///  - Bot pairs (tasks flag) which match and then keep WebSocket
+    ///    connections open and send typing indicators to each other
+    TypingIndicator,
 }
 
 impl fmt::Display for SelectedBenchmark {
@@ -451,7 +474,8 @@ pub enum ConfigViewMode {
         file: PathBuf,
     },
     /// View available profile index sizes using
-    /// dimensions from config file
+    /// dimensions from config file and cell sizes for every
+    /// row of the configured index
     IndexInfo {
         /// Server config dir
         dir: PathBuf,
@@ -473,4 +497,26 @@ pub enum BackupMode {
         #[arg(long, value_name = "FILE")]
         key_file: PathBuf,
     },
+    /// Verify, decrypt and restore SQLite databases and media content
+    /// from backup to a server data directory
+    Restore {
+        /// Backup root directory
+        dir: PathBuf,
+        /// Path to file containing the 16-byte AES-128-GCM key (base64-encoded)
+        #[arg(long, value_name = "FILE")]
+        key_file: PathBuf,
+        /// Server data directory where the backup is restored
+        #[arg(long, value_name = "DIR")]
+        target_dir: PathBuf,
+        /// Select database backups which creation time (UTC) starts
+        /// with this text. The time format is "YYYY-MM-DD_HH-MM-SS", so
+        /// for example "2025-01-31" selects the latest backups from that
+        /// day. By default the latest database backups are selected.
+        /// Media content backup contains only the latest content.
+        #[arg(long, value_name = "TIME")]
+        snapshot: Option<String>,
+        /// Restore even if the target directory is not empty
+        #[arg(long)]
+        force: bool,
+    },
 }
@@ -79,7 +79,12 @@ impl BotConfigFile {
             let server_config = ConfigFile::load(server_config_path).attach(
                 "Bot config does not have [location] and server config could not be loaded. Try adding [location] to bot config.",
             )?;
-            bot_config.location = server_config.location;
+            bot_config.location = server_config.location.or_else(|| {
+                server_config
+                    .location_regions
+                    .and_then(|regions| regions.into_iter().next())
+                    .map(|region| region.location)
+            });
         }
 
         Ok(bot_config)
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 use simple_backend_config::file::IpAddressAccessConfig;
 use simple_backend_model::VersionNumber;
 use simple_backend_utils::{
-    Result,
+    ContextExt, IntoReportFromString, Result,
     byte::ByteCount,
     time::{DurationValue, TimeValue, UtcTimeValue},
 };
@@ -36,18 +36,39 @@ pub const DEFAULT_CONFIG_FILE_TEXT: &str = r#"
 # [grant_admin_access]
 # email = "admin@example.com"
 
+# Admin permissions of non-bot accounts are usable only when
+# the login session is verified with TOTP code. Admin permissions
+# are granted only to accounts which have TOTP enabled, so enroll
+# TOTP before completing initial setup of the account configured
+# in [grant_admin_access]. Existing admins without TOTP must have
+# their permissions removed, enroll TOTP and then have their
+# permissions granted again.
+# [admin_totp]
+# required = true
+# verification_validity_duration = "1h"
+
 # [location]
 # latitude_top_left = 70.1
 # longitude_top_left = 19.5
 # latitude_bottom_right = 59.8
 # longitude_bottom_right = 31.58
-# index_cell_square_km = 255       # 1-255 and area width and height must be larger than 255 km
+# index_cell_square_km = 255       # 1-255, cell side length target in km at the latitude nearest to the equator
+
+# Alternative for [location] when multiple location index regions are needed
+# [[location_regions]]
+# name = "finland"
+# latitude_top_left = 70.1
+# longitude_top_left = 19.5
+# latitude_bottom_right = 59.8
+# longitude_bottom_right = 31.58
+# index_cell_square_km = 255
 
 # [limits.common]
 # send_report_daily_max_count = 20
 # websocket_connection_attempts_daily_max_count = 150
 # auto_ban_spam_reporters_rejected_report_threshold = 10
 # auto_ban_spam_reporters_ban_duration = "90d"
+# ban_evasion_detection_new_account_max_age = "7d"
 #
 # [limits.common.processed_report_deletion_wait_duration]
 # profile_name = "90d"
@@ -75,6 +96,7 @@ pub const DEFAULT_CONFIG_FILE_TEXT: &str = r#"
 # account_verification_queue_max_length = 100
 # custom_email_send_to_all_accounts_monthly_max_count = 1
 # custom_email_send_draft_to_my_email_address_monthly_max_count = 10
+# totp_code_attempts_daily_max_count = 20
 
 # [limits.chat]
 # max_public_key_count = 20
@@ -85,6 +107,8 @@ pub const DEFAULT_CONFIG_FILE_TEXT: &str = r#"
 # new_like_email_with_push_notification_device_token = "7d"
 # new_like_email_without_push_notification_device_token = "1d"
 # backup_transfer_yearly_max_bytes = "100M"
+# message_attachment_max_bytes = "5M"
+# message_attachment_pending_max_count = 20
 
 # [limits.media]
 # max_content_count = 20
@@ -102,6 +126,18 @@ pub const DEFAULT_CONFIG_FILE_TEXT: &str = r#"
 # get_profile_daily_max_count = 2000
 # favorite_profiles_max_count = 100
 
+# Per IP address token bucket rate limits for unauthenticated
+# login related routes. Bucket capacity 0 disables the limit.
+# [limits.ip_rate_limit]
+# ipv4_prefix_length = 32 # For example, 24 makes /24 networks share limits
+# ipv6_prefix_length = 64 # Value 128 disables IPv6 network grouping
+# max_bucket_count = 100000
+# exempted_ip_lists = [] # IP list names from simple backend config
+# sign_in_with = { capacity = 10, refill_duration = "6s" }
+# email_login = { capacity = 10, refill_duration = "30s" }
+# demo_account = { capacity = 10, refill_duration = "6s" }
+# remote_bot_login = { capacity = 50, refill_duration = "2s" }
+
 # [[profile_name_allowlists]]
 # csv_file = "names.csv"
 # delimiter = ";"
@@ -167,10 +203,15 @@ pub struct ConfigFile {
     pub automatic_profile_search: AutomaticProfileSearchConfig,
     #[serde(default)]
     pub scheduled_tasks: ScheduledTasksConfig,
+    #[serde(default)]
+    pub admin_totp: AdminTotpConfig,
 
     pub remote_bot_login: Option<RemoteBotLoginConfig>,
     pub grant_admin_access: Option<GrantAdminAccessConfig>,
     pub location: Option<LocationConfig>,
+    /// Alternative for [Self::location] when multiple location index
+    /// regions are needed.
+    pub location_regions: Option<Vec<LocationRegionConfig>>,
     pub demo_accounts: Option<Vec<DemoAccountConfig>>,
     pub limits: Option<LimitsConfig>,
     pub profile_name_allowlists: Option<Vec<ProfiletNameAllowlistConfig>>,
@@ -187,9 +228,11 @@ impl ConfigFile {
             api: ApiConfig::default(),
             automatic_profile_search: AutomaticProfileSearchConfig::default(),
             scheduled_tasks: ScheduledTasksConfig::default(),
+            admin_totp: AdminTotpConfig::default(),
             remote_bot_login: None,
             grant_admin_access: None,
             location: None,
+            location_regions: None,
             demo_accounts: None,
             limits: None,
             profile_name_allowlists: None,
@@ -218,6 +261,22 @@ impl ConfigFile {
         let file: Self =
             toml::from_str(&config_string).change_context(ConfigFileError::LoadConfig)?;
 
+        if let Some(location) = &file.location {
+            location
+                .validate()
+                .into_error_string(ConfigFileError::InvalidConfig)?;
+        }
+
+        if let Some(regions) = &file.location_regions {
+            if file.location.is_some() {
+                return Err(ConfigFileError::InvalidConfig.report()).attach(
+                    "Config [location] and [[location_regions]] can not be used at the same time",
+                );
+            }
+            LocationRegionConfig::validate_regions(regions)
+                .into_error_string(ConfigFileError::InvalidConfig)?;
+        }
+
         Ok(file)
     }
 }
@@ -322,11 +381,125 @@ pub struct LocationConfig {
     /// Minimum "x-axis" angle for top left corner of the location index.
     /// Index can in reality end further away.
     pub longitude_bottom_right: f64,
-    /// Index cell map size target value. Might be smaller or larger depending
-    /// the supported tile sizes.
+    /// Index cell side length target value in kilometers. Might be smaller
+    /// or larger depending the supported tile sizes. Cell side length depends
+    /// on the latitude, so the target value is used for the index row
+    /// which is nearest to the equator.
     pub index_cell_square_km: NonZeroU8,
 }
 
+impl LocationConfig {
+    /// Web Mercator projection can not represent latitudes beyond this value.
+    pub const MAX_LATITUDE: f64 = 85.051_128;
+
+    /// Latitude of the index area which is nearest to the equator.
+    /// Index cells are largest at that latitude.
+    pub fn reference_latitude(&self) -> f64 {
+        if self.latitude_top_left >= 0.0 && self.latitude_bottom_right <= 0.0 {
+            0.0
+        } else if self.latitude_bottom_right > 0.0 {
+            self.latitude_bottom_right
+        } else {
+            self.latitude_top_left
+        }
+    }
+
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        let latitude_range = -Self::MAX_LATITUDE..=Self::MAX_LATITUDE;
+        for (name, value) in [
+            ("latitude_top_left", self.latitude_top_left),
+            ("latitude_bottom_right", self.latitude_bottom_right),
+        ] {
+            if !latitude_range.contains(&value) {
+                return Err(format!(
+                    "Location config error: {name} must be in range [{}, {}]",
+                    latitude_range.start(),
+                    latitude_range.end(),
+                ));
+            }
+        }
+        for (name, value) in [
+            ("longitude_top_left", self.longitude_top_left),
+            ("longitude_bottom_right", self.longitude_bottom_right),
+        ] {
+            if !(-180.0..=180.0).contains(&value) {
+                return Err(format!(
+                    "Location config error: {name} must be in range [-180, 180]"
+                ));
+            }
+        }
+        if self.latitude_top_left <= self.latitude_bottom_right {
+            return Err(
+                "Location config error: latitude_top_left must be larger than latitude_bottom_right"
+                    .to_string(),
+            );
+        }
+        if self.longitude_top_left >= self.longitude_bottom_right {
+            return Err(
+                "Location config error: longitude_top_left must be smaller than longitude_bottom_right"
+                    .to_string(),
+            );
+        }
+        Ok(())
+    }
+}
+
+impl LocationConfig {
+    fn overlaps(&self, other: &LocationConfig) -> bool {
+        self.latitude_bottom_right < other.latitude_top_left
+            && other.latitude_bottom_right < self.latitude_top_left
+            && self.longitude_top_left < other.longitude_bottom_right
+            && other.longitude_top_left < self.longitude_bottom_right
+    }
+}
+
+/// Named location index region. Every region has its own location index.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LocationRegionConfig {
+    pub name: String,
+    #[serde(flatten)]
+    pub location: LocationConfig,
+}
+
+impl LocationRegionConfig {
+    pub const DEFAULT_REGION_NAME: &str = "default";
+    /// Region ID is stored as u8.
+    pub const MAX_REGION_COUNT: usize = 256;
+
+    fn validate_regions(regions: &[LocationRegionConfig]) -> std::result::Result<(), String> {
+        if regions.is_empty() {
+            return Err("Location region list is empty".to_string());
+        }
+        if regions.len() > Self::MAX_REGION_COUNT {
+            return Err(format!(
+                "Max location region count is {}",
+                Self::MAX_REGION_COUNT
+            ));
+        }
+        for (i, region) in regions.iter().enumerate() {
+            if region.name.trim().is_empty() {
+                return Err("Location region name is empty".to_string());
+            }
+            region
+                .location
+                .validate()
+                .map_err(|e| format!("Location region {}: {e}", region.name))?;
+            for other in regions.iter().skip(i + 1) {
+                if region.name == other.name {
+                    return Err(format!("Duplicate location region name {}", region.name));
+                }
+                if region.location.overlaps(&other.location) {
+                    return Err(format!(
+                        "Location regions {} and {} overlap",
+                        region.name, other.name
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 impl Default for LocationConfig {
     fn default() -> Self {
         Self {
@@ -350,6 +523,7 @@ pub struct LimitsConfig {
     pub chat: Option<ChatLimitsConfig>,
     pub media: Option<MediaLimitsConfig>,
     pub profile: Option<ProfileLimitsConfig>,
+    pub ip_rate_limit: Option<IpRateLimitConfig>,
 }
 
 /// Common limits config for all server components
@@ -361,6 +535,9 @@ pub struct CommonLimitsConfig {
     pub websocket_connection_attempts_daily_max_count: u16,
     pub auto_ban_spam_reporters_rejected_report_threshold: u16,
     pub auto_ban_spam_reporters_ban_duration: DurationValue,
+    /// Hourly ban evasion detection compares accounts
+    /// created within this time to banned accounts.
+    pub ban_evasion_detection_new_account_max_age: DurationValue,
 }
 
 impl Default for CommonLimitsConfig {
@@ -371,6 +548,7 @@ impl Default for CommonLimitsConfig {
             websocket_connection_attempts_daily_max_count: 150,
             auto_ban_spam_reporters_rejected_report_threshold: 10,
             auto_ban_spam_reporters_ban_duration: DurationValue::from_days(90),
+            ban_evasion_detection_new_account_max_age: DurationValue::from_days(7),
         }
     }
 }
@@ -391,6 +569,69 @@ impl Default for ProcessedReportDeletionConfig {
     }
 }
 
+/// Per IP address rate limits for unauthenticated routes
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct IpRateLimitConfig {
+    /// IPv4 addresses in the same network share the same limits.
+    /// Default value 32 disables the grouping.
+    pub ipv4_prefix_length: u8,
+    /// IPv6 addresses in the same network share the same limits.
+    /// Default value is 64 as one client usually has at least /64
+    /// network. Value 128 disables the grouping.
+    pub ipv6_prefix_length: u8,
+    /// Max count for stored token buckets. When the count is reached,
+    /// full buckets are removed. If all buckets are in use, requests
+    /// from new IP addresses are rate limited.
+    pub max_bucket_count: u32,
+    /// Names of IP lists (simple backend config `ip_info.lists`) which
+    /// IP addresses are not rate limited.
+    pub exempted_ip_lists: Vec<String>,
+    /// Sign in with login routes
+    pub sign_in_with: TokenBucketConfig,
+    /// Email login token request and email login routes
+    pub email_login: TokenBucketConfig,
+    /// Demo account routes
+    pub demo_account: TokenBucketConfig,
+    /// Remote bot login route
+    pub remote_bot_login: TokenBucketConfig,
+}
+
+impl Default for IpRateLimitConfig {
+    fn default() -> Self {
+        Self {
+            ipv4_prefix_length: 32,
+            ipv6_prefix_length: 64,
+            max_bucket_count: 100_000,
+            exempted_ip_lists: Vec::new(),
+            sign_in_with: TokenBucketConfig {
+                capacity: 10,
+                refill_duration: DurationValue::from_seconds(6),
+            },
+            email_login: TokenBucketConfig {
+                capacity: 10,
+                refill_duration: DurationValue::from_seconds(30),
+            },
+            demo_account: TokenBucketConfig {
+                capacity: 10,
+                refill_duration: DurationValue::from_seconds(6),
+            },
+            remote_bot_login: TokenBucketConfig {
+                capacity: 50,
+                refill_duration: DurationValue::from_seconds(2),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+pub struct TokenBucketConfig {
+    /// Max request burst size. Value 0 disables the limit.
+    pub capacity: u16,
+    /// Time which it takes to add one request back to the bucket
+    pub refill_duration: DurationValue,
+}
+
 /// Account related limits config
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(default)]
@@ -436,6 +677,8 @@ pub struct AccountLimitsConfig {
     pub account_verification_queue_max_length: u16,
     pub custom_email_send_to_all_accounts_monthly_max_count: u16,
     pub custom_email_send_draft_to_my_email_address_monthly_max_count: u16,
+    /// Limit for TOTP and recovery code checks.
+    pub totp_code_attempts_daily_max_count: u16,
 }
 
 impl Default for AccountLimitsConfig {
@@ -466,6 +709,7 @@ impl Default for AccountLimitsConfig {
             account_verification_queue_max_length: 100,
             custom_email_send_to_all_accounts_monthly_max_count: 1,
             custom_email_send_draft_to_my_email_address_monthly_max_count: 10,
+            totp_code_attempts_daily_max_count: 20,
         }
     }
 }
@@ -494,7 +738,13 @@ pub struct ChatLimitsConfig {
     pub new_message_email_without_push_notification_device_token: DurationValue,
     pub new_like_email_with_push_notification_device_token: DurationValue,
     pub new_like_email_without_push_notification_device_token: DurationValue,
+    /// Uploaded message attachment bytes are also counted
+    /// to this limit.
     pub backup_transfer_yearly_max_bytes: ByteCount,
+    pub message_attachment_max_bytes: ByteCount,
+    /// Max count for sender's attachments which are waiting for
+    /// message acknowledgements.
+    pub message_attachment_pending_max_count: u16,
 }
 
 impl Default for ChatLimitsConfig {
@@ -508,6 +758,8 @@ impl Default for ChatLimitsConfig {
             new_like_email_with_push_notification_device_token: DurationValue::from_days(7),
             new_like_email_without_push_notification_device_token: DurationValue::from_days(1),
             backup_transfer_yearly_max_bytes: ByteCount::from_megabytes(100),
+            message_attachment_max_bytes: ByteCount::from_megabytes(5),
+            message_attachment_pending_max_count: 20,
         }
     }
 }
@@ -666,6 +918,45 @@ impl Default for ScheduledTasksConfig {
     }
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct AdminTotpConfig {
+    /// Require TOTP verification for using admin permissions.
+    ///
+    /// When enabled, admin permissions are granted only to accounts
+    /// which have TOTP enabled and TOTP enrollment is not possible
+    /// for accounts which have admin permissions. Existing admins
+    /// without TOTP must have their permissions removed, enroll TOTP
+    /// and then have their permissions granted again.
+    pub required: bool,
+    /// Require TOTP verification also from bot accounts.
+    /// This is mainly for testing.
+    pub required_from_bots: bool,
+    /// How long TOTP verification of a login session is valid.
+    pub verification_validity_duration: DurationValue,
+}
+
+impl AdminTotpConfig {
+    /// Returns `None` if TOTP verification is not required.
+    pub fn required_verification_validity(&self, is_bot: bool) -> Option<DurationValue> {
+        if self.required && (!is_bot || self.required_from_bots) {
+            Some(self.verification_validity_duration)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for AdminTotpConfig {
+    fn default() -> Self {
+        Self {
+            required: true,
+            required_from_bots: false,
+            verification_validity_duration: DurationValue::from_seconds(60 * 60),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct AssociationServerConfig {
     pub members_only_info_markdown: Option<StringResourceInternal>,
@@ -1,10 +1,15 @@
-use std::{collections::HashMap, io::Write, path::Path};
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    io::Write,
+    path::{Path, PathBuf},
+};
 
 use error_stack::ResultExt;
 use handlebars::Handlebars;
 use model::StringResourceInternal;
 use serde::Deserialize;
 use serde_json::json;
+use simple_backend_model::{EmailBody, EmailInlineImage};
 use simple_backend_utils::Result;
 
 use crate::file::ConfigFileError;
@@ -22,6 +27,30 @@ email_body_template = """
 
 email_body_content_type_is_html = false
 
+# Optional common HTML template for all emails (non-translatable). If set,
+# emails are sent as multipart/alternative emails and email_body_template
+# is used for the plain text part. The same keys as in email_body_template
+# are available in the template. Setting this requires that
+# email_body_content_type_is_html is false.
+# email_html_body_template = """
+# <html>
+# <body>
+# <img src="cid:logo" alt="Logo">
+# <h1>{{subject}}</h1>
+# <p>{{body}}</p>
+# <p>{{footer}}</p>
+# </body>
+# </html>
+# """
+
+# Optional images for HTML emails. The images are embedded to the emails
+# as inline attachments. The image file path is relative to the directory
+# which contains this file.
+# [[inline_images]]
+# content_id = "logo"
+# file = "email_logo.png"
+# content_type = "image/png"
+
 [custom_keys.footer]
 default = "Footer"
 
@@ -102,8 +131,7 @@ default = "Here is your login code: {{token}}"
 #[derive(Debug, Clone)]
 pub struct EmailContent {
     pub subject: String,
-    pub body: String,
-    pub body_is_html: bool,
+    pub body: EmailBody,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -112,10 +140,22 @@ struct EmailContentStrings {
     body: StringResourceInternal,
 }
 
+#[derive(Debug, Deserialize)]
+struct InlineImageConfig {
+    content_id: String,
+    file: PathBuf,
+    content_type: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct EmailContentFile {
     email_body_template: String,
     email_body_content_type_is_html: bool,
+    email_html_body_template: Option<String>,
+    #[serde(default)]
+    inline_images: Vec<InlineImageConfig>,
+    #[serde(skip)]
+    loaded_inline_images: Vec<EmailInlineImage>,
     #[serde(default)]
     custom_keys: HashMap<String, StringResourceInternal>,
     /// "{{token}}" is replaced with email verification token
@@ -145,6 +185,9 @@ impl Default for EmailContentFile {
         Self {
             email_body_template: DEFAULT_EMAIL_TEMPLATE.to_string(),
             email_body_content_type_is_html: false,
+            email_html_body_template: None,
+            inline_images: vec![],
+            loaded_inline_images: vec![],
             custom_keys: HashMap::new(),
             email_verification: None,
             new_message: None,
@@ -176,7 +219,7 @@ impl EmailContentFile {
         }
         let config_content =
             std::fs::read_to_string(file).change_context(ConfigFileError::LoadConfig)?;
-        let config: EmailContentFile =
+        let mut config: EmailContentFile =
             toml::from_str(&config_content).change_context(ConfigFileError::LoadConfig)?;
 
         if let Some(key) = config.other.keys().next() {
@@ -185,19 +228,28 @@ impl EmailContentFile {
             ));
         }
 
-        // Validate that template can be parsed
-        if let Err(e) = Handlebars::new().render_template_with_context_to_write(
-            &config.email_body_template,
-            &handlebars::Context::null(),
-            &mut std::io::sink(),
-        ) {
-            return Err(ConfigFileError::InvalidConfig)
-                .attach(format!("Template parsing error: {e}"));
+        if config.email_html_body_template.is_some() && config.email_body_content_type_is_html {
+            return Err(ConfigFileError::InvalidConfig).attach(
+                "email_body_content_type_is_html must be false when email_html_body_template is set"
+                    .to_string(),
+            );
+        }
+
+        // Validate that templates can be parsed
+        for template in config.templates() {
+            if let Err(e) = Handlebars::new().render_template_with_context_to_write(
+                template,
+                &handlebars::Context::null(),
+                &mut std::io::sink(),
+            ) {
+                return Err(ConfigFileError::InvalidConfig)
+                    .attach(format!("Template parsing error: {e}"));
+            }
         }
 
-        // Find all variable references in the template
-        let mut referenced_keys = std::collections::HashSet::new();
-        for line in config.email_body_template.lines() {
+        // Find all variable references in the templates
+        let mut referenced_keys = HashSet::new();
+        for line in config.templates().flat_map(|v| v.lines()) {
             for cap in line.match_indices("{{") {
                 if let Some(end_pos) = line[cap.0..].find("}}") {
                     let var_content = &line[cap.0 + 2..cap.0 + end_pos].trim();
@@ -227,9 +279,103 @@ impl EmailContentFile {
                 .attach("'{{token}}' is missing from email_verification body text".to_string());
         }
 
+        let html_template = if config.email_body_content_type_is_html {
+            Some(&config.email_body_template)
+        } else {
+            config.email_html_body_template.as_ref()
+        };
+        let inline_image_dir = path.parent().unwrap_or(Path::new(""));
+        let mut loaded_inline_images = vec![];
+        for image in &config.inline_images {
+            let Some(html_template) = html_template else {
+                return Err(ConfigFileError::InvalidConfig)
+                    .attach("Inline images require HTML email template".to_string());
+            };
+            if !html_template.contains(&format!("cid:{}", image.content_id)) {
+                return Err(ConfigFileError::InvalidConfig).attach(format!(
+                    "Inline image '{}' is defined but not referenced in the HTML template",
+                    image.content_id,
+                ));
+            }
+            let image_file = inline_image_dir.join(&image.file);
+            let data = std::fs::read(&image_file)
+                .change_context(ConfigFileError::LoadConfig)
+                .attach_opaque_with(|| format!("Inline image file: {}", image_file.display()))?;
+            loaded_inline_images.push(EmailInlineImage {
+                content_id: image.content_id.clone(),
+                content_type: image.content_type.clone(),
+                data: data.into(),
+            });
+        }
+        config.loaded_inline_images = loaded_inline_images;
+
         Ok(config)
     }
 
+    fn templates(&self) -> impl Iterator<Item = &String> {
+        std::iter::once(&self.email_body_template).chain(&self.email_html_body_template)
+    }
+
+    fn email_strings(&self) -> impl Iterator<Item = &EmailContentStrings> {
+        [
+            &self.email_verification,
+            &self.new_message,
+            &self.new_like,
+            &self.account_deletion_remainder_first,
+            &self.account_deletion_remainder_second,
+            &self.account_deletion_remainder_third,
+            &self.email_change_verification,
+            &self.email_change_notification,
+            &self.email_login,
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    /// Render all emails in all configured languages using sample data.
+    /// Handlebars strict mode is used, so references to undefined
+    /// variables are also detected.
+    pub fn render_all_with_sample_data(&self) -> Result<(), ConfigFileError> {
+        let mut languages = BTreeSet::new();
+        for resource in self
+            .email_strings()
+            .flat_map(|v| [&v.subject, &v.body])
+            .chain(self.custom_keys.values())
+        {
+            languages.extend(resource.translations.keys().map(|v| v.as_str()));
+        }
+
+        let token = "123456";
+        for language in std::iter::once("").chain(languages) {
+            let getter = EmailStringGetter {
+                config: self,
+                language,
+                strict_mode: true,
+            };
+            for content in [
+                getter.email_verification(token),
+                getter.new_message(),
+                getter.new_like(),
+                getter.account_deletion_remainder_first(),
+                getter.account_deletion_remainder_second(),
+                getter.account_deletion_remainder_third(),
+                getter.email_change_verification(token),
+                getter.email_change_notification(),
+                getter.email_login(token),
+            ] {
+                content.attach_opaque_with(|| {
+                    if language.is_empty() {
+                        "Language: default".to_string()
+                    } else {
+                        format!("Language: {language}")
+                    }
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn email_body_content_type_is_html(&self) -> bool {
         self.email_body_content_type_is_html
     }
@@ -238,6 +384,7 @@ impl EmailContentFile {
         EmailStringGetter {
             config: self,
             language: language.map(|v| v.as_ref()).unwrap_or_default(),
+            strict_mode: false,
         }
     }
 }
@@ -245,6 +392,7 @@ impl EmailContentFile {
 pub struct EmailStringGetter<'a> {
     config: &'a EmailContentFile,
     language: &'a str,
+    strict_mode: bool,
 }
 
 impl<'a> EmailStringGetter<'a> {
@@ -267,18 +415,19 @@ impl<'a> EmailStringGetter<'a> {
         let subject = resource
             .as_ref()
             .map(|v| &v.subject)
-            .map(|v| v.translations.get(self.language).unwrap_or(&v.default))
-            .cloned()
+            .map(|v| v.translation(self.language).to_string())
             .unwrap_or_else(|| default_subject.to_string());
 
         let body = resource
             .as_ref()
             .map(|v| &v.body)
-            .map(|v| v.translations.get(self.language).unwrap_or(&v.default))
-            .cloned()
+            .map(|v| v.translation(self.language).to_string())
             .unwrap_or_else(|| default_body.to_string());
 
-        let rendered_body = Handlebars::new()
+        let mut handlebars = Handlebars::new();
+        handlebars.set_strict_mode(self.strict_mode);
+
+        let rendered_body = handlebars
             .render_template(&body, &body_data)
             .change_context(ConfigFileError::InvalidConfig)
             .attach_opaque_with(|| "Template rendering error".to_string())?;
@@ -290,23 +439,42 @@ impl<'a> EmailStringGetter<'a> {
 
         // Add custom keys
         for (key, resource) in &self.config.custom_keys {
-            let value = resource
-                .translations
-                .get(self.language)
-                .unwrap_or(&resource.default);
+            let value = resource.translation(self.language);
             data[key] = json!(value);
         }
 
-        let rendered = Handlebars::new()
-            .render_template(&self.config.email_body_template, &data)
-            .change_context(ConfigFileError::InvalidConfig)
-            .attach_opaque_with(|| "Template rendering error".to_string())?;
+        let body = if let Some(html_template) = &self.config.email_html_body_template {
+            let rendered_html = handlebars
+                .render_template(html_template, &data)
+                .change_context(ConfigFileError::InvalidConfig)
+                .attach_opaque_with(|| "HTML template rendering error".to_string())?;
+            // Plain text part does not need HTML escaping
+            handlebars.register_escape_fn(handlebars::no_escape);
+            let rendered_text = handlebars
+                .render_template(&self.config.email_body_template, &data)
+                .change_context(ConfigFileError::InvalidConfig)
+                .attach_opaque_with(|| "Template rendering error".to_string())?;
+            EmailBody::TextAndHtml {
+                text: rendered_text,
+                html: rendered_html,
+                inline_images: self.config.loaded_inline_images.clone(),
+            }
+        } else {
+            let rendered = handlebars
+                .render_template(&self.config.email_body_template, &data)
+                .change_context(ConfigFileError::InvalidConfig)
+                .attach_opaque_with(|| "Template rendering error".to_string())?;
+            if self.config.email_body_content_type_is_html {
+                EmailBody::Html {
+                    html: rendered,
+                    inline_images: self.config.loaded_inline_images.clone(),
+                }
+            } else {
+                EmailBody::Text(rendered)
+            }
+        };
 
-        Ok(EmailContent {
-            subject,
-            body: rendered,
-            body_is_html: self.config.email_body_content_type_is_html,
-        })
+        Ok(EmailContent { subject, body })
     }
 
     pub fn email_verification(&self, token: &str) -> Result<EmailContent, ConfigFileError> {
@@ -384,3 +552,106 @@ impl<'a> EmailStringGetter<'a> {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HTML_CONTENT: &str = r#"
+email_body_template = """
+{{subject}}
+{{body}}
+"""
+email_body_content_type_is_html = false
+email_html_body_template = """
+<img src="cid:logo">
+<p>{{body}}</p>
+"""
+
+[[inline_images]]
+content_id = "logo"
+file = "logo.png"
+content_type = "image/png"
+"#;
+
+    /// Creates a new directory for one test and returns the directory path
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "afrodite_email_content_test_{}_{name}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn html_template_and_inline_image_creates_multipart_email() {
+        let dir = test_dir("html");
+        std::fs::write(dir.join("logo.png"), [1, 2, 3]).unwrap();
+        let file = dir.join(EmailContentFile::CONFIG_FILE_NAME);
+        std::fs::write(&file, HTML_CONTENT).unwrap();
+
+        let config = EmailContentFile::load(&file, false).unwrap();
+        config.render_all_with_sample_data().unwrap();
+        let content = config.get::<String>(None).email_login("123").unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let EmailBody::TextAndHtml {
+            text,
+            html,
+            inline_images,
+        } = content.body
+        else {
+            panic!("Unexpected email body {:?}", content.body);
+        };
+        assert!(text.contains("Here is your login code: 123"));
+        assert!(html.contains("<img src=\"cid:logo\">"));
+        assert!(html.contains("Here is your login code: 123"));
+        assert_eq!(inline_images.len(), 1);
+        assert_eq!(inline_images[0].content_id, "logo");
+        assert_eq!(inline_images[0].content_type, "image/png");
+        assert_eq!(&*inline_images[0].data, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn inline_image_path_is_relative_to_email_content_file() {
+        let dir = test_dir("relative_path");
+        let file = dir.join(EmailContentFile::CONFIG_FILE_NAME);
+        std::fs::write(&file, HTML_CONTENT).unwrap();
+
+        // Image is missing
+        assert!(EmailContentFile::load(&file, false).is_err());
+
+        std::fs::write(dir.join("logo.png"), [1]).unwrap();
+        let result = EmailContentFile::load(&file, false);
+        let _ = std::fs::remove_dir_all(&dir);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn undefined_variable_fails_strict_rendering() {
+        let dir = test_dir("undefined_variable");
+        let file = dir.join(EmailContentFile::CONFIG_FILE_NAME);
+        std::fs::write(
+            &file,
+            r#"
+email_body_template = "{{subject}} {{body}}"
+email_body_content_type_is_html = false
+
+[new_message.subject]
+default = "New message"
+
+[new_message.body]
+default = "Hello {{name}}"
+"#,
+        )
+        .unwrap();
+
+        let config = EmailContentFile::load(&file, false).unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+        assert!(config.render_all_with_sample_data().is_err());
+        // Non-strict rendering renders undefined variable as empty string
+        assert!(config.get::<String>(None).new_message().is_ok());
+    }
+}
@@ -222,8 +222,7 @@ impl<'a> NotificationStringGetter<'a> {
         let title = resource
             .as_ref()
             .map(|v| &v.title)
-            .map(|v| v.translations.get(self.language).unwrap_or(&v.default))
-            .cloned()
+            .map(|v| v.translation(self.language).to_string())
             .unwrap_or_else(|| default.to_string());
 
         NotificationTitle { title }
@@ -238,15 +237,13 @@ impl<'a> NotificationStringGetter<'a> {
         let title = resource
             .as_ref()
             .map(|v| &v.title)
-            .map(|v| v.translations.get(self.language).unwrap_or(&v.default))
-            .cloned()
+            .map(|v| v.translation(self.language).to_string())
             .unwrap_or_else(|| default_title.to_string());
 
         let body = resource
             .as_ref()
             .map(|v| &v.body)
-            .map(|v| v.translations.get(self.language).unwrap_or(&v.default))
-            .cloned()
+            .map(|v| v.translation(self.language).to_string())
             .unwrap_or_else(|| default_body.to_string());
 
         NotificationTitleAndBody { title, body }
@@ -206,15 +206,13 @@ impl<'a> WebStringGetter<'a> {
         let title = resource
             .as_ref()
             .map(|v| &v.title)
-            .map(|v| v.translations.get(self.language).unwrap_or(&v.default))
-            .cloned()
+            .map(|v| v.translation(self.language).to_string())
             .unwrap_or_else(|| default_title.to_string());
 
         let body = resource
             .as_ref()
             .map(|v| &v.body)
-            .map(|v| v.translations.get(self.language).unwrap_or(&v.default))
-            .cloned()
+            .map(|v| v.translation(self.language).to_string())
             .unwrap_or_else(|| default_body.to_string());
 
         let rendered_body = Handlebars::new()
@@ -255,22 +253,19 @@ impl<'a> WebStringGetter<'a> {
         let title = resource
             .as_ref()
             .map(|v| &v.title)
-            .map(|v| v.translations.get(self.language).unwrap_or(&v.default))
-            .cloned()
+            .map(|v| v.translation(self.language).to_string())
             .unwrap_or_else(|| "Verify Email".to_string());
 
         let body = resource
             .as_ref()
             .map(|v| &v.body)
-            .map(|v| v.translations.get(self.language).unwrap_or(&v.default))
-            .cloned()
+            .map(|v| v.translation(self.language).to_string())
             .unwrap_or_else(|| "Click the button below to verify your email address.".to_string());
 
         let button = resource
             .as_ref()
             .map(|v| &v.button)
-            .map(|v| v.translations.get(self.language).unwrap_or(&v.default))
-            .cloned()
+            .map(|v| v.translation(self.language).to_string())
             .unwrap_or_else(|| "Verify Email".to_string());
 
         let template = resource
@@ -24,9 +24,9 @@ use bot_config_file::BotConfigFile;
 use csv::profile_name_allowlist::{ProfileNameAllowlistBuilder, ProfileNameAllowlistData};
 use error_stack::ResultExt;
 use file::{
-    AccountLimitsConfig, AppUpdateAvailableConfig, AutomaticProfileSearchConfig, ChatLimitsConfig,
-    CommonLimitsConfig, DemoAccountConfig, GrantAdminAccessConfig, MediaLimitsConfig,
-    RemoteBotLoginConfig, ScheduledTasksConfig,
+    AccountLimitsConfig, AdminTotpConfig, AppUpdateAvailableConfig, AutomaticProfileSearchConfig,
+    ChatLimitsConfig, CommonLimitsConfig, DemoAccountConfig, GrantAdminAccessConfig,
+    IpRateLimitConfig, MediaLimitsConfig, RemoteBotLoginConfig, ScheduledTasksConfig,
 };
 use file_email_content::EmailContentFile;
 use file_web_content::WebContentFile;
@@ -35,10 +35,11 @@ use model::{CustomReportsConfig, StringResourceInternal};
 use sha2::{Digest, Sha256};
 use simple_backend_config::{SimpleBackendConfig, args::ServerMode, file::SimpleBackendConfigFile};
 use simple_backend_utils::{
-    IntoReportFromString, Result, dir::abs_path_for_directory_or_file_which_might_not_exists,
+    ContextExt, IntoReportFromString, Result,
+    dir::abs_path_for_directory_or_file_which_might_not_exists,
 };
 
-use self::file::{AssociationServerConfig, ConfigFile, LocationConfig};
+use self::file::{AssociationServerConfig, ConfigFile, LocationConfig, LocationRegionConfig};
 use crate::{
     file::{
         ApiConfig, ClientVersionTrackingConfig, GeneralConfig, MinClientVersion,
@@ -123,7 +124,30 @@ impl Config {
     }
 
     pub fn location(&self) -> LocationConfig {
-        self.file.location.clone().unwrap_or_default()
+        if let Some(location) = &self.file.location {
+            location.clone()
+        } else if let Some(region) = self
+            .file
+            .location_regions
+            .as_ref()
+            .and_then(|regions| regions.first())
+        {
+            region.location.clone()
+        } else {
+            LocationConfig::default()
+        }
+    }
+
+    /// Location index regions. If [[location_regions]] is not configured,
+    /// then [Self::location] is the only region.
+    pub fn location_regions(&self) -> Vec<LocationRegionConfig> {
+        match &self.file.location_regions {
+            Some(regions) if !regions.is_empty() => regions.clone(),
+            _ => vec![LocationRegionConfig {
+                name: LocationRegionConfig::DEFAULT_REGION_NAME.to_string(),
+                location: self.location(),
+            }],
+        }
     }
 
     /// Server should run in debug mode.
@@ -178,6 +202,14 @@ impl Config {
             .unwrap_or_default()
     }
 
+    pub fn limits_ip_rate_limit(&self) -> IpRateLimitConfig {
+        self.file
+            .limits
+            .as_ref()
+            .and_then(|v| v.ip_rate_limit.as_ref().cloned())
+            .unwrap_or_default()
+    }
+
     pub fn limits_profile(&self) -> ProfileLimitsConfig {
         self.file
             .limits
@@ -210,6 +242,10 @@ impl Config {
         self.file.scheduled_tasks.clone()
     }
 
+    pub fn admin_totp(&self) -> &AdminTotpConfig {
+        &self.file.admin_totp
+    }
+
     pub fn email_content(&self) -> &EmailContentFile {
         &self.email_content
     }
@@ -400,6 +436,27 @@ pub fn get_config(
             None
         };
 
+    if let Some(ip_rate_limit) = file_config
+        .limits
+        .as_ref()
+        .and_then(|v| v.ip_rate_limit.as_ref())
+    {
+        if ip_rate_limit.ipv4_prefix_length > 32 || ip_rate_limit.ipv6_prefix_length > 128 {
+            return Err(GetConfigError::InvalidConfiguration.report())
+                .attach_opaque("Invalid IP rate limit network prefix length");
+        }
+        for name in &ip_rate_limit.exempted_ip_lists {
+            if !simple_backend_config
+                .ip_lists()
+                .iter()
+                .any(|v| v.name() == name)
+            {
+                return Err(GetConfigError::InvalidConfiguration.report())
+                    .attach_opaque_with(|| format!("IP list {name} does not exist"));
+            }
+        }
+    }
+
     let bot_config_abs_file_path =
         abs_path_for_directory_or_file_which_might_not_exists(BotConfigFile::CONFIG_FILE_NAME)
             .change_context(GetConfigError::LoadFileError)?;
@@ -1,11 +1,12 @@
 use base64::Engine;
 use diesel::{alias, prelude::*};
+use error_stack::ResultExt;
 use model::{
-    AccountId, AccountIdDb, AccountIdInternal, AccountInteractionInternal, ChatMessageReport,
-    ContentId, MessageNumber, ReportAccountInfo, ReportChatInfo, ReportChatInfoInteractionState,
-    ReportContent, ReportDetailed, ReportDetailedInfo, ReportDetailedInfoInternal,
-    ReportDetailedWithId, ReportIdDb, ReportInternal, ReportProcessingState, ReportTypeInternal,
-    UnixTime,
+    AccountId, AccountIdDb, AccountIdInternal, AccountInteractionInternal, BanEvasionEvidence,
+    ChatMessageReport, ContentId, MessageNumber, ReportAccountInfo, ReportChatInfo,
+    ReportChatInfoInteractionState, ReportContent, ReportDetailed, ReportDetailedInfo,
+    ReportDetailedInfoInternal, ReportDetailedWithId, ReportIdDb, ReportInternal,
+    ReportProcessingState, ReportTypeInternal, UnixTime,
 };
 use simple_backend_model::NonEmptyString;
 use simple_backend_utils::Result;
@@ -105,6 +106,7 @@ impl CurrentReadCommonReport<'_> {
         let mut profile_text = None;
         let mut profile_content = None;
         let mut chat_message = None;
+        let mut ban_evasion = None;
 
         match report.info.report_type {
             ReportTypeInternal::ProfileName => {
@@ -119,6 +121,7 @@ impl CurrentReadCommonReport<'_> {
             ReportTypeInternal::ChatMessage => {
                 chat_message = self.chat_message_report(report.id)?
             }
+            ReportTypeInternal::BanEvasion => ban_evasion = self.ban_evasion_report(report.id)?,
             ReportTypeInternal::CustomReport(_) => {
                 // Currently custom reports don't support any content
             }
@@ -130,6 +133,7 @@ impl CurrentReadCommonReport<'_> {
                 profile_text,
                 profile_content,
                 chat_message,
+                ban_evasion,
             },
             info: ReportDetailedInfo {
                 id: report.id.into(),
@@ -226,6 +230,25 @@ impl CurrentReadCommonReport<'_> {
         }
     }
 
+    fn ban_evasion_report(
+        &mut self,
+        id: ReportIdDb,
+    ) -> Result<Option<BanEvasionEvidence>, DieselDatabaseError> {
+        use crate::schema::common_report_ban_evasion::dsl::*;
+
+        let json: Option<String> = common_report_ban_evasion
+            .find(id)
+            .select(evidence_json)
+            .first(self.conn())
+            .optional()
+            .into_db_error(())?;
+
+        json.map(|json| {
+            serde_json::from_str(&json).change_context(DieselDatabaseError::SerdeDeserialize)
+        })
+        .transpose()
+    }
+
     pub fn get_report_account_info(
         &mut self,
         id: AccountIdDb,
@@ -4,6 +4,7 @@ use simple_backend_utils::{Result, db::DieselDatabaseError};
 
 use crate::{IntoDatabaseError, define_current_read_commands};
 
+mod ban_evasion;
 mod notification;
 mod report;
 mod statistics;
@@ -20,6 +21,9 @@ impl<'a> CurrentReadCommonAdmin<'a> {
     pub fn report(self) -> report::CurrentReadCommonAdminReport<'a> {
         report::CurrentReadCommonAdminReport::new(self.cmds)
     }
+    pub fn ban_evasion(self) -> ban_evasion::CurrentReadCommonAdminBanEvasion<'a> {
+        ban_evasion::CurrentReadCommonAdminBanEvasion::new(self.cmds)
+    }
 }
 
 impl CurrentReadCommonAdmin<'_> {
@@ -0,0 +1,359 @@
+use std::collections::{HashMap, HashSet};
+
+use config::Config;
+use diesel::prelude::*;
+use model::{
+    AccountIdDb, AccountIdInternal, BanEvasionCandidate, BanEvasionEvidence, ContentId,
+    IpAddressInternal, UnixTime,
+};
+use simple_backend_utils::Result;
+
+use crate::{DieselDatabaseError, IntoDatabaseError, define_current_read_commands};
+
+define_current_read_commands!(CurrentReadCommonAdminBanEvasion);
+
+#[derive(Clone, Copy)]
+enum SignalSource {
+    BannedAccounts,
+    Account(AccountIdDb),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum SignInWithIdentity {
+    Apple(String),
+    Google(String),
+    Oidc { provider: String, id: String },
+}
+
+#[derive(Default)]
+struct AccountSignals {
+    ip_addresses: HashSet<IpAddressInternal>,
+    ip_lists: HashSet<String>,
+    sign_in_with: HashSet<SignInWithIdentity>,
+    media_content: Vec<(ContentId, Vec<u8>)>,
+}
+
+impl CurrentReadCommonAdminBanEvasion<'_> {
+    /// Compare accounts created after `created_after` to all banned
+    /// accounts. Only linked accounts are returned.
+    pub fn find_ban_evasion_candidates(
+        &mut self,
+        created_after: UnixTime,
+        config: &Config,
+    ) -> Result<Vec<BanEvasionCandidate>, DieselDatabaseError> {
+        let new_accounts = self.new_accounts_which_are_not_banned(created_after)?;
+        if new_accounts.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let banned_accounts = self.banned_accounts()?;
+        if banned_accounts.is_empty() {
+            return Ok(vec![]);
+        }
+        let banned_signals = self.load_signals(SignalSource::BannedAccounts, config)?;
+
+        let mut candidates = vec![];
+        for target in new_accounts {
+            let Some(target_signals) = self
+                .load_signals(SignalSource::Account(target.into_db_id()), config)?
+                .remove(target.as_db_id())
+            else {
+                continue;
+            };
+
+            for banned_account in &banned_accounts {
+                let Some(signals) = banned_signals.get(banned_account.as_db_id()) else {
+                    continue;
+                };
+                let evidence = compare_signals(&target_signals, signals);
+                if evidence.accounts_linked() {
+                    candidates.push(BanEvasionCandidate {
+                        banned_account: *banned_account,
+                        target,
+                        evidence,
+                    });
+                }
+            }
+        }
+
+        Ok(candidates)
+    }
+
+    fn new_accounts_which_are_not_banned(
+        &mut self,
+        created_after: UnixTime,
+    ) -> Result<Vec<AccountIdInternal>, DieselDatabaseError> {
+        use crate::schema::{account_id, account_state, shared_state};
+
+        account_id::table
+            .inner_join(shared_state::table.on(shared_state::account_id.eq(account_id::id)))
+            .inner_join(account_state::table.on(account_state::account_id.eq(account_id::id)))
+            .filter(shared_state::account_state_banned.eq(false))
+            .filter(shared_state::bot_account_type_number.is_null())
+            .filter(account_state::account_created_unix_time.ge(created_after))
+            .select(AccountIdInternal::as_select())
+            .load(self.conn())
+            .into_db_error(())
+    }
+
+    fn banned_accounts(&mut self) -> Result<Vec<AccountIdInternal>, DieselDatabaseError> {
+        use crate::schema::{account_id, shared_state};
+
+        account_id::table
+            .inner_join(shared_state::table.on(shared_state::account_id.eq(account_id::id)))
+            .filter(shared_state::account_state_banned.eq(true))
+            .select(AccountIdInternal::as_select())
+            .load(self.conn())
+            .into_db_error(())
+    }
+
+    fn load_signals(
+        &mut self,
+        source: SignalSource,
+        config: &Config,
+    ) -> Result<HashMap<AccountIdDb, AccountSignals>, DieselDatabaseError> {
+        use crate::schema::{
+            ip_address_usage_statistics, media_content, shared_state, sign_in_with_info,
+        };
+
+        let banned_accounts = || {
+            shared_state::table
+                .filter(shared_state::account_state_banned.eq(true))
+                .select(shared_state::account_id)
+        };
+
+        let mut signals: HashMap<AccountIdDb, AccountSignals> = HashMap::new();
+        if let SignalSource::Account(id) = source {
+            signals.insert(id, AccountSignals::default());
+        }
+
+        let ip_addresses: Vec<(AccountIdDb, IpAddressInternal)> = {
+            let q = ip_address_usage_statistics::table
+                .select((
+                    ip_address_usage_statistics::account_id,
+                    ip_address_usage_statistics::ip_address,
+                ))
+                .into_boxed();
+            let q = match source {
+                SignalSource::BannedAccounts => {
+                    q.filter(ip_address_usage_statistics::account_id.eq_any(banned_accounts()))
+                }
+                SignalSource::Account(id) => {
+                    q.filter(ip_address_usage_statistics::account_id.eq(id))
+                }
+            };
+            q.load(self.conn()).into_db_error(())?
+        };
+
+        for (id, ip) in ip_addresses {
+            let s = signals.entry(id).or_default();
+            let ip_addr = ip.to_ip_addr();
+            for l in config.simple_backend().ip_lists() {
+                if l.contains(ip_addr) {
+                    s.ip_lists.insert(l.name().to_string());
+                }
+            }
+            s.ip_addresses.insert(ip);
+        }
+
+        type SignInWithRow = (
+            AccountIdDb,
+            Option<String>,
+            Option<String>,
+            Option<String>,
+            Option<String>,
+        );
+        let sign_in_with: Vec<SignInWithRow> = {
+            let q = sign_in_with_info::table
+                .select((
+                    sign_in_with_info::account_id,
+                    sign_in_with_info::apple_account_id,
+                    sign_in_with_info::google_account_id,
+                    sign_in_with_info::oidc_provider,
+                    sign_in_with_info::oidc_account_id,
+                ))
+                .into_boxed();
+            let q = match source {
+                SignalSource::BannedAccounts => {
+                    q.filter(sign_in_with_info::account_id.eq_any(banned_accounts()))
+                }
+                SignalSource::Account(id) => q.filter(sign_in_with_info::account_id.eq(id)),
+            };
+            q.load(self.conn()).into_db_error(())?
+        };
+
+        for (id, apple, google, oidc_provider, oidc_id) in sign_in_with {
+            let s = signals.entry(id).or_default();
+            if let Some(apple) = apple {
+                s.sign_in_with.insert(SignInWithIdentity::Apple(apple));
+            }
+            if let Some(google) = google {
+                s.sign_in_with.insert(SignInWithIdentity::Google(google));
+            }
+            if let (Some(provider), Some(id)) = (oidc_provider, oidc_id) {
+                s.sign_in_with
+                    .insert(SignInWithIdentity::Oidc { provider, id });
+            }
+        }
+
+        let media_content: Vec<(AccountIdDb, ContentId, Option<Vec<u8>>)> = {
+            let q = media_content::table
+                .filter(media_content::raw_content_sha256.is_not_null())
+                .select((
+                    media_content::account_id,
+                    media_content::uuid,
+                    media_content::raw_content_sha256,
+                ))
+                .into_boxed();
+            let q = match source {
+                SignalSource::BannedAccounts => {
+                    q.filter(media_content::account_id.eq_any(banned_accounts()))
+                }
+                SignalSource::Account(id) => q.filter(media_content::account_id.eq(id)),
+            };
+            q.load(self.conn()).into_db_error(())?
+        };
+
+        for (id, content_id, hash) in media_content {
+            if let Some(hash) = hash {
+                signals
+                    .entry(id)
+                    .or_default()
+                    .media_content
+                    .push((content_id, hash));
+            }
+        }
+
+        Ok(signals)
+    }
+}
+
+fn compare_signals(target: &AccountSignals, banned: &AccountSignals) -> BanEvasionEvidence {
+    let mut shared_ip_addresses: Vec<_> = target
+        .ip_addresses
+        .intersection(&banned.ip_addresses)
+        .map(|v| v.to_ip_addr())
+        .collect();
+    shared_ip_addresses.sort();
+
+    let mut shared_ip_lists: Vec<_> = target
+        .ip_lists
+        .intersection(&banned.ip_lists)
+        .cloned()
+        .collect();
+    shared_ip_lists.sort();
+
+    let banned_hashes: HashSet<&Vec<u8>> = banned.media_content.iter().map(|(_, h)| h).collect();
+
+    BanEvasionEvidence {
+        shared_ip_addresses: shared_ip_addresses
+            .into_iter()
+            .map(|v| v.to_string())
+            .collect(),
+        shared_ip_lists,
+        shared_sign_in_with_account: !target.sign_in_with.is_disjoint(&banned.sign_in_with),
+        identical_media_content: target
+            .media_content
+            .iter()
+            .filter(|(_, hash)| banned_hashes.contains(&hash))
+            .map(|(id, _)| *id)
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use super::*;
+
+    const SHARED_IP: IpAddr = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1));
+
+    fn signals() -> AccountSignals {
+        AccountSignals::default()
+    }
+
+    fn with_ip(mut signals: AccountSignals, ip: IpAddr) -> AccountSignals {
+        signals.ip_addresses.insert(ip.into());
+        signals
+    }
+
+    fn with_ip_list(mut signals: AccountSignals, list: &str) -> AccountSignals {
+        signals.ip_lists.insert(list.to_string());
+        signals
+    }
+
+    fn with_sign_in_with(mut signals: AccountSignals, id: &str) -> AccountSignals {
+        signals
+            .sign_in_with
+            .insert(SignInWithIdentity::Google(id.to_string()));
+        signals
+    }
+
+    fn with_media(mut signals: AccountSignals, hash: &[u8]) -> (AccountSignals, ContentId) {
+        let content_id = ContentId::new_random();
+        signals.media_content.push((content_id, hash.to_vec()));
+        (signals, content_id)
+    }
+
+    #[test]
+    fn no_shared_signals() {
+        let target = with_sign_in_with(with_ip(signals(), SHARED_IP), "a");
+        let banned = with_sign_in_with(
+            with_ip(signals(), IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2))),
+            "b",
+        );
+        let evidence = compare_signals(&target, &banned);
+        assert_eq!(evidence, BanEvasionEvidence::default());
+        assert!(!evidence.accounts_linked());
+    }
+
+    #[test]
+    fn shared_ip_address_alone_does_not_link_accounts() {
+        let evidence = compare_signals(
+            &with_ip(signals(), SHARED_IP),
+            &with_ip(signals(), SHARED_IP),
+        );
+        assert_eq!(evidence.shared_ip_addresses, vec![SHARED_IP.to_string()]);
+        assert!(!evidence.accounts_linked());
+    }
+
+    #[test]
+    fn shared_ip_list_alone_does_not_link_accounts() {
+        let evidence = compare_signals(
+            &with_ip_list(signals(), "vpn"),
+            &with_ip_list(signals(), "vpn"),
+        );
+        assert_eq!(evidence.shared_ip_lists, vec!["vpn".to_string()]);
+        assert!(!evidence.accounts_linked());
+    }
+
+    #[test]
+    fn shared_ip_address_and_ip_list_do_not_link_accounts() {
+        let evidence = compare_signals(
+            &with_ip_list(with_ip(signals(), SHARED_IP), "vpn"),
+            &with_ip_list(with_ip(signals(), SHARED_IP), "vpn"),
+        );
+        assert!(!evidence.accounts_linked());
+    }
+
+    #[test]
+    fn shared_sign_in_with_account_links_accounts() {
+        let evidence = compare_signals(
+            &with_sign_in_with(signals(), "a"),
+            &with_sign_in_with(signals(), "a"),
+        );
+        assert!(evidence.shared_sign_in_with_account);
+        assert!(evidence.accounts_linked());
+    }
+
+    #[test]
+    fn identical_media_content_links_accounts() {
+        let (target, target_content) = with_media(signals(), &[1, 2, 3]);
+        let (target, _) = with_media(target, &[4, 5, 6]);
+        let (banned, _) = with_media(signals(), &[1, 2, 3]);
+        let evidence = compare_signals(&target, &banned);
+        assert_eq!(evidence.identical_media_content, vec![target_content]);
+        assert!(evidence.accounts_linked());
+    }
+}
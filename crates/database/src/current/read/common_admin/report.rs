@@ -359,6 +359,8 @@ impl CurrentReadCommonAdminReport<'_> {
 
     /// Get the count of rejected reports for a single creator, excluding rejected
     /// reports against targets where the creator also has at least one accepted report.
+    ///
+    /// Ban evasion reports are excluded as server creates those.
     pub fn get_rejected_report_count_for_report_spammer_detection(
         &mut self,
         creator: AccountIdDb,
@@ -368,6 +370,7 @@ impl CurrentReadCommonAdminReport<'_> {
         // Get all reports (accepted and rejected) for this creator, grouped by target
         let results: Vec<(AccountIdDb, ReportProcessingState, i64)> = common_report
             .filter(creator_account_id.eq(creator))
+            .filter(report_type_number.ne(ReportTypeInternal::BanEvasion.db_value()))
             .filter(
                 processing_state
                     .eq_any(ReportProcessingState::rejected_states())
@@ -1,7 +1,7 @@
 use diesel::{insert_into, prelude::*, update};
 use model::{
     AccountIdInternal, PushNotificationDeviceToken, PushNotificationEncryptionKey,
-    PushNotificationFlagsDb, SyncVersion, UnixTime,
+    PushNotificationFlagsDb, PushNotificationProvider, SyncVersion, UnixTime,
 };
 use simple_backend_utils::{Result, db::MyRunQueryDsl};
 
@@ -53,6 +53,7 @@ impl CurrentWriteCommonPushNotification<'_> {
         &mut self,
         id: AccountIdInternal,
         token: PushNotificationDeviceToken,
+        provider: PushNotificationProvider,
     ) -> Result<PushNotificationEncryptionKey, DieselDatabaseError> {
         use model::schema::push_notification::dsl::*;
 
@@ -71,6 +72,7 @@ impl CurrentWriteCommonPushNotification<'_> {
         update(push_notification.find(id.as_db_id()))
             .set((
                 device_token.eq(token),
+                device_token_provider.eq(provider),
                 device_token_unix_time.eq(UnixTime::current_time()),
                 encryption_key.eq(notification_token.clone()),
             ))
@@ -1,9 +1,14 @@
-use diesel::{ExpressionMethods, prelude::*, update};
-use model::{AccountIdInternal, ReportIdDb, ReportProcessingState, UnixTime};
+use diesel::{ExpressionMethods, insert_into, prelude::*, update};
+use error_stack::ResultExt;
+use model::{
+    AccountIdInternal, BanEvasionEvidence, ReportIdDb, ReportProcessingState, ReportTypeInternal,
+    UnixTime,
+};
 use simple_backend_utils::Result;
 
 use crate::{
-    DieselDatabaseError, IntoDatabaseError, current::read::GetDbReadCommandsCommon,
+    DieselDatabaseError, IntoDatabaseError,
+    current::{read::GetDbReadCommandsCommon, write::GetDbWriteCommandsCommon},
     define_current_write_commands,
 };
 
@@ -44,4 +49,32 @@ impl CurrentWriteCommonAdminReport<'_> {
 
         Ok(())
     }
+
+    pub fn insert_ban_evasion_report(
+        &mut self,
+        banned_account: AccountIdInternal,
+        target: AccountIdInternal,
+        evidence: &BanEvasionEvidence,
+    ) -> Result<(), DieselDatabaseError> {
+        let json =
+            serde_json::to_string(evidence).change_context(DieselDatabaseError::SerdeSerialize)?;
+
+        let id = self.write().common().report().insert_report_content(
+            banned_account,
+            target,
+            ReportTypeInternal::BanEvasion,
+            ReportProcessingState::Waiting,
+        )?;
+
+        {
+            use model::schema::common_report_ban_evasion::dsl::*;
+
+            insert_into(common_report_ban_evasion)
+                .values((report_id.eq(id), evidence_json.eq(json)))
+                .execute(self.conn())
+                .into_db_error((banned_account, target))?;
+        }
+
+        Ok(())
+    }
 }
@@ -1,4 +1,5 @@
-use diesel::{RunQueryDsl, sql_query, sql_types::Text};
+use diesel::{prelude::*, sql_query, sql_types::Text};
+use model::{AdminAuditLogEntryInternal, AdminAuditLogPage, AdminAuditLogQuery};
 use simple_backend_database::diesel_db::DieselDatabaseError;
 use simple_backend_utils::Result;
 
@@ -26,3 +27,42 @@ impl HistoryReadCommon<'_> {
         Ok(())
     }
 }
+
+impl HistoryReadCommon<'_> {
+    pub fn admin_audit_log_page(
+        &mut self,
+        query: AdminAuditLogQuery,
+    ) -> Result<AdminAuditLogPage, DieselDatabaseError> {
+        use crate::schema::history_admin_audit_log::dsl::*;
+
+        const PAGE_SIZE: i64 = 25;
+
+        let mut db_query = history_admin_audit_log
+            .filter(unix_time.le(query.start_position))
+            .into_boxed();
+        if let Some(admin) = query.admin {
+            db_query = db_query.filter(admin_account.eq(admin));
+        }
+        if let Some(account) = query.target_account {
+            db_query = db_query.filter(target_account.eq(account));
+        }
+        if let Some(content) = query.target_content {
+            db_query = db_query.filter(target_content.eq(content));
+        }
+        if let Some(wanted_action) = query.action {
+            db_query = db_query.filter(action.eq(wanted_action));
+        }
+
+        let entries: Vec<AdminAuditLogEntryInternal> = db_query
+            .select(AdminAuditLogEntryInternal::as_select())
+            .order(id.desc())
+            .limit(PAGE_SIZE)
+            .offset(PAGE_SIZE.saturating_mul(query.page))
+            .load(self.conn())
+            .into_db_error(())?;
+
+        Ok(AdminAuditLogPage {
+            entries: entries.into_iter().map(Into::into).collect(),
+        })
+    }
+}
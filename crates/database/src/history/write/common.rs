@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use diesel::{ExpressionMethods, insert_into};
-use model::{StatisticsSaveTimeId, UnixTime};
+use model::{NewAdminAuditLogEntry, StatisticsSaveTimeId, UnixTime};
 use simple_backend_database::diesel_db::DieselDatabaseError;
 use simple_backend_model::{IpCountryCounters, IpCountryKey, MetricKey, PerfMetricValueArea};
 use simple_backend_utils::{Result, db::MyRunQueryDsl};
@@ -129,4 +129,24 @@ impl HistoryWriteCommon<'_> {
 
         Ok(())
     }
+
+    pub fn write_admin_audit_log_entry(
+        &mut self,
+        entry: NewAdminAuditLogEntry,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::history_admin_audit_log::dsl::*;
+        insert_into(history_admin_audit_log)
+            .values((
+                unix_time.eq(UnixTime::current_time()),
+                admin_account.eq(entry.admin),
+                action.eq(entry.action),
+                target_account.eq(entry.target_account),
+                target_content.eq(entry.target_content),
+                parameters.eq(entry.parameters),
+                ip_address.eq(entry.ip_address),
+            ))
+            .execute_my_conn(self.conn())
+            .into_db_error(())?;
+        Ok(())
+    }
 }
@@ -13,6 +13,7 @@ mod news;
 mod notification;
 mod report;
 mod sign_in_with;
+mod totp;
 mod verification;
 
 impl<'a> CurrentReadAccount<'a> {
@@ -60,6 +61,10 @@ impl<'a> CurrentReadAccount<'a> {
         notification::CurrentReadAccountNotification::new(self.cmds)
     }
 
+    pub fn totp(self) -> totp::CurrentReadAccountTotp<'a> {
+        totp::CurrentReadAccountTotp::new(self.cmds)
+    }
+
     pub fn verification(self) -> verification::CurrentReadAccountVerification<'a> {
         verification::CurrentReadAccountVerification::new(self.cmds)
     }
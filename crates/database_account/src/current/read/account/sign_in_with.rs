@@ -1,7 +1,9 @@
 use database::{DieselDatabaseError, define_current_read_commands};
 use diesel::prelude::*;
 use model::AccountIdInternal;
-use model_account::{AppleAccountId, GoogleAccountId, SignInWithHistoryEntry, SignInWithInfoRaw};
+use model_account::{
+    AppleAccountId, GoogleAccountId, OidcAccountId, SignInWithHistoryEntry, SignInWithInfoRaw,
+};
 use simple_backend_utils::Result;
 
 use crate::IntoDatabaseError;
@@ -39,6 +41,23 @@ impl CurrentReadAccountSignInWith<'_> {
             .into_db_error(google_id)
     }
 
+    pub fn oidc_account_id_to_account_id(
+        &mut self,
+        provider: &str,
+        oidc_id: OidcAccountId,
+    ) -> Result<Option<AccountIdInternal>, DieselDatabaseError> {
+        use crate::schema::{account_id, sign_in_with_info};
+
+        sign_in_with_info::table
+            .inner_join(account_id::table)
+            .filter(sign_in_with_info::oidc_provider.eq(provider))
+            .filter(sign_in_with_info::oidc_account_id.eq(&oidc_id))
+            .select(AccountIdInternal::as_select())
+            .first(self.conn())
+            .optional()
+            .into_db_error(oidc_id)
+    }
+
     pub fn sign_in_with_info_raw(
         &mut self,
         id: AccountIdInternal,
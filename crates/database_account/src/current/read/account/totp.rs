@@ -0,0 +1,38 @@
+use database::{DieselDatabaseError, define_current_read_commands};
+use diesel::prelude::*;
+use model::AccountIdInternal;
+use model_account::AccountTotpInternal;
+use simple_backend_utils::Result;
+
+use crate::IntoDatabaseError;
+
+define_current_read_commands!(CurrentReadAccountTotp);
+
+impl CurrentReadAccountTotp<'_> {
+    pub fn totp(
+        &mut self,
+        id: AccountIdInternal,
+    ) -> Result<Option<AccountTotpInternal>, DieselDatabaseError> {
+        use crate::schema::account_totp::dsl::*;
+
+        account_totp
+            .filter(account_id.eq(id.as_db_id()))
+            .select(AccountTotpInternal::as_select())
+            .first(self.conn())
+            .optional()
+            .into_db_error(id)
+    }
+
+    pub fn totp_recovery_code_count(
+        &mut self,
+        id: AccountIdInternal,
+    ) -> Result<i64, DieselDatabaseError> {
+        use crate::schema::account_totp_recovery_code::dsl::*;
+
+        account_totp_recovery_code
+            .filter(account_id.eq(id.as_db_id()))
+            .count()
+            .get_result(self.conn())
+            .into_db_error(id)
+    }
+}
@@ -6,6 +6,7 @@ mod association;
 mod custom_email;
 mod login;
 mod news;
+mod search;
 
 impl<'a> CurrentReadAccountAdmin<'a> {
     pub fn association(self) -> association::CurrentReadAccountAssociationAdmin<'a> {
@@ -20,6 +21,10 @@ impl<'a> CurrentReadAccountAdmin<'a> {
         news::CurrentReadAccountNewsAdmin::new(self.cmds)
     }
 
+    pub fn search(self) -> search::CurrentReadAccountSearchAdmin<'a> {
+        search::CurrentReadAccountSearchAdmin::new(self.cmds)
+    }
+
     pub fn custom_email(self) -> custom_email::CurrentReadAccountCustomEmailAdmin<'a> {
         custom_email::CurrentReadAccountCustomEmailAdmin::new(self.cmds)
     }
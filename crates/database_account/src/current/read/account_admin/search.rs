@@ -0,0 +1,200 @@
+use database::{DieselDatabaseError, define_current_read_commands};
+use diesel::{
+    define_sql_function,
+    prelude::*,
+    sql_types::{Nullable, Text},
+};
+use model::{
+    AccountId, AccountIdDb, AccountStateRelatedSharedState, IpAddressInternal, Permissions,
+    UnixTime,
+};
+use model_account::{
+    AdminAccountSearchQuery, AdminAccountSearchResult, AdminAccountSearchResultEntry,
+    AdminAccountSearchState,
+};
+use simple_backend_utils::Result;
+
+use crate::IntoDatabaseError;
+
+const PAGE_SIZE: i64 = 25;
+
+// Make LIKE case-insensitive also on PostgreSQL.
+define_sql_function! { fn lower(x: Nullable<Text>) -> Nullable<Text> }
+
+define_current_read_commands!(CurrentReadAccountSearchAdmin);
+
+impl CurrentReadAccountSearchAdmin<'_> {
+    pub fn search_accounts(
+        &mut self,
+        query: &AdminAccountSearchQuery,
+    ) -> Result<AdminAccountSearchResult, DieselDatabaseError> {
+        use crate::schema::{
+            account_email_address_state, account_id, account_state, ip_address_usage_statistics,
+            profile, shared_state, sign_in_with_info,
+        };
+
+        let Some(offset) = query.page.checked_mul(PAGE_SIZE) else {
+            return Ok(AdminAccountSearchResult { entries: vec![] });
+        };
+
+        let permission_holders = if let Some(wanted) = &query.permissions {
+            Some(self.permission_holders(wanted)?)
+        } else {
+            None
+        };
+
+        let mut q = account_id::table
+            .inner_join(shared_state::table.on(account_id::id.eq(shared_state::account_id)))
+            .inner_join(account_state::table.on(account_id::id.eq(account_state::account_id)))
+            .left_outer_join(
+                account_email_address_state::table
+                    .on(account_id::id.eq(account_email_address_state::account_id)),
+            )
+            .left_outer_join(profile::table.on(account_id::id.eq(profile::account_id)))
+            .left_outer_join(
+                sign_in_with_info::table.on(account_id::id.eq(sign_in_with_info::account_id)),
+            )
+            .select((
+                account_id::uuid,
+                account_email_address_state::email.nullable(),
+                profile::profile_name.nullable(),
+                AccountStateRelatedSharedState::as_select(),
+                account_state::account_created_unix_time,
+            ))
+            .into_boxed();
+
+        if let Some(email) = &query.email {
+            q = q.filter(
+                lower(account_email_address_state::email)
+                    .like(lower(like_pattern(email)))
+                    .escape('\\'),
+            );
+        }
+
+        if let Some(name) = &query.profile_name {
+            q = q.filter(
+                lower(profile::profile_name)
+                    .like(lower(like_pattern(name)))
+                    .escape('\\'),
+            );
+        }
+
+        if let Some(id) = &query.sign_in_with_account_id {
+            q = q.filter(
+                sign_in_with_info::apple_account_id
+                    .eq(id)
+                    .or(sign_in_with_info::google_account_id.eq(id))
+                    .or(sign_in_with_info::oidc_account_id.eq(id)),
+            );
+        }
+
+        if let Some(ip) = query.ip_address {
+            q = q.filter(
+                account_id::id.eq_any(
+                    ip_address_usage_statistics::table
+                        .filter(
+                            ip_address_usage_statistics::ip_address.eq(IpAddressInternal::from(ip)),
+                        )
+                        .select(ip_address_usage_statistics::account_id),
+                ),
+            );
+        }
+
+        match query.state {
+            Some(AdminAccountSearchState::InitialSetup) => {
+                q = q.filter(shared_state::account_state_initial_setup_completed.eq(false));
+            }
+            Some(AdminAccountSearchState::Banned) => {
+                q = q.filter(shared_state::account_state_banned.eq(true));
+            }
+            Some(AdminAccountSearchState::PendingDeletion) => {
+                q = q.filter(shared_state::account_state_pending_deletion.eq(true));
+            }
+            None => (),
+        }
+
+        if let Some(ids) = permission_holders {
+            q = q.filter(account_id::id.eq_any(ids));
+        }
+
+        if let Some(time) = query.created_after {
+            q = q.filter(account_state::account_created_unix_time.ge(time));
+        }
+
+        if let Some(time) = query.created_before {
+            q = q.filter(account_state::account_created_unix_time.le(time));
+        }
+
+        let entries: Vec<(
+            AccountId,
+            Option<String>,
+            Option<String>,
+            AccountStateRelatedSharedState,
+            UnixTime,
+        )> = q
+            .order(account_id::id.asc())
+            .offset(offset)
+            .limit(PAGE_SIZE)
+            .load(self.conn())
+            .into_db_error(())?;
+
+        let entries = entries
+            .into_iter()
+            .map(
+                |(aid, email, profile_name, state, account_created_unix_time)| {
+                    AdminAccountSearchResultEntry {
+                        aid,
+                        email,
+                        profile_name,
+                        state: state.state_container(),
+                        account_created_unix_time,
+                    }
+                },
+            )
+            .collect();
+
+        Ok(AdminAccountSearchResult { entries })
+    }
+
+    /// Accounts which have all permissions enabled in `wanted` or
+    /// at least one permission if `wanted` has no permissions enabled.
+    fn permission_holders(
+        &mut self,
+        wanted: &Permissions,
+    ) -> Result<Vec<AccountIdDb>, DieselDatabaseError> {
+        use crate::schema::account_permissions;
+
+        let all: Vec<(AccountIdDb, Permissions)> = account_permissions::table
+            .select((account_permissions::account_id, Permissions::as_select()))
+            .load(self.conn())
+            .into_db_error(())?;
+
+        let ids = all
+            .into_iter()
+            .filter(|(_, permissions)| {
+                if wanted.some_permission_granted() {
+                    permissions.contains_all(wanted)
+                } else {
+                    permissions.some_permission_granted()
+                }
+            })
+            .map(|(id, _)| id)
+            .collect();
+
+        Ok(ids)
+    }
+}
+
+/// Create pattern for partial match with SQL LIKE operator which
+/// uses `\` as an escape character.
+fn like_pattern(text: &str) -> String {
+    let mut pattern = String::from("%");
+    for c in text.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            pattern.push('\\');
+        }
+        pattern.push(c);
+    }
+    pattern.push('%');
+    pattern
+}
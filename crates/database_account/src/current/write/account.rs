@@ -10,6 +10,7 @@ mod news;
 mod notification;
 mod report;
 mod sign_in_with;
+mod totp;
 mod verification;
 
 define_current_write_commands!(CurrentWriteAccount);
@@ -55,6 +56,10 @@ impl<'a> CurrentWriteAccount<'a> {
         notification::CurrentWriteAccountNotification::new(self.cmds)
     }
 
+    pub fn totp(self) -> totp::CurrentWriteAccountTotp<'a> {
+        totp::CurrentWriteAccountTotp::new(self.cmds)
+    }
+
     pub fn verification(self) -> verification::CurrentWriteAccountVerification<'a> {
         verification::CurrentWriteAccountVerification::new(self.cmds)
     }
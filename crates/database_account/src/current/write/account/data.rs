@@ -1,6 +1,6 @@
 use database::{DieselDatabaseError, define_current_write_commands};
 use diesel::{delete, insert_into, prelude::*, update};
-use model::{AccountCreatedTime, AccountId, AccountIdInternal};
+use model::{AccountCreatedTime, AccountId, AccountIdDb, AccountIdInternal};
 use model_account::{AccountGlobalState, EmailAddress, EmailAddressStateInternal};
 use simple_backend_utils::{Result, db::MyRunQueryDsl};
 
@@ -101,6 +101,36 @@ impl CurrentWriteAccountData<'_> {
         })
     }
 
+    /// Returns `None` if the account ID is already used.
+    pub fn new_account_id_if_unused(
+        &mut self,
+        aid: AccountId,
+    ) -> Result<Option<AccountIdInternal>, DieselDatabaseError> {
+        use model::schema::used_account_ids::dsl::*;
+
+        let existing: Option<AccountIdDb> = used_account_ids
+            .filter(uuid.eq(aid))
+            .select(id)
+            .first(self.conn())
+            .optional()
+            .into_db_error(aid)?;
+
+        if existing.is_some() {
+            return Ok(None);
+        }
+
+        let db_id = insert_into(used_account_ids)
+            .values(uuid.eq(aid))
+            .returning(id)
+            .get_result(self.conn())
+            .into_db_error(aid)?;
+
+        Ok(Some(AccountIdInternal {
+            id: db_id,
+            uuid: aid,
+        }))
+    }
+
     pub fn update_account_created_unix_time(
         &mut self,
         id: AccountIdInternal,
@@ -2,7 +2,7 @@ use database::{DieselDatabaseError, define_current_write_commands};
 use diesel::{delete, insert_into, prelude::*, update};
 use model::{AccountIdInternal, UnixTime};
 use model_account::{
-    AppleAccountId, GoogleAccountId, SignInWithInfo, SignInWithProviderTypeNumber,
+    AppleAccountId, GoogleAccountId, OidcAccountId, SignInWithInfo, SignInWithProviderTypeNumber,
 };
 use simple_backend_utils::{Result, db::MyRunQueryDsl};
 
@@ -96,6 +96,45 @@ impl CurrentWriteAccountSignInWith<'_> {
         Ok(())
     }
 
+    /// The `new_oidc_id` tuple contains the provider name and the account ID.
+    pub fn update_oidc_account_id(
+        &mut self,
+        id: AccountIdInternal,
+        new_oidc_id: Option<(String, OidcAccountId)>,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::sign_in_with_info::dsl::*;
+
+        let old_oidc_id: Option<(String, OidcAccountId)> = sign_in_with_info
+            .filter(account_id.eq(id.as_db_id()))
+            .select((oidc_provider, oidc_account_id))
+            .first::<(Option<String>, Option<OidcAccountId>)>(self.conn())
+            .optional()
+            .into_db_error(id)?
+            .and_then(|(provider, oidc_id)| provider.zip(oidc_id));
+
+        if old_oidc_id == new_oidc_id {
+            return Ok(());
+        }
+
+        let (new_provider, new_id) = new_oidc_id.clone().unzip();
+        update(sign_in_with_info.find(id.as_db_id()))
+            .set((oidc_provider.eq(new_provider), oidc_account_id.eq(new_id)))
+            .execute(self.conn())
+            .into_db_error(id)?;
+
+        let history_id =
+            |(provider, oidc_id): (String, OidcAccountId)| format!("{}:{}", provider, oidc_id.0);
+        self.insert_sign_in_with_history_entry(
+            id,
+            SignInWithProviderTypeNumber::Oidc,
+            old_oidc_id.map(history_id),
+            new_oidc_id.map(history_id),
+            UnixTime::current_time(),
+        )?;
+
+        Ok(())
+    }
+
     fn insert_sign_in_with_history_entry(
         &mut self,
         account: AccountIdInternal,
@@ -0,0 +1,105 @@
+use database::{DieselDatabaseError, define_current_write_commands};
+use diesel::{delete, insert_into, prelude::*, update};
+use model::AccountIdInternal;
+use simple_backend_utils::{Result, db::MyRunQueryDsl};
+
+use crate::IntoDatabaseError;
+
+define_current_write_commands!(CurrentWriteAccountTotp);
+
+impl CurrentWriteAccountTotp<'_> {
+    /// Replaces possible existing TOTP secret with a new disabled one
+    /// and deletes recovery codes.
+    pub fn upsert_pending_totp_secret(
+        &mut self,
+        id: AccountIdInternal,
+        new_secret: Vec<u8>,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::account_totp::dsl::*;
+
+        insert_into(account_totp)
+            .values((
+                account_id.eq(id.as_db_id()),
+                secret.eq(&new_secret),
+                enabled.eq(false),
+                last_used_time_step.eq(0),
+            ))
+            .on_conflict(account_id)
+            .do_update()
+            .set((
+                secret.eq(&new_secret),
+                enabled.eq(false),
+                last_used_time_step.eq(0),
+            ))
+            .execute_my_conn(self.conn())
+            .into_db_error(id)?;
+
+        self.replace_totp_recovery_codes(id, vec![])
+    }
+
+    pub fn update_totp_enabled_and_last_used_time_step(
+        &mut self,
+        id: AccountIdInternal,
+        value: bool,
+        time_step: i64,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::account_totp::dsl::*;
+
+        update(account_totp.find(id.as_db_id()))
+            .set((enabled.eq(value), last_used_time_step.eq(time_step)))
+            .execute(self.conn())
+            .into_db_error(id)?;
+
+        Ok(())
+    }
+
+    pub fn delete_totp(&mut self, id: AccountIdInternal) -> Result<(), DieselDatabaseError> {
+        use model::schema::account_totp::dsl::*;
+
+        delete(account_totp.find(id.as_db_id()))
+            .execute(self.conn())
+            .into_db_error(id)?;
+
+        self.replace_totp_recovery_codes(id, vec![])
+    }
+
+    pub fn replace_totp_recovery_codes(
+        &mut self,
+        id: AccountIdInternal,
+        code_hashes: Vec<Vec<u8>>,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::account_totp_recovery_code::dsl::*;
+
+        delete(account_totp_recovery_code.filter(account_id.eq(id.as_db_id())))
+            .execute(self.conn())
+            .into_db_error(id)?;
+
+        for hash in code_hashes {
+            insert_into(account_totp_recovery_code)
+                .values((account_id.eq(id.as_db_id()), code_hash.eq(hash)))
+                .execute(self.conn())
+                .into_db_error(id)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if the recovery code existed.
+    pub fn delete_totp_recovery_code(
+        &mut self,
+        id: AccountIdInternal,
+        hash: Vec<u8>,
+    ) -> Result<bool, DieselDatabaseError> {
+        use model::schema::account_totp_recovery_code::dsl::*;
+
+        let deleted_count = delete(
+            account_totp_recovery_code
+                .filter(account_id.eq(id.as_db_id()))
+                .filter(code_hash.eq(hash)),
+        )
+        .execute(self.conn())
+        .into_db_error(id)?;
+
+        Ok(deleted_count > 0)
+    }
+}
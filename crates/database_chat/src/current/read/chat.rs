@@ -5,6 +5,7 @@ use simple_backend_utils::Result;
 
 use crate::IntoDatabaseError;
 
+pub mod attachment;
 mod interaction;
 mod limits;
 mod message;
@@ -16,6 +17,10 @@ pub mod transfer;
 define_current_read_commands!(CurrentReadChat);
 
 impl<'a> CurrentReadChat<'a> {
+    pub fn attachment(self) -> attachment::CurrentReadChatAttachment<'a> {
+        attachment::CurrentReadChatAttachment::new(self.cmds)
+    }
+
     pub fn interaction(self) -> interaction::CurrentReadChatInteraction<'a> {
         interaction::CurrentReadChatInteraction::new(self.cmds)
     }
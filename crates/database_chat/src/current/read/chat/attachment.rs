@@ -0,0 +1,87 @@
+use database::{DieselDatabaseError, define_current_read_commands};
+use diesel::prelude::*;
+use model::{AccountIdDb, MessageId};
+use model_chat::AccountIdInternal;
+use simple_backend_utils::Result;
+
+use crate::IntoDatabaseError;
+
+define_current_read_commands!(CurrentReadChatAttachment);
+
+pub struct PendingMessageForAttachment {
+    pub pending_message_id: i64,
+    pub recipient: AccountIdDb,
+    pub attachment_exists: bool,
+}
+
+impl CurrentReadChatAttachment<'_> {
+    /// Find sent message which is waiting for sender and recipient
+    /// acknowledgement.
+    pub fn pending_message_for_attachment_upload(
+        &mut self,
+        id_message_sender: AccountIdInternal,
+        message: MessageId,
+    ) -> Result<Option<PendingMessageForAttachment>, DieselDatabaseError> {
+        use crate::schema::{pending_message_attachment, pending_messages};
+
+        let value: Option<(i64, AccountIdDb, Option<i64>)> = pending_messages::table
+            .left_join(pending_message_attachment::table)
+            .filter(pending_messages::account_id_sender.eq(id_message_sender.as_db_id()))
+            .filter(pending_messages::message_id.eq(&message))
+            .filter(pending_messages::sender_acknowledgement.eq(false))
+            .filter(pending_messages::recipient_acknowledgement.eq(false))
+            .select((
+                pending_messages::id,
+                pending_messages::account_id_recipient,
+                pending_message_attachment::pending_message_id.nullable(),
+            ))
+            .first(self.conn())
+            .optional()
+            .into_db_error(id_message_sender)?;
+
+        Ok(value.map(
+            |(pending_message_id, recipient, attachment)| PendingMessageForAttachment {
+                pending_message_id,
+                recipient,
+                attachment_exists: attachment.is_some(),
+            },
+        ))
+    }
+
+    /// Count of attachments which are waiting for sender or recipient
+    /// acknowledgement.
+    pub fn pending_attachment_count(
+        &mut self,
+        id_message_sender: AccountIdInternal,
+    ) -> Result<i64, DieselDatabaseError> {
+        use crate::schema::{pending_message_attachment, pending_messages};
+
+        pending_message_attachment::table
+            .inner_join(pending_messages::table)
+            .filter(pending_messages::account_id_sender.eq(id_message_sender.as_db_id()))
+            .count()
+            .get_result(self.conn())
+            .into_db_error(id_message_sender)
+    }
+
+    /// Get attachment for message which is waiting for sender or
+    /// recipient acknowledgement.
+    pub fn received_message_attachment(
+        &mut self,
+        id_message_recipient: AccountIdInternal,
+        id_message_sender: AccountIdInternal,
+        message: MessageId,
+    ) -> Result<Option<Vec<u8>>, DieselDatabaseError> {
+        use crate::schema::{pending_message_attachment, pending_messages};
+
+        pending_message_attachment::table
+            .inner_join(pending_messages::table)
+            .filter(pending_messages::account_id_recipient.eq(id_message_recipient.as_db_id()))
+            .filter(pending_messages::account_id_sender.eq(id_message_sender.as_db_id()))
+            .filter(pending_messages::message_id.eq(&message))
+            .select(pending_message_attachment::attachment_bytes)
+            .first(self.conn())
+            .optional()
+            .into_db_error((id_message_recipient, id_message_sender))
+    }
+}
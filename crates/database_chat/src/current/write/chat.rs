@@ -9,6 +9,7 @@ use simple_backend_utils::{ContextExt, Result, db::MyRunQueryDsl};
 
 use crate::{IntoDatabaseError, current::read::GetDbReadCommandsChat};
 
+mod attachment;
 mod interaction;
 mod limits;
 mod message;
@@ -20,6 +21,10 @@ pub mod transfer;
 define_current_write_commands!(CurrentWriteChat);
 
 impl<'a> CurrentWriteChat<'a> {
+    pub fn attachment(self) -> attachment::CurrentWriteChatAttachment<'a> {
+        attachment::CurrentWriteChatAttachment::new(self.cmds)
+    }
+
     pub fn interaction(self) -> interaction::CurrentWriteChatInteraction<'a> {
         interaction::CurrentWriteChatInteraction::new(self.cmds)
     }
@@ -0,0 +1,35 @@
+use database::{DieselDatabaseError, define_current_write_commands};
+use diesel::{insert_into, prelude::*};
+use model::UnixTime;
+use model_chat::AccountIdInternal;
+use simple_backend_utils::Result;
+
+use crate::IntoDatabaseError;
+
+define_current_write_commands!(CurrentWriteChatAttachment);
+
+impl CurrentWriteChatAttachment<'_> {
+    /// The attachment is removed automatically when the pending message
+    /// is removed.
+    pub fn insert_pending_message_attachment(
+        &mut self,
+        id_message_sender: AccountIdInternal,
+        pending_message_id_value: i64,
+        attachment: Vec<u8>,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::pending_message_attachment::dsl::*;
+
+        let time = UnixTime::current_time();
+
+        insert_into(pending_message_attachment)
+            .values((
+                pending_message_id.eq(pending_message_id_value),
+                upload_unix_time.eq(time),
+                attachment_bytes.eq(attachment),
+            ))
+            .execute(self.conn())
+            .into_db_error(id_message_sender)?;
+
+        Ok(())
+    }
+}
@@ -237,6 +237,7 @@ impl CurrentWriteMediaContent<'_> {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn insert_content_id(
         &mut self,
         content_uploader: AccountIdInternal,
@@ -245,6 +246,7 @@ impl CurrentWriteMediaContent<'_> {
         content_params: NewContentParams,
         face_detected_value: bool,
         face_detected_manual_value: Option<bool>,
+        raw_content_sha256_value: Vec<u8>,
     ) -> Result<(), DieselDatabaseError> {
         use model::schema::media_content::dsl::*;
 
@@ -275,6 +277,7 @@ impl CurrentWriteMediaContent<'_> {
                 initial_content.eq(initial_content_value),
                 creation_unix_time.eq(current_time),
                 moderation_state.eq(state_value),
+                raw_content_sha256.eq(raw_content_sha256_value),
             ))
             .execute(self.conn())
             .into_db_error((content_uploader, content_id))?;
@@ -207,7 +207,7 @@ impl CurrentReadProfileData<'_> {
         &mut self,
         id: AccountIdInternal,
     ) -> Result<Vec<ProfileAttributeFilterValue>, DieselDatabaseError> {
-        let data: Vec<(AttributeId, bool, bool)> = {
+        let data: Vec<(AttributeId, bool, bool, Option<i64>, Option<i64>)> = {
             use crate::schema::profile_attributes_filter_settings::dsl::*;
 
             profile_attributes_filter_settings
@@ -216,6 +216,8 @@ impl CurrentReadProfileData<'_> {
                     attribute_id,
                     filter_accept_missing_attribute,
                     filter_use_logical_operator_and,
+                    filter_min,
+                    filter_max,
                 ))
                 .load(self.conn())
                 .change_context(DieselDatabaseError::Execute)?
@@ -263,16 +265,20 @@ impl CurrentReadProfileData<'_> {
 
         let mut data: Vec<ProfileAttributeFilterValue> = data
             .into_iter()
-            .map(|(id, accept_missing_attribute, use_logical_operator_and)| {
-                let values = all_values.remove(&id).unwrap_or_default();
-                ProfileAttributeFilterValue::new(
-                    id,
-                    values.wanted,
-                    values.unwanted,
-                    accept_missing_attribute,
-                    use_logical_operator_and,
-                )
-            })
+            .map(
+                |(id, accept_missing_attribute, use_logical_operator_and, min, max)| {
+                    let values = all_values.remove(&id).unwrap_or_default();
+                    ProfileAttributeFilterValue::new(
+                        id,
+                        values.wanted,
+                        values.unwanted,
+                        min.map(|v| v as u32),
+                        max.map(|v| v as u32),
+                        accept_missing_attribute,
+                        use_logical_operator_and,
+                    )
+                },
+            )
             .collect();
 
         data.sort_by_key(|v| v.id());
@@ -303,6 +303,8 @@ impl CurrentWriteProfileData<'_> {
                         attribute_id.eq(a.id),
                         filter_accept_missing_attribute.eq(a.accept_missing_attribute),
                         filter_use_logical_operator_and.eq(a.use_logical_operator_and),
+                        filter_min.eq(a.min.map(i64::from)),
+                        filter_max.eq(a.max.map(i64::from)),
                     ))
                     .execute(self.conn())
                     .into_db_error(())?;
@@ -1,5 +1,6 @@
 use std::net::SocketAddr;
 
+use backup::RpcBackup;
 use error_stack::ResultExt;
 use manager_api::{
     ClientConfig, ManagerClient,
@@ -20,6 +21,7 @@ use crate::{
     server::app::S,
 };
 
+pub mod backup;
 pub mod scheduled_task;
 pub mod secure_storage;
 pub mod software;
@@ -40,6 +42,8 @@ pub enum JsonRpcError {
     ScheduledTaskManager,
     #[error("Update manager error")]
     UpdateManager,
+    #[error("Backup snapshot listing failed")]
+    BackupSnapshots,
 }
 
 pub async fn handle_json_rpc<C: ClientConnectionReadWrite>(
@@ -134,5 +138,6 @@ pub async fn handle_request_type(
         }
         JsonRpcRequestType::UnscheduleTask(task) => state.rpc_unschedule_task(task).await,
         JsonRpcRequestType::GetScheduledTasksStatus => state.rpc_get_scheduled_tasks_status().await,
+        JsonRpcRequestType::GetBackupSnapshots => state.rpc_get_backup_snapshots().await,
     }
 }
@@ -0,0 +1,17 @@
+use error_stack::ResultExt;
+use manager_model::JsonRpcResponse;
+use simple_backend_utils::Result;
+
+use super::JsonRpcError;
+use crate::{api::GetConfig, server::link::backup::target::ListFileBackups};
+
+pub trait RpcBackup: GetConfig {
+    async fn rpc_get_backup_snapshots(&self) -> Result<JsonRpcResponse, JsonRpcError> {
+        let snapshots = ListFileBackups::run(self.config())
+            .await
+            .change_context(JsonRpcError::BackupSnapshots)?;
+        Ok(JsonRpcResponse::backup_snapshots(snapshots))
+    }
+}
+
+impl<T: GetConfig> RpcBackup for T {}
@@ -74,6 +74,13 @@ pub async fn handle_api_client_mode(args: ManagerApiClientMode) -> Result<(), Cl
                 .change_context(ClientError::RemoteApiRequest)?;
             println!("{info:#?}");
         }
+        ApiCommand::BackupSnapshots => {
+            let list = client
+                .get_backup_snapshots()
+                .await
+                .change_context(ClientError::RemoteApiRequest)?;
+            println!("{list:#?}");
+        }
         ApiCommand::SoftwareDownload => client
             .trigger_software_update_task(SoftwareUpdateTaskType::Download)
             .await
@@ -14,7 +14,7 @@ use manager_model::{
     AccountAndContent, BackupMessage, BackupMessageType, ContentQueryAnswer, Sha256Bytes,
     SourceToTargetMessage, TargetToSourceMessage,
 };
-use simple_backend_model::ContentQualityVariant;
+use simple_backend_model::ContentFileVariant;
 use simple_backend_utils::{ContextExt, IntoReportFromString, Result};
 use tokio::{sync::mpsc, task::JoinHandle};
 use tracing::{error, info, warn};
@@ -25,9 +25,12 @@ use crate::{
 };
 
 mod backup;
+mod retention;
+
+pub use backup::ListFileBackups;
 
 #[derive(thiserror::Error, Debug)]
-enum BackupTargetError {
+pub enum BackupTargetError {
     #[error("Reading error")]
     Read,
 
@@ -76,9 +79,6 @@ enum BackupTargetError {
     #[error("File rename")]
     FileRename,
 
-    #[error("Time related error")]
-    Time,
-
     #[error("Content data corruption detected")]
     ContentDataCorruptionDetected,
 }
@@ -357,9 +357,12 @@ impl BackupSessionTaskTarget {
 
             for a in &m {
                 let mut content_state = backup.update_account_content_backup(a.account_id).await?;
-                for &c in &a.content_ids {
+                for content in &a.content {
+                    let c = content.content_id;
                     let mut needed_variants = HashSet::new();
-                    for &v in &ContentQualityVariant::all_variants() {
+                    for v in ContentFileVariant::all_variants_for_formats(
+                        content.formats.iter().copied(),
+                    ) {
                         if content_state.exists_variant(c, v) {
                             content_state.mark_as_still_existing(c, v);
                         } else {
@@ -437,7 +440,7 @@ impl BackupSessionTaskTarget {
 
     pub async fn receive_content(
         &mut self,
-        needed: &HashSet<ContentQualityVariant>,
+        needed: &HashSet<ContentFileVariant>,
     ) -> Result<ContentQueryAnswer, BackupTargetError> {
         let Some(m) = self.receiver.recv().await else {
             return Err(BackupTargetError::BrokenMessageChannel.report());
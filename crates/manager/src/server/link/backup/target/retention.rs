@@ -0,0 +1,161 @@
+//! Grandfather-father-son retention for database backups
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{Datelike, NaiveDateTime};
+use manager_config::file::FileBackupRetentionConfig;
+
+const FILE_BACKUP_NAME_PREFIX: &str = "backup_";
+pub const FILE_BACKUP_TIME_FORMAT: &str = "%Y-%m-%d_%H-%M-%S";
+/// Length of time text created with [FILE_BACKUP_TIME_FORMAT]
+const FILE_BACKUP_TIME_TEXT_LEN: usize = 19;
+
+pub struct FileBackupName<'a> {
+    pub database: &'a str,
+    pub time: NaiveDateTime,
+}
+
+impl<'a> FileBackupName<'a> {
+    /// Parse `backup_{database}_{time}` file name. Checksum files
+    /// and other files return `None`.
+    pub fn parse(name: &'a str) -> Option<Self> {
+        let name = name.strip_prefix(FILE_BACKUP_NAME_PREFIX)?;
+        let time_start = name.len().checked_sub(FILE_BACKUP_TIME_TEXT_LEN)?;
+        let (database, time) = name.split_at_checked(time_start)?;
+        let database = database.strip_suffix('_')?;
+        if database.is_empty() {
+            return None;
+        }
+        let time = NaiveDateTime::parse_from_str(time, FILE_BACKUP_TIME_FORMAT).ok()?;
+        Some(Self { database, time })
+    }
+}
+
+/// Returns backup file names which are not part of any retention tier.
+/// Each database is handled separately. File names which are not
+/// backup file names are ignored.
+pub fn file_backups_to_delete<'a>(
+    names: impl IntoIterator<Item = &'a str>,
+    config: FileBackupRetentionConfig,
+) -> Vec<&'a str> {
+    let mut databases: HashMap<&str, Vec<(NaiveDateTime, &str)>> = HashMap::new();
+    for name in names {
+        if let Some(backup) = FileBackupName::parse(name) {
+            databases
+                .entry(backup.database)
+                .or_default()
+                .push((backup.time, name));
+        }
+    }
+
+    let mut to_delete = vec![];
+    for mut backups in databases.into_values() {
+        // Latest first
+        backups.sort_by(|a, b| b.cmp(a));
+
+        let mut keep = HashSet::new();
+        keep_latest_for_each_period(&backups, config.daily, &mut keep, |t| {
+            (t.year(), t.ordinal())
+        });
+        keep_latest_for_each_period(&backups, config.weekly, &mut keep, |t| {
+            let week = t.iso_week();
+            (week.year(), week.week())
+        });
+        keep_latest_for_each_period(&backups, config.monthly, &mut keep, |t| {
+            (t.year(), t.month())
+        });
+
+        to_delete.extend(
+            backups
+                .into_iter()
+                .map(|(_, name)| name)
+                .filter(|name| !keep.contains(name)),
+        );
+    }
+
+    to_delete.sort_unstable();
+    to_delete
+}
+
+fn keep_latest_for_each_period<'a>(
+    latest_first: &[(NaiveDateTime, &'a str)],
+    count: u16,
+    keep: &mut HashSet<&'a str>,
+    period: impl Fn(&NaiveDateTime) -> (i32, u32),
+) {
+    let mut periods = HashSet::new();
+    for (time, name) in latest_first {
+        if periods.len() >= usize::from(count) {
+            break;
+        }
+        if periods.insert(period(time)) {
+            keep.insert(*name);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(daily: u16, weekly: u16, monthly: u16) -> FileBackupRetentionConfig {
+        FileBackupRetentionConfig {
+            daily,
+            weekly,
+            monthly,
+        }
+    }
+
+    #[test]
+    fn parse_file_backup_name() {
+        let backup = FileBackupName::parse("backup_current_2025-01-31_03-04-05").unwrap();
+        assert_eq!(backup.database, "current");
+        assert_eq!(
+            backup.time.format(FILE_BACKUP_TIME_FORMAT).to_string(),
+            "2025-01-31_03-04-05"
+        );
+        assert!(FileBackupName::parse("backup_current_2025-01-31_03-04-05.sha256").is_none());
+        assert!(FileBackupName::parse("backup__2025-01-31_03-04-05").is_none());
+        assert!(FileBackupName::parse("file.tmp").is_none());
+    }
+
+    #[test]
+    fn daily_tier_keeps_latest_backup_for_each_day() {
+        let names = [
+            "backup_current_2025-01-01_01-00-00",
+            "backup_current_2025-01-01_02-00-00",
+            "backup_current_2025-01-02_01-00-00",
+            "backup_current_2025-01-03_01-00-00",
+        ];
+        let deleted = file_backups_to_delete(names, config(2, 0, 0));
+        assert_eq!(
+            deleted,
+            [
+                "backup_current_2025-01-01_01-00-00",
+                "backup_current_2025-01-01_02-00-00",
+            ]
+        );
+    }
+
+    #[test]
+    fn tiers_are_combined_and_databases_are_separate() {
+        let names = [
+            "backup_current_2024-11-15_01-00-00",
+            "backup_current_2024-12-15_01-00-00",
+            "backup_current_2025-01-06_01-00-00",
+            "backup_current_2025-01-07_01-00-00",
+            "backup_history_2025-01-07_01-00-00",
+            "backup_current_2025-01-07_01-00-00.sha256",
+        ];
+        // Daily keeps 01-07, weekly keeps 01-07 and monthly keeps
+        // 01-07 and 12-15.
+        let deleted = file_backups_to_delete(names, config(1, 1, 2));
+        assert_eq!(
+            deleted,
+            [
+                "backup_current_2024-11-15_01-00-00",
+                "backup_current_2025-01-06_01-00-00",
+            ]
+        );
+    }
+}
@@ -10,25 +10,33 @@ use std::{
 use archive::extract_backend_binary;
 use backend::BackendUtils;
 use error_stack::{IntoReport, ResultExt};
-use github::GitHubApi;
-use manager_config::{Config, file::SoftwareUpdateConfig};
+use github::{GitHubApi, SignedReleaseAsset};
+use health_check::BackendHealthCheck;
+use https::{HttpsReleaseSource, ReleaseManifest};
+use manager_config::file::{AutomaticRollbackConfig, SoftwareUpdateConfig};
 use manager_model::{
-    SoftwareInfo, SoftwareUpdateState, SoftwareUpdateStatus, SoftwareUpdateTaskType,
+    SoftwareInfo, SoftwareRollback, SoftwareUpdateError, SoftwareUpdateState, SoftwareUpdateStatus,
+    SoftwareUpdateTaskType,
 };
 use sha2::Digest;
+use signature::verify_signature;
+use simple_backend_model::UnixTime;
 use simple_backend_utils::{ContextExt, Result};
 use tokio::{sync::Mutex, task::JoinHandle};
 use tracing::{error, info, warn};
 
 use super::{ServerQuitWatcher, app::S};
 use crate::{
-    api::GetConfig,
+    api::{GetBackendManager, GetConfig},
     utils::{InProgressChannel, InProgressReceiver, InProgressSender},
 };
 
 pub mod archive;
 pub mod backend;
 pub mod github;
+pub mod health_check;
+pub mod https;
+pub mod signature;
 
 #[derive(thiserror::Error, Debug)]
 pub enum UpdateError {
@@ -101,6 +109,33 @@ pub enum UpdateError {
     #[error("Software downaload failed")]
     SoftwareDownloadFailed,
 
+    #[error("Software download failed. Signature file not found.")]
+    SoftwareDownloadFailedSignatureNotFound,
+
+    #[error("Software download failed. SHA-256 does not match.")]
+    SoftwareDownloadFailedSha256Mismatch,
+
+    #[error("HTTPS release source related error")]
+    HttpsReleaseSource,
+
+    #[error("Signature verification failed")]
+    SignatureVerificationFailed,
+
+    #[error("Invalid signature public key")]
+    InvalidSignaturePublicKey,
+
+    #[error("Backend controlling related config is missing")]
+    ControlBackendConfigMissing,
+
+    #[error("Backend manager error")]
+    BackendManager,
+
+    #[error("Backend health check failed")]
+    HealthCheckFailed,
+
+    #[error("Backend health check failed and previous backend was restored")]
+    HealthCheckFailedAndRolledBack,
+
     #[error("Blocking task failed")]
     BlockingTaskFailed,
 
@@ -312,7 +347,8 @@ impl UpdateManagerInternal {
         self.set_internal_state_to(SoftwareUpdateState::Downloading)
             .await;
         let r = self.software_download_impl().await;
-        self.set_internal_state_to(SoftwareUpdateState::Idle).await;
+        self.set_internal_state_to_idle(&r, SoftwareUpdateError::DownloadFailed)
+            .await;
         r
     }
 
@@ -320,7 +356,8 @@ impl UpdateManagerInternal {
         self.set_internal_state_to(SoftwareUpdateState::Installing)
             .await;
         let r = self.software_install_impl(info).await;
-        self.set_internal_state_to(SoftwareUpdateState::Idle).await;
+        self.set_internal_state_to_idle(&r, SoftwareUpdateError::InstallFailed)
+            .await;
         r
     }
 
@@ -329,19 +366,36 @@ impl UpdateManagerInternal {
         state.state = new_state;
     }
 
-    async fn software_download_impl(&self) -> Result<(), UpdateError> {
-        let github_api = GitHubApi {
-            client: &self.client,
-            updater_config: &self.config,
-            user_agent: &self.user_agent,
+    async fn set_internal_state_to_idle(
+        &self,
+        result: &Result<(), UpdateError>,
+        task_error: SoftwareUpdateError,
+    ) {
+        let mut state = self.internal_state.lock().await;
+        state.state = SoftwareUpdateState::Idle;
+        state.last_error = match result {
+            Ok(()) => None,
+            Err(e) => Some(match e.current_context() {
+                UpdateError::SignatureVerificationFailed => {
+                    SoftwareUpdateError::SignatureVerificationFailed
+                }
+                UpdateError::HealthCheckFailedAndRolledBack => {
+                    SoftwareUpdateError::HealthCheckFailedAndRolledBack
+                }
+                _ => task_error,
+            }),
         };
+    }
 
-        let Some(asset) = github_api.get_latest_release_asset().await? else {
-            return Err(UpdateError::SoftwareDownloadFailedNoMatchingFile.into_report());
-        };
+    async fn software_download_impl(&self) -> Result<(), UpdateError> {
+        let release = self.get_latest_release().await?;
 
         if let Some(downloaded) = self.update_dir().downloaded_backend_info().await?
-            && downloaded.name == asset.name
+            && downloaded.name == release.name()
+            && self
+                .verify_downloaded_backend_signature(Some(release.file_name()?))
+                .await
+                .is_ok()
         {
             info!("Already downloaded");
             return Ok(());
@@ -353,14 +407,32 @@ impl UpdateManagerInternal {
 
         self.internal_state.lock().await.downloaded = None;
 
-        github_api
-            .download_asset(&asset, self.update_dir().downloaded_backend_path())
-            .await?;
+        self.download_release(&release).await?;
+
+        if let Err(e) = self
+            .verify_downloaded_backend_signature(Some(release.file_name()?))
+            .await
+        {
+            self.update_dir()
+                .remove_downloaded_backend_and_info_json()
+                .await?;
+            return Err(e);
+        }
 
         let sha256 = self.update_dir().calculate_backend_sha256().await?;
 
+        if let LatestRelease::Https(manifest) = &release
+            && !manifest.sha256.eq_ignore_ascii_case(&sha256)
+        {
+            self.update_dir()
+                .remove_downloaded_backend_and_info_json()
+                .await?;
+            return Err(UpdateError::SoftwareDownloadFailedSha256Mismatch.into_report())
+                .attach(format!("expected: {}, actual: {}", manifest.sha256, sha256));
+        }
+
         let info = SoftwareInfo {
-            name: asset.name,
+            name: release.name().to_string(),
             sha256,
         };
 
@@ -375,9 +447,79 @@ impl UpdateManagerInternal {
         Ok(())
     }
 
+    async fn get_latest_release(&self) -> Result<LatestRelease, UpdateError> {
+        if let Some(config) = &self.config.github {
+            let github_api = GitHubApi {
+                client: &self.client,
+                config,
+                user_agent: &self.user_agent,
+            };
+            let Some(signed_asset) = github_api.get_latest_release_asset().await? else {
+                return Err(UpdateError::SoftwareDownloadFailedNoMatchingFile.into_report());
+            };
+            Ok(LatestRelease::GitHub(signed_asset))
+        } else if let Some(config) = &self.config.https {
+            let source = HttpsReleaseSource::new(config, &self.user_agent)?;
+            let manifest = source.get_release_manifest().await?;
+            Ok(LatestRelease::Https(manifest))
+        } else {
+            Err(UpdateError::SoftwareUpdaterConfigMissing.into_report())
+        }
+    }
+
+    /// Download release file and its signature
+    async fn download_release(&self, release: &LatestRelease) -> Result<(), UpdateError> {
+        match release {
+            LatestRelease::GitHub(signed_asset) => {
+                let config = self
+                    .config
+                    .github
+                    .as_ref()
+                    .ok_or(UpdateError::SoftwareUpdaterConfigMissing.report())?;
+                let github_api = GitHubApi {
+                    client: &self.client,
+                    config,
+                    user_agent: &self.user_agent,
+                };
+                github_api
+                    .download_asset(
+                        &signed_asset.asset,
+                        self.update_dir().downloaded_backend_path(),
+                    )
+                    .await?;
+                github_api
+                    .download_asset(
+                        &signed_asset.signature,
+                        self.update_dir().downloaded_backend_signature_path(),
+                    )
+                    .await?;
+            }
+            LatestRelease::Https(manifest) => {
+                let config = self
+                    .config
+                    .https
+                    .as_ref()
+                    .ok_or(UpdateError::SoftwareUpdaterConfigMissing.report())?;
+                let source = HttpsReleaseSource::new(config, &self.user_agent)?;
+                source
+                    .download_file(manifest, self.update_dir().downloaded_backend_path())
+                    .await?;
+                tokio::fs::write(
+                    self.update_dir().downloaded_backend_signature_path(),
+                    &manifest.signature,
+                )
+                .await
+                .change_context(UpdateError::FileWritingFailed)?;
+            }
+        }
+
+        Ok(())
+    }
+
     async fn software_install_impl(&self, info: SoftwareInfo) -> Result<(), UpdateError> {
-        if let Some(installed) = self.update_dir().installed_backend_info().await?
-            && info == installed
+        let previous = self.update_dir().installed_backend_info().await?;
+        if let Some(installed) = &previous
+            && info == *installed
         {
             info!("Already installed");
             return Ok(());
@@ -391,8 +533,12 @@ impl UpdateManagerInternal {
             return Err(UpdateError::SelectedVersionNotFound.report());
         }
 
+        // Downloaded files might have been modified after downloading.
+        // File name is checked when downloading.
+        self.verify_downloaded_backend_signature(None).await?;
+
         let backend_binary =
-            if let Some(archive_file_path) = &self.config.github.archive_backend_binary_path {
+            if let Some(archive_file_path) = self.config.archive_backend_binary_path() {
                 let extracted = self.update_dir().extracted_backend_path();
                 extract_backend_binary(
                     self.update_dir().downloaded_backend_path(),
@@ -413,11 +559,61 @@ impl UpdateManagerInternal {
         UpdateDirUtils::save_info_json(&info, self.update_dir().installed_backend_info_json_path())
             .await?;
 
-        self.internal_state.lock().await.installed = Some(info);
+        self.internal_state.lock().await.installed = Some(info.clone());
+
+        if let Some(rollback_config) = &self.config.automatic_rollback {
+            self.restart_backend_and_rollback_if_needed(rollback_config, info, previous)
+                .await?;
+        }
 
         Ok(())
     }
 
+    async fn restart_backend_and_rollback_if_needed(
+        &self,
+        rollback_config: &AutomaticRollbackConfig,
+        installed: SoftwareInfo,
+        previous: Option<SoftwareInfo>,
+    ) -> Result<(), UpdateError> {
+        let control_backend = self
+            .state
+            .config()
+            .control_backend()
+            .ok_or(UpdateError::ControlBackendConfigMissing.report())?;
+
+        let control = ManagerBackendControl {
+            state: &self.state,
+            health_check: BackendHealthCheck {
+                client: &self.client,
+                config: rollback_config,
+                control_backend,
+            },
+        };
+
+        restart_backend_and_rollback_if_needed(
+            &control,
+            self.backend_utils(),
+            self.update_dir(),
+            &self.internal_state,
+            installed,
+            previous,
+        )
+        .await
+    }
+
+    async fn verify_downloaded_backend_signature(
+        &self,
+        expected_file_name: Option<&str>,
+    ) -> Result<(), UpdateError> {
+        verify_signature(
+            self.config.signature_public_keys.clone(),
+            expected_file_name.map(|v| v.to_string()),
+            self.update_dir().downloaded_backend_path(),
+            self.update_dir().downloaded_backend_signature_path(),
+        )
+        .await
+    }
+
     fn backend_utils(&self) -> BackendUtils<'_> {
         BackendUtils {
             config: &self.config,
@@ -426,18 +622,133 @@ impl UpdateManagerInternal {
 
     fn update_dir(&self) -> UpdateDirUtils<'_> {
         UpdateDirUtils {
-            config: self.state.config(),
+            storage_dir: self.state.config().storage_dir(),
+        }
+    }
+}
+
+/// Backend controlling for automatic rollback
+trait BackendControl {
+    async fn stop_backend(&self) -> Result<(), UpdateError>;
+    async fn start_backend(&self) -> Result<(), UpdateError>;
+    async fn health_check(&self) -> Result<(), UpdateError>;
+}
+
+struct ManagerBackendControl<'a> {
+    state: &'a S,
+    health_check: BackendHealthCheck<'a>,
+}
+
+impl BackendControl for ManagerBackendControl<'_> {
+    async fn stop_backend(&self) -> Result<(), UpdateError> {
+        self.state
+            .backend_manager()
+            .stop_backend()
+            .await
+            .change_context(UpdateError::BackendManager)
+    }
+
+    async fn start_backend(&self) -> Result<(), UpdateError> {
+        self.state
+            .backend_manager()
+            .start_backend()
+            .await
+            .change_context(UpdateError::BackendManager)
+    }
+
+    async fn health_check(&self) -> Result<(), UpdateError> {
+        self.health_check.run().await
+    }
+}
+
+/// Restart backend and restore the previous backend binary if
+/// the health check fails.
+async fn restart_backend_and_rollback_if_needed(
+    control: &impl BackendControl,
+    backend_utils: BackendUtils<'_>,
+    update_dir: UpdateDirUtils<'_>,
+    internal_state: &Mutex<SoftwareUpdateStatus>,
+    installed: SoftwareInfo,
+    previous: Option<SoftwareInfo>,
+) -> Result<(), UpdateError> {
+    info!("Restarting backend");
+    control.stop_backend().await?;
+    control.start_backend().await?;
+
+    let Err(e) = control.health_check().await else {
+        return Ok(());
+    };
+
+    error!("Backend health check failed. Error: {:?}", e);
+
+    control.stop_backend().await?;
+
+    let restored = backend_utils
+        .restore_previous_backend_binary()
+        .await
+        .change_context(UpdateError::BackendUtils)?;
+    if !restored {
+        return Err(e).attach("Rollback failed. Previous backend binary not found.");
+    }
+
+    info!("Previous backend binary restored");
+
+    match &previous {
+        Some(previous) => {
+            UpdateDirUtils::save_info_json(previous, update_dir.installed_backend_info_json_path())
+                .await?
+        }
+        None => update_dir.remove_installed_backend_info_json().await?,
+    }
+
+    {
+        let mut state = internal_state.lock().await;
+        state.installed = previous.clone();
+        state.last_rollback = Some(SoftwareRollback {
+            time: UnixTime::current_time(),
+            failed: installed,
+            restored: previous,
+        });
+    }
+
+    control.start_backend().await?;
+
+    Err(e.change_context(UpdateError::HealthCheckFailedAndRolledBack))
+}
+
+enum LatestRelease {
+    GitHub(SignedReleaseAsset),
+    Https(ReleaseManifest),
+}
+
+impl LatestRelease {
+    fn name(&self) -> &str {
+        match self {
+            Self::GitHub(signed_asset) => &signed_asset.asset.name,
+            Self::Https(manifest) => &manifest.version,
+        }
+    }
+
+    /// File name which the signature trusted comment must contain
+    fn file_name(&self) -> Result<&str, UpdateError> {
+        match self {
+            Self::GitHub(signed_asset) => Ok(&signed_asset.asset.name),
+            Self::Https(manifest) => manifest.file_name().ok_or_else(|| {
+                UpdateError::HttpsReleaseSource
+                    .into_report()
+                    .attach(format!("File name missing from URL: {}", manifest.url))
+            }),
         }
     }
 }
 
 struct UpdateDirUtils<'a> {
-    config: &'a Config,
+    storage_dir: &'a Path,
 }
 
 impl UpdateDirUtils<'_> {
     fn create_update_dir_if_needed(&self) -> PathBuf {
-        let dir = self.config.storage_dir().join("update");
+        let dir = self.storage_dir.join("update");
 
         if !Path::new(&dir).exists() {
             info!("Creating update directory");
@@ -463,6 +774,11 @@ impl UpdateDirUtils<'_> {
             .join("downloaded_backend")
     }
 
+    fn downloaded_backend_signature_path(&self) -> PathBuf {
+        self.create_update_dir_if_needed()
+            .join("downloaded_backend.minisig")
+    }
+
     fn downloaded_backend_info_json_path(&self) -> PathBuf {
         self.create_update_dir_if_needed()
             .join("downloaded_backend.json")
@@ -505,6 +821,13 @@ impl UpdateDirUtils<'_> {
                 .change_context(UpdateError::FileRemovingFailed)?;
         }
 
+        let signature = self.downloaded_backend_signature_path();
+        if signature.exists() {
+            tokio::fs::remove_file(signature)
+                .await
+                .change_context(UpdateError::FileRemovingFailed)?;
+        }
+
         let info = self.downloaded_backend_info_json_path();
         if info.exists() {
             tokio::fs::remove_file(info)
@@ -515,6 +838,17 @@ impl UpdateDirUtils<'_> {
         Ok(())
     }
 
+    pub async fn remove_installed_backend_info_json(&self) -> Result<(), UpdateError> {
+        let info = self.installed_backend_info_json_path();
+        if info.exists() {
+            tokio::fs::remove_file(info)
+                .await
+                .change_context(UpdateError::FileRemovingFailed)?;
+        }
+
+        Ok(())
+    }
+
     pub async fn save_info_json(
         info: &SoftwareInfo,
         path: impl AsRef<Path>,
@@ -551,3 +885,227 @@ impl UpdateDirUtils<'_> {
         .change_context(UpdateError::BlockingTaskFailed)?
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex as StdMutex;
+
+    use super::*;
+
+    struct TestBackendControl {
+        health_check_result: bool,
+        calls: StdMutex<Vec<&'static str>>,
+    }
+
+    impl TestBackendControl {
+        fn new(health_check_result: bool) -> Self {
+            Self {
+                health_check_result,
+                calls: StdMutex::new(vec![]),
+            }
+        }
+
+        fn calls(&self) -> Vec<&'static str> {
+            self.calls.lock().unwrap().clone()
+        }
+    }
+
+    impl BackendControl for TestBackendControl {
+        async fn stop_backend(&self) -> Result<(), UpdateError> {
+            self.calls.lock().unwrap().push("stop");
+            Ok(())
+        }
+
+        async fn start_backend(&self) -> Result<(), UpdateError> {
+            self.calls.lock().unwrap().push("start");
+            Ok(())
+        }
+
+        async fn health_check(&self) -> Result<(), UpdateError> {
+            self.calls.lock().unwrap().push("health_check");
+            if self.health_check_result {
+                Ok(())
+            } else {
+                Err(UpdateError::HealthCheckFailed.report())
+            }
+        }
+    }
+
+    /// Creates a new directory for one test and returns the directory path
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "afrodite_manager_update_test_{}_{name}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn software_update_config(dir: &Path) -> SoftwareUpdateConfig {
+        SoftwareUpdateConfig {
+            backend_install_location: dir.join("backend"),
+            signature_public_keys: vec![],
+            github: None,
+            https: None,
+            automatic_rollback: None,
+        }
+    }
+
+    fn info(name: &str) -> SoftwareInfo {
+        SoftwareInfo {
+            name: name.to_string(),
+            sha256: name.to_string(),
+        }
+    }
+
+    /// Install new binary like the update manager does
+    async fn install_new_backend(
+        dir: &Path,
+        config: &SoftwareUpdateConfig,
+        update_dir: &UpdateDirUtils<'_>,
+    ) {
+        std::fs::write(&config.backend_install_location, "previous").unwrap();
+        std::fs::write(dir.join("new"), "new").unwrap();
+        BackendUtils { config }
+            .replace_backend_binary(&dir.join("new"))
+            .await
+            .unwrap();
+        UpdateDirUtils::save_info_json(&info("new"), update_dir.installed_backend_info_json_path())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn failed_health_check_restores_previous_backend() {
+        let dir = test_dir("rollback");
+        let config = software_update_config(&dir);
+        let update_dir = UpdateDirUtils { storage_dir: &dir };
+        install_new_backend(&dir, &config, &update_dir).await;
+        let internal_state = Mutex::new(SoftwareUpdateStatus::new_idle());
+        let control = TestBackendControl::new(false);
+
+        let result = restart_backend_and_rollback_if_needed(
+            &control,
+            BackendUtils { config: &config },
+            UpdateDirUtils { storage_dir: &dir },
+            &internal_state,
+            info("new"),
+            Some(info("previous")),
+        )
+        .await;
+
+        let error = result.unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            UpdateError::HealthCheckFailedAndRolledBack
+        ));
+        assert_eq!(
+            control.calls(),
+            vec!["stop", "start", "health_check", "stop", "start"]
+        );
+        assert_eq!(
+            std::fs::read_to_string(&config.backend_install_location).unwrap(),
+            "previous"
+        );
+        assert_eq!(
+            update_dir.installed_backend_info().await.unwrap(),
+            Some(info("previous"))
+        );
+        let state = internal_state.lock().await;
+        assert_eq!(state.installed, Some(info("previous")));
+        let rollback = state.last_rollback.clone().unwrap();
+        assert_eq!(rollback.failed, info("new"));
+        assert_eq!(rollback.restored, Some(info("previous")));
+    }
+
+    #[tokio::test]
+    async fn failed_health_check_without_previous_info_removes_installed_info() {
+        let dir = test_dir("rollback_no_previous_info");
+        let config = software_update_config(&dir);
+        let update_dir = UpdateDirUtils { storage_dir: &dir };
+        install_new_backend(&dir, &config, &update_dir).await;
+        let internal_state = Mutex::new(SoftwareUpdateStatus::new_idle());
+        let control = TestBackendControl::new(false);
+
+        let result = restart_backend_and_rollback_if_needed(
+            &control,
+            BackendUtils { config: &config },
+            UpdateDirUtils { storage_dir: &dir },
+            &internal_state,
+            info("new"),
+            None,
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            std::fs::read_to_string(&config.backend_install_location).unwrap(),
+            "previous"
+        );
+        assert_eq!(update_dir.installed_backend_info().await.unwrap(), None);
+        assert_eq!(internal_state.lock().await.installed, None);
+    }
+
+    #[tokio::test]
+    async fn successful_health_check_keeps_new_backend() {
+        let dir = test_dir("no_rollback");
+        let config = software_update_config(&dir);
+        let update_dir = UpdateDirUtils { storage_dir: &dir };
+        install_new_backend(&dir, &config, &update_dir).await;
+        let internal_state = Mutex::new(SoftwareUpdateStatus::new_idle());
+        let control = TestBackendControl::new(true);
+
+        let result = restart_backend_and_rollback_if_needed(
+            &control,
+            BackendUtils { config: &config },
+            UpdateDirUtils { storage_dir: &dir },
+            &internal_state,
+            info("new"),
+            Some(info("previous")),
+        )
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(control.calls(), vec!["stop", "start", "health_check"]);
+        assert_eq!(
+            std::fs::read_to_string(&config.backend_install_location).unwrap(),
+            "new"
+        );
+        assert_eq!(
+            update_dir.installed_backend_info().await.unwrap(),
+            Some(info("new"))
+        );
+        assert!(internal_state.lock().await.last_rollback.is_none());
+    }
+
+    #[tokio::test]
+    async fn rollback_fails_if_previous_backend_binary_is_missing() {
+        let dir = test_dir("rollback_no_previous_binary");
+        let config = software_update_config(&dir);
+        std::fs::write(&config.backend_install_location, "new").unwrap();
+        let internal_state = Mutex::new(SoftwareUpdateStatus::new_idle());
+        let control = TestBackendControl::new(false);
+
+        let result = restart_backend_and_rollback_if_needed(
+            &control,
+            BackendUtils { config: &config },
+            UpdateDirUtils { storage_dir: &dir },
+            &internal_state,
+            info("new"),
+            Some(info("previous")),
+        )
+        .await;
+
+        let error = result.unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            UpdateError::HealthCheckFailed
+        ));
+        assert_eq!(
+            control.calls(),
+            vec!["stop", "start", "health_check", "stop"]
+        );
+        assert!(internal_state.lock().await.last_rollback.is_none());
+    }
+}
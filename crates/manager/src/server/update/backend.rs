@@ -1,7 +1,7 @@
 use std::{path::Path, process::ExitStatus};
 
 use error_stack::ResultExt;
-use manager_config::file::SoftwareUpdateConfig;
+use manager_config::file::{ControlBackendConfig, SoftwareUpdateConfig};
 use simple_backend_utils::{ContextExt, Result};
 use tokio::process::Command;
 use tracing::info;
@@ -30,6 +30,10 @@ pub enum BackendUtilsError {
     ResetDataDirectoryNoFileName,
 }
 
+fn previous_binary_path(target: &Path) -> std::path::PathBuf {
+    target.with_extension("old")
+}
+
 pub struct BackendUtils<'a> {
     pub config: &'a SoftwareUpdateConfig,
 }
@@ -39,7 +43,7 @@ impl BackendUtils<'_> {
         let target = self.config.backend_install_location.clone();
 
         if target.exists() {
-            tokio::fs::rename(&target, &target.with_extension("old"))
+            tokio::fs::rename(&target, previous_binary_path(&target))
                 .await
                 .change_context(BackendUtilsError::FileMovingFailed)?;
         }
@@ -61,6 +65,64 @@ impl BackendUtils<'_> {
 
         Ok(())
     }
+
+    /// Replace current backend binary with the binary which
+    /// [Self::replace_backend_binary] kept.
+    ///
+    /// Returns false if previous binary does not exist.
+    pub async fn restore_previous_backend_binary(&self) -> Result<bool, BackendUtilsError> {
+        let target = self.config.backend_install_location.clone();
+        let previous = previous_binary_path(&target);
+
+        if !previous.exists() {
+            return Ok(false);
+        }
+
+        tokio::fs::rename(&previous, &target)
+            .await
+            .change_context(BackendUtilsError::FileMovingFailed)?;
+
+        Ok(true)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BackendServiceState {
+    Active,
+    /// Service is starting or in some other transitional state.
+    Starting,
+    /// Service is `failed` or `inactive`.
+    Stopped,
+}
+
+impl BackendServiceState {
+    /// Parse `systemctl is-active` output
+    fn parse(output: &str) -> Self {
+        match output.trim() {
+            "active" => Self::Active,
+            "failed" | "inactive" => Self::Stopped,
+            _ => Self::Starting,
+        }
+    }
+}
+
+/// Check backend service status using `systemctl is-active`.
+/// It does not require root privileges.
+pub async fn backend_service_state(
+    config: &ControlBackendConfig,
+) -> Result<BackendServiceState, BackendUtilsError> {
+    // Exit status is not checked because it is non-zero when
+    // the service is not active.
+    let output = Command::new("systemctl")
+        .arg("is-active")
+        .arg(&config.service)
+        .output()
+        .await
+        .change_context(BackendUtilsError::ProcessWaitFailed)?;
+
+    Ok(BackendServiceState::parse(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
 }
 
 pub async fn reset_backend_data(backend_reset_data_dir: &Path) -> Result<(), BackendUtilsError> {
@@ -103,3 +165,32 @@ pub async fn reset_backend_data(backend_reset_data_dir: &Path) -> Result<(), Bac
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_backend_service_state() {
+        assert_eq!(
+            BackendServiceState::parse("active\n"),
+            BackendServiceState::Active
+        );
+        assert_eq!(
+            BackendServiceState::parse("activating\n"),
+            BackendServiceState::Starting
+        );
+        assert_eq!(
+            BackendServiceState::parse("reloading\n"),
+            BackendServiceState::Starting
+        );
+        assert_eq!(
+            BackendServiceState::parse("failed\n"),
+            BackendServiceState::Stopped
+        );
+        assert_eq!(
+            BackendServiceState::parse("inactive\n"),
+            BackendServiceState::Stopped
+        );
+    }
+}
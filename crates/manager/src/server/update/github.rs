@@ -2,7 +2,7 @@ use std::path::Path;
 
 use error_stack::{IntoReport, ResultExt};
 use futures::StreamExt;
-use manager_config::file::SoftwareUpdateConfig;
+use manager_config::file::SoftwareUpdateGitHubConfig;
 use reqwest::{
     Client, StatusCode,
     header::{ACCEPT, USER_AGENT},
@@ -15,24 +15,34 @@ use super::UpdateError;
 
 const GITHUB_API_VERSION: &str = "2022-11-28";
 
+const SIGNATURE_FILE_NAME_SUFFIX: &str = ".minisig";
+
 pub struct ReleaseAsset {
     pub name: String,
     pub id: i64,
 }
 
+pub struct SignedReleaseAsset {
+    pub asset: ReleaseAsset,
+    /// Detached minisign signature for the asset
+    pub signature: ReleaseAsset,
+}
+
 pub struct GitHubApi<'a> {
-    pub updater_config: &'a SoftwareUpdateConfig,
+    pub config: &'a SoftwareUpdateGitHubConfig,
     pub client: &'a Client,
     pub user_agent: &'a str,
 }
 
 impl GitHubApi<'_> {
-    pub async fn get_latest_release_asset(&self) -> Result<Option<ReleaseAsset>, UpdateError> {
-        let config = self.updater_config;
+    pub async fn get_latest_release_asset(
+        &self,
+    ) -> Result<Option<SignedReleaseAsset>, UpdateError> {
+        let config = self.config;
 
         let url = format!(
             "https://api.github.com/repos/{}/{}/releases/latest",
-            config.github.owner, config.github.repository
+            config.owner, config.repository
         );
 
         let request = self
@@ -42,7 +52,7 @@ impl GitHubApi<'_> {
             .header(USER_AGENT, self.user_agent)
             .header("X-GitHub-Api-Version", GITHUB_API_VERSION);
 
-        let request = if let Some(token) = config.github.token.clone() {
+        let request = if let Some(token) = config.token.clone() {
             request.bearer_auth(token)
         } else {
             request
@@ -81,6 +91,7 @@ impl GitHubApi<'_> {
             .unwrap_or_default();
 
         let mut selected_asset: Option<ReleaseAsset> = None;
+        let mut signature_assets: Vec<ReleaseAsset> = vec![];
         for a in assets {
             let Some(name) = a
                 .as_object()
@@ -105,14 +116,19 @@ impl GitHubApi<'_> {
                 return Err(UpdateError::GitHubApi.into_report());
             };
 
-            if name.ends_with(&config.github.file_name_ending) {
+            if name.ends_with(SIGNATURE_FILE_NAME_SUFFIX) {
+                signature_assets.push(ReleaseAsset {
+                    name: name.to_string(),
+                    id,
+                });
+            } else if name.ends_with(&config.file_name_ending) {
                 if let Some(selected) = selected_asset {
                     return Err(UpdateError::SotwareDownloadFailedAmbiguousFileName
                         .into_report()
                         .attach(selected.name.to_string())
                         .attach(name.to_string()));
                 } else {
-                    if let Some(required_uploader) = &config.github.uploader
+                    if let Some(required_uploader) = &config.uploader
                         && uploader != required_uploader
                     {
                         return Err(UpdateError::SotwareDownloadFailedUnknownFileUploader
@@ -129,7 +145,21 @@ impl GitHubApi<'_> {
             }
         }
 
-        Ok(selected_asset)
+        let Some(asset) = selected_asset else {
+            return Ok(None);
+        };
+
+        let signature_name = format!("{}{}", asset.name, SIGNATURE_FILE_NAME_SUFFIX);
+        let Some(signature) = signature_assets
+            .into_iter()
+            .find(|v| v.name == signature_name)
+        else {
+            return Err(UpdateError::SoftwareDownloadFailedSignatureNotFound
+                .into_report()
+                .attach(signature_name));
+        };
+
+        Ok(Some(SignedReleaseAsset { asset, signature }))
     }
 
     pub async fn download_asset(
@@ -137,11 +167,11 @@ impl GitHubApi<'_> {
         asset: &ReleaseAsset,
         download_location: impl AsRef<Path>,
     ) -> Result<(), UpdateError> {
-        let config = self.updater_config;
+        let config = self.config;
 
         let url = format!(
             "https://api.github.com/repos/{}/{}/releases/assets/{}",
-            config.github.owner, config.github.repository, asset.id,
+            config.owner, config.repository, asset.id,
         );
 
         let request = self
@@ -151,7 +181,7 @@ impl GitHubApi<'_> {
             .header(USER_AGENT, self.user_agent)
             .header("X-GitHub-Api-Version", GITHUB_API_VERSION);
 
-        let request = if let Some(token) = config.github.token.clone() {
+        let request = if let Some(token) = config.token.clone() {
             request.bearer_auth(token)
         } else {
             request
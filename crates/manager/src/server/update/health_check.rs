@@ -0,0 +1,73 @@
+//! Check that restarted backend works
+
+use std::time::Duration;
+
+use error_stack::{IntoReport, ResultExt};
+use manager_config::file::{AutomaticRollbackConfig, ControlBackendConfig};
+use reqwest::Client;
+use simple_backend_utils::Result;
+use tokio::time::Instant;
+use tracing::{info, warn};
+
+use super::{
+    UpdateError,
+    backend::{BackendServiceState, backend_service_state},
+};
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(5);
+const HEALTH_CHECK_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub struct BackendHealthCheck<'a> {
+    pub client: &'a Client,
+    pub config: &'a AutomaticRollbackConfig,
+    pub control_backend: &'a ControlBackendConfig,
+}
+
+impl BackendHealthCheck<'_> {
+    /// Fails if backend service stops at some point during
+    /// the grace period or the last health check request fails.
+    pub async fn run(&self) -> Result<(), UpdateError> {
+        let grace_period_end =
+            Instant::now() + Duration::from_secs(self.config.grace_period_seconds.into());
+
+        loop {
+            tokio::time::sleep(CHECK_INTERVAL).await;
+
+            let service_state = backend_service_state(self.control_backend)
+                .await
+                .change_context(UpdateError::BackendUtils)?;
+            if service_state == BackendServiceState::Stopped {
+                return Err(UpdateError::HealthCheckFailed.into_report())
+                    .attach("Backend service is stopped");
+            }
+
+            let health_check_result = self.health_check_request().await;
+
+            if Instant::now() >= grace_period_end {
+                info!("Backend health check completed");
+                return health_check_result;
+            }
+
+            if let Err(e) = health_check_result {
+                warn!("Backend health check request failed. Error: {:?}", e);
+            }
+        }
+    }
+
+    async fn health_check_request(&self) -> Result<(), UpdateError> {
+        let response = self
+            .client
+            .get(self.config.health_check_url.clone())
+            .timeout(HEALTH_CHECK_REQUEST_TIMEOUT)
+            .send()
+            .await
+            .change_context(UpdateError::HealthCheckFailed)?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(UpdateError::HealthCheckFailed.into_report()).attach(status);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,265 @@
+use std::path::Path;
+
+use error_stack::{IntoReport, ResultExt};
+use futures::StreamExt;
+use manager_config::file::SoftwareUpdateHttpsConfig;
+use reqwest::{Client, StatusCode, Url, header::USER_AGENT, redirect::Policy};
+use serde::Deserialize;
+use simple_backend_utils::Result;
+use tokio::io::AsyncWriteExt;
+
+use super::UpdateError;
+
+/// Release manifest JSON
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReleaseManifest {
+    pub version: String,
+    /// Absolute URL or URL relative to the manifest URL.
+    pub url: String,
+    /// Hex encoded SHA-256 of the file
+    pub sha256: String,
+    /// Detached minisign signature of the file
+    pub signature: String,
+}
+
+impl ReleaseManifest {
+    /// File name from the last path segment of the file URL
+    pub fn file_name(&self) -> Option<&str> {
+        self.url
+            .split(['?', '#'])
+            .next()?
+            .rsplit('/')
+            .next()
+            .filter(|v| !v.is_empty())
+    }
+}
+
+const MAX_REDIRECTS: usize = 10;
+
+pub struct HttpsReleaseSource<'a> {
+    config: &'a SoftwareUpdateHttpsConfig,
+    client: Client,
+    user_agent: &'a str,
+}
+
+impl<'a> HttpsReleaseSource<'a> {
+    pub fn new(
+        config: &'a SoftwareUpdateHttpsConfig,
+        user_agent: &'a str,
+    ) -> Result<Self, UpdateError> {
+        let client = Client::builder()
+            .redirect(https_only_redirect_policy())
+            .build()
+            .change_context(UpdateError::HttpsReleaseSource)?;
+        Ok(Self {
+            config,
+            client,
+            user_agent,
+        })
+    }
+
+    pub async fn get_release_manifest(&self) -> Result<ReleaseManifest, UpdateError> {
+        let response = self
+            .client
+            .get(self.config.manifest_url.clone())
+            .header(USER_AGENT, self.user_agent)
+            .send()
+            .await
+            .change_context(UpdateError::HttpsReleaseSource)?;
+
+        let status = response.status();
+        if status != StatusCode::OK {
+            return Err(UpdateError::HttpsReleaseSource.into_report().attach(status));
+        }
+
+        response
+            .json()
+            .await
+            .change_context(UpdateError::HttpsReleaseSource)
+    }
+
+    pub async fn download_file(
+        &self,
+        manifest: &ReleaseManifest,
+        download_location: impl AsRef<Path>,
+    ) -> Result<(), UpdateError> {
+        let url = self.file_url(manifest)?;
+
+        let response = self
+            .client
+            .get(url)
+            .header(USER_AGENT, self.user_agent)
+            .send()
+            .await
+            .change_context(UpdateError::HttpsReleaseSource)?;
+
+        let status = response.status();
+        if status != StatusCode::OK {
+            return Err(UpdateError::HttpsReleaseSource.into_report().attach(status));
+        }
+
+        let mut file = tokio::fs::File::create(download_location)
+            .await
+            .change_context(UpdateError::FileWritingFailed)?;
+
+        let mut stream = response.bytes_stream();
+        while let Some(bytes) = stream.next().await {
+            let bytes = bytes.change_context(UpdateError::HttpsReleaseSource)?;
+            file.write_all(&bytes)
+                .await
+                .change_context(UpdateError::FileWritingFailed)?;
+        }
+
+        Ok(())
+    }
+
+    fn file_url(&self, manifest: &ReleaseManifest) -> Result<Url, UpdateError> {
+        let url = self
+            .config
+            .manifest_url
+            .join(&manifest.url)
+            .change_context(UpdateError::HttpsReleaseSource)?;
+
+        if url.scheme() != "https" {
+            return Err(UpdateError::HttpsReleaseSource
+                .into_report()
+                .attach(format!("File URL is not HTTPS URL: {url}")));
+        }
+
+        Ok(url)
+    }
+}
+
+/// Follow only redirects to HTTPS URLs
+fn https_only_redirect_policy() -> Policy {
+    Policy::custom(|attempt| {
+        if attempt.url().scheme() != "https" {
+            let error = format!("Redirect to non-HTTPS URL: {}", attempt.url());
+            attempt.error(error)
+        } else if attempt.previous().len() > MAX_REDIRECTS {
+            attempt.error("Too many redirects")
+        } else {
+            attempt.follow()
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use tokio::{io::AsyncReadExt, net::TcpListener};
+
+    use super::*;
+
+    const MANIFEST: &str = r#"{
+        "version": "1.0.0",
+        "url": "afrodite-backend-1.0.0.tar.gz",
+        "sha256": "abcd",
+        "signature": "signature"
+    }"#;
+
+    fn config(manifest_url: &str) -> SoftwareUpdateHttpsConfig {
+        SoftwareUpdateHttpsConfig {
+            manifest_url: Url::parse(manifest_url).unwrap(),
+            archive_backend_binary_path: None,
+        }
+    }
+
+    fn manifest(url: &str) -> ReleaseManifest {
+        ReleaseManifest {
+            url: url.to_string(),
+            ..serde_json::from_str(MANIFEST).unwrap()
+        }
+    }
+
+    fn file_url(manifest_url: &str, url: &str) -> Result<Url, UpdateError> {
+        let config = config(manifest_url);
+        HttpsReleaseSource::new(&config, "test")
+            .unwrap()
+            .file_url(&manifest(url))
+    }
+
+    #[test]
+    fn parse_manifest() {
+        let manifest: ReleaseManifest = serde_json::from_str(MANIFEST).unwrap();
+        assert_eq!(manifest.version, "1.0.0");
+        assert_eq!(manifest.url, "afrodite-backend-1.0.0.tar.gz");
+        assert_eq!(manifest.sha256, "abcd");
+        assert_eq!(manifest.signature, "signature");
+        assert_eq!(manifest.file_name(), Some("afrodite-backend-1.0.0.tar.gz"));
+
+        let missing_signature = r#"{"version": "1.0.0", "url": "a.tar.gz", "sha256": "abcd"}"#;
+        assert!(serde_json::from_str::<ReleaseManifest>(missing_signature).is_err());
+    }
+
+    #[test]
+    fn manifest_file_name() {
+        assert_eq!(
+            manifest("https://example.com/releases/a.tar.gz?token=1").file_name(),
+            Some("a.tar.gz")
+        );
+        assert_eq!(manifest("https://example.com/releases/").file_name(), None);
+    }
+
+    #[test]
+    fn relative_file_url_is_resolved_using_manifest_url() {
+        let manifest_url = "https://example.com/releases/latest/manifest.json";
+        assert_eq!(
+            file_url(manifest_url, "a.tar.gz").unwrap().as_str(),
+            "https://example.com/releases/latest/a.tar.gz"
+        );
+        assert_eq!(
+            file_url(manifest_url, "../1.0.0/a.tar.gz")
+                .unwrap()
+                .as_str(),
+            "https://example.com/releases/1.0.0/a.tar.gz"
+        );
+        assert_eq!(
+            file_url(manifest_url, "/a.tar.gz").unwrap().as_str(),
+            "https://example.com/a.tar.gz"
+        );
+    }
+
+    #[test]
+    fn absolute_file_url_must_be_https() {
+        let manifest_url = "https://example.com/manifest.json";
+        assert_eq!(
+            file_url(manifest_url, "https://cdn.example.com/a.tar.gz")
+                .unwrap()
+                .as_str(),
+            "https://cdn.example.com/a.tar.gz"
+        );
+        assert!(file_url(manifest_url, "http://cdn.example.com/a.tar.gz").is_err());
+    }
+
+    #[tokio::test]
+    async fn redirect_to_http_url_is_not_followed() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let requests = Arc::new(AtomicUsize::new(0));
+        let requests_in_server = requests.clone();
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut stream, _)) = listener.accept().await else {
+                    return;
+                };
+                requests_in_server.fetch_add(1, Ordering::SeqCst);
+                let mut buf = vec![0u8; 4096];
+                let _ = stream.read(&mut buf).await;
+                let response = format!(
+                    "HTTP/1.1 302 Found\r\nlocation: http://{address}/manifest.json\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+
+        let config = config(&format!("http://{address}/redirect"));
+        let source = HttpsReleaseSource::new(&config, "test").unwrap();
+        assert!(source.get_release_manifest().await.is_err());
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,185 @@
+//! Minisign signature verification for downloaded software
+
+use std::{
+    fs::File,
+    io::{Read, Seek},
+    path::PathBuf,
+};
+
+use error_stack::{IntoReport, ResultExt};
+use minisign_verify::{PublicKey, Signature};
+use simple_backend_utils::Result;
+
+use super::UpdateError;
+
+/// Verify that detached minisign signature of the file is valid for
+/// at least one of the public keys.
+///
+/// Only prehashed signatures are supported. Those are created by default
+/// with current minisign versions.
+///
+/// If `expected_file_name` is set, the signature trusted comment must
+/// contain the same file name. This prevents accepting a validly signed
+/// but different file.
+pub async fn verify_signature(
+    public_keys: Vec<String>,
+    expected_file_name: Option<String>,
+    file: PathBuf,
+    signature_file: PathBuf,
+) -> Result<(), UpdateError> {
+    tokio::task::spawn_blocking(move || {
+        let signature = std::fs::read_to_string(signature_file)
+            .change_context(UpdateError::FileReadingFailed)?;
+        let file = File::open(file).change_context(UpdateError::FileReadingFailed)?;
+        verify_signature_sync(
+            &public_keys,
+            expected_file_name.as_deref(),
+            file,
+            &signature,
+        )
+    })
+    .await
+    .change_context(UpdateError::BlockingTaskFailed)?
+}
+
+fn verify_signature_sync(
+    public_keys: &[String],
+    expected_file_name: Option<&str>,
+    mut file: impl Read + Seek,
+    signature: &str,
+) -> Result<(), UpdateError> {
+    let signature = Signature::decode(signature).map_err(|e| {
+        UpdateError::SignatureVerificationFailed
+            .into_report()
+            .attach(e.to_string())
+    })?;
+
+    if let Some(name) = expected_file_name
+        && trusted_comment_file_name(signature.trusted_comment()) != Some(name)
+    {
+        return Err(UpdateError::SignatureVerificationFailed
+            .into_report()
+            .attach(format!(
+                "Trusted comment does not contain file name {name}: {}",
+                signature.trusted_comment()
+            )));
+    }
+
+    for key in public_keys {
+        let key = PublicKey::from_base64(key).map_err(|e| {
+            UpdateError::InvalidSignaturePublicKey
+                .into_report()
+                .attach(e.to_string())
+        })?;
+
+        // Fails if key ID does not match
+        let Ok(mut verifier) = key.verify_stream(&signature) else {
+            continue;
+        };
+
+        file.rewind()
+            .change_context(UpdateError::FileReadingFailed)?;
+        let mut buf = vec![0u8; 1024 * 1024];
+        loop {
+            let n = file
+                .read(&mut buf)
+                .change_context(UpdateError::FileReadingFailed)?;
+            if n == 0 {
+                break;
+            }
+            verifier.update(&buf[..n]);
+        }
+
+        if verifier.finalize().is_ok() {
+            return Ok(());
+        }
+    }
+
+    Err(UpdateError::SignatureVerificationFailed.into_report())
+}
+
+/// Get file name from trusted comment which has minisign default format
+/// "timestamp:<timestamp>\tfile:<file name>\thashed".
+fn trusted_comment_file_name(trusted_comment: &str) -> Option<&str> {
+    trusted_comment
+        .split('\t')
+        .find_map(|v| v.strip_prefix("file:"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    /// Test vector created with a fixed Ed25519 key.
+    const PUBLIC_KEY: &str = "RWQBAgMEBQYHCAOhB7/zzhC+HXDdGOdLwJln5NYwm6UNXx3chmQSVTG4";
+    const FILE: &[u8] = b"backend binary\n";
+    const FILE_NAME: &str = "backend.tar.gz";
+    const SIGNATURE: &str = "untrusted comment: signature from minisign secret key
+RUQBAgMEBQYHCJkBbyOxRkRs842Qp9ltqXixOh+cVZY+nKB0nvy5ZRyVyI9I5/S39us5CKPBRpwSSyMWEpXCkBqUpoZnAWnTEQI=
+trusted comment: timestamp:1700000000\tfile:backend.tar.gz\thashed
+pW0fE3YYJ7k29KrpK++pOLDJ1xabGLrUk12QkrtVoWNLiVpFru7oCbC1CfhZSuYp+ho0A8RT6zd0tywxsC5aCw==
+";
+
+    fn verify(
+        public_keys: &[&str],
+        expected_file_name: Option<&str>,
+        file: &[u8],
+        signature: &str,
+    ) -> Result<(), UpdateError> {
+        let public_keys: Vec<String> = public_keys.iter().map(|v| v.to_string()).collect();
+        verify_signature_sync(
+            &public_keys,
+            expected_file_name,
+            Cursor::new(file),
+            signature,
+        )
+    }
+
+    #[test]
+    fn valid_signature() {
+        assert!(verify(&[PUBLIC_KEY], Some(FILE_NAME), FILE, SIGNATURE).is_ok());
+        assert!(verify(&[PUBLIC_KEY], None, FILE, SIGNATURE).is_ok());
+    }
+
+    #[test]
+    fn modified_file_is_rejected() {
+        assert!(
+            verify(
+                &[PUBLIC_KEY],
+                Some(FILE_NAME),
+                b"backend binary\n\n",
+                SIGNATURE
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn different_file_name_is_rejected() {
+        assert!(verify(&[PUBLIC_KEY], Some("backend-old.tar.gz"), FILE, SIGNATURE).is_err());
+    }
+
+    #[test]
+    fn modified_trusted_comment_is_rejected() {
+        let signature = SIGNATURE.replace("timestamp:1700000000", "timestamp:1700000001");
+        assert!(verify(&[PUBLIC_KEY], Some(FILE_NAME), FILE, &signature).is_err());
+    }
+
+    #[test]
+    fn unknown_key_is_rejected() {
+        let other_key = "RWQIBwYFBAMCAQOhB7/zzhC+HXDdGOdLwJln5NYwm6UNXx3chmQSVTG4";
+        assert!(verify(&[other_key], Some(FILE_NAME), FILE, SIGNATURE).is_err());
+        assert!(verify(&[other_key, PUBLIC_KEY], Some(FILE_NAME), FILE, SIGNATURE).is_ok());
+    }
+
+    #[test]
+    fn trusted_comment_file_name_parsing() {
+        assert_eq!(
+            trusted_comment_file_name("timestamp:1\tfile:a.tar.gz\thashed"),
+            Some("a.tar.gz")
+        );
+        assert_eq!(trusted_comment_file_name("timestamp:1"), None);
+    }
+}
@@ -1,8 +1,8 @@
 use error_stack::{IntoReport, ResultExt};
 use manager_model::{
-    BackupMessage, BackupMessageHeader, BackupMessageType, JsonRpcLinkHeader, JsonRpcLinkMessage,
-    JsonRpcLinkMessageType, JsonRpcRequest, JsonRpcRequestType, JsonRpcResponse,
-    JsonRpcResponseType, ManagerApiManualTaskType, ManagerApiNotifyBackend,
+    BackupMessage, BackupMessageHeader, BackupMessageType, BackupSnapshotList, JsonRpcLinkHeader,
+    JsonRpcLinkMessage, JsonRpcLinkMessageType, JsonRpcRequest, JsonRpcRequestType,
+    JsonRpcResponse, JsonRpcResponseType, ManagerApiManualTaskType, ManagerApiNotifyBackend,
     ManagerApiScheduledTaskStatus, ManagerApiScheduledTaskType, ManagerInstanceName,
     ManagerInstanceNameList, ManagerProtocolMode, ManagerProtocolVersion,
     SecureStorageEncryptionKey, ServerEvent, SoftwareUpdateStatus, SoftwareUpdateTaskType,
@@ -383,6 +383,19 @@ pub trait RequestSenderCmds: Sized {
         );
         self.send_request(request).await?.require_successful()
     }
+
+    async fn get_backup_snapshots(self) -> Result<BackupSnapshotList, ClientError> {
+        let request = JsonRpcRequest::new(
+            self.request_recipient_name(),
+            JsonRpcRequestType::GetBackupSnapshots,
+        );
+        let response = self.send_request(request).await?;
+        if let JsonRpcResponseType::BackupSnapshots(snapshots) = response.into_response() {
+            Ok(snapshots)
+        } else {
+            Err(ClientError::InvalidResponse.into_report())
+        }
+    }
 }
 
 trait RpcResponseExtensions: Sized {
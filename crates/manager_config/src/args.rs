@@ -166,4 +166,6 @@ pub enum ApiCommand {
         name: String,
         sha256: String,
     },
+    /// List database backups which backup link target has received
+    BackupSnapshots,
 }
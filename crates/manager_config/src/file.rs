@@ -6,11 +6,7 @@ use std::{
 use error_stack::ResultExt;
 use manager_model::{ManagerInstanceName, SecureStorageEncryptionKey};
 use serde::{Deserialize, Serialize};
-use simple_backend_utils::{
-    ContextExt, Result,
-    byte::ByteCount,
-    time::{DurationValue, UtcTimeValue},
-};
+use simple_backend_utils::{ContextExt, Result, byte::ByteCount, time::UtcTimeValue};
 use url::Url;
 
 use super::GetConfigError;
@@ -51,7 +47,14 @@ public_api = "127.0.0.1:4000"
 # [backup_link.target]
 # password = "password"
 # url = "tls://127.0.0.1:4000"
-# file_backup_retention_time = "30d" # Default
+#
+# Latest database backup for each day, week and month is kept until
+# the backup count for the retention tier is exceeded.
+#
+# [backup_link.target.file_backup_retention]
+# daily = 7 # Default
+# weekly = 4 # Default
+# monthly = 12 # Default
 
 # [backup_link.server]
 # password_target = "password"
@@ -73,6 +76,7 @@ public_api = "127.0.0.1:4000"
 
 # [software_update]
 # backend_install_location = "/home/afrodite/afrodite-backend"
+# signature_public_keys = ["TODO"] # Minisign public keys
 
 # [software_update.github]
 # owner = "TODO"
@@ -81,6 +85,15 @@ public_api = "127.0.0.1:4000"
 # uploader = "TODO" # Optional
 # archive_backend_binary_path = "TODO" # Optional
 
+# Alternative for GitHub
+# [software_update.https]
+# manifest_url = "https://example.com/afrodite-backend/latest.json"
+# archive_backend_binary_path = "TODO" # Optional
+
+# [software_update.automatic_rollback]
+# health_check_url = "http://127.0.0.1:9090/metrics"
+# grace_period_seconds = 60
+
 # [manual_tasks]
 # allow_backend_restart = true
 # allow_system_reboot = true
@@ -283,7 +296,44 @@ pub struct SecureStorageConfig {
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct SoftwareUpdateConfig {
     pub backend_install_location: PathBuf,
-    pub github: SoftwareUpdateGitHubConfig,
+    /// Minisign public keys in base64 format. Downloaded software
+    /// is accepted only if its detached minisign signature verifies
+    /// with one of these keys and the signature trusted comment contains
+    /// the downloaded file name (`file:<name>`), which minisign adds by
+    /// default. At least one key is required.
+    pub signature_public_keys: Vec<String>,
+    /// Release source. Only one source can be configured.
+    pub github: Option<SoftwareUpdateGitHubConfig>,
+    /// Release source. Only one source can be configured.
+    pub https: Option<SoftwareUpdateHttpsConfig>,
+    /// Restart the backend after installing and restore the previous
+    /// backend binary if the new binary does not work.
+    /// Requires [ControlBackendConfig].
+    pub automatic_rollback: Option<AutomaticRollbackConfig>,
+}
+
+impl SoftwareUpdateConfig {
+    pub fn archive_backend_binary_path(&self) -> Option<&SimplePatternPath> {
+        if let Some(github) = &self.github {
+            github.archive_backend_binary_path.as_ref()
+        } else if let Some(https) = &self.https {
+            https.archive_backend_binary_path.as_ref()
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AutomaticRollbackConfig {
+    /// Local URL which should respond with successful HTTP status
+    /// when the backend works. For example backend metrics API
+    /// URL `http://127.0.0.1:9090/metrics`.
+    pub health_check_url: Url,
+    /// Backend service must not stop (systemd state `failed` or
+    /// `inactive`) during this time after restarting and the last
+    /// health check during the time must be successful.
+    pub grace_period_seconds: u32,
 }
 
 /// Download latest release from GitHub repository.
@@ -294,6 +344,10 @@ pub struct SoftwareUpdateGitHubConfig {
     pub owner: String,
     pub repository: String,
     /// Select release asset which name ends with this text.
+    ///
+    /// The release must also contain the detached minisign signature
+    /// of the asset. Signature file name is the asset file name with
+    /// `.minisig` suffix.
     pub file_name_ending: String,
     /// Require specific release asset uploader.
     pub uploader: Option<String>,
@@ -307,6 +361,33 @@ pub struct SoftwareUpdateGitHubConfig {
     pub archive_backend_binary_path: Option<SimplePatternPath>,
 }
 
+/// Download latest release using release manifest JSON file.
+///
+/// The manifest has the following format:
+///
+/// ```json
+/// {
+///   "version": "1.0.0",
+///   "url": "afrodite-backend-1.0.0.tar.gz",
+///   "sha256": "SHA-256 of the file as hex",
+///   "signature": "Detached minisign signature of the file"
+/// }
+/// ```
+///
+/// The `url` can be absolute URL or relative to the manifest URL.
+/// Only HTTPS URLs are allowed.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SoftwareUpdateHttpsConfig {
+    pub manifest_url: Url,
+    /// Release file is an `.tar.gz` archive and backend binary is
+    /// in the archive at this path.
+    ///
+    /// If path component is `*` then any string will match it.
+    /// If the archive has multiple matching files the extracting
+    /// will fail.
+    pub archive_backend_binary_path: Option<SimplePatternPath>,
+}
+
 /// File path where path components might be equal with `*` string value.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(transparent)]
@@ -411,11 +492,34 @@ impl BackupLinkConfig {
         self.server.as_ref().map(|v| v.password_source.as_str())
     }
 
-    pub fn file_backup_retention_time(&self) -> DurationValue {
+    pub fn file_backup_retention(&self) -> FileBackupRetentionConfig {
         self.target
             .as_ref()
-            .and_then(|v| v.file_backup_retention_time)
-            .unwrap_or(DurationValue::from_days(30))
+            .map(|v| v.file_backup_retention)
+            .unwrap_or_default()
+    }
+
+    pub fn validate(&self) -> Result<(), GetConfigError> {
+        let Some(target) = &self.target else {
+            return Ok(());
+        };
+
+        if target.file_backup_retention_time.is_some() {
+            return Err(GetConfigError::BackupLinkConfig.report()).attach(
+                "Config backup_link.target.file_backup_retention_time is not supported anymore. \
+                Use backup_link.target.file_backup_retention instead.",
+            );
+        }
+
+        let retention = target.file_backup_retention;
+        if retention.daily == 0 && retention.weekly == 0 && retention.monthly == 0 {
+            return Err(GetConfigError::BackupLinkConfig.report()).attach(
+                "All backup_link.target.file_backup_retention tiers are disabled. \
+                At least one tier must keep backups.",
+            );
+        }
+
+        Ok(())
     }
 }
 
@@ -426,7 +530,35 @@ pub struct BackupLinkConfigTarget {
     pub password: String,
     /// Manager server address
     pub url: Url,
-    file_backup_retention_time: Option<DurationValue>,
+    #[serde(default)]
+    file_backup_retention: FileBackupRetentionConfig,
+    /// Removed config which is detected to prevent
+    /// silently changing the backup retention.
+    #[serde(default, skip_serializing)]
+    file_backup_retention_time: Option<toml::Value>,
+}
+
+/// Grandfather-father-son retention for database backups.
+/// Count zero disables the tier. At least one tier must be enabled.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default)]
+pub struct FileBackupRetentionConfig {
+    /// How many latest daily backups are kept
+    pub daily: u16,
+    /// How many latest weekly backups are kept. Week starts on Monday.
+    pub weekly: u16,
+    /// How many latest monthly backups are kept
+    pub monthly: u16,
+}
+
+impl Default for FileBackupRetentionConfig {
+    fn default() -> Self {
+        Self {
+            daily: 7,
+            weekly: 4,
+            monthly: 12,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
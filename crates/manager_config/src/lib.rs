@@ -55,6 +55,20 @@ pub enum GetConfigError {
     #[error("Missing script")]
     ScriptLocationError,
 
+    #[error("Software update signature public key is missing")]
+    SoftwareUpdateSignaturePublicKeyMissing,
+    #[error("Software update signature public key is invalid")]
+    SoftwareUpdateSignaturePublicKeyInvalid,
+
+    #[error("Automatic rollback requires backend controlling")]
+    AutomaticRollbackRequiresControlBackend,
+
+    #[error("Software update release source config is invalid")]
+    SoftwareUpdateReleaseSource,
+
+    #[error("Backup link config is invalid")]
+    BackupLinkConfig,
+
     #[error("Invalid constant")]
     InvalidConstant,
     #[error("Certificate file reading failed")]
@@ -239,6 +253,50 @@ pub fn get_config(
             .attach("TLS must be configured when debug mode is false");
     }
 
+    if let Some(software_update) = &file_config.software_update
+        && software_update.signature_public_keys.is_empty()
+    {
+        return Err(GetConfigError::SoftwareUpdateSignaturePublicKeyMissing)
+            .attach("Config software_update.signature_public_keys is empty");
+    }
+
+    if let Some(software_update) = &file_config.software_update {
+        for key in &software_update.signature_public_keys {
+            if let Err(e) = minisign_verify::PublicKey::from_base64(key) {
+                return Err(GetConfigError::SoftwareUpdateSignaturePublicKeyInvalid)
+                    .attach(format!("{key}: {e}"));
+            }
+        }
+    }
+
+    if let Some(software_update) = &file_config.software_update {
+        match (&software_update.github, &software_update.https) {
+            (Some(_), Some(_)) => {
+                return Err(GetConfigError::SoftwareUpdateReleaseSource)
+                    .attach("Only one software update release source can be configured");
+            }
+            (None, None) => {
+                return Err(GetConfigError::SoftwareUpdateReleaseSource)
+                    .attach("Software update release source is not configured");
+            }
+            (None, Some(https)) if https.manifest_url.scheme() != "https" => {
+                return Err(GetConfigError::SoftwareUpdateReleaseSource)
+                    .attach("Manifest URL is not HTTPS URL");
+            }
+            _ => (),
+        }
+    }
+
+    if let Some(software_update) = &file_config.software_update
+        && software_update.automatic_rollback.is_some()
+        && file_config.control_backend.is_none()
+    {
+        return Err(GetConfigError::AutomaticRollbackRequiresControlBackend)
+            .attach("Config control_backend is missing");
+    }
+
+    file_config.backup_link.validate()?;
+
     let script_locations =
         check_script_locations(&file_config.dir.scripts, file_config.general.debug())?;
 
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use simple_backend_model::UnixTime;
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct BackupSnapshotList {
+    /// Latest snapshot first
+    pub snapshots: Vec<BackupSnapshot>,
+}
+
+/// Database backup file which the backup link target has received.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct BackupSnapshot {
+    pub file_name: String,
+    pub database: String,
+    /// Backup creation time
+    pub time: UnixTime,
+    pub size_bytes: u64,
+}
@@ -3,9 +3,9 @@ use simple_backend_model::UnixTime;
 use utoipa::{IntoParams, ToSchema};
 
 use crate::{
-    ManagerApiManualTaskType, ManagerApiNotifyBackend, ManagerApiScheduledTaskStatus,
-    ManagerApiScheduledTaskType, SecureStorageEncryptionKey, SoftwareUpdateStatus,
-    SoftwareUpdateTaskType, SystemInfo,
+    BackupSnapshotList, ManagerApiManualTaskType, ManagerApiNotifyBackend,
+    ManagerApiScheduledTaskStatus, ManagerApiScheduledTaskType, SecureStorageEncryptionKey,
+    SoftwareUpdateStatus, SoftwareUpdateTaskType, SystemInfo,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, num_enum::TryFromPrimitive)]
@@ -57,6 +57,8 @@ pub enum JsonRpcRequestType {
     ScheduleTask(ManagerApiScheduledTaskType, ManagerApiNotifyBackend),
     /// Response [JsonRpcResponseType::Successful]
     UnscheduleTask(ManagerApiScheduledTaskType),
+    /// Response [JsonRpcResponseType::BackupSnapshots]
+    GetBackupSnapshots,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
@@ -107,6 +109,12 @@ impl JsonRpcResponse {
         }
     }
 
+    pub fn backup_snapshots(snapshots: BackupSnapshotList) -> Self {
+        Self {
+            response: JsonRpcResponseType::BackupSnapshots(snapshots),
+        }
+    }
+
     pub fn into_response(self) -> JsonRpcResponseType {
         self.response
     }
@@ -130,6 +138,7 @@ pub enum JsonRpcResponseType {
     SystemInfo(SystemInfo),
     SoftwareUpdateStatus(SoftwareUpdateStatus),
     ScheduledTasksStatus(ManagerApiScheduledTaskStatus),
+    BackupSnapshots(BackupSnapshotList),
     Successful,
     RequestRecipientNotFound,
 }
@@ -3,6 +3,7 @@
 #![deny(unused_features)]
 #![warn(unused_crate_dependencies)]
 
+pub mod backup;
 pub mod json_rpc;
 pub mod link;
 pub mod secure_storage;
@@ -10,6 +11,7 @@ pub mod software;
 pub mod system_info;
 pub mod task;
 
+pub use backup::*;
 pub use json_rpc::*;
 pub use link::*;
 pub use secure_storage::*;
@@ -4,7 +4,7 @@ use std::{
     num::Wrapping,
 };
 
-use simple_backend_model::ContentQualityVariant;
+use simple_backend_model::{ContentFileVariant, ContentImageFormat, ContentQualityVariant};
 use simple_backend_utils::UuidBase64Url;
 
 #[derive(Debug, Clone, Copy, PartialEq, num_enum::TryFromPrimitive)]
@@ -22,7 +22,10 @@ pub enum BackupMessageType {
     ///
     /// - Account ID UUID (16 bytes, big-endian)
     /// - Content ID UUID count (u8)
-    /// - Content ID UUID (16 bytes, big-endian)
+    /// - Repeated for each content:
+    ///   - Content ID UUID (16 bytes, big-endian)
+    ///   - Available image formats (u8, bit flags, bit index is the
+    ///     format byte value of [ContentImageFormat])
     ContentList = 2,
     /// Target sends this to source if it does not have data for the content.
     ///
@@ -30,8 +33,9 @@ pub enum BackupMessageType {
     ///
     /// - Account ID UUID (16 bytes, big-endian)
     /// - Content ID UUID (16 bytes, big-endian)
-    /// - Mode byte (1 byte): 0=all variants, 1=variant list follows
-    /// - If mode=1: variant bytes until end of data (6=High, 5=Medium, 4=Low, 3=Lower, 2=VeryLow)
+    /// - Mode byte (1 byte): 0=all JPEG variants, 1=variant list follows
+    /// - If mode=1: variant bytes until end of data. Format of the
+    ///   variant byte is defined in [ContentFileVariant::as_u8].
     ContentQuery = 3,
     /// Source sends this to target when answering to content query.
     ///
@@ -40,7 +44,7 @@ pub enum BackupMessageType {
     /// Data:
     ///
     /// - Repeated for each variant:
-    ///   - Content quality variant byte (1 byte): High=6, Medium=5, Low=4
+    ///   - Content file variant byte (1 byte, [ContentFileVariant::as_u8])
     ///   - Content SHA-256 (32 bytes)
     ///   - Content data length (4 bytes, little-endian, u32)
     ///   - Content data
@@ -98,10 +102,31 @@ pub struct BackupMessageHeader {
 
 pub struct AccountAndContent {
     pub account_id: UuidBase64Url,
-    pub content_ids: Vec<UuidBase64Url>,
+    pub content: Vec<ContentAndFormats>,
+}
+
+pub struct ContentAndFormats {
+    pub content_id: UuidBase64Url,
+    /// Image formats which are available for the content
+    pub formats: Vec<ContentImageFormat>,
 }
 
-pub type ContentQueryAnswer = Vec<(ContentQualityVariant, Sha256Bytes, Vec<u8>)>;
+impl ContentAndFormats {
+    fn formats_to_u8(&self) -> u8 {
+        self.formats
+            .iter()
+            .fold(0, |flags, format| flags | (1 << format.as_u8()))
+    }
+
+    fn formats_from_u8(flags: u8) -> Vec<ContentImageFormat> {
+        ContentImageFormat::all_formats()
+            .into_iter()
+            .filter(|format| flags & (1 << format.as_u8()) != 0)
+            .collect()
+    }
+}
+
+pub type ContentQueryAnswer = Vec<(ContentFileVariant, Sha256Bytes, Vec<u8>)>;
 
 pub enum SourceToTargetMessage {
     StartBackupSession,
@@ -129,11 +154,12 @@ impl SourceToTargetMessage {
                 let mut serialized = vec![];
                 for item in data {
                     serialized.extend(item.account_id.as_bytes());
-                    let content_count: u8 = TryInto::<u8>::try_into(item.content_ids.len())
-                        .map_err(|e| e.to_string())?;
+                    let content_count: u8 =
+                        TryInto::<u8>::try_into(item.content.len()).map_err(|e| e.to_string())?;
                     serialized.push(content_count);
-                    for c in item.content_ids {
-                        serialized.extend(c.as_bytes());
+                    for c in item.content {
+                        serialized.extend(c.content_id.as_bytes());
+                        serialized.push(c.formats_to_u8());
                     }
                 }
                 serialized
@@ -198,18 +224,25 @@ impl TryFrom<BackupMessage> for SourceToTargetMessage {
                         .map_err(|e| e.to_string())?;
                     let content_count = bytes[0];
 
-                    let mut content_ids = vec![];
+                    let mut content = vec![];
                     for _ in 0..content_count {
                         let mut bytes = [0u8; 16];
                         data_reader
                             .read_exact(&mut bytes)
                             .map_err(|e| e.to_string())?;
-                        content_ids.push(UuidBase64Url::from_bytes(bytes));
+                        let mut formats = [0u8; 1];
+                        data_reader
+                            .read_exact(&mut formats)
+                            .map_err(|e| e.to_string())?;
+                        content.push(ContentAndFormats {
+                            content_id: UuidBase64Url::from_bytes(bytes),
+                            formats: ContentAndFormats::formats_from_u8(formats[0]),
+                        });
                     }
 
                     parsed.push(AccountAndContent {
                         account_id,
-                        content_ids,
+                        content,
                     });
                 }
 
@@ -226,8 +259,8 @@ impl TryFrom<BackupMessage> for SourceToTargetMessage {
                         Err(e) if e.kind() == ErrorKind::UnexpectedEof => break,
                         Err(e) => return Err(e.to_string()),
                     }
-                    let Some(variant) = ContentQualityVariant::from_u8(variant_byte[0]) else {
-                        return Err("Invalid content quality variant byte".to_string());
+                    let Some(variant) = ContentFileVariant::from_u8(variant_byte[0]) else {
+                        return Err("Invalid content file variant byte".to_string());
                     };
                     let mut sha256 = [0u8; 32];
                     data_reader
@@ -272,7 +305,7 @@ pub enum TargetToSourceMessage {
     ContentQuery {
         account_id: UuidBase64Url,
         content_id: UuidBase64Url,
-        variants: HashSet<ContentQualityVariant>,
+        variants: HashSet<ContentFileVariant>,
     },
 }
 
@@ -294,7 +327,7 @@ impl TargetToSourceMessage {
                 data.extend(account_id.as_bytes());
                 data.extend(content_id.as_bytes());
 
-                if variants.len() == ContentQualityVariant::VARIANT_COUNT {
+                if variants == all_jpeg_variants() {
                     data.push(0);
                 } else {
                     data.push(1);
@@ -356,7 +389,7 @@ impl TryFrom<BackupMessage> for TargetToSourceMessage {
                 let mode = mode[0];
 
                 let variants = if mode == 0 {
-                    HashSet::from(ContentQualityVariant::all_variants())
+                    all_jpeg_variants()
                 } else {
                     let mut variants = HashSet::new();
                     loop {
@@ -366,7 +399,7 @@ impl TryFrom<BackupMessage> for TargetToSourceMessage {
                             Err(e) if e.kind() == ErrorKind::UnexpectedEof => break,
                             Err(e) => return Err(e.to_string()),
                         }
-                        let Some(v) = ContentQualityVariant::from_u8(variant_byte[0]) else {
+                        let Some(v) = ContentFileVariant::from_u8(variant_byte[0]) else {
                             return Err("Invalid variant byte".to_string());
                         };
                         variants.insert(v);
@@ -386,6 +419,13 @@ impl TryFrom<BackupMessage> for TargetToSourceMessage {
     }
 }
 
+fn all_jpeg_variants() -> HashSet<ContentFileVariant> {
+    ContentQualityVariant::all_variants()
+        .into_iter()
+        .map(ContentFileVariant::jpeg)
+        .collect()
+}
+
 pub struct Sha256Bytes(pub [u8; 32]);
 
 impl Sha256Bytes {
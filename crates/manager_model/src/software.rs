@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use simple_backend_model::UnixTime;
 use utoipa::{IntoParams, ToSchema};
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ToSchema)]
@@ -10,6 +11,14 @@ pub struct SoftwareUpdateStatus {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[schema(nullable = false)]
     pub installed: Option<SoftwareInfo>,
+    /// Error from the latest download or install task.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub last_error: Option<SoftwareUpdateError>,
+    /// Latest automatic rollback since manager start.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub last_rollback: Option<SoftwareRollback>,
 }
 
 impl SoftwareUpdateStatus {
@@ -18,6 +27,8 @@ impl SoftwareUpdateStatus {
             state: SoftwareUpdateState::Idle,
             downloaded: None,
             installed: None,
+            last_error: None,
+            last_rollback: None,
         }
     }
 }
@@ -29,6 +40,29 @@ pub enum SoftwareUpdateState {
     Installing,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ToSchema)]
+pub enum SoftwareUpdateError {
+    DownloadFailed,
+    InstallFailed,
+    /// Detached signature of the downloaded software did not verify
+    /// with any of the configured public keys.
+    SignatureVerificationFailed,
+    /// Installed software did not pass health checks and
+    /// the previous software was restored.
+    HealthCheckFailedAndRolledBack,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct SoftwareRollback {
+    pub time: UnixTime,
+    /// Software which failed health checks
+    pub failed: SoftwareInfo,
+    /// Software which was restored
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub restored: Option<SoftwareInfo>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema, IntoParams)]
 pub struct SoftwareInfo {
     pub name: String,
@@ -189,6 +189,16 @@ macro_rules! define_permissions {
                     )*
                 }
             }
+
+            pub fn some_permission_granted(&self) -> bool {
+                false $( || self.$name )*
+            }
+
+            /// Returns true if all permissions granted in `other`
+            /// are also granted in `self`.
+            pub fn contains_all(&self, other: &Self) -> bool {
+                true $( && (!other.$name || self.$name) )*
+            }
         }
 
     };
@@ -253,6 +263,8 @@ define_permissions!(
     /// attribute/value `name`, `icon`, and `translations`.
     admin_edit_profile_attributes_schema_visible_content,
     admin_custom_email,
+    admin_view_admin_audit_log,
+    admin_import_account_data,
 );
 
 impl Permissions {
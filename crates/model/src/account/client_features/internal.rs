@@ -7,7 +7,9 @@ use super::{
     AssociationConfig, ChatConfig, FeaturesConfig, LikesConfig, MapConfig, MembershipType,
     NewsConfig, ProfileConfig,
 };
-use crate::{AccountVerificationConfig, AgeVerificationConfig, ClientFeaturesConfig};
+use crate::{
+    AccountVerificationConfig, AgeVerificationConfig, ClientFeaturesConfig, language_fallback_chain,
+};
 
 const DEFAULT_CONFIG_FILE_TEXT: &str = r#"
 [attribution.generic]
@@ -132,12 +134,29 @@ pub struct AttributionConfigInternal {
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct StringResourceInternal {
     pub default: String,
-    /// Keys are country codes like "en".
+    /// Keys are language tags like "en" or "pt-BR".
     #[serde(flatten)]
     pub translations: BTreeMap<String, String>,
 }
 
 impl StringResourceInternal {
+    /// Get translation for the language. If there is no exact match,
+    /// languages from [language_fallback_chain] are tried before using
+    /// the default value.
+    ///
+    /// Language tags are compared case-insensitively like BCP 47
+    /// defines, so "pt-br" matches translation "pt-BR".
+    pub fn translation(&self, language: &str) -> &str {
+        language_fallback_chain(language)
+            .find_map(|l| {
+                self.translations
+                    .iter()
+                    .find(|(k, _)| k.eq_ignore_ascii_case(l))
+                    .map(|(_, v)| v)
+            })
+            .unwrap_or(&self.default)
+    }
+
     pub fn values(&self) -> impl Iterator<Item = &str> {
         std::iter::once(self.default.as_str()).chain(self.translations.values().map(|v| v.as_str()))
     }
@@ -198,3 +217,52 @@ impl From<MembershipTypeInternal> for MembershipType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resource() -> StringResourceInternal {
+        StringResourceInternal {
+            default: "default".to_string(),
+            translations: [("pt", "pt"), ("pt-BR", "pt-BR"), ("fi", "fi")]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn translation_for_regional_language_tag() {
+        assert_eq!(resource().translation("pt-BR"), "pt-BR");
+    }
+
+    #[test]
+    fn translation_falls_back_to_base_language() {
+        assert_eq!(resource().translation("pt-PT"), "pt");
+        assert_eq!(resource().translation("fi_FI"), "fi");
+    }
+
+    #[test]
+    fn translation_falls_back_to_default() {
+        assert_eq!(resource().translation("sv-SE"), "default");
+        assert_eq!(resource().translation("sv"), "default");
+        assert_eq!(resource().translation(""), "default");
+    }
+
+    #[test]
+    fn missing_translation_uses_default() {
+        let resource = StringResourceInternal {
+            default: "default".to_string(),
+            translations: BTreeMap::new(),
+        };
+        assert_eq!(resource.translation("pt-BR"), "default");
+    }
+
+    #[test]
+    fn translation_language_tag_comparison_is_case_insensitive() {
+        assert_eq!(resource().translation("pt-br"), "pt-BR");
+        assert_eq!(resource().translation("PT-pt"), "pt");
+        assert_eq!(resource().translation("FI"), "fi");
+    }
+}
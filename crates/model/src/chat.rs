@@ -204,6 +204,13 @@ pub struct NewReceivedLikesCountResult {
     pub l: ReceivedLikeId,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, PartialEq)]
+pub struct PendingMessageId {
+    /// Sender of the message.
+    pub sender: AccountId,
+    pub id: MessageId,
+}
+
 #[derive(Debug, Clone)]
 pub struct PendingMessageInfo {
     /// ID column value
@@ -279,3 +286,15 @@ pub struct DataExportLatestSeenMessage {
     a: AccountId,
     mn: i64,
 }
+
+/// Message push notification is allowed to be sent if recipient side
+/// of acknowledgement queue is empty when sending a new message.
+/// This avoids sending multiple push notifications if client is running
+/// in background and can receive push notifications.
+pub struct PushNotificationAllowed;
+
+pub struct NewPendingMessage {
+    /// Backend signed PGP message containing `SignedMessageData`.
+    pub signed_message: Vec<u8>,
+    pub push_notification_allowed: Option<PushNotificationAllowed>,
+}
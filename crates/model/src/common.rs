@@ -83,6 +83,58 @@ pub enum ResponseNextProfilePageStatus {
     InternalServerError = 3,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+#[repr(u8)]
+pub enum ResponseSendMessageStatus {
+    Success = 0,
+    RateLimited = 1,
+    InternalServerError = 2,
+    TooManyRecipientAcknowledgementsMissing = 3,
+    TooManySenderAcknowledgementsMissing = 4,
+    SenderPublicKeyOutdated = 5,
+    RecipientPublicKeyOutdated = 6,
+    RecipientBlockedSenderOrRecipientNotFound = 7,
+    TooManyPendingDeliveryInfosExists = 8,
+}
+
+/// Remaining daily messages count is sent to client when the count is
+/// this or less.
+pub const DAILY_MESSAGES_REMAINING_WARNING_THRESHOLD: u16 = 50;
+
+/// WebSocket version of `SendMessageResult`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseSendMessage {
+    pub status: ResponseSendMessageStatus,
+    /// Remaining daily messages count. The value is available only
+    /// if there is 50 or less messages left.
+    pub remaining_messages: Option<u16>,
+    /// Remaining messages which can be sent to conversation before
+    /// delivery to recipient or message sending acknowledgement must happen.
+    /// The value is available only if there is 5 or less messages left.
+    pub remaining_conversation_messages: Option<u16>,
+    /// Backend signed PGP message containing `SignedMessageData`.
+    /// Empty if status is not success.
+    pub signed_message: Vec<u8>,
+}
+
+impl ResponseSendMessage {
+    pub fn error(status: ResponseSendMessageStatus) -> Self {
+        Self {
+            status,
+            remaining_messages: None,
+            remaining_conversation_messages: None,
+            signed_message: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+#[repr(u8)]
+pub enum ResponseAcknowledgeMessagesStatus {
+    Success = 0,
+    InternalServerError = 1,
+}
+
 /// Internal data type for events.
 ///
 /// If data is not included in the event it might be too large to send
@@ -138,6 +190,16 @@ pub enum EventToClientInternal {
     OnlineStatusUpdated(OnlineStatusUpdate),
     MessageDeliveryInfoChanged,
     LatestSeenMessageChanged,
+    ResponseSendMessage {
+        request_id: u8,
+        response: ResponseSendMessage,
+    },
+    /// Backend signed PGP message containing `SignedMessageData`.
+    PendingMessage(Vec<u8>),
+    ResponseAcknowledgeMessages {
+        request_id: u8,
+        status: ResponseAcknowledgeMessagesStatus,
+    },
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -82,6 +82,26 @@ impl TryFrom<NonEmptyString> for ClientLanguage {
 
 diesel_non_empty_string_wrapper!(ClientLanguage);
 
+/// Languages to try when selecting a translation for the language.
+/// Language subtags are removed one at a time, so for example "pt-BR"
+/// returns "pt-BR" and "pt". Both '-' and '_' are accepted as subtag
+/// separators.
+///
+/// The case of the language is not changed. Language tags are
+/// case-insensitive, so compare the returned values with
+/// [str::eq_ignore_ascii_case].
+pub fn language_fallback_chain(language: &str) -> impl Iterator<Item = &str> {
+    let mut next = Some(language).filter(|v| !v.is_empty());
+    std::iter::from_fn(move || {
+        let current = next?;
+        next = current
+            .rfind(['-', '_'])
+            .map(|i| &current[..i])
+            .filter(|v| !v.is_empty());
+        Some(current)
+    })
+}
+
 impl AsRef<str> for ClientLanguage {
     fn as_ref(&self) -> &str {
         self.as_str()
@@ -94,3 +114,17 @@ pub struct GetClientLanguage {
     #[schema(nullable = false)]
     pub l: Option<ClientLanguage>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn language_fallback_chain_removes_subtags() {
+        let chain = |l| language_fallback_chain(l).collect::<Vec<_>>();
+        assert_eq!(chain("pt-BR"), vec!["pt-BR", "pt"]);
+        assert_eq!(chain("zh_Hant_TW"), vec!["zh_Hant_TW", "zh_Hant", "zh"]);
+        assert_eq!(chain("fi"), vec!["fi"]);
+        assert!(chain("").is_empty());
+    }
+}
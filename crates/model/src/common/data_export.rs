@@ -56,7 +56,7 @@ impl DataExportState {
     }
 }
 
-#[derive(Debug, Clone, Copy, Deserialize, ToSchema, PartialEq)]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, ToSchema, PartialEq)]
 pub enum DataExportType {
     /// User initiated data export which
     /// doesn't expose information on other users.
@@ -66,7 +66,7 @@ pub enum DataExportType {
     Admin,
 }
 
-#[derive(Deserialize, ToSchema)]
+#[derive(Deserialize, Serialize, ToSchema)]
 pub struct PostStartDataExport {
     /// Data reading source account.
     pub source: AccountId,
@@ -6,7 +6,9 @@ use diesel::{
     sql_types::{BigInt, SmallInt, Text},
 };
 use serde::{Deserialize, Serialize};
-use simple_backend_model::{diesel_db_i16_is_i8_struct, diesel_i64_wrapper, diesel_string_wrapper};
+use simple_backend_model::{
+    SimpleDieselEnum, diesel_db_i16_is_i8_struct, diesel_i64_wrapper, diesel_string_wrapper,
+};
 use utils::random_bytes::random_128_bits;
 use utoipa::ToSchema;
 
@@ -105,7 +107,37 @@ impl From<PushNotificationFlags> for PushNotificationFlagsDb {
     }
 }
 
-/// Firebase Cloud Messaging device token.
+/// Push notification service which is used for sending
+/// notifications to a device token.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    Deserialize,
+    Serialize,
+    ToSchema,
+    PartialEq,
+    Eq,
+    SimpleDieselEnum,
+    diesel::FromSqlRow,
+    diesel::AsExpression,
+    num_enum::TryFromPrimitive,
+)]
+#[diesel(sql_type = SmallInt)]
+#[repr(i16)]
+pub enum PushNotificationProvider {
+    /// FCM, APNs or web push depending on [crate::ClientType].
+    #[default]
+    Platform = 0,
+    /// Device token is UnifiedPush distributor endpoint URL.
+    UnifiedPush = 1,
+}
+
+/// Push notification device token.
+///
+/// Firebase Cloud Messaging device token, APNs device token,
+/// web push subscription JSON or UnifiedPush endpoint URL.
 #[derive(
     Debug,
     Serialize,
@@ -212,6 +244,7 @@ pub struct PushNotificationDbState {
     pub pending_flags: PushNotificationFlagsDb,
     pub encryption_key: Option<PushNotificationEncryptionKey>,
     pub device_token: Option<PushNotificationDeviceToken>,
+    pub device_token_provider: PushNotificationProvider,
 }
 
 /// Notification ID for an event. Can be used to prevent showing
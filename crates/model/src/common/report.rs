@@ -85,6 +85,9 @@ pub enum ReportTypeInternal {
     ProfileText,
     ProfileContent,
     ChatMessage,
+    /// Server creates this report type. Report creator is the banned
+    /// account and target is the possible ban evasion account.
+    BanEvasion,
     /// Values from 64 to 127
     CustomReport(CustomReportTypeValue),
 }
@@ -100,6 +103,7 @@ impl ReportTypeInternal {
             Self::ProfileText => 1,
             Self::ProfileContent => 2,
             Self::ChatMessage => 3,
+            Self::BanEvasion => 4,
             Self::CustomReport(value) => value.0,
         }
     }
@@ -114,6 +118,7 @@ impl TryFrom<i16> for ReportTypeInternal {
             1 => Self::ProfileText,
             2 => Self::ProfileContent,
             3 => Self::ChatMessage,
+            4 => Self::BanEvasion,
             64..=127 => Self::CustomReport(CustomReportTypeValue(value)),
             v => return Err(format!("Unknown report type number value {v}")),
         };
@@ -177,6 +182,7 @@ impl From<ReportTypeInternal> for ReportType {
 /// * ProfileText = 1
 /// * ProfileContent = 2
 /// * ChatMessage = 3
+/// * BanEvasion = 4
 /// * CustomReport = values from 64 to 127
 #[derive(Debug, Clone, Copy, Deserialize, Serialize, ToSchema)]
 pub struct ReportType {
@@ -190,6 +196,10 @@ impl ReportType {
     pub const LAST_CUSTOM_REPORT_TYPE_NUMBER: i8 = 127;
     /// Max count for reports related to some account with specific type.
     pub const MAX_COUNT: usize = 100;
+
+    pub fn is_ban_evasion(&self) -> bool {
+        self.t == ReportTypeInternal::BanEvasion.to_i8()
+    }
 }
 
 #[derive(
@@ -25,6 +25,26 @@ use utoipa::ToSchema;
 /// - `TypingStop` (121): payload is empty.
 /// - `CheckOnlineStatus` (122): payload is 16 bytes account UUID. Optional
 ///   17th byte can be included for online status hint (0 = false, non-zero = true).
+/// - `SendMessage` (123): server responds with `ResponseSendMessage`.
+///   Payload format:
+///   - request id byte (u8)
+///   - recipient account UUID (16 bytes, big-endian byte order)
+///   - message UUID (16 bytes, big-endian byte order)
+///   - sender public key ID as minimal i64
+///   - recipient public key ID as minimal i64
+///   - message data until payload ends (max size is u16::MAX)
+/// - `AcknowledgeReceivedMessages` (124): server responds with
+///   `ResponseAcknowledgeMessages`. Payload format:
+///   - request id byte (u8)
+///   - delivery failed (u8, 0 = false, non-zero = true)
+///   - repeated entries until payload ends:
+///     - sender account UUID (16 bytes, big-endian byte order)
+///     - message UUID (16 bytes, big-endian byte order)
+/// - `AcknowledgeSentMessages` (125): server responds with
+///   `ResponseAcknowledgeMessages`. Payload format:
+///   - request id byte (u8)
+///   - repeated message UUIDs (16 bytes, big-endian byte order) until
+///     payload ends
 ///
 /// # Data formats
 ///
@@ -51,6 +71,9 @@ pub enum ClientMessageType {
     TypingStart = 120,
     TypingStop = 121,
     CheckOnlineStatus = 122,
+    SendMessage = 123,
+    AcknowledgeReceivedMessages = 124,
+    AcknowledgeSentMessages = 125,
 }
 
 #[derive(Debug, Clone, Copy)]
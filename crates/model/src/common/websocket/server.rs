@@ -6,7 +6,7 @@ use utoipa::ToSchema;
 use crate::{
     AccountId, ContentProcessingStateChanged, ContentProcessingStateInternal,
     EventToClientInternal, OnlineStatusUpdate, ProfileLink, ResponseNextProfilePageStatus,
-    ResponseResetProfilePagingStatus,
+    ResponseResetProfilePagingStatus, ResponseSendMessage, ResponseSendMessageStatus,
 };
 
 mod parser;
@@ -105,6 +105,39 @@ pub use parser::parse_server_binary_message;
 ///   followed by null last seen time (0 byte) or last seen time as minimal i64.
 /// - `MessageDeliveryInfoChanged` (127): payload is empty.
 /// - `LatestSeenMessageChanged` (128): payload is empty.
+/// - `ResponseSendMessage` (129): payload format:
+///   - request id byte (u8)
+///   - status byte:
+///     - 0: success
+///     - 1: rate limited
+///     - 2: internal server error
+///     - 3: too many recipient acknowledgements missing
+///     - 4: too many sender acknowledgements missing
+///     - 5: sender public key outdated
+///     - 6: recipient public key outdated
+///     - 7: recipient blocked sender or recipient not found
+///     - 8: too many pending delivery infos exists
+///   - null remaining daily messages (0 byte) or remaining daily messages
+///     as minimal i64. The value is sent only if there is 50 or less
+///     messages left.
+///   - if status is 0:
+///     - null remaining conversation messages (0 byte) or remaining
+///       conversation messages as minimal i64. The value is sent only if
+///       there is 5 or less messages left.
+///     - backend signed PGP message containing `SignedMessageData` until
+///       payload ends
+/// - `PendingMessage` (130): payload is backend signed PGP message
+///   containing `SignedMessageData`. Message is sent to message recipient
+///   when new message is received while WebSocket connection is open.
+///   `NewMessageReceived` is also sent at the same time for clients which
+///   fetch pending messages using HTTP API. When WebSocket connection is
+///   created and there is pending messages, only `NewMessageReceived` is
+///   sent.
+/// - `ResponseAcknowledgeMessages` (131): payload format:
+///   - request id byte (u8)
+///   - status byte:
+///     - 0: success
+///     - 1: internal server error
 ///
 /// # Data formats
 ///
@@ -150,6 +183,9 @@ pub enum ServerMessageType {
     OnlineStatusUpdated = 126,
     MessageDeliveryInfoChanged = 127,
     LatestSeenMessageChanged = 128,
+    ResponseSendMessage = 129,
+    PendingMessage = 130,
+    ResponseAcknowledgeMessages = 131,
 }
 
 pub fn create_server_binary_message(event: &EventToClientInternal) -> Vec<u8> {
@@ -209,6 +245,11 @@ pub fn create_server_binary_message(event: &EventToClientInternal) -> Vec<u8> {
         EventToClientInternal::LatestSeenMessageChanged => {
             ServerMessageType::LatestSeenMessageChanged
         }
+        EventToClientInternal::ResponseSendMessage { .. } => ServerMessageType::ResponseSendMessage,
+        EventToClientInternal::PendingMessage(_) => ServerMessageType::PendingMessage,
+        EventToClientInternal::ResponseAcknowledgeMessages { .. } => {
+            ServerMessageType::ResponseAcknowledgeMessages
+        }
     };
 
     let mut message = vec![message_type as u8];
@@ -275,6 +316,19 @@ pub fn create_server_binary_message(event: &EventToClientInternal) -> Vec<u8> {
         } => {
             append_response_next_profile_page_payload(&mut message, *request_id, *status, profiles);
         }
+        EventToClientInternal::ResponseSendMessage {
+            request_id,
+            response,
+        } => {
+            append_response_send_message_payload(&mut message, *request_id, response);
+        }
+        EventToClientInternal::PendingMessage(value) => {
+            message.extend_from_slice(value);
+        }
+        EventToClientInternal::ResponseAcknowledgeMessages { request_id, status } => {
+            message.push(*request_id);
+            message.push(*status as u8);
+        }
         EventToClientInternal::AccountStateChanged
         | EventToClientInternal::NewMessageReceived
         | EventToClientInternal::PendingChatNotificationsChanged
@@ -347,6 +401,34 @@ fn append_response_next_profile_page_payload(
     }
 }
 
+fn append_response_send_message_payload(
+    buffer: &mut Vec<u8>,
+    request_id: u8,
+    response: &ResponseSendMessage,
+) {
+    buffer.push(request_id);
+    buffer.push(response.status as u8);
+    append_optional_u16_payload(buffer, response.remaining_messages);
+
+    if !matches!(response.status, ResponseSendMessageStatus::Success) {
+        return;
+    }
+
+    append_optional_u16_payload(buffer, response.remaining_conversation_messages);
+    buffer.extend_from_slice(&response.signed_message);
+}
+
+fn append_optional_u16_payload(buffer: &mut Vec<u8>, value: Option<u16>) {
+    match value {
+        Some(value) => {
+            minimal_i64::add_minimal_i64(buffer, value.into());
+        }
+        None => {
+            buffer.push(0);
+        }
+    }
+}
+
 fn append_scheduled_maintenance_status_payload(
     buffer: &mut Vec<u8>,
     value: &ScheduledMaintenanceStatus,
@@ -3,8 +3,9 @@ use utils::minimal_i64;
 use crate::{
     AccountId, ContentProcessingStateChanged, ContentProcessingStateInternal,
     ContentProcessingStateType, EventToClientInternal, LastSeenTime, OnlineStatusUpdate,
-    ProfileContentVersion, ProfileLink, ProfileVersion, ResponseNextProfilePageStatus,
-    ResponseResetProfilePagingStatus, ScheduledMaintenanceStatus, ServerMessageType, UnixTime,
+    ProfileContentVersion, ProfileLink, ProfileVersion, ResponseAcknowledgeMessagesStatus,
+    ResponseNextProfilePageStatus, ResponseResetProfilePagingStatus, ResponseSendMessage,
+    ResponseSendMessageStatus, ScheduledMaintenanceStatus, ServerMessageType, UnixTime,
 };
 
 pub fn parse_server_binary_message(message: &[u8]) -> Result<EventToClientInternal, String> {
@@ -115,6 +116,28 @@ pub fn parse_server_binary_message(message: &[u8]) -> Result<EventToClientIntern
         ServerMessageType::LatestSeenMessageChanged => {
             EventToClientInternal::LatestSeenMessageChanged
         }
+        ServerMessageType::ResponseSendMessage => {
+            let (request_id, response) = parse_response_send_message_payload(&mut message_iter)?;
+            EventToClientInternal::ResponseSendMessage {
+                request_id,
+                response,
+            }
+        }
+        ServerMessageType::PendingMessage => {
+            EventToClientInternal::PendingMessage(message_iter.by_ref().collect())
+        }
+        ServerMessageType::ResponseAcknowledgeMessages => {
+            let request_id = next_payload_byte(
+                &mut message_iter,
+                "response acknowledge messages request id",
+            )?;
+            let status_raw =
+                next_payload_byte(&mut message_iter, "response acknowledge messages status")?;
+            let status = ResponseAcknowledgeMessagesStatus::try_from(status_raw).map_err(|_| {
+                format!("unsupported acknowledge messages status value {status_raw}")
+            })?;
+            EventToClientInternal::ResponseAcknowledgeMessages { request_id, status }
+        }
     };
 
     ensure_payload_fully_consumed(&mut message_iter, message_type)?;
@@ -341,6 +364,60 @@ fn parse_optional_last_seen_time_payload(
     Ok(Some(LastSeenTime::new(value)))
 }
 
+fn parse_response_send_message_payload(
+    payload_iter: &mut impl Iterator<Item = u8>,
+) -> Result<(u8, ResponseSendMessage), String> {
+    let request_id = next_payload_byte(payload_iter, "response send message request id")?;
+    let status_raw = next_payload_byte(payload_iter, "response send message status")?;
+    let status = ResponseSendMessageStatus::try_from(status_raw)
+        .map_err(|_| format!("unsupported send message status value {status_raw}"))?;
+    let remaining_messages = parse_optional_u16_payload(payload_iter, "remaining messages")?;
+
+    if !matches!(status, ResponseSendMessageStatus::Success) {
+        if payload_iter.next().is_some() {
+            return Err("unexpected payload for non-success send message response".to_owned());
+        }
+        let mut response = ResponseSendMessage::error(status);
+        response.remaining_messages = remaining_messages;
+        return Ok((request_id, response));
+    }
+
+    let remaining_conversation_messages =
+        parse_optional_u16_payload(payload_iter, "remaining conversation messages")?;
+    let signed_message = payload_iter.collect();
+
+    Ok((
+        request_id,
+        ResponseSendMessage {
+            status,
+            remaining_messages,
+            remaining_conversation_messages,
+            signed_message,
+        },
+    ))
+}
+
+fn parse_optional_u16_payload(
+    payload_iter: &mut impl Iterator<Item = u8>,
+    payload_name: &'static str,
+) -> Result<Option<u16>, String> {
+    let marker = next_payload_byte(payload_iter, payload_name)?;
+
+    if marker == 0 {
+        return Ok(None);
+    }
+
+    let value = parse_minimal_i64_value_from_marker(
+        marker,
+        payload_iter,
+        "invalid or truncated u16 payload",
+    )?;
+
+    u16::try_from(value)
+        .map(Some)
+        .map_err(|_| format!("{payload_name} value {value} is out of range"))
+}
+
 fn parse_content_processing_state_changed_payload(
     payload_iter: &mut impl Iterator<Item = u8>,
 ) -> Result<ContentProcessingStateChanged, String> {
@@ -410,8 +487,9 @@ mod tests {
     use crate::{
         AccountId, ContentProcessingStateChanged, ContentProcessingStateInternal,
         EventToClientInternal, LastSeenTime, OnlineStatusUpdate, ProfileContentVersion,
-        ProfileLink, ProfileVersion, ResponseNextProfilePageStatus,
-        ResponseResetProfilePagingStatus, UnixTime,
+        ProfileLink, ProfileVersion, ResponseAcknowledgeMessagesStatus,
+        ResponseNextProfilePageStatus, ResponseResetProfilePagingStatus, ResponseSendMessage,
+        ResponseSendMessageStatus, UnixTime,
         common::websocket::server::create_server_binary_message,
     };
 
@@ -910,4 +988,99 @@ mod tests {
             _ => panic!("unexpected event parsed"),
         }
     }
+
+    #[test]
+    fn roundtrip_response_send_message_message() {
+        let request_id = 14;
+        let response = ResponseSendMessage {
+            status: ResponseSendMessageStatus::Success,
+            remaining_messages: Some(0),
+            remaining_conversation_messages: None,
+            signed_message: vec![1, 2, 3, 0, 4],
+        };
+
+        let message = create_server_binary_message(&EventToClientInternal::ResponseSendMessage {
+            request_id,
+            response: response.clone(),
+        });
+        let parsed = parse_server_binary_message(&message).expect("send message should parse");
+
+        match parsed {
+            EventToClientInternal::ResponseSendMessage {
+                request_id: parsed_request_id,
+                response: parsed_response,
+            } => {
+                assert_eq!(parsed_request_id, request_id);
+                assert_eq!(parsed_response, response);
+            }
+            _ => panic!("unexpected event parsed"),
+        }
+    }
+
+    #[test]
+    fn roundtrip_response_send_message_error_message() {
+        let request_id = 15;
+        let mut response =
+            ResponseSendMessage::error(ResponseSendMessageStatus::SenderPublicKeyOutdated);
+        response.remaining_messages = Some(50);
+
+        let message = create_server_binary_message(&EventToClientInternal::ResponseSendMessage {
+            request_id,
+            response: response.clone(),
+        });
+        let parsed =
+            parse_server_binary_message(&message).expect("send message error should parse");
+
+        match parsed {
+            EventToClientInternal::ResponseSendMessage {
+                request_id: parsed_request_id,
+                response: parsed_response,
+            } => {
+                assert_eq!(parsed_request_id, request_id);
+                assert_eq!(parsed_response, response);
+            }
+            _ => panic!("unexpected event parsed"),
+        }
+    }
+
+    #[test]
+    fn roundtrip_pending_message_message() {
+        let data = vec![5, 6, 7];
+
+        let message =
+            create_server_binary_message(&EventToClientInternal::PendingMessage(data.clone()));
+        let parsed = parse_server_binary_message(&message).expect("pending message should parse");
+
+        match parsed {
+            EventToClientInternal::PendingMessage(parsed_data) => {
+                assert_eq!(parsed_data, data);
+            }
+            _ => panic!("unexpected event parsed"),
+        }
+    }
+
+    #[test]
+    fn roundtrip_response_acknowledge_messages_message() {
+        let request_id = 16;
+        let status = ResponseAcknowledgeMessagesStatus::InternalServerError;
+
+        let message =
+            create_server_binary_message(&EventToClientInternal::ResponseAcknowledgeMessages {
+                request_id,
+                status,
+            });
+        let parsed = parse_server_binary_message(&message)
+            .expect("acknowledge messages response should parse");
+
+        match parsed {
+            EventToClientInternal::ResponseAcknowledgeMessages {
+                request_id: parsed_request_id,
+                status: parsed_status,
+            } => {
+                assert_eq!(parsed_request_id, request_id);
+                assert_eq!(parsed_status, status);
+            }
+            _ => panic!("unexpected event parsed"),
+        }
+    }
 }
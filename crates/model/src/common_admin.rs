@@ -16,6 +16,12 @@ pub use ip_address::*;
 mod admin_bot;
 pub use admin_bot::*;
 
+mod audit_log;
+pub use audit_log::*;
+
+mod data_import;
+pub use data_import::*;
+
 #[derive(Debug, Clone, Deserialize, Serialize, ToSchema, Default)]
 pub struct BotConfig {
     /// Enable remote bot login API
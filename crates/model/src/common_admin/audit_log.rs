@@ -0,0 +1,194 @@
+use std::net::IpAddr;
+
+use diesel::{Selectable, prelude::Queryable, sql_types::SmallInt};
+use serde::{Deserialize, Serialize};
+use simple_backend_model::{SimpleDieselEnum, UnixTime};
+use utoipa::ToSchema;
+
+use crate::{AccountId, AccountIdInternal, ContentId, IpAddressInternal};
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Deserialize,
+    Serialize,
+    ToSchema,
+    PartialEq,
+    Eq,
+    Hash,
+    SimpleDieselEnum,
+    diesel::FromSqlRow,
+    diesel::AsExpression,
+    num_enum::TryFromPrimitive,
+)]
+#[diesel(sql_type = SmallInt)]
+#[repr(i16)]
+pub enum AdminAuditLogAction {
+    // Common
+    EditMaintenanceNotification = 0,
+    EditBotConfig = 1,
+    EditServerConfig = 2,
+    ProcessReport = 3,
+    TriggerSoftwareUpdateDownload = 4,
+    TriggerSoftwareUpdateInstall = 5,
+    TriggerServerDataReset = 6,
+    TriggerServerRestart = 7,
+    TriggerSystemReboot = 8,
+    TriggerSystemShutdown = 9,
+    ScheduleTask = 10,
+    UnscheduleTask = 11,
+    ImportAccountData = 12,
+    StartDataExport = 13,
+    // Account
+    SetBanState = 100,
+    DeleteAccount = 101,
+    SetPermissions = 102,
+    SetAccountLockedState = 103,
+    Logout = 104,
+    CancelEmailChange = 105,
+    InitEmailChange = 106,
+    ManualAssociationMembershipRegistry = 107,
+    DeleteAssociationMembership = 108,
+    UpdateAssociationMembershipType = 109,
+    RemoveAccountVerificationQueueItem = 110,
+    SaveInfoBanners = 111,
+    CreateNewsItem = 112,
+    DeleteNewsItem = 113,
+    UpdateNewsTranslation = 114,
+    DeleteNewsTranslation = 115,
+    SetNewsPublicity = 116,
+    CreateCustomEmail = 117,
+    UpdateCustomEmail = 118,
+    SendCustomEmailToAllAccounts = 119,
+    SetAccountDeletionRequestState = 120,
+    GetAccountDeletionRequestState = 121,
+    SetEmailLoginEnabled = 122,
+    GetAccountBanTime = 123,
+    // Media
+    SetSecurityContentVerifiedValue = 200,
+    SetMediaContentFaceDetectedValue = 201,
+    SetMediaContentFaceVerifiedValue = 202,
+    ModerateMediaContent = 203,
+    EditImageProcessingConfig = 204,
+    DeleteMediaContent = 205,
+    // Profile
+    SetProfileAgeRangeVerifiedValue = 300,
+    SetProfileNameVerifiedValue = 301,
+    ModerateProfileString = 302,
+    SetProfileName = 303,
+    EditProfileAttributesSchema = 304,
+}
+
+/// Admin audit log entry which will be saved to history database.
+#[derive(Debug, Clone)]
+pub struct NewAdminAuditLogEntry {
+    pub admin: AccountId,
+    pub action: AdminAuditLogAction,
+    pub target_account: Option<AccountId>,
+    pub target_content: Option<ContentId>,
+    /// JSON
+    pub parameters: Option<String>,
+    pub ip_address: IpAddressInternal,
+}
+
+impl NewAdminAuditLogEntry {
+    pub fn new(admin: AccountIdInternal, ip_address: IpAddr, action: AdminAuditLogAction) -> Self {
+        Self {
+            admin: admin.as_id(),
+            action,
+            target_account: None,
+            target_content: None,
+            parameters: None,
+            ip_address: ip_address.into(),
+        }
+    }
+
+    pub fn target_account(mut self, account: AccountId) -> Self {
+        self.target_account = Some(account);
+        self
+    }
+
+    pub fn target_content(mut self, content: ContentId) -> Self {
+        self.target_content = Some(content);
+        self
+    }
+
+    pub fn parameters(mut self, parameters: &impl Serialize) -> Self {
+        self.parameters = serde_json::to_string(parameters).ok();
+        self
+    }
+}
+
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = crate::schema::history_admin_audit_log)]
+#[diesel(check_for_backend(crate::Db))]
+pub struct AdminAuditLogEntryInternal {
+    pub unix_time: UnixTime,
+    pub admin_account: AccountId,
+    pub action: AdminAuditLogAction,
+    pub target_account: Option<AccountId>,
+    pub target_content: Option<ContentId>,
+    pub parameters: Option<String>,
+    pub ip_address: IpAddressInternal,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct AdminAuditLogEntry {
+    pub time: UnixTime,
+    pub admin: AccountId,
+    pub action: AdminAuditLogAction,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub target_account: Option<AccountId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub target_content: Option<ContentId>,
+    /// Action parameters as JSON
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub parameters: Option<String>,
+    pub ip_address: String,
+}
+
+impl From<AdminAuditLogEntryInternal> for AdminAuditLogEntry {
+    fn from(value: AdminAuditLogEntryInternal) -> Self {
+        Self {
+            time: value.unix_time,
+            admin: value.admin_account,
+            action: value.action,
+            target_account: value.target_account,
+            target_content: value.target_content,
+            parameters: value.parameters,
+            ip_address: value.ip_address.to_ip_addr().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct AdminAuditLogQuery {
+    /// Only entries created at this time or before are returned.
+    pub start_position: UnixTime,
+    pub page: i64,
+    /// Filter by admin account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub admin: Option<AccountId>,
+    /// Filter by target account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub target_account: Option<AccountId>,
+    /// Filter by target content
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub target_content: Option<ContentId>,
+    /// Filter by action
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub action: Option<AdminAuditLogAction>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct AdminAuditLogPage {
+    pub entries: Vec<AdminAuditLogEntry>,
+}
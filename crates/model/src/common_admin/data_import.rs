@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::AccountId;
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize, ToSchema)]
+pub struct PostImportAccountDataResult {
+    /// Account ID of the imported account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub aid: Option<AccountId>,
+    /// Number of images which were not imported because image
+    /// processing failed.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    #[schema(default = 0)]
+    pub failed_image_count: i64,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[schema(default = false)]
+    pub error: bool,
+    /// The archive is not a user data export archive or
+    /// it contains invalid data.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[schema(default = false)]
+    pub error_invalid_archive: bool,
+    /// Some other account uses the email address from the archive.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[schema(default = false)]
+    pub error_email_already_used: bool,
+}
+
+impl PostImportAccountDataResult {
+    pub fn ok(aid: AccountId, failed_image_count: i64) -> Self {
+        Self {
+            aid: Some(aid),
+            failed_image_count,
+            ..Default::default()
+        }
+    }
+
+    pub fn error_invalid_archive() -> Self {
+        Self {
+            error: true,
+            error_invalid_archive: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn error_email_already_used() -> Self {
+        Self {
+            error: true,
+            error_email_already_used: true,
+            ..Default::default()
+        }
+    }
+}
+
+fn is_zero(value: &i64) -> bool {
+    *value == 0
+}
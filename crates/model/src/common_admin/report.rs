@@ -110,6 +110,47 @@ pub struct ReportContent {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[schema(nullable = false)]
     pub chat_message: Option<ChatMessageReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub ban_evasion: Option<BanEvasionEvidence>,
+}
+
+/// Signals which link the report target to the banned report creator.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct BanEvasionEvidence {
+    /// IP addresses which both accounts have used
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub shared_ip_addresses: Vec<String>,
+    /// Names of IP lists which contain IP addresses of both accounts
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub shared_ip_lists: Vec<String>,
+    /// Both accounts have the same Apple, Google or OIDC account
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[schema(default = false)]
+    pub shared_sign_in_with_account: bool,
+    /// Target's media content which is identical to some
+    /// media content of the creator.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub identical_media_content: Vec<ContentId>,
+}
+
+pub struct BanEvasionCandidate {
+    pub banned_account: AccountIdInternal,
+    pub target: AccountIdInternal,
+    pub evidence: BanEvasionEvidence,
+}
+
+impl BanEvasionEvidence {
+    /// Shared sign in with account or identical media content links
+    /// the accounts.
+    ///
+    /// IP addresses are shared between unrelated users because of
+    /// NAT, mobile networks and VPNs and IP lists can contain large
+    /// networks, so IP related signals alone do not link the accounts.
+    /// Those are only additional evidence for admins.
+    pub fn accounts_linked(&self) -> bool {
+        self.shared_sign_in_with_account || !self.identical_media_content.is_empty()
+    }
 }
 
 #[derive(Serialize, ToSchema)]
@@ -124,6 +165,10 @@ pub struct ProcessReport {
     pub report_type: ReportType,
     pub content: ReportContent,
     /// True marks the report as Accepted, false marks it as Rejected.
+    ///
+    /// Accepting a ban evasion report bans the report target until
+    /// the report creator's ban ends. That requires the
+    /// `admin_ban_account` permission.
     pub accepted: bool,
 }
 
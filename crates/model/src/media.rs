@@ -4,7 +4,8 @@ use diesel::{
 };
 use serde::{Deserialize, Serialize};
 pub use simple_backend_model::{
-    ContentQualityVariant, ImageProcessingDynamicConfig, ImageProcessingWarnings,
+    ContentFileVariant, ContentImageFormat, ContentQualityVariant, ImageProcessingDynamicConfig,
+    ImageProcessingWarnings,
 };
 use simple_backend_model::{SimpleDieselEnum, diesel_i64_wrapper, diesel_uuid_wrapper};
 use utoipa::{IntoParams, ToSchema};
@@ -1,4 +1,8 @@
-use std::{collections::HashSet, num::NonZeroU8, str::FromStr};
+use std::{
+    collections::HashSet,
+    num::{NonZeroU8, NonZeroU32},
+    str::FromStr,
+};
 
 use base64::Engine;
 use diesel::{deserialize::FromSqlRow, expression::AsExpression, sql_types::SmallInt};
@@ -205,6 +209,10 @@ pub struct Attribute {
     pub name: String,
     /// Mode of the attribute.
     pub mode: AttributeMode,
+    /// Value range for [AttributeMode::Numeric] attribute.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub numeric: Option<AttributeNumericRange>,
     #[serde(
         default = "value_non_zero_u8_one",
         skip_serializing_if = "value_non_zero_u8_is_one"
@@ -245,6 +253,10 @@ pub struct Attribute {
     ///
     /// Values are sorted by AttributeValue ID. Indexing with it is
     /// not possible as ID might be a bitflag value.
+    ///
+    /// Empty for [AttributeMode::Numeric] attribute.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schema(default = json!([]))]
     pub values: Vec<AttributeValue>,
     /// Translations for attribute name and attribute values.
     #[serde(default = "value_empty_vec", skip_serializing_if = "value_is_empty")]
@@ -297,11 +309,39 @@ impl Attribute {
             }
         }
 
-        if self.values.is_empty() {
-            return Err(format!(
-                "Attribute {} must have at least one value",
-                self.key
-            ));
+        match (self.mode, &self.numeric) {
+            (AttributeMode::Numeric, Some(range)) => {
+                range.validate()?;
+                if !self.values.is_empty() {
+                    return Err(format!("Numeric attribute {} cannot have values", self.key));
+                }
+                if self.max_selected.get() != 1 {
+                    return Err(format!(
+                        "Numeric attribute {} must have max_selected value 1",
+                        self.key
+                    ));
+                }
+            }
+            (AttributeMode::Numeric, None) => {
+                return Err(format!(
+                    "Numeric attribute {} must have numeric range",
+                    self.key
+                ));
+            }
+            (_, Some(_)) => {
+                return Err(format!(
+                    "Only numeric attributes can have numeric range, attribute: {}",
+                    self.key
+                ));
+            }
+            (_, None) => {
+                if self.values.is_empty() {
+                    return Err(format!(
+                        "Attribute {} must have at least one value",
+                        self.key
+                    ));
+                }
+            }
         }
 
         // Check that correct IDs are used.
@@ -504,6 +544,8 @@ pub enum AttributeMode {
     OneLevel,
     /// u32 values
     TwoLevel,
+    /// u32 value which is in range which [Attribute::numeric] defines.
+    Numeric,
 }
 
 impl AttributeMode {
@@ -514,6 +556,46 @@ impl AttributeMode {
     pub fn is_one_level(&self) -> bool {
         *self == Self::OneLevel
     }
+
+    pub fn is_numeric(&self) -> bool {
+        *self == Self::Numeric
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct AttributeNumericRange {
+    /// Min value (inclusive)
+    pub min: u32,
+    /// Max value (inclusive)
+    pub max: u32,
+    /// Valid values are `min + n * step` where `n` is 0 or greater
+    /// integer.
+    #[schema(value_type = u32, minimum = 1)]
+    pub step: NonZeroU32,
+}
+
+impl AttributeNumericRange {
+    fn validate(&self) -> Result<(), String> {
+        if self.min >= self.max {
+            return Err(format!(
+                "Numeric range min {} must be less than max {}",
+                self.min, self.max
+            ));
+        }
+
+        if !(self.max - self.min).is_multiple_of(self.step.get()) {
+            return Err(format!(
+                "Numeric range max {} is not reachable from min {} using step {}",
+                self.max, self.min, self.step
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn is_valid_value(&self, value: u32) -> bool {
+        (self.min..=self.max).contains(&value) && (value - self.min).is_multiple_of(self.step.get())
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
@@ -598,3 +680,35 @@ impl From<IconResource> for String {
         format!("{}:{}", location, icon.identifier)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(min: u32, max: u32, step: u32) -> AttributeNumericRange {
+        AttributeNumericRange {
+            min,
+            max,
+            step: NonZeroU32::new(step).unwrap(),
+        }
+    }
+
+    #[test]
+    fn numeric_range_validation() {
+        assert!(range(140, 220, 1).validate().is_ok());
+        assert!(range(0, 10, 5).validate().is_ok());
+        assert!(range(0, 10, 3).validate().is_err());
+        assert!(range(10, 10, 1).validate().is_err());
+    }
+
+    #[test]
+    fn numeric_range_valid_values() {
+        let r = range(10, 20, 5);
+        assert!(r.is_valid_value(10));
+        assert!(r.is_valid_value(15));
+        assert!(r.is_valid_value(20));
+        assert!(!r.is_valid_value(5));
+        assert!(!r.is_valid_value(12));
+        assert!(!r.is_valid_value(25));
+    }
+}
@@ -195,6 +195,8 @@ diesel::table! {
         admin_edit_profile_attributes_schema -> Bool,
         admin_edit_profile_attributes_schema_visible_content -> Bool,
         admin_custom_email -> Bool,
+        admin_view_admin_audit_log -> Bool,
+        admin_import_account_data -> Bool,
     }
 }
 
@@ -229,6 +231,22 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    account_totp (account_id) {
+        account_id -> Int8,
+        secret -> Bytea,
+        enabled -> Bool,
+        last_used_time_step -> Int8,
+    }
+}
+
+diesel::table! {
+    account_totp_recovery_code (account_id, code_hash) {
+        account_id -> Int8,
+        code_hash -> Bytea,
+    }
+}
+
 diesel::table! {
     admin_notification_settings (account_id) {
         account_id -> Int8,
@@ -375,6 +393,13 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    common_report_ban_evasion (report_id) {
+        report_id -> Int8,
+        evidence_json -> Text,
+    }
+}
+
 diesel::table! {
     common_state (account_id) {
         account_id -> Int8,
@@ -500,6 +525,19 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    history_admin_audit_log (id) {
+        id -> Int8,
+        unix_time -> Int8,
+        admin_account -> Bytea,
+        action -> Int2,
+        target_account -> Nullable<Bytea>,
+        target_content -> Nullable<Bytea>,
+        parameters -> Nullable<Text>,
+        ip_address -> Bytea,
+    }
+}
+
 diesel::table! {
     history_client_version_statistics (time_id, version_id) {
         time_id -> Int8,
@@ -728,6 +766,7 @@ diesel::table! {
         moderation_moderator_account_id -> Nullable<Int8>,
         usage_start_unix_time -> Nullable<Int8>,
         usage_end_unix_time -> Nullable<Int8>,
+        raw_content_sha256 -> Nullable<Bytea>,
     }
 }
 
@@ -803,6 +842,14 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    pending_message_attachment (pending_message_id) {
+        pending_message_id -> Int8,
+        upload_unix_time -> Int8,
+        attachment_bytes -> Bytea,
+    }
+}
+
 diesel::table! {
     pending_messages (id) {
         id -> Int8,
@@ -859,6 +906,8 @@ diesel::table! {
         attribute_id -> Int2,
         filter_accept_missing_attribute -> Bool,
         filter_use_logical_operator_and -> Bool,
+        filter_min -> Nullable<Int8>,
+        filter_max -> Nullable<Int8>,
     }
 }
 
@@ -987,6 +1036,7 @@ diesel::table! {
         pending_flags -> Int8,
         encryption_key -> Nullable<Text>,
         device_token -> Nullable<Text>,
+        device_token_provider -> Int2,
         device_token_unix_time -> Nullable<Int8>,
         sync_version -> Int2,
     }
@@ -1013,6 +1063,8 @@ diesel::table! {
         account_id -> Int8,
         apple_account_id -> Nullable<Text>,
         google_account_id -> Nullable<Text>,
+        oidc_provider -> Nullable<Text>,
+        oidc_account_id -> Nullable<Text>,
     }
 }
 
@@ -1052,6 +1104,8 @@ diesel::joinable!(account_id -> used_account_ids (id));
 diesel::joinable!(account_interaction_index -> account_interaction (interaction_id));
 diesel::joinable!(account_permissions -> account_id (account_id));
 diesel::joinable!(account_sign_in_with_history -> account_id (account_id));
+diesel::joinable!(account_totp -> account_id (account_id));
+diesel::joinable!(account_totp_recovery_code -> account_id (account_id));
 diesel::joinable!(admin_notification_settings -> account_id (account_id));
 diesel::joinable!(admin_notification_subscriptions -> account_id (account_id));
 diesel::joinable!(api_usage_statistics_metric_value -> account_id (account_id));
@@ -1062,6 +1116,7 @@ diesel::joinable!(chat_email_notification_settings -> account_id (account_id));
 diesel::joinable!(chat_privacy_settings -> account_id (account_id));
 diesel::joinable!(chat_report_chat_message -> common_report (report_id));
 diesel::joinable!(chat_state -> account_id (account_id));
+diesel::joinable!(common_report_ban_evasion -> common_report (report_id));
 diesel::joinable!(common_state -> account_id (account_id));
 diesel::joinable!(current_account_media -> account_id (account_id));
 diesel::joinable!(custom_email -> account_id (account_id_creator));
@@ -1093,6 +1148,7 @@ diesel::joinable!(media_state -> account_id (account_id));
 diesel::joinable!(news -> account_id (account_id_creator));
 diesel::joinable!(news_translations -> news (news_id));
 diesel::joinable!(pending_app_notifications -> account_id (account_id));
+diesel::joinable!(pending_message_attachment -> pending_messages (pending_message_id));
 diesel::joinable!(profile -> account_id (account_id));
 diesel::joinable!(profile_app_notification_settings -> account_id (account_id));
 diesel::joinable!(profile_attributes_filter_list_unwanted -> account_id (account_id));
@@ -1130,6 +1186,8 @@ diesel::allow_tables_to_appear_in_same_query!(
     account_permissions,
     account_sign_in_with_history,
     account_state,
+    account_totp,
+    account_totp_recovery_code,
     admin_notification_settings,
     admin_notification_subscriptions,
     api_usage_statistics_metric_name,
@@ -1145,6 +1203,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     chat_state,
     client_features_file_hash,
     common_report,
+    common_report_ban_evasion,
     common_state,
     conversation_id,
     current_account_media,
@@ -1159,6 +1218,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     dynamic_server_config,
     email_registration_limits,
     favorite_profile,
+    history_admin_audit_log,
     history_client_version_statistics,
     history_client_version_statistics_version_number,
     history_common_statistics_save_time,
@@ -1192,6 +1252,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     news,
     news_translations,
     pending_app_notifications,
+    pending_message_attachment,
     pending_chat_notifications,
     pending_messages,
     profile,
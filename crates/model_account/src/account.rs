@@ -3,7 +3,8 @@ use model::{
     ClientLanguage, ClientType, ClientVersion, EmailLoginToken, NewsSyncVersion, UnixTime,
 };
 use model_server_data::{
-    AppleAccountId, AuthPair, EmailAddress, GoogleAccountId, PublicationId, SignInWithInfo,
+    AppleAccountId, AuthPair, EmailAddress, GoogleAccountId, OidcAccountId, PublicationId,
+    SignInWithInfo,
 };
 use model_server_state::DemoAccountToken;
 use serde::{Deserialize, Serialize};
@@ -30,6 +31,9 @@ pub use report::*;
 mod client_features;
 pub use client_features::*;
 
+mod totp;
+pub use totp::*;
+
 pub mod verification;
 pub use verification::{
     AccountVerificationDataInternal, AccountVerificationQueueStatus,
@@ -473,6 +477,9 @@ pub struct SignInWithLoginInfo {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[schema(nullable = false)]
     pub google: Option<SignInWithGoogleInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub oidc: Option<SignInWithOidcInfo>,
 }
 
 #[derive(Deserialize, ToSchema)]
@@ -495,6 +502,19 @@ pub struct SignInWithGoogleInfo {
     pub nonce: String,
 }
 
+#[derive(Deserialize, ToSchema)]
+pub struct SignInWithOidcInfo {
+    /// OpenID Connect provider name from server config
+    pub provider: String,
+    /// ID token
+    pub token: String,
+    /// Base64 URL (with possible padding) encoded nonce.
+    ///
+    /// The token contains Base64 URL (with possible padding) encoded SHA-256
+    /// of the nonce.
+    pub nonce: String,
+}
+
 #[derive(Deserialize, ToSchema)]
 pub struct PutSignInWithApple {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -509,13 +529,26 @@ pub struct PutSignInWithGoogle {
     pub google: Option<SignInWithGoogleInfo>,
 }
 
+#[derive(Deserialize, ToSchema)]
+pub struct PutSignInWithOidc {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub oidc: Option<SignInWithOidcInfo>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, ToSchema, PartialEq)]
 pub struct SignInWithState {
     pub apple: bool,
     pub google: bool,
+    /// OpenID Connect provider name if OpenID Connect sign in is
+    /// associated with the account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub oidc: Option<String>,
 }
 
-/// Result of a sign in with Apple or Google association change.
+/// Result of a sign in with Apple, Google or OpenID Connect
+/// association change.
 #[derive(Debug, Default, Clone, Deserialize, Serialize, ToSchema)]
 pub struct PutSignInWithResult {
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
@@ -548,6 +581,8 @@ pub struct SignInWithInfoRaw {
     pub account_id: AccountIdDb,
     pub google_account_id: Option<GoogleAccountId>,
     pub apple_account_id: Option<AppleAccountId>,
+    pub oidc_provider: Option<String>,
+    pub oidc_account_id: Option<OidcAccountId>,
 }
 
 impl From<SignInWithInfoRaw> for SignInWithInfo {
@@ -555,6 +590,8 @@ impl From<SignInWithInfoRaw> for SignInWithInfo {
         Self {
             google_account_id: raw.google_account_id,
             apple_account_id: raw.apple_account_id,
+            oidc_provider: raw.oidc_provider,
+            oidc_account_id: raw.oidc_account_id,
         }
     }
 }
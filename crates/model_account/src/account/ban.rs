@@ -40,6 +40,7 @@ pub enum AccountBannedAdminType {
 /// - `2` = media content
 /// - `3` = chat message
 /// - `4` = report spam
+/// - `5` = ban evasion
 #[derive(
     Debug,
     Serialize,
@@ -62,6 +63,7 @@ pub struct AccountBanReasonCategory {
 
 impl AccountBanReasonCategory {
     pub const REPORT_SPAM: Self = Self { value: 4 };
+    pub const BAN_EVASION: Self = Self { value: 5 };
 }
 
 impl TryFrom<i16> for AccountBanReasonCategory {
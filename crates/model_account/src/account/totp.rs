@@ -0,0 +1,108 @@
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Clone, Queryable, Selectable)]
+#[diesel(table_name = crate::schema::account_totp)]
+#[diesel(check_for_backend(crate::Db))]
+pub struct AccountTotpInternal {
+    pub secret: Vec<u8>,
+    pub enabled: bool,
+    pub last_used_time_step: i64,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct TotpState {
+    /// TOTP enrollment is confirmed.
+    pub enabled: bool,
+    pub recovery_codes_left: i64,
+    /// Admin permissions are available only after TOTP verification.
+    pub verification_required: bool,
+    /// Current login session has valid TOTP verification.
+    pub verified: bool,
+}
+
+/// TOTP secret for authenticator apps.
+///
+/// Codes have 6 digits and they are generated with SHA1 and
+/// 30 second time step.
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct TotpEnrollment {
+    /// Base32 without padding
+    pub secret: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct TotpEnrollmentResult {
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[schema(default = false)]
+    error_already_enabled: bool,
+    /// Server requires TOTP for admin permissions and the account
+    /// has admin permissions. Enrollment is possible after
+    /// the permissions are removed.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[schema(default = false)]
+    error_admin_permissions: bool,
+    enrollment: Option<TotpEnrollment>,
+}
+
+impl TotpEnrollmentResult {
+    pub fn ok(secret: String) -> Self {
+        Self {
+            enrollment: Some(TotpEnrollment { secret }),
+            ..Default::default()
+        }
+    }
+
+    pub fn error_already_enabled() -> Self {
+        Self {
+            error_already_enabled: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn error_admin_permissions() -> Self {
+        Self {
+            error_admin_permissions: true,
+            ..Default::default()
+        }
+    }
+}
+
+/// TOTP code or recovery code
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct TotpCode {
+    pub code: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct TotpCodeResult {
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[schema(default = false)]
+    error_invalid_code: bool,
+    /// New recovery codes. Server does not store the codes, so
+    /// the client should show the codes to the user.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schema(default = json!([]))]
+    recovery_codes: Vec<String>,
+}
+
+impl TotpCodeResult {
+    pub fn ok() -> Self {
+        Self::default()
+    }
+
+    pub fn ok_with_recovery_codes(recovery_codes: Vec<String>) -> Self {
+        Self {
+            recovery_codes,
+            ..Default::default()
+        }
+    }
+
+    pub fn error_invalid_code() -> Self {
+        Self {
+            error_invalid_code: true,
+            ..Default::default()
+        }
+    }
+}
@@ -12,3 +12,24 @@ pub struct AdminInfo {
     pub aid: AccountId,
     pub permissions: Permissions,
 }
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct SetPermissionsResult {
+    /// Server requires TOTP for admin permissions and the account
+    /// does not have TOTP enabled. Permissions were not changed.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[schema(default = false)]
+    error_totp_not_enabled: bool,
+}
+
+impl SetPermissionsResult {
+    pub fn ok() -> Self {
+        Self::default()
+    }
+
+    pub fn error_totp_not_enabled() -> Self {
+        Self {
+            error_totp_not_enabled: true,
+        }
+    }
+}
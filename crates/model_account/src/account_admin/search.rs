@@ -1,4 +1,6 @@
-use model::AccountId;
+use std::net::IpAddr;
+
+use model::{AccountId, AccountStateContainer, Permissions, UnixTime};
 use model_server_data::EmailAddress;
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
@@ -14,3 +16,75 @@ pub struct GetAccountIdFromEmailResult {
     #[schema(nullable = false)]
     pub aid: Option<AccountId>,
 }
+
+/// Admin account search query.
+///
+/// All set filters must match.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct AdminAccountSearchQuery {
+    /// Case-insensitive partial email address match
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub email: Option<String>,
+    /// Case-insensitive partial profile name match
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub profile_name: Option<String>,
+    /// Sign in with Apple, Google or OpenID Connect account ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub sign_in_with_account_id: Option<String>,
+    /// IP address from IP address usage history.
+    ///
+    /// Requires permission
+    /// [model::Permissions::admin_view_account_ip_address_usage].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<String>, nullable = false)]
+    pub ip_address: Option<IpAddr>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub state: Option<AdminAccountSearchState>,
+    /// Account has all permissions which are enabled in this value.
+    /// If no permissions are enabled, account has at least one
+    /// permission.
+    ///
+    /// Requires permission [model::Permissions::admin_view_permissions].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub permissions: Option<Permissions>,
+    /// Account creation time is equal or after this time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub created_after: Option<UnixTime>,
+    /// Account creation time is equal or before this time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub created_before: Option<UnixTime>,
+    /// Page number starting from 0
+    pub page: i64,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, ToSchema, PartialEq)]
+pub enum AdminAccountSearchState {
+    InitialSetup,
+    Banned,
+    PendingDeletion,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct AdminAccountSearchResult {
+    pub entries: Vec<AdminAccountSearchResultEntry>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct AdminAccountSearchResultEntry {
+    pub aid: AccountId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub profile_name: Option<String>,
+    pub state: AccountStateContainer,
+    pub account_created_unix_time: UnixTime,
+}
@@ -2,13 +2,17 @@ use base64::Engine;
 use diesel::prelude::*;
 use model::{
     ConversationId, DailyLikesLeftSyncVersion, MatchId, MessageNumber, NewReceivedLikesCount,
-    NewReceivedLikesCountResult, PublicKeyId, ReceivedLikeId, ReceivedLikesSyncVersion, UnixTime,
+    NewReceivedLikesCountResult, PublicKeyId, ReceivedLikeId, ReceivedLikesSyncVersion,
+    ResponseSendMessage, ResponseSendMessageStatus, UnixTime,
 };
 use model_server_data::LimitedActionStatus;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use utoipa::{IntoParams, ToSchema};
 
-use crate::{AccountId, AccountIdDb, MessageId};
+use crate::{AccountId, AccountIdDb, MessageId, PendingMessageId};
+
+mod attachment;
+pub use attachment::*;
 
 mod public_key;
 pub use public_key::*;
@@ -84,13 +88,6 @@ pub struct SentBlocksPage {
     pub profiles: Vec<AccountId>,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, PartialEq)]
-pub struct PendingMessageId {
-    /// Sender of the message.
-    pub sender: AccountId,
-    pub id: MessageId,
-}
-
 #[derive(Debug, Clone, Deserialize, Serialize, ToSchema, PartialEq, Default)]
 pub struct PendingMessageAcknowledgementList {
     pub ids: Vec<PendingMessageId>,
@@ -274,6 +271,47 @@ impl SendMessageResult {
         self.remaining_messages = Some(remaining_messages);
         self
     }
+
+    pub fn to_websocket_response(&self) -> ResponseSendMessage {
+        let error_status = if self.error_too_many_recipient_acknowledgements_missing {
+            Some(ResponseSendMessageStatus::TooManyRecipientAcknowledgementsMissing)
+        } else if self.error_too_many_sender_acknowledgements_missing {
+            Some(ResponseSendMessageStatus::TooManySenderAcknowledgementsMissing)
+        } else if self.error_sender_public_key_outdated {
+            Some(ResponseSendMessageStatus::SenderPublicKeyOutdated)
+        } else if self.error_recipient_public_key_outdated {
+            Some(ResponseSendMessageStatus::RecipientPublicKeyOutdated)
+        } else if self.error_recipient_blocked_sender_or_recipient_not_found {
+            Some(ResponseSendMessageStatus::RecipientBlockedSenderOrRecipientNotFound)
+        } else if self.error_too_many_pending_delivery_infos_exists {
+            Some(ResponseSendMessageStatus::TooManyPendingDeliveryInfosExists)
+        } else if self.error {
+            Some(ResponseSendMessageStatus::InternalServerError)
+        } else {
+            None
+        };
+
+        let signed_message = self
+            .d
+            .as_ref()
+            .and_then(|d| base64::engine::general_purpose::STANDARD.decode(d).ok());
+
+        match (error_status, signed_message) {
+            (None, Some(signed_message)) => ResponseSendMessage {
+                status: ResponseSendMessageStatus::Success,
+                remaining_messages: self.remaining_messages,
+                remaining_conversation_messages: self.remaining_conversation_messages,
+                signed_message,
+            },
+            (error_status, _) => {
+                let mut response = ResponseSendMessage::error(
+                    error_status.unwrap_or(ResponseSendMessageStatus::InternalServerError),
+                );
+                response.remaining_messages = self.remaining_messages;
+                response
+            }
+        }
+    }
 }
 
 #[derive(Serialize, ToSchema)]
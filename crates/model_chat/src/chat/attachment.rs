@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    AccountId, MessageId, account_id_as_string, account_id_from_uuid, message_id_as_string,
+    message_id_from_string,
+};
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, IntoParams)]
+pub struct UploadMessageAttachmentParams {
+    /// Sent message which the attachment is for.
+    #[serde(
+        serialize_with = "message_id_as_string",
+        deserialize_with = "message_id_from_string"
+    )]
+    #[param(value_type = String)]
+    pub message_id: MessageId,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, IntoParams)]
+pub struct GetMessageAttachmentParams {
+    /// Sender of the message.
+    #[serde(
+        serialize_with = "account_id_as_string",
+        deserialize_with = "account_id_from_uuid"
+    )]
+    #[param(value_type = String)]
+    pub sender: AccountId,
+    /// Received message which the attachment is for.
+    #[serde(
+        serialize_with = "message_id_as_string",
+        deserialize_with = "message_id_from_string"
+    )]
+    #[param(value_type = String)]
+    pub message_id: MessageId,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema, PartialEq)]
+pub struct UploadMessageAttachmentResult {
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[schema(default = false)]
+    error: bool,
+    /// Message is not pending, sender or recipient has already
+    /// acknowledged the message or messaging is not possible anymore
+    /// because of a block or unmatching.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[schema(default = false)]
+    error_message_not_found: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[schema(default = false)]
+    error_attachment_already_exists: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[schema(default = false)]
+    error_too_many_pending_attachments: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[schema(default = false)]
+    error_transfer_budget_exceeded: bool,
+}
+
+impl UploadMessageAttachmentResult {
+    pub fn is_err(&self) -> bool {
+        self.error
+    }
+
+    pub fn message_not_found() -> Self {
+        Self {
+            error: true,
+            error_message_not_found: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn attachment_already_exists() -> Self {
+        Self {
+            error: true,
+            error_attachment_already_exists: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn too_many_pending_attachments() -> Self {
+        Self {
+            error: true,
+            error_too_many_pending_attachments: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn transfer_budget_exceeded() -> Self {
+        Self {
+            error: true,
+            error_transfer_budget_exceeded: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn successful() -> Self {
+        Self::default()
+    }
+}
@@ -552,7 +552,7 @@ impl UpdateProfileContentResult {
 ///   picture has effective face verified value true.
 /// - SECURITY_CONTENT_VERIFIED = 0x2. Current security content has effective
 ///   security verified value true.
-#[derive(Debug, PartialEq, Serialize, ToSchema)]
+#[derive(Debug, PartialEq, Deserialize, Serialize, ToSchema)]
 pub struct MediaVerificationStatus {
     pub v: i16,
 }
@@ -602,7 +602,7 @@ impl From<CurrentAccountMediaInternal> for ProfileContent {
 }
 
 /// Current content in public profile.
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct MyProfileContent {
     /// First image is primary profile image which is shown in grid view.
     pub content: Vec<MyContentInfo>,
@@ -662,7 +662,7 @@ impl SecurityContentAdminInfo {
     }
 }
 
-#[derive(Serialize)]
+#[derive(Deserialize, Serialize)]
 pub struct SecurityContentUserDataExport {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content: Option<MyContentInfo>,
@@ -695,6 +695,10 @@ pub struct GetContentQueryParams {
     /// Server may downgrade quality based on load. Response header "q"
     /// contains the actual quality returned.
     pub q: Option<String>,
+    /// Preferred image format. Use value: jpeg, webp or avif.
+    /// If not set, the format is selected using the `Accept` header.
+    /// JPEG is returned if the preferred format is not available.
+    pub f: Option<String>,
 }
 
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, IntoParams)]
@@ -71,11 +71,13 @@ fn append_attribute_with_values(
     attribute: &ProfileAttributeValue,
     schema: &ProfileAttributesInternal,
 ) {
-    let use_u32_values = schema
-        .get_attribute(attribute.id())
-        .map(|value| value.mode)
-        .unwrap_or_else(|| AttributeMode::TwoLevel)
-        == AttributeMode::TwoLevel;
+    let use_u32_values = matches!(
+        schema
+            .get_attribute(attribute.id())
+            .map(|value| value.mode)
+            .unwrap_or_else(|| AttributeMode::TwoLevel),
+        AttributeMode::TwoLevel | AttributeMode::Numeric
+    );
 
     let attribute_id = attribute.id().to_u16();
     let encoded_attribute_id = if use_u32_values {
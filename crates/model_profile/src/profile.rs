@@ -241,12 +241,32 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
 impl ProfileUpdateInternal {
     /// `AcceptedProfileAges` is checked only if it is Some.
     pub fn validate(
-        mut self,
+        self,
         attribute_info: &ProfileAttributesInternal,
         profile_name_regex: Option<&Regex>,
         current_profile: &Profile,
         initial_age: Option<InitialProfileAge>,
         is_bot: bool,
+    ) -> Result<ProfileUpdateValidated, String> {
+        if self.age != current_profile.age
+            && let Some(age_range) = initial_age
+            && !age_range.is_age_valid(self.age)
+        {
+            return Err(
+                "The new profile age is not in the current accepted profile age range".to_string(),
+            );
+        }
+
+        self.validate_without_age_range(attribute_info, profile_name_regex, is_bot)
+    }
+
+    /// Validate profile for an account which does not have
+    /// [InitialProfileAge] yet.
+    pub fn validate_without_age_range(
+        mut self,
+        attribute_info: &ProfileAttributesInternal,
+        profile_name_regex: Option<&Regex>,
+        is_bot: bool,
     ) -> Result<ProfileUpdateValidated, String> {
         let mut hash_set = HashSet::new();
         for a in &mut self.attributes {
@@ -272,6 +292,12 @@ impl ProfileUpdateInternal {
                     } else if a.v.len() > info.max_selected.get().into() {
                         return error();
                     }
+
+                    if let Some(range) = info.numeric
+                        && a.v.iter().any(|v| !range.is_valid_value(*v))
+                    {
+                        return Err("Numeric attribute value is not valid".to_string());
+                    }
                 }
             }
         }
@@ -309,15 +335,6 @@ impl ProfileUpdateInternal {
             }
         }
 
-        if self.age != current_profile.age
-            && let Some(age_range) = initial_age
-            && !age_range.is_age_valid(self.age)
-        {
-            return Err(
-                "The new profile age is not in the current accepted profile age range".to_string(),
-            );
-        }
-
         Ok(ProfileUpdateValidated {
             ptext: self.ptext,
             name: self.name,
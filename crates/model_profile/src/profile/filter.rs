@@ -47,6 +47,19 @@ pub struct ProfileFiltersUpdate {
 }
 
 impl ProfileFiltersUpdate {
+    /// Data export JSON for [GetProfileFilters] can be deserialized
+    /// as [ProfileFiltersUpdate]. The export contains only enabled attribute
+    /// filters, so all attribute filters are enabled. Filters for unknown
+    /// attributes are removed.
+    pub fn from_data_export(mut self, attribute_info: &ProfileAttributesInternal) -> Self {
+        self.attribute_filters
+            .retain(|a| attribute_info.get_attribute(a.id).is_some());
+        for a in &mut self.attribute_filters {
+            a.enabled = true;
+        }
+        self
+    }
+
     pub fn validate(
         self,
         attribute_info: &ProfileAttributesInternal,
@@ -80,6 +93,24 @@ impl ProfileFiltersUpdate {
                         Ok(())
                     };
 
+                    if info.mode.is_numeric() {
+                        if !a.wanted.is_empty() || !a.unwanted.is_empty() {
+                            return Err(
+                                "Numeric attribute supports only min and max filters".to_string()
+                            );
+                        }
+                        if let (Some(min), Some(max)) = (a.min, a.max)
+                            && min > max
+                        {
+                            return Err("Min filter value is larger than max".to_string());
+                        }
+                    } else if a.min.is_some() || a.max.is_some() {
+                        return Err(
+                            "Min and max filters are supported only for numeric attributes"
+                                .to_string(),
+                        );
+                    }
+
                     check(&a.wanted)?;
                     check(&a.unwanted)?;
                 }
@@ -175,6 +206,16 @@ pub struct ProfileAttributeFilterValueUpdate {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     #[schema(default = json!([]))]
     pub unwanted: Vec<u32>,
+    /// Min value (inclusive) for numeric attribute.
+    ///
+    /// Numeric attributes support only min and max filters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub min: Option<u32>,
+    /// Max value (inclusive) for numeric attribute.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub max: Option<u32>,
     /// Defines should missing attribute be accepted.
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     #[schema(default = false)]
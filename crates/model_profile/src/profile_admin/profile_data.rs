@@ -12,7 +12,7 @@ pub struct GetProfileAgeAndName {
     pub name: Option<NonEmptyString>,
 }
 
-#[derive(Deserialize, ToSchema)]
+#[derive(Serialize, Deserialize, ToSchema)]
 pub struct SetProfileName {
     pub account: AccountId,
     #[serde(skip_serializing_if = "Option::is_none")]
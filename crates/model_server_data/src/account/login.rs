@@ -13,11 +13,16 @@ use utoipa::ToSchema;
 pub struct SignInWithInfo {
     pub apple_account_id: Option<AppleAccountId>,
     pub google_account_id: Option<GoogleAccountId>,
+    /// OpenID Connect provider name from server config
+    pub oidc_provider: Option<String>,
+    pub oidc_account_id: Option<OidcAccountId>,
 }
 
 impl SignInWithInfo {
     pub fn some_sign_in_with_method_is_set(&self) -> bool {
-        self.google_account_id.is_some() || self.apple_account_id.is_some()
+        self.google_account_id.is_some()
+            || self.apple_account_id.is_some()
+            || self.oidc_account_id.is_some()
     }
 }
 
@@ -26,6 +31,7 @@ impl SignInWithInfo {
 /// Known values:
 /// - `0` = Apple
 /// - `1` = Google
+/// - `2` = OpenID Connect
 #[derive(
     Debug,
     Default,
@@ -47,6 +53,8 @@ pub enum SignInWithProviderTypeNumber {
     #[default]
     Apple = 0,
     Google = 1,
+    /// History entry IDs have format `{provider}:{id}`.
+    Oidc = 2,
 }
 
 /// A single sign in with provider ID change history entry.
@@ -125,3 +133,37 @@ impl AsRef<str> for AppleAccountId {
 }
 
 diesel_string_wrapper!(AppleAccountId);
+
+/// Value of OpenID Connect ID token sub claim
+#[derive(
+    Debug, Serialize, Deserialize, Clone, PartialEq, diesel::FromSqlRow, diesel::AsExpression,
+)]
+#[diesel(sql_type = Text)]
+#[serde(transparent)]
+pub struct OidcAccountId(pub String);
+
+impl OidcAccountId {
+    pub fn new(id: String) -> Self {
+        Self(id)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for OidcAccountId {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Ok(Self(value))
+    }
+}
+
+impl AsRef<str> for OidcAccountId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+diesel_string_wrapper!(OidcAccountId);
@@ -10,4 +10,5 @@ disable_logging!(
     // Account
     AppleAccountId,
     GoogleAccountId,
+    OidcAccountId,
 );
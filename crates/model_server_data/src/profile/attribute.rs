@@ -16,6 +16,8 @@ pub struct ProfileAttributeValueUpdate {
     /// For two level attributes the values are u32 values
     /// with most significant u16 containing attribute value ID and
     /// least significant u16 containing group value ID.
+    ///
+    /// For numeric attributes the list only has one u32 value.
     pub v: Vec<u32>,
 }
 
@@ -32,6 +34,8 @@ pub struct ProfileAttributeValue {
     /// with most significant u16 containing attribute value ID and
     /// least significant u16 containing group value ID.
     ///
+    /// For numeric attributes the list only has one u32 value.
+    ///
     /// Values are in ascending order.
     v: Vec<u32>,
 }
@@ -128,7 +132,7 @@ impl AttributeValueReader {
                     filter & attribute != 0
                 }
             }
-            AttributeMode::OneLevel => Self::wanted_is_number_lists_match(
+            AttributeMode::OneLevel | AttributeMode::Numeric => Self::wanted_is_number_lists_match(
                 filter_data,
                 attribute_data,
                 logical_and,
@@ -186,11 +190,13 @@ impl AttributeValueReader {
                 let attribute = !(attribute_data.first().copied().unwrap_or_default() as u16);
                 filter & attribute == filter
             }
-            AttributeMode::OneLevel => Self::unwanted_is_number_lists_match(
-                filter_data,
-                attribute_data,
-                NumberExistence::one_level_attribute_find_from_sorted,
-            ),
+            AttributeMode::OneLevel | AttributeMode::Numeric => {
+                Self::unwanted_is_number_lists_match(
+                    filter_data,
+                    attribute_data,
+                    NumberExistence::one_level_attribute_find_from_sorted,
+                )
+            }
             AttributeMode::TwoLevel => Self::unwanted_is_number_lists_match(
                 filter_data,
                 attribute_data,
@@ -25,6 +25,14 @@ pub struct ProfileAttributeFilterValue {
     ///
     /// The unwanted values are checked always with AND operator.
     unwanted: Vec<u32>,
+    /// Min value (inclusive) for numeric attribute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    min: Option<u32>,
+    /// Max value (inclusive) for numeric attribute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    max: Option<u32>,
     accept_missing_attribute: bool,
     use_logical_operator_and: bool,
 }
@@ -34,6 +42,8 @@ impl ProfileAttributeFilterValue {
         id: AttributeId,
         mut wanted: Vec<u32>,
         mut unwanted: Vec<u32>,
+        min: Option<u32>,
+        max: Option<u32>,
         accept_missing_attribute: bool,
         use_logical_operator_and: bool,
     ) -> Self {
@@ -43,6 +53,8 @@ impl ProfileAttributeFilterValue {
             id,
             wanted,
             unwanted,
+            min,
+            max,
             accept_missing_attribute,
             use_logical_operator_and,
         }
@@ -83,6 +95,10 @@ impl ProfileAttributeFilterValue {
             )
         };
 
-        wanted_match && unwanted_match
+        let numeric_value = value.raw_values().first().copied().unwrap_or_default();
+        let min_match = self.min.map(|min| numeric_value >= min).unwrap_or(true);
+        let max_match = self.max.map(|max| numeric_value <= max).unwrap_or(true);
+
+        wanted_match && unwanted_match && min_match && max_match
     }
 }
@@ -70,6 +70,13 @@ pub struct LocationInternal {
 }
 
 impl LocationInternal {
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+        }
+    }
+
     pub fn latitude(&self) -> f64 {
         self.latitude
     }
@@ -78,13 +85,26 @@ impl LocationInternal {
         self.longitude
     }
 
+    /// Great-circle distance in kilometers.
+    pub fn distance_km(&self, other: &Self) -> f64 {
+        // https://en.wikipedia.org/wiki/Haversine_formula
+        const R_EARTH: f64 = 6371.0;
+        let lat1 = self.latitude.to_radians();
+        let lat2 = other.latitude.to_radians();
+        let d_lat = lat2 - lat1;
+        let d_lon = (other.longitude - self.longitude).to_radians();
+        let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+        2.0 * R_EARTH * a.sqrt().min(1.0).asin()
+    }
+
     pub fn move_kilometers(&self, dy: f64, dx: f64) -> Self {
         // https://stackoverflow.com/questions/7477003/calculating-new-longitude-latitude-from-old-n-meters
         use std::f64::consts::PI;
         const R_EARTH: f64 = 6378.0;
         let new_latitude = self.latitude + (dy / R_EARTH) * (180.0 / PI);
+        // Longitude correction is done using the current latitude.
         let new_longitude =
-            self.longitude + (dx / R_EARTH) * (180.0 / PI) / (self.latitude * (PI / 180.0));
+            self.longitude + (dx / R_EARTH) * (180.0 / PI) / (self.latitude * (PI / 180.0)).cos();
         let new_latitude = new_latitude.clamp(-90.0, 90.0);
         let new_longitude = new_longitude.clamp(-180.0, 180.0);
         Self {
@@ -150,4 +170,22 @@ mod test {
         assert_eq!(value.latitude(), default_location().latitude());
         assert!(value.longitude() > default_location().longitude());
     }
+
+    #[test]
+    fn move_kilometers_longitude_correction() {
+        let equator = LocationInternal {
+            latitude: 0.0,
+            longitude: DEFAULT_DEGREES,
+        }
+        .move_kilometers(0.0, 100.0);
+        let north = LocationInternal {
+            latitude: 60.0,
+            longitude: DEFAULT_DEGREES,
+        }
+        .move_kilometers(0.0, 100.0);
+        let equator_degrees = equator.longitude() - DEFAULT_DEGREES;
+        let north_degrees = north.longitude() - DEFAULT_DEGREES;
+        // cos(60°) = 0.5
+        assert!((north_degrees - 2.0 * equator_degrees).abs() < 0.0001);
+    }
 }
@@ -49,6 +49,8 @@ impl ApiDoc {
             .merge_from(server_api::common_admin::router_manager(state.clone()).into_openapi())
             .merge_from(server_api::common_admin::router_report(state.clone()).into_openapi())
             .merge_from(server_api::common_admin::router_notification(state.clone()).into_openapi())
+            .merge_from(server_api::common_admin::router_audit_log(state.clone()).into_openapi())
+            .merge_from(server_api::common_admin::router_data_import(state.clone()).into_openapi())
             .tag_routes("common_admin");
         doc.merge(common_admin);
         // Account
@@ -69,6 +71,7 @@ impl ApiDoc {
             .merge_from(
                 server_api_account::account::router_sign_in_with(state.clone()).into_openapi(),
             )
+            .merge_from(server_api_account::account::router_totp(state.clone()).into_openapi())
             .merge_from(server_api_account::account::router_settings(state.clone()).into_openapi())
             .merge_from(server_api_account::account::router_state(state.clone()).into_openapi())
             .merge_from(
@@ -231,6 +234,7 @@ impl ApiDoc {
             .merge_from(server_api_chat::chat::router_like(state.clone()).into_openapi())
             .merge_from(server_api_chat::chat::router_match(state.clone()).into_openapi())
             .merge_from(server_api_chat::chat::router_message(state.clone()).into_openapi())
+            .merge_from(server_api_chat::chat::router_attachment(state.clone()).into_openapi())
             .merge_from(server_api_chat::chat::router_public_key(state.clone()).into_openapi())
             .merge_from(server_api_chat::chat::router_chat_report(state.clone()).into_openapi())
             .merge_from(server_api_chat::chat::router_notification(state.clone()).into_openapi())
@@ -16,6 +16,7 @@ use server_data::{
 };
 use server_data_media::{read::GetReadMediaCommands, write::GetWriteCommandsMedia};
 use server_state::{S, app::AdminNotificationProvider};
+use sha2::{Digest, Sha256};
 use simple_backend::{
     ServerQuitWatcher,
     image::{ImageProcess, ImageProcessError},
@@ -207,6 +208,13 @@ impl ContentProcessingManager {
             .await
             .change_context(ContentProcessingError::DatabaseError)?;
 
+        let raw_img = state
+            .tmp_raw_img
+            .read_all()
+            .await
+            .change_context(ContentProcessingError::ContentProcessingFailed)?;
+        let raw_content_sha256 = Sha256::digest(raw_img).to_vec();
+
         let state_copy = state.clone();
         let content_id = db_write_raw!(self.state, move |cmds| {
             cmds.media()
@@ -221,6 +229,7 @@ impl ContentProcessingManager {
                     state_copy.new_content_params,
                     face_detected,
                     if is_bot { Some(true) } else { None },
+                    raw_content_sha256,
                 )
                 .await
         })
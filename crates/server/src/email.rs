@@ -18,7 +18,7 @@ use server_data::{
 };
 use server_data_account::{read::GetReadCommandsAccount, write::GetWriteCommandsAccount};
 use server_state::S;
-use simple_backend::email::SmtpClient;
+use simple_backend::email::EmailClient;
 use simple_backend_config::SimpleBackendConfig;
 use tokio::sync::mpsc::Receiver;
 use tracing::{error, warn};
@@ -43,7 +43,7 @@ impl EmailManagerQuitHandle {
 
 pub struct EmailManager {
     state: S,
-    smtp_client: Arc<SmtpClient>,
+    email_client: Arc<EmailClient>,
     config: Arc<SimpleBackendConfig>,
     normal_receiver: Receiver<NormalEmailMsg>,
     high_priority_receiver: Receiver<HighPriorityEmailMsg>,
@@ -52,22 +52,22 @@ pub struct EmailManager {
 impl EmailManager {
     pub fn new_manager(
         state: S,
-        smtp_client: SmtpClient,
+        email_client: EmailClient,
         config: Arc<SimpleBackendConfig>,
         mut quit_notification: ServerQuitWatcher,
         normal_receiver: Receiver<NormalEmailMsg>,
         high_priority_receiver: Receiver<HighPriorityEmailMsg>,
         custom_receiver: Receiver<CustomEmailMsg>,
     ) -> EmailManagerQuitHandle {
-        let smtp_client = Arc::new(smtp_client);
+        let email_client = Arc::new(email_client);
         let custom_handler =
-            CustomEmailHandler::new(state.clone(), smtp_client.clone(), custom_receiver);
+            CustomEmailHandler::new(state.clone(), email_client.clone(), custom_receiver);
 
         EmailManagerQuitHandle {
             task: tokio::spawn(async move {
                 let mut manager = EmailManager {
                     state,
-                    smtp_client,
+                    email_client,
                     config,
                     normal_receiver,
                     high_priority_receiver,
@@ -79,7 +79,7 @@ impl EmailManager {
                 }
 
                 // Save email limit state on quit
-                manager.smtp_client.save_state(&manager.config).await;
+                manager.email_client.save_state(&manager.config).await;
             }),
         }
     }
@@ -138,13 +138,8 @@ impl EmailManager {
             return Ok(());
         };
 
-        self.smtp_client
-            .send(
-                &info.email_address,
-                &info.subject,
-                &info.body,
-                info.body_is_html,
-            )
+        self.email_client
+            .send(&info.email_address, &info.subject, &info.body)
             .await
             .change_context(EmailError::SendingFailed)?;
 
@@ -167,13 +162,8 @@ impl EmailManager {
             .email_login(token)
             .change_context(EmailError::GettingEmailDataFailed)?;
 
-        self.smtp_client
-            .send(
-                email.as_str(),
-                &content.subject,
-                &content.body,
-                content.body_is_html,
-            )
+        self.email_client
+            .send(email.as_str(), &content.subject, &content.body)
             .await
             .change_context(EmailError::SendingFailed)?;
 
@@ -297,7 +287,6 @@ impl EmailManager {
             email_address: email,
             subject: content.subject,
             body: content.body,
-            body_is_html: content.body_is_html,
         };
 
         Ok(Some(email_data))
@@ -2,37 +2,37 @@ use std::sync::Arc;
 
 use config::file_email_content::EmailContent;
 use error_stack::{IntoReport, ResultExt};
-use model::AccountIdInternal;
+use model::{AccountIdInternal, language_fallback_chain};
 use model_account::CustomEmailId;
 use server_api::{
     app::{GetConfig, ReadData, WriteData},
     db_write_raw,
 };
 use server_data::{
-    email::{CustomEmailMsg, EmailData, EmailError},
+    email::{CustomEmailMsg, EmailBody, EmailData, EmailError},
     read::GetReadCommandsCommon,
 };
 use server_data_account::{read::GetReadCommandsAccount, write::GetWriteCommandsAccount};
 use server_state::S;
-use simple_backend::email::SmtpClient;
+use simple_backend::email::EmailClient;
 use tokio::sync::mpsc::Receiver;
 use tracing::{error, warn};
 
 pub struct CustomEmailHandler {
     state: S,
-    smtp_client: Arc<SmtpClient>,
+    email_client: Arc<EmailClient>,
     custom_receiver: Receiver<CustomEmailMsg>,
 }
 
 impl CustomEmailHandler {
     pub fn new(
         state: S,
-        smtp_client: Arc<SmtpClient>,
+        email_client: Arc<EmailClient>,
         custom_receiver: Receiver<CustomEmailMsg>,
     ) -> Self {
         Self {
             state,
-            smtp_client,
+            email_client,
             custom_receiver,
         }
     }
@@ -104,13 +104,8 @@ impl CustomEmailHandler {
             return Ok(());
         };
 
-        self.smtp_client
-            .send(
-                &info.email_address,
-                &info.subject,
-                &info.body,
-                info.body_is_html,
-            )
+        self.email_client
+            .send(&info.email_address, &info.subject, &info.body)
             .await
             .change_context(EmailError::SendingFailed)?;
 
@@ -127,13 +122,8 @@ impl CustomEmailHandler {
             return Ok(());
         };
 
-        self.smtp_client
-            .send(
-                &info.email_address,
-                &info.subject,
-                &info.body,
-                info.body_is_html,
-            )
+        self.email_client
+            .send(&info.email_address, &info.subject, &info.body)
             .await
             .change_context(EmailError::SendingFailed)?;
 
@@ -190,18 +180,25 @@ impl CustomEmailHandler {
 
         let translation = language
             .as_ref()
-            .and_then(|lang| translations.iter().find(|t| t.locale == lang.as_str()))
+            .and_then(|lang| {
+                language_fallback_chain(lang.as_str()).find_map(|l| {
+                    translations
+                        .iter()
+                        .find(|t| t.locale.eq_ignore_ascii_case(l))
+                })
+            })
             .or_else(|| translations.iter().find(|t| t.locale == "default"));
 
         let content = match translation {
             Some(t) => EmailContent {
                 subject: t.subject.clone(),
-                body: t.body.clone(),
-                body_is_html: self
-                    .state
-                    .config()
-                    .email_content()
-                    .email_body_content_type_is_html(),
+                body: EmailBody::new(
+                    t.body.clone(),
+                    self.state
+                        .config()
+                        .email_content()
+                        .email_body_content_type_is_html(),
+                ),
             },
             None => return Err(EmailError::GettingEmailDataFailed.into_report()),
         };
@@ -210,7 +207,6 @@ impl CustomEmailHandler {
             email_address: email,
             subject: content.subject,
             body: content.body,
-            body_is_html: content.body_is_html,
         };
 
         Ok(Some(email_data))
@@ -1,9 +1,13 @@
 use std::time::Duration;
 
-use server_api::{app::WriteData, db_write_raw};
+use model::{AdminNotificationTypes, UnixTime};
+use server_api::{
+    app::{GetConfig, WriteData},
+    db_write_raw,
+};
 use server_common::result::{Result, WrappedResultExt};
 use server_data::write::GetWriteCommandsCommon;
-use server_state::S;
+use server_state::{S, app::AdminNotificationProvider};
 use simple_backend::{
     ServerQuitWatcher,
     app::{IpCountryTrackerProvider, PerfCounterDataProvider},
@@ -92,6 +96,7 @@ impl HourlyTaskManager {
         TaskUtils::save_ip_address_statistics(&self.state)
             .await
             .change_context(HourlyTaskError::DatabaseError)?;
+        self.detect_ban_evasion().await?;
         Ok(())
     }
 
@@ -122,4 +127,34 @@ impl HourlyTaskManager {
 
         Ok(())
     }
+
+    /// Run after IP address statistics are saved to database
+    /// so that latest IP addresses are included in the comparison.
+    pub async fn detect_ban_evasion(&self) -> Result<(), HourlyTaskError> {
+        let max_age = self
+            .state
+            .config()
+            .limits_common()
+            .ban_evasion_detection_new_account_max_age;
+        let created_after =
+            UnixTime::new(UnixTime::current_time().ut - Into::<i64>::into(max_age.seconds));
+
+        let new_reports = db_write_raw!(self.state, move |cmds| {
+            cmds.common_admin()
+                .report()
+                .report_ban_evasion_candidates(created_after)
+                .await
+        })
+        .await
+        .change_context(HourlyTaskError::DatabaseError)?;
+
+        if new_reports {
+            self.state
+                .admin_notification()
+                .send_notification_if_needed(AdminNotificationTypes::ProcessReports)
+                .await;
+        }
+
+        Ok(())
+    }
 }
@@ -63,7 +63,7 @@ use server_state::{
 };
 use shutdown_tasks::ShutdownTasks;
 use simple_backend::{
-    BusinessLogic, ServerQuitWatcher, app::SimpleBackendAppState, email::SmtpClient,
+    BusinessLogic, ServerQuitWatcher, app::SimpleBackendAppState, email::EmailClient,
     perf::counters::AllCounters, web_socket::WebSocketManager,
 };
 use startup_tasks::StartupTasks;
@@ -442,11 +442,11 @@ impl BusinessLogic for DatingAppBusinessLogic {
         )
         .await;
 
-        let smtp_client = SmtpClient::new(self.config.simple_backend()).await;
+        let email_client = EmailClient::new(self.config.simple_backend()).await;
 
         let email_manager_quit_handle = EmailManager::new_manager(
             app_state.clone(),
-            smtp_client,
+            email_client,
             self.config.simple_backend_arc(),
             server_quit_watcher.resubscribe(),
             email_channel_receiver,
@@ -9,6 +9,7 @@ use server_api::{
         COMMON_PROFILE_PAGING_COUNTERS_LIST, COMMON_PUSH_NOTIFICATION_COUNTERS_LIST,
     },
     common_admin::{
+        COMMON_ADMIN_AUDIT_LOG_COUNTERS_LIST, COMMON_ADMIN_DATA_IMPORT_COUNTERS_LIST,
         COMMON_ADMIN_MAINTENANCE_COUNTERS_LIST, COMMON_ADMIN_NOTIFICATION_COUNTERS_LIST,
         COMMON_ADMIN_REPORT_COUNTERS_LIST,
     },
@@ -20,7 +21,8 @@ use server_api_account::{
         ACCOUNT_CLIENT_FEATURES_COUNTERS_LIST, ACCOUNT_EMAIL_COUNTERS_LIST,
         ACCOUNT_LOGOUT_COUNTERS_LIST, ACCOUNT_NEWS_COUNTERS_LIST,
         ACCOUNT_NOTIFICATION_COUNTERS_LIST, ACCOUNT_REPORT_COUNTERS_LIST,
-        ACCOUNT_SIGN_IN_WITH_COUNTERS_LIST, ACCOUNT_VERIFICATION_COUNTERS_LIST,
+        ACCOUNT_SIGN_IN_WITH_COUNTERS_LIST, ACCOUNT_TOTP_COUNTERS_LIST,
+        ACCOUNT_VERIFICATION_COUNTERS_LIST,
     },
     account_admin::{
         ACCOUNT_ADMIN_ASSOCIATION_COUNTERS_LIST, ACCOUNT_ADMIN_BAN_COUNTERS_LIST,
@@ -34,8 +36,9 @@ use server_api_account::{
     },
 };
 use server_api_chat::chat::{
-    CHAT_NOTIFICATION_COUNTERS_LIST, CHAT_PRIVACY_COUNTERS_LIST, CHAT_PUBLIC_KEY_COUNTERS_LIST,
-    CHAT_REPORT_COUNTERS_LIST, CHAT_TRANSFER_COUNTERS_LIST, CHAT_VIDEO_CALL_COUNTERS_LIST,
+    CHAT_ATTACHMENT_COUNTERS_LIST, CHAT_NOTIFICATION_COUNTERS_LIST, CHAT_PRIVACY_COUNTERS_LIST,
+    CHAT_PUBLIC_KEY_COUNTERS_LIST, CHAT_REPORT_COUNTERS_LIST, CHAT_TRANSFER_COUNTERS_LIST,
+    CHAT_VIDEO_CALL_COUNTERS_LIST,
 };
 use server_api_media::{
     media::{
@@ -117,6 +120,14 @@ pub static ALL_COUNTERS: &[&CounterCategory] = &[
         "common_admin_notification",
         COMMON_ADMIN_NOTIFICATION_COUNTERS_LIST,
     ),
+    &CounterCategory::new(
+        "common_admin_audit_log",
+        COMMON_ADMIN_AUDIT_LOG_COUNTERS_LIST,
+    ),
+    &CounterCategory::new(
+        "common_admin_data_import",
+        COMMON_ADMIN_DATA_IMPORT_COUNTERS_LIST,
+    ),
     // Account
     &CounterCategory::new("account_association", ACCOUNT_ASSOCIATION_COUNTERS_LIST),
     &CounterCategory::new("account_register", ACCOUNT_REGISTER_COUNTERS_LIST),
@@ -125,6 +136,7 @@ pub static ALL_COUNTERS: &[&CounterCategory] = &[
     &CounterCategory::new("account_ban", ACCOUNT_BAN_COUNTERS_LIST),
     &CounterCategory::new("account_delete", ACCOUNT_DELETE_COUNTERS_LIST),
     &CounterCategory::new("account_sign_in_with", ACCOUNT_SIGN_IN_WITH_COUNTERS_LIST),
+    &CounterCategory::new("account_totp", ACCOUNT_TOTP_COUNTERS_LIST),
     &CounterCategory::new("account_settings", ACCOUNT_SETTINGS_COUNTERS_LIST),
     &CounterCategory::new("account_state", ACCOUNT_STATE_COUNTERS_LIST),
     &CounterCategory::new("account_demo", ACCOUNT_DEMO_COUNTERS_LIST),
@@ -235,6 +247,7 @@ pub static ALL_COUNTERS: &[&CounterCategory] = &[
     &CounterCategory::new("chat_block", CHAT_BLOCK_COUNTERS_LIST),
     &CounterCategory::new("chat_match", CHAT_MATCH_COUNTERS_LIST),
     &CounterCategory::new("chat_message", CHAT_MESSAGE_COUNTERS_LIST),
+    &CounterCategory::new("chat_attachment", CHAT_ATTACHMENT_COUNTERS_LIST),
     &CounterCategory::new("chat_public_key", CHAT_PUBLIC_KEY_COUNTERS_LIST),
     &CounterCategory::new("chat_report", CHAT_REPORT_COUNTERS_LIST),
     &CounterCategory::new("chat_notification", CHAT_NOTIFICATION_COUNTERS_LIST),
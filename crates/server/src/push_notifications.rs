@@ -1,6 +1,7 @@
 use error_stack::ResultExt;
 use model::{
-    AccountIdInternal, ClientType, PushNotificationSendingInfo, PushNotificationsToMarkAsSent,
+    AccountIdInternal, ClientType, PushNotificationProvider, PushNotificationSendingInfo,
+    PushNotificationsToMarkAsSent,
 };
 use server_api::{
     app::{EventManagerProvider, ReadData, WriteData},
@@ -142,4 +143,19 @@ impl PushNotificationStateProvider for ServerPushNotificationStateProvider {
             .map_err(|e| e.into_report())
             .change_context(PushNotificationError::GetClientType)
     }
+
+    async fn push_notification_provider(
+        &self,
+        account_id: AccountIdInternal,
+    ) -> simple_backend_utils::Result<PushNotificationProvider, PushNotificationError> {
+        self.state
+            .read()
+            .common()
+            .push_notification()
+            .push_notification_db_state(account_id)
+            .await
+            .map(|v| v.device_token_provider)
+            .map_err(|e| e.into_report())
+            .change_context(PushNotificationError::GetPushNotificationProvider)
+    }
 }
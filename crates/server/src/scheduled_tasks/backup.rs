@@ -1,8 +1,10 @@
 use std::sync::atomic::{AtomicU32, Ordering};
 
 use manager_api::backup::BackupSourceClient;
-use manager_model::{AccountAndContent, Sha256Bytes, SourceToTargetMessage, TargetToSourceMessage};
-use model::{AccountId, ContentId, ContentQualityVariant};
+use manager_model::{
+    AccountAndContent, ContentAndFormats, Sha256Bytes, SourceToTargetMessage, TargetToSourceMessage,
+};
+use model::{AccountId, ContentFileVariant, ContentId, ContentImageFormat, ContentQualityVariant};
 use server_api::{
     DataError,
     app::{GetConfig, ReadData},
@@ -68,11 +70,27 @@ pub async fn backup_data(
                 .await
                 .change_context(ScheduledTaskError::DatabaseError)?;
 
-            let content_ids = content.iter().map(|v| v.content_id().cid).collect();
+            let mut content_and_formats = vec![];
+            for c in content {
+                let high = state
+                    .read()
+                    .media()
+                    .content_data_variant(a.as_id(), c.content_id(), ContentQualityVariant::High)
+                    .change_context(ScheduledTaskError::DatabaseError)?;
+                // JPEG is always available. Other formats are optional.
+                let formats = ContentImageFormat::all_formats()
+                    .into_iter()
+                    .filter(|&f| f == ContentImageFormat::Jpeg || high.format_variant(f).exists())
+                    .collect();
+                content_and_formats.push(ContentAndFormats {
+                    content_id: c.content_id().cid,
+                    formats,
+                });
+            }
 
             data.push(AccountAndContent {
                 account_id: a.as_id().aid,
-                content_ids,
+                content: content_and_formats,
             });
         }
 
@@ -95,13 +113,13 @@ pub async fn backup_data(
                     variants,
                 } => {
                     let read_variant_data = async |variant| -> Result<
-                        (ContentQualityVariant, Sha256Bytes, Vec<u8>),
+                        (ContentFileVariant, Sha256Bytes, Vec<u8>),
                         ScheduledTaskError,
                     > {
                         let content_data = state
                             .read()
                             .media()
-                            .content_data_variant(
+                            .content_data_file_variant(
                                 AccountId { aid: account_id },
                                 ContentId { cid: content_id },
                                 variant,
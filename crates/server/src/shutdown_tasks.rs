@@ -20,7 +20,7 @@ impl ShutdownTasks {
     }
 
     /// Other quit tasks not located here:
-    /// - [simple_backend::email::SmtpClient::save_state]
+    /// - [simple_backend::email::EmailClient::save_state]
     /// - [server_common::push_notifications::PushNotificationManager::quit_logic]
     pub async fn run_and_wait_completion(self) -> Result<(), DataError> {
         Self::persist_email_login_tokens(&self.state).await?;
@@ -67,6 +67,7 @@ mod push_notification;
 pub use push_notification::*;
 
 mod websocket;
+pub use websocket::chat::send_new_message_events;
 
 pub const PATH_GET_SERVER_ONLINE: &str = "/common_api/server_online";
 
@@ -727,4 +728,7 @@ create_counters!(
     event_to_server_typing_start,
     event_to_server_typing_stop,
     event_to_server_check_online_status,
+    event_to_server_send_message,
+    event_to_server_acknowledge_received_messages,
+    event_to_server_acknowledge_sent_messages,
 );
@@ -1,15 +1,15 @@
-use std::str::FromStr;
+use std::{net::SocketAddr, str::FromStr};
 
 use axum::{
     Extension,
     body::Body,
-    extract::{Query, State},
+    extract::{ConnectInfo, Query, State},
 };
 use axum_extra::TypedHeader;
 use headers::{ContentLength, ContentType};
 use model::{
-    AccountIdInternal, DataExportName, DataExportState, DataExportStateType, DataExportType,
-    Permissions, PostStartDataExport,
+    AccountIdInternal, AdminAuditLogAction, DataExportName, DataExportState, DataExportStateType,
+    DataExportType, NewAdminAuditLogEntry, Permissions, PostStartDataExport,
 };
 use server_data::{
     data_export::{SourceAccount, TargetAccount},
@@ -84,6 +84,7 @@ pub async fn post_start_data_export(
     State(state): State<S>,
     Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(api_caller_permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(settings): Json<PostStartDataExport>,
 ) -> Result<(), StatusCode> {
     COMMON.post_start_data_export.incr();
@@ -91,9 +92,22 @@ pub async fn post_start_data_export(
     let export_state = state.data_export().get_state(api_caller_id).await;
 
     let account_for_exporting = SourceAccount(state.get_internal_id(settings.source).await?);
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        api_caller_id,
+        address.ip(),
+        AdminAuditLogAction::StartDataExport,
+    )
+    .target_account(settings.source)
+    .parameters(&settings);
     let api_caller_id = TargetAccount(api_caller_id);
 
     if api_caller_permissions.admin_export_data {
+        db_write!(state, move |cmds| {
+            cmds.common_history()
+                .write_admin_audit_log_entry(audit_log_entry)
+                .await;
+            Ok(())
+        })?;
         state
             .data_export()
             .send_export_cmd_if_export_file_is_not_in_use(
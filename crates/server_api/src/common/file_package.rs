@@ -230,24 +230,10 @@ pub async fn is_ip_address_accepted(
     address: SocketAddr,
     config: &IpAddressAccessConfig,
 ) -> bool {
-    if config.allow_all_ip_addresses || config.ip_allowlist.iter().any(|v| *v == address.ip()) {
-        return true;
-    }
-
-    if !config.ip_country_allowlist.is_empty() {
-        let ip_db = state.maxmind_db().current_db_ref().await;
-        if let Some(ip_db) = ip_db.as_ref()
-            && let Some(country) = ip_db.get_country_ref(address.ip())
-            && config
-                .ip_country_allowlist
-                .iter()
-                .any(|v| v == country.as_str())
-        {
-            return true;
-        }
-    }
-
-    false
+    state
+        .maxmind_db()
+        .is_ip_address_accepted(address.ip(), config)
+        .await
 }
 
 fn create_access_denied_response(
@@ -1,14 +1,16 @@
 use axum::{Extension, extract::State};
 use model::{
     AccountIdInternal, ClientType, GetPushNotificationInfo, PushNotificationDeviceToken,
-    PushNotificationEncryptionKey, VapidPublicKey,
+    PushNotificationEncryptionKey, PushNotificationProvider, VapidPublicKey,
 };
+use server_common::push_notifications::validate_unified_push_endpoint;
 use server_data::{
     app::{GetConfig, ReadData},
     read::GetReadCommandsCommon,
     write::GetWriteCommandsCommon,
 };
 use simple_backend::create_counters;
+use tracing::warn;
 
 use super::super::utils::{Json, StatusCode};
 use crate::{S, app::WriteData, create_open_api_router, db_write};
@@ -36,7 +38,57 @@ pub async fn post_set_device_token(
     let encryption_key = db_write!(state, move |cmds| {
         cmds.common()
             .push_notification()
-            .set_device_token(id, device_token)
+            .set_device_token(id, device_token, PushNotificationProvider::Platform)
+            .await
+    })?;
+
+    Ok(encryption_key.into())
+}
+
+const PATH_POST_SET_UNIFIED_PUSH_ENDPOINT: &str = "/common_api/set_unified_push_endpoint";
+
+/// Set UnifiedPush distributor endpoint URL as device token.
+///
+/// Notifications are sent to the endpoint using HTTP POST instead of FCM,
+/// APNs or web push. The request body is the same JSON which FCM
+/// notification data contains.
+///
+/// The endpoint must use HTTPS unless server config allows HTTP.
+/// Endpoints which resolve to loopback, private, link-local, unique local
+/// or unspecified IP addresses are rejected unless server config allows
+/// those.
+#[utoipa::path(
+    post,
+    path = PATH_POST_SET_UNIFIED_PUSH_ENDPOINT,
+    request_body(content = PushNotificationDeviceToken),
+    responses(
+        (status = 200, description = "Success.", body = PushNotificationEncryptionKey),
+        (status = 400, description = "Invalid endpoint."),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_set_unified_push_endpoint(
+    State(state): State<S>,
+    Extension(id): Extension<AccountIdInternal>,
+    Json(endpoint): Json<PushNotificationDeviceToken>,
+) -> Result<Json<PushNotificationEncryptionKey>, StatusCode> {
+    COMMON.post_set_unified_push_endpoint.incr();
+
+    let Some(config) = state.config().simple_backend().unified_push_config() else {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    if let Err(e) = validate_unified_push_endpoint(endpoint.as_str(), config).await {
+        warn!("Invalid UnifiedPush endpoint: {e}");
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let encryption_key = db_write!(state, move |cmds| {
+        cmds.common()
+            .push_notification()
+            .set_device_token(id, endpoint, PushNotificationProvider::UnifiedPush)
             .await
     })?;
 
@@ -98,12 +150,13 @@ pub async fn get_push_notification_info(
     Ok(key.into())
 }
 
-create_open_api_router!(fn router_push_notification_private, post_set_device_token, get_push_notification_info,);
+create_open_api_router!(fn router_push_notification_private, post_set_device_token, post_set_unified_push_endpoint, get_push_notification_info,);
 
 create_counters!(
     CommonCounters,
     COMMON,
     COMMON_PUSH_NOTIFICATION_COUNTERS_LIST,
     post_set_device_token,
+    post_set_unified_push_endpoint,
     get_push_notification_info,
 );
@@ -1,7 +1,8 @@
 use axum::extract::ws::{Message, WebSocket};
 use model::{
     AccountId, AccountIdInternal, ClientMessageForDataAllCrate, ClientMessageType,
-    EventToClientInternal, ScheduledMaintenanceStatus, create_server_binary_message,
+    EventToClientInternal, MessageId, PendingMessageId, PublicKeyId, ScheduledMaintenanceStatus,
+    create_server_binary_message,
 };
 use model_server_data::{AutomaticProfileSearchIteratorSessionId, ProfileIteratorSessionId};
 use server_common::websocket::WebSocketError;
@@ -43,6 +44,23 @@ pub enum ClientMessageForServerApiCrate {
         check_account: AccountId,
         is_online: bool,
     },
+    SendMessage {
+        request_id: u8,
+        recipient: AccountId,
+        message_id: MessageId,
+        sender_public_key_id: PublicKeyId,
+        recipient_public_key_id: PublicKeyId,
+        message: Vec<u8>,
+    },
+    AcknowledgeReceivedMessages {
+        request_id: u8,
+        delivery_failed: bool,
+        messages: Vec<PendingMessageId>,
+    },
+    AcknowledgeSentMessages {
+        request_id: u8,
+        messages: Vec<MessageId>,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -141,6 +159,81 @@ pub fn parse_client_binary_message(
                 },
             ))
         }
+        ClientMessageType::SendMessage => {
+            let (request_id, payload) = split_request_id_payload(payload)?;
+            let (recipient_payload, payload) = payload
+                .split_at_checked(16)
+                .ok_or(WebSocketError::ProtocolError.report())?;
+            let recipient = parse_account_id(recipient_payload)?;
+            let (message_id_payload, payload) = payload
+                .split_at_checked(16)
+                .ok_or(WebSocketError::ProtocolError.report())?;
+            let message_id = parse_message_id(message_id_payload)?;
+
+            let mut iterator = payload.iter().copied();
+            let sender_public_key_id = minimal_i64::parse_minimal_i64_from_iter(&mut iterator)
+                .ok_or(WebSocketError::ProtocolError.report())?;
+            let recipient_public_key_id = minimal_i64::parse_minimal_i64_from_iter(&mut iterator)
+                .ok_or(WebSocketError::ProtocolError.report())?;
+            let message: Vec<u8> = iterator.collect();
+
+            if message.len() > u16::MAX.into() {
+                return Err(WebSocketError::ProtocolError.report());
+            }
+
+            Ok(ClientMessageParsed::ForServerApi(
+                ClientMessageForServerApiCrate::SendMessage {
+                    request_id,
+                    recipient,
+                    message_id,
+                    sender_public_key_id: PublicKeyId {
+                        id: sender_public_key_id,
+                    },
+                    recipient_public_key_id: PublicKeyId {
+                        id: recipient_public_key_id,
+                    },
+                    message,
+                },
+            ))
+        }
+        ClientMessageType::AcknowledgeReceivedMessages => {
+            let (request_id, payload) = split_request_id_payload(payload)?;
+            let (delivery_failed, payload) = payload
+                .split_first()
+                .ok_or(WebSocketError::ProtocolError.report())?;
+
+            let mut messages = vec![];
+            for entry in chunks_exact_checked(payload, 32)? {
+                let (sender, message_id) = entry.split_at(16);
+                messages.push(PendingMessageId {
+                    sender: parse_account_id(sender)?,
+                    id: parse_message_id(message_id)?,
+                });
+            }
+
+            Ok(ClientMessageParsed::ForServerApi(
+                ClientMessageForServerApiCrate::AcknowledgeReceivedMessages {
+                    request_id,
+                    delivery_failed: *delivery_failed != 0,
+                    messages,
+                },
+            ))
+        }
+        ClientMessageType::AcknowledgeSentMessages => {
+            let (request_id, payload) = split_request_id_payload(payload)?;
+
+            let mut messages = vec![];
+            for message_id in chunks_exact_checked(payload, 16)? {
+                messages.push(parse_message_id(message_id)?);
+            }
+
+            Ok(ClientMessageParsed::ForServerApi(
+                ClientMessageForServerApiCrate::AcknowledgeSentMessages {
+                    request_id,
+                    messages,
+                },
+            ))
+        }
     }
 }
 
@@ -152,6 +245,25 @@ fn parse_account_id(payload: &[u8]) -> crate::result::Result<AccountId, WebSocke
     Ok(AccountId::new_base_64_url(UuidBase64Url::from_bytes(bytes)))
 }
 
+fn parse_message_id(payload: &[u8]) -> crate::result::Result<MessageId, WebSocketError> {
+    let bytes: [u8; 16] = payload
+        .try_into()
+        .map_err(|_| WebSocketError::ProtocolError.report())?;
+
+    Ok(MessageId::new(UuidBase64Url::from_bytes(bytes)))
+}
+
+fn chunks_exact_checked(
+    payload: &[u8],
+    chunk_size: usize,
+) -> crate::result::Result<std::slice::ChunksExact<'_, u8>, WebSocketError> {
+    if !payload.len().is_multiple_of(chunk_size) {
+        return Err(WebSocketError::ProtocolError.report());
+    }
+
+    Ok(payload.chunks_exact(chunk_size))
+}
+
 fn split_request_id_payload(payload: &[u8]) -> crate::result::Result<(u8, &[u8]), WebSocketError> {
     let (request_id, payload_rest) = payload
         .split_first()
@@ -293,6 +405,51 @@ pub async fn handle_message_from_client(
             };
             chat::handle_check_online_status(state, id, check_account, is_online).await
         }
+        ClientMessageForServerApiCrate::SendMessage {
+            request_id,
+            recipient,
+            message_id,
+            sender_public_key_id,
+            recipient_public_key_id,
+            message,
+        } => {
+            COMMON.event_to_server_send_message.incr();
+            chat::handle_send_message(
+                state,
+                socket,
+                id,
+                request_id,
+                recipient,
+                message_id,
+                sender_public_key_id,
+                recipient_public_key_id,
+                message,
+            )
+            .await
+        }
+        ClientMessageForServerApiCrate::AcknowledgeReceivedMessages {
+            request_id,
+            delivery_failed,
+            messages,
+        } => {
+            COMMON.event_to_server_acknowledge_received_messages.incr();
+            chat::handle_acknowledge_received_messages(
+                state,
+                socket,
+                id,
+                request_id,
+                delivery_failed,
+                messages,
+            )
+            .await
+        }
+        ClientMessageForServerApiCrate::AcknowledgeSentMessages {
+            request_id,
+            messages,
+        } => {
+            COMMON.event_to_server_acknowledge_sent_messages.incr();
+            chat::handle_acknowledge_sent_messages(state, socket, id, request_id, messages).await
+        }
     }
 }
 
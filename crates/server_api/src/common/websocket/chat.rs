@@ -1,13 +1,27 @@
-use model::{AccountIdInternal, EventToClientInternal, OnlineStatusUpdate, TypingIndicatorConfig};
+use axum::extract::ws::WebSocket;
+use model::{
+    AccountId, AccountIdInternal, DAILY_MESSAGES_REMAINING_WARNING_THRESHOLD,
+    EventToClientInternal, MessageId, NewPendingMessage, NotificationEvent, OnlineStatusUpdate,
+    PendingMessageId, PublicKeyId, PushNotificationAllowed, ResponseAcknowledgeMessagesStatus,
+    ResponseSendMessage, ResponseSendMessageStatus, TypingIndicatorConfig,
+};
 use server_common::websocket::WebSocketError;
 use server_data::{
     app::{GetConfig, ReadData},
     cache::chat::CurrentlyTypingToAccess,
+    db_manager::InternalReading,
+    event::EventManagerWithCacheReference,
     result::WrappedResultExt,
 };
-use server_state::S;
+use server_state::{S, utils::ConvertDataErrorToStatusCode};
+use tracing::error;
 
-use crate::{app::EventManagerProvider, result::Result};
+use super::send_event;
+use crate::{
+    app::{ApiLimitsProvider, ApiUsageTrackerProvider, DataSignerProvider, EventManagerProvider},
+    result::Result,
+    utils::StatusCode,
+};
 
 pub async fn handle_typing_start(
     state: &S,
@@ -219,3 +233,191 @@ pub async fn handle_check_online_status(
 
     Ok(())
 }
+
+#[allow(clippy::too_many_arguments)]
+pub async fn handle_send_message(
+    state: &S,
+    socket: &mut WebSocket,
+    id: AccountIdInternal,
+    request_id: u8,
+    recipient: AccountId,
+    message_id: MessageId,
+    sender_public_key_id: PublicKeyId,
+    recipient_public_key_id: PublicKeyId,
+    message: Vec<u8>,
+) -> Result<(), WebSocketError> {
+    let response = match send_message(
+        state,
+        id,
+        recipient,
+        message_id,
+        sender_public_key_id,
+        recipient_public_key_id,
+        message,
+    )
+    .await
+    {
+        Ok(response) => response,
+        Err(StatusCode::TOO_MANY_REQUESTS) => {
+            ResponseSendMessage::error(ResponseSendMessageStatus::RateLimited)
+        }
+        Err(_) => ResponseSendMessage::error(ResponseSendMessageStatus::InternalServerError),
+    };
+
+    send_event(
+        socket,
+        EventToClientInternal::ResponseSendMessage {
+            request_id,
+            response,
+        },
+    )
+    .await
+}
+
+/// WebSocket version of HTTP route `post_send_message`.
+async fn send_message(
+    state: &S,
+    id: AccountIdInternal,
+    recipient: AccountId,
+    message_id: MessageId,
+    sender_public_key_id: PublicKeyId,
+    recipient_public_key_id: PublicKeyId,
+    message: Vec<u8>,
+) -> std::result::Result<ResponseSendMessage, StatusCode> {
+    state
+        .api_usage_tracker()
+        .incr(id, |u| &u.post_send_message)
+        .await;
+
+    let Some(recipient) = state
+        .read()
+        .cache()
+        .to_account_id_internal_optional(recipient)
+        .await
+    else {
+        return Ok(ResponseSendMessage::error(
+            ResponseSendMessageStatus::RecipientBlockedSenderOrRecipientNotFound,
+        ));
+    };
+
+    let current_messages = state.api_limits(id).chat().post_send_message().await?;
+    let max_messages = state.config().limits_chat().send_message_daily_max_count;
+    let remaining_messages = max_messages.saturating_sub(current_messages);
+
+    let keys = state.data_signer().keys().await?;
+    let (mut response, new_message) = state
+        .data_all_access()
+        .send_message(
+            id,
+            recipient,
+            message,
+            sender_public_key_id,
+            recipient_public_key_id,
+            message_id,
+            keys,
+        )
+        .await?;
+
+    if let Some(new_message) = new_message {
+        send_new_message_events(state.event_manager(), recipient, new_message).await;
+    }
+
+    if remaining_messages <= DAILY_MESSAGES_REMAINING_WARNING_THRESHOLD {
+        response.remaining_messages = Some(remaining_messages);
+    }
+
+    Ok(response)
+}
+
+/// Notify message recipient about a new pending message.
+///
+/// Connected recipient receives the message with
+/// [EventToClientInternal::PendingMessage] event. Event
+/// [EventToClientInternal::NewMessageReceived] is also sent as clients
+/// might fetch pending messages using HTTP API.
+pub async fn send_new_message_events(
+    events: EventManagerWithCacheReference<'_>,
+    recipient: AccountIdInternal,
+    new_message: NewPendingMessage,
+) {
+    events
+        .send_connected_event(
+            recipient,
+            EventToClientInternal::PendingChatNotificationsChanged,
+        )
+        .await
+        .ignore_and_log_error();
+
+    events
+        .send_connected_event(recipient, EventToClientInternal::NewMessageReceived)
+        .await
+        .ignore_and_log_error();
+
+    events
+        .send_connected_event(
+            recipient,
+            EventToClientInternal::PendingMessage(new_message.signed_message),
+        )
+        .await
+        .ignore_and_log_error();
+
+    if let Some(PushNotificationAllowed) = new_message.push_notification_allowed {
+        events
+            .send_notification(recipient, NotificationEvent::NewMessageReceived)
+            .await
+            .ignore_and_log_error();
+    }
+}
+
+pub async fn handle_acknowledge_received_messages(
+    state: &S,
+    socket: &mut WebSocket,
+    id: AccountIdInternal,
+    request_id: u8,
+    delivery_failed: bool,
+    messages: Vec<PendingMessageId>,
+) -> Result<(), WebSocketError> {
+    let result = state
+        .data_all_access()
+        .add_recipient_acknowledgement(id, messages, delivery_failed)
+        .await
+        .change_context(WebSocketError::EventToServerHandlingFailed);
+
+    send_acknowledge_messages_response(socket, request_id, result).await
+}
+
+pub async fn handle_acknowledge_sent_messages(
+    state: &S,
+    socket: &mut WebSocket,
+    id: AccountIdInternal,
+    request_id: u8,
+    messages: Vec<MessageId>,
+) -> Result<(), WebSocketError> {
+    let result = state
+        .data_all_access()
+        .add_sender_acknowledgement(id, messages)
+        .await
+        .change_context(WebSocketError::EventToServerHandlingFailed);
+
+    send_acknowledge_messages_response(socket, request_id, result).await
+}
+
+async fn send_acknowledge_messages_response(
+    socket: &mut WebSocket,
+    request_id: u8,
+    result: Result<(), WebSocketError>,
+) -> Result<(), WebSocketError> {
+    let status = match result {
+        Ok(()) => ResponseAcknowledgeMessagesStatus::Success,
+        Err(e) => {
+            error!("{e:?}");
+            ResponseAcknowledgeMessagesStatus::InternalServerError
+        }
+    };
+
+    send_event(
+        socket,
+        EventToClientInternal::ResponseAcknowledgeMessages { request_id, status },
+    )
+    .await
+}
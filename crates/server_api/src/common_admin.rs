@@ -1,13 +1,17 @@
 //! Common routes related to admin features
 
+pub mod audit_log;
 pub mod config;
+pub mod data_import;
 pub mod maintenance;
 pub mod manager;
 pub mod notification;
 pub mod report;
 pub mod statistics;
 
+pub use audit_log::*;
 pub use config::*;
+pub use data_import::*;
 pub use maintenance::*;
 pub use manager::*;
 pub use notification::*;
@@ -0,0 +1,57 @@
+use axum::{Extension, extract::State};
+use model::{AdminAuditLogPage, AdminAuditLogQuery, Permissions};
+use server_data::{app::ReadData, read::GetReadCommandsCommon};
+use simple_backend::create_counters;
+
+use crate::{
+    S, create_open_api_router,
+    utils::{Json, StatusCode},
+};
+
+const PATH_POST_GET_ADMIN_AUDIT_LOG_PAGE: &str = "/common_api/admin_audit_log_page";
+
+/// Get max 25 admin audit log entries. Returns entries from newest to oldest.
+///
+/// HTTP method is POST because JSON request body requires it.
+///
+/// # Permissions
+/// Requires [Permissions::admin_view_admin_audit_log].
+#[utoipa::path(
+    post,
+    path = PATH_POST_GET_ADMIN_AUDIT_LOG_PAGE,
+    request_body = AdminAuditLogQuery,
+    responses(
+        (status = 200, description = "Successfull.", body = AdminAuditLogPage),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_get_admin_audit_log_page(
+    State(state): State<S>,
+    Extension(api_caller_permissions): Extension<Permissions>,
+    Json(query): Json<AdminAuditLogQuery>,
+) -> Result<Json<AdminAuditLogPage>, StatusCode> {
+    COMMON_ADMIN.post_get_admin_audit_log_page.incr();
+
+    if !api_caller_permissions.admin_view_admin_audit_log {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    let page = state
+        .read()
+        .common_history()
+        .admin_audit_log_page(query)
+        .await?;
+
+    Ok(page.into())
+}
+
+create_open_api_router!(fn router_audit_log, post_get_admin_audit_log_page,);
+
+create_counters!(
+    CommonAdminCounters,
+    COMMON_ADMIN,
+    COMMON_ADMIN_AUDIT_LOG_COUNTERS_LIST,
+    post_get_admin_audit_log_page,
+);
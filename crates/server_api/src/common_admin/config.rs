@@ -1,6 +1,14 @@
-use axum::{Extension, extract::State};
+use std::net::SocketAddr;
+
+use axum::{
+    Extension,
+    extract::{ConnectInfo, State},
+};
 use config::bot_config_file::internal::ProfileStringModerationLlmConfigInternal;
-use model::{AccountIdInternal, BotConfig, DynamicServerConfig, Permissions};
+use model::{
+    AccountIdInternal, AdminAuditLogAction, BotConfig, DynamicServerConfig, NewAdminAuditLogEntry,
+    Permissions,
+};
 use server_data::{
     app::GetDynamicServerConfig, read::GetReadCommandsCommon, write::GetWriteCommandsCommon,
 };
@@ -79,7 +87,9 @@ const PATH_POST_BOT_CONFIG: &str = "/common_api/bot_config";
 )]
 pub async fn post_bot_config(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(api_caller_permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(bot_config): Json<BotConfig>,
 ) -> Result<(), StatusCode> {
     COMMON_ADMIN.post_bot_config.incr();
@@ -139,7 +149,22 @@ pub async fn post_bot_config(
         }
     }
 
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        api_caller_id,
+        address.ip(),
+        AdminAuditLogAction::EditBotConfig,
+    )
+    .parameters(&bot_config);
+
     state.write_config(bot_config).await?;
+
+    db_write!(state, move |cmds| {
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
+        Ok(())
+    })?;
+
     Ok(())
 }
 
@@ -197,7 +222,9 @@ const PATH_POST_DYNAMIC_SERVER_CONFIG: &str = "/common_api/dynamic_server_config
 )]
 pub async fn post_dynamic_server_config(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(api_caller_permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(config): Json<DynamicServerConfig>,
 ) -> Result<(), StatusCode> {
     COMMON_ADMIN.post_dynamic_server_config.incr();
@@ -206,11 +233,22 @@ pub async fn post_dynamic_server_config(
         return Err(StatusCode::UNAUTHORIZED);
     }
 
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        api_caller_id,
+        address.ip(),
+        AdminAuditLogAction::EditServerConfig,
+    )
+    .parameters(&config);
+
     db_write!(state, move |cmds| {
         cmds.common()
             .client_config()
             .upsert_dynamic_server_config(config)
-            .await
+            .await?;
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
+        Ok(())
     })?;
 
     Ok(())
@@ -0,0 +1,250 @@
+use std::{collections::HashMap, net::SocketAddr, time::Duration};
+
+use axum::{
+    Extension,
+    body::Body,
+    extract::{ConnectInfo, State},
+};
+use model::{
+    AccountIdInternal, AdminAuditLogAction, AdminNotificationTypes, ContentId,
+    ContentProcessingStateType, ContentSlot, NewAdminAuditLogEntry, Permissions,
+    PostImportAccountDataResult,
+};
+use model_server_data::{MediaContentUploadType, NewContentParams};
+use server_data::{
+    DataError,
+    content_processing::ContentProcessingOngoing,
+    data_import::{DataImportImage, DataImportProfileContent, DataImportResult},
+    write::GetWriteCommandsCommon,
+    write_concurrent::{ConcurrentWriteAction, ConcurrentWriteContentHandle},
+};
+use simple_backend::create_counters;
+
+use crate::{
+    S,
+    app::{AdminNotificationProvider, ContentProcessingProvider, WriteData},
+    create_open_api_router, db_write,
+    utils::{Json, StatusCode, body_to_bytes},
+};
+
+/// Max size for user data export archive
+const MAX_ARCHIVE_SIZE: usize = 1024 * 1024 * 200;
+/// Max wait time for content processing of one image
+const IMAGE_PROCESSING_TIMEOUT: Duration = Duration::from_secs(60 * 5);
+const IMAGE_PROCESSING_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+const PATH_POST_IMPORT_ACCOUNT_DATA: &str = "/common_api/import_account_data";
+
+/// Create new account from user data export archive.
+///
+/// The archive must be created using user data export. The imported data
+/// is profile, profile images, security content, settings and
+/// favorites. Favorites are imported only if the favorite account exists
+/// on this server. Other data which refers to other accounts is not
+/// imported. The account ID from the archive is used if it is not already
+/// used on this server.
+///
+/// Profile images are processed using content processing, so this
+/// might take a while. Images which fail the processing are not imported.
+///
+/// The imported account does not have any sign in with login methods, so
+/// email login must be used.
+///
+/// Max archive size is 200 MiB.
+///
+/// # Permissions
+/// Requires [Permissions::admin_import_account_data].
+#[utoipa::path(
+    post,
+    path = PATH_POST_IMPORT_ACCOUNT_DATA,
+    request_body(content = inline(model::BinaryData), content_type = "application/zip"),
+    responses(
+        (status = 200, description = "Successfull.", body = PostImportAccountDataResult),
+        (status = 401, description = "Unauthorized."),
+        (status = 413, description = "Archive is larger than the max size."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_import_account_data(
+    State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
+    Extension(api_caller_permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    archive: Body,
+) -> Result<Json<PostImportAccountDataResult>, StatusCode> {
+    COMMON_ADMIN.post_import_account_data.incr();
+
+    if !api_caller_permissions.admin_import_account_data {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    let archive = body_to_bytes(archive, MAX_ARCHIVE_SIZE).await?;
+
+    let (id, images, profile_content) = match state
+        .data_all_access()
+        .import_account_data(archive.into())
+        .await?
+    {
+        DataImportResult::Ok {
+            id,
+            images,
+            profile_content,
+        } => (id, images, profile_content),
+        DataImportResult::InvalidArchive => {
+            return Ok(PostImportAccountDataResult::error_invalid_archive().into());
+        }
+        DataImportResult::EmailAlreadyExists => {
+            return Ok(PostImportAccountDataResult::error_email_already_used().into());
+        }
+    };
+
+    let failed_image_count = match import_images(&state, id, images, profile_content).await {
+        Ok(count) => count,
+        Err(e) => {
+            state
+                .data_all_access()
+                .cancel_account_data_import(id)
+                .await?;
+            return Err(e);
+        }
+    };
+
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        api_caller_id,
+        address.ip(),
+        AdminAuditLogAction::ImportAccountData,
+    )
+    .target_account(id.as_id());
+    db_write!(state, move |cmds| {
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
+        Ok(())
+    })?;
+
+    for notification in [
+        AdminNotificationTypes::ModerateInitialMediaContentBot,
+        AdminNotificationTypes::ModerateProfileNamesBot,
+        AdminNotificationTypes::ModerateProfileTextsBot,
+    ] {
+        state
+            .admin_notification()
+            .send_notification_if_needed(notification)
+            .await;
+    }
+
+    Ok(PostImportAccountDataResult::ok(id.as_id(), failed_image_count).into())
+}
+
+/// Returns failed image count.
+async fn import_images(
+    state: &S,
+    id: AccountIdInternal,
+    images: Vec<DataImportImage>,
+    profile_content: DataImportProfileContent,
+) -> Result<i64, StatusCode> {
+    let mut imported = HashMap::new();
+    let mut failed_image_count = 0;
+    for (slot, image) in images.into_iter().enumerate() {
+        let source_content_id = image.source_content_id;
+        match process_image(state, id, slot, image).await? {
+            Some(content) => {
+                imported.insert(source_content_id, content);
+            }
+            None => failed_image_count += 1,
+        }
+    }
+
+    let profile_content = profile_content.to_imported(|c| imported.get(&c).copied());
+    state
+        .data_all_access()
+        .complete_account_data_import(id, profile_content)
+        .await?;
+
+    Ok(failed_image_count)
+}
+
+/// Returns new content ID and face detected value if processing
+/// was successful.
+async fn process_image(
+    state: &S,
+    id: AccountIdInternal,
+    slot: usize,
+    image: DataImportImage,
+) -> Result<Option<(ContentId, bool)>, StatusCode> {
+    let content_slot =
+        ContentSlot::try_from(slot as i16).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let result = tokio::time::timeout(IMAGE_PROCESSING_TIMEOUT, async {
+        let upload_permit = loop {
+            match state.content_processing().begin_upload(id).await {
+                Ok(v) => break v,
+                Err(ContentProcessingOngoing) => {
+                    tokio::time::sleep(IMAGE_PROCESSING_POLL_INTERVAL).await
+                }
+            }
+        };
+
+        let stream = Body::from(image.data).into_data_stream();
+        let content_info = state
+            .write_concurrent(id.as_id(), move |cmds| async move {
+                let out: ConcurrentWriteAction<crate::result::Result<_, DataError>> = cmds
+                    .accquire_image(move |cmds: ConcurrentWriteContentHandle| {
+                        Box::new(async move { cmds.save_to_tmp(id, stream, upload_permit).await })
+                    })
+                    .await;
+                out
+            })
+            .await??;
+
+        state
+            .content_processing()
+            .queue_new_content(
+                id,
+                content_slot,
+                content_info,
+                NewContentParams {
+                    slot_id: slot as u8,
+                    processing_id_from_client: 0,
+                    secure_capture: image.secure_capture,
+                    content_type: MediaContentUploadType::Image,
+                },
+            )
+            .await;
+
+        loop {
+            tokio::time::sleep(IMAGE_PROCESSING_POLL_INTERVAL).await;
+            let Some(processing_state) = state.content_processing().get_current_state(id).await
+            else {
+                return Ok::<_, StatusCode>(None);
+            };
+            match processing_state.state {
+                ContentProcessingStateType::InQueue | ContentProcessingStateType::Processing => (),
+                ContentProcessingStateType::Completed => {
+                    return Ok(processing_state
+                        .cid
+                        .map(|cid| (cid, processing_state.face_detected.unwrap_or_default())));
+                }
+                ContentProcessingStateType::Failed | ContentProcessingStateType::NsfwDetected => {
+                    return Ok(None);
+                }
+            }
+        }
+    })
+    .await;
+
+    match result {
+        Ok(result) => result,
+        Err(_) => Ok(None),
+    }
+}
+
+create_open_api_router!(fn router_data_import, post_import_account_data,);
+
+create_counters!(
+    CommonAdminCounters,
+    COMMON_ADMIN,
+    COMMON_ADMIN_DATA_IMPORT_COUNTERS_LIST,
+    post_import_account_data,
+);
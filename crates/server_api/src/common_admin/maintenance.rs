@@ -1,10 +1,23 @@
-use axum::{Extension, extract::State};
-use model::{EventToClientInternal, Permissions, ServerMaintenanceStatus, ServerVersion};
-use server_data::app::{EventManagerProvider, ServerVersionProvider};
+use std::net::SocketAddr;
+
+use axum::{
+    Extension,
+    extract::{ConnectInfo, State},
+};
+use model::{
+    AccountIdInternal, AdminAuditLogAction, EventToClientInternal, NewAdminAuditLogEntry,
+    Permissions, ServerMaintenanceStatus, ServerVersion,
+};
+use server_data::{
+    app::{EventManagerProvider, ServerVersionProvider},
+    write::GetWriteCommandsCommon,
+};
 use simple_backend::{app::GetManagerApi, create_counters};
 
 use crate::{
-    S, create_open_api_router,
+    S,
+    app::WriteData,
+    create_open_api_router, db_write,
     utils::{Json, StatusCode},
 };
 
@@ -90,7 +103,9 @@ const PATH_POST_EDIT_MAINTENANCE_NOTIFICATION: &str = "/common_api/edit_maintena
 )]
 pub async fn post_edit_maintenance_notification(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(api_caller_permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(status): Json<ServerMaintenanceStatus>,
 ) -> Result<(), StatusCode> {
     COMMON_ADMIN.post_edit_maintenance_notification.incr();
@@ -101,6 +116,19 @@ pub async fn post_edit_maintenance_notification(
             .set_maintenance_time(status.start(), status.end())
             .await;
 
+        let audit_log_entry = NewAdminAuditLogEntry::new(
+            api_caller_id,
+            address.ip(),
+            AdminAuditLogAction::EditMaintenanceNotification,
+        )
+        .parameters(&status);
+        db_write!(state, move |cmds| {
+            cmds.common_history()
+                .write_admin_audit_log_entry(audit_log_entry)
+                .await;
+            Ok(())
+        })?;
+
         let status = state.manager_api_client().maintenance_status().await;
         state
             .event_manager()
@@ -1,6 +1,8 @@
+use std::net::SocketAddr;
+
 use axum::{
     Extension,
-    extract::{Query, State},
+    extract::{ConnectInfo, Query, State},
 };
 use manager_api::RequestSenderCmds;
 use manager_model::{
@@ -8,12 +10,16 @@ use manager_model::{
     ScheduledTaskStatus, ScheduledTaskType, ScheduledTaskTypeValue, SoftwareInfo,
     SoftwareUpdateStatus, SoftwareUpdateTaskType, SystemInfo,
 };
-use model::Permissions;
-use server_data::{app::GetConfig, data_reset::BACKEND_DATA_RESET_STATE};
+use model::{AccountIdInternal, AdminAuditLogAction, NewAdminAuditLogEntry, Permissions};
+use server_data::{
+    app::GetConfig, data_reset::BACKEND_DATA_RESET_STATE, write::GetWriteCommandsCommon,
+};
 use simple_backend::{app::GetManagerApi, create_counters};
 
 use crate::{
-    S, create_open_api_router,
+    S,
+    app::WriteData,
+    create_open_api_router, db_write,
     utils::{Json, StatusCode},
 };
 
@@ -158,12 +164,21 @@ const PATH_POST_TRIGGER_SOFTWARE_UPDATE_DOWNLOAD: &str =
 )]
 pub async fn post_trigger_software_update_download(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(api_caller_permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Query(manager): Query<ManagerInstanceNameValue>,
 ) -> Result<(), StatusCode> {
     COMMON_ADMIN.post_trigger_software_update_download.incr();
 
     if api_caller_permissions.admin_server_software_update {
+        let audit_log_entry = NewAdminAuditLogEntry::new(
+            api_caller_id,
+            address.ip(),
+            AdminAuditLogAction::TriggerSoftwareUpdateDownload,
+        )
+        .parameters(&manager);
+        write_audit_log_entry(&state, audit_log_entry).await?;
         state
             .manager_request_to(manager)
             .await?
@@ -195,13 +210,25 @@ const PATH_POST_TRIGGER_SOFTWARE_UPDATE_INSTALL: &str =
 )]
 pub async fn post_trigger_software_update_install(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(api_caller_permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Query(manager): Query<ManagerInstanceNameValue>,
     Query(info): Query<SoftwareInfo>,
 ) -> Result<(), StatusCode> {
     COMMON_ADMIN.post_trigger_software_update_install.incr();
 
     if api_caller_permissions.admin_server_software_update {
+        let audit_log_entry = NewAdminAuditLogEntry::new(
+            api_caller_id,
+            address.ip(),
+            AdminAuditLogAction::TriggerSoftwareUpdateInstall,
+        )
+        .parameters(&serde_json::json!({
+            "manager": manager,
+            "software": info,
+        }));
+        write_audit_log_entry(&state, audit_log_entry).await?;
         state
             .manager_request_to(manager)
             .await?
@@ -244,7 +271,9 @@ const PATH_POST_TRIGGER_SERVER_DATA_RESET: &str = "/common_api/trigger_server_da
 )]
 pub async fn post_trigger_server_data_reset(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(api_caller_permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Query(manager): Query<ManagerInstanceNameValue>,
 ) -> Result<(), StatusCode> {
     COMMON_ADMIN.post_trigger_server_data_reset.incr();
@@ -261,6 +290,14 @@ pub async fn post_trigger_server_data_reset(
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        api_caller_id,
+        address.ip(),
+        AdminAuditLogAction::TriggerServerDataReset,
+    )
+    .parameters(&manager);
+    write_audit_log_entry(&state, audit_log_entry).await?;
+
     state.data_all_access().delete_all_accounts().await?;
 
     state
@@ -290,12 +327,21 @@ const PATH_POST_TRIGGER_SERVER_RESTART: &str = "/common_api/trigger_server_resta
 )]
 pub async fn post_trigger_server_restart(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(api_caller_permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Query(manager): Query<ManagerInstanceNameValue>,
 ) -> Result<(), StatusCode> {
     COMMON_ADMIN.post_trigger_server_restart.incr();
 
     if api_caller_permissions.admin_server_restart {
+        let audit_log_entry = NewAdminAuditLogEntry::new(
+            api_caller_id,
+            address.ip(),
+            AdminAuditLogAction::TriggerServerRestart,
+        )
+        .parameters(&manager);
+        write_audit_log_entry(&state, audit_log_entry).await?;
         state
             .manager_request_to(manager)
             .await?
@@ -326,12 +372,21 @@ const PATH_POST_TRIGGER_SYSTEM_REBOOT: &str = "/common_api/trigger_system_reboot
 )]
 pub async fn post_trigger_system_reboot(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(api_caller_permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Query(manager): Query<ManagerInstanceNameValue>,
 ) -> Result<(), StatusCode> {
     COMMON_ADMIN.post_trigger_system_reboot.incr();
 
     if api_caller_permissions.admin_server_reboot {
+        let audit_log_entry = NewAdminAuditLogEntry::new(
+            api_caller_id,
+            address.ip(),
+            AdminAuditLogAction::TriggerSystemReboot,
+        )
+        .parameters(&manager);
+        write_audit_log_entry(&state, audit_log_entry).await?;
         state
             .manager_request_to(manager)
             .await?
@@ -362,12 +417,21 @@ const PATH_POST_TRIGGER_SYSTEM_SHUTDOWN: &str = "/common_api/trigger_system_shut
 )]
 pub async fn post_trigger_system_shutdown(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(api_caller_permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Query(manager): Query<ManagerInstanceNameValue>,
 ) -> Result<(), StatusCode> {
     COMMON_ADMIN.post_trigger_system_shutdown.incr();
 
     if api_caller_permissions.admin_server_shutdown {
+        let audit_log_entry = NewAdminAuditLogEntry::new(
+            api_caller_id,
+            address.ip(),
+            AdminAuditLogAction::TriggerSystemShutdown,
+        )
+        .parameters(&manager);
+        write_audit_log_entry(&state, audit_log_entry).await?;
         state
             .manager_request_to(manager)
             .await?
@@ -438,7 +502,9 @@ const PATH_POST_SCHEDULE_TASK: &str = "/common_api/schedule_task";
 )]
 pub async fn post_schedule_task(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(api_caller_permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Query(manager): Query<ManagerInstanceNameValue>,
     Query(task): Query<ScheduledTaskTypeValue>,
     Query(notify_server): Query<NotifyServer>,
@@ -451,6 +517,17 @@ pub async fn post_schedule_task(
     };
 
     if authorized {
+        let audit_log_entry = NewAdminAuditLogEntry::new(
+            api_caller_id,
+            address.ip(),
+            AdminAuditLogAction::ScheduleTask,
+        )
+        .parameters(&serde_json::json!({
+            "manager": manager,
+            "task": task,
+            "notify_server": notify_server,
+        }));
+        write_audit_log_entry(&state, audit_log_entry).await?;
         state
             .manager_request_to(manager)
             .await?
@@ -482,7 +559,9 @@ const PATH_POST_UNSCHEDULE_TASK: &str = "/common_api/unschedule_task";
 )]
 pub async fn post_unschedule_task(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(api_caller_permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Query(manager): Query<ManagerInstanceNameValue>,
     Query(task): Query<ScheduledTaskTypeValue>,
 ) -> Result<(), StatusCode> {
@@ -494,6 +573,16 @@ pub async fn post_unschedule_task(
     };
 
     if authorized {
+        let audit_log_entry = NewAdminAuditLogEntry::new(
+            api_caller_id,
+            address.ip(),
+            AdminAuditLogAction::UnscheduleTask,
+        )
+        .parameters(&serde_json::json!({
+            "manager": manager,
+            "task": task,
+        }));
+        write_audit_log_entry(&state, audit_log_entry).await?;
         state
             .manager_request_to(manager)
             .await?
@@ -505,6 +594,17 @@ pub async fn post_unschedule_task(
     }
 }
 
+/// Audit log entry is written before the manager request as the request
+/// might stop the server.
+async fn write_audit_log_entry(state: &S, entry: NewAdminAuditLogEntry) -> Result<(), StatusCode> {
+    db_write!(state, move |cmds| {
+        cmds.common_history()
+            .write_admin_audit_log_entry(entry)
+            .await;
+        Ok(())
+    })
+}
+
 create_open_api_router!(
         fn router_manager,
         get_manager_instance_names,
@@ -1,8 +1,13 @@
-use axum::{Extension, extract::State};
+use std::net::SocketAddr;
+
+use axum::{
+    Extension,
+    extract::{ConnectInfo, State},
+};
 use model::{
-    AccountIdInternal, GetChatMessageReports, GetChatMessageReportsInternal, GetReportList,
-    GetReportQueuePage, Permissions, ProcessReports, ReportIteratorQuery,
-    ReportIteratorQueryInternal, UnixTime,
+    AccountIdInternal, AdminAuditLogAction, GetChatMessageReports, GetChatMessageReportsInternal,
+    GetReportList, GetReportQueuePage, NewAdminAuditLogEntry, Permissions, ProcessReports,
+    ReportIteratorQuery, ReportIteratorQueryInternal, UnixTime,
 };
 use server_data::{read::GetReadCommandsCommon, write::GetWriteCommandsCommon};
 use simple_backend::create_counters;
@@ -72,6 +77,7 @@ pub async fn post_process_reports(
     State(state): State<S>,
     Extension(permissions): Extension<Permissions>,
     Extension(moderator_id): Extension<AccountIdInternal>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(data): Json<ProcessReports>,
 ) -> Result<(), StatusCode> {
     COMMON.post_process_reports.incr();
@@ -80,11 +86,46 @@ pub async fn post_process_reports(
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
+    let mut accounts_to_ban = vec![];
+    for report in data
+        .values
+        .iter()
+        .filter(|v| v.accepted && v.report_type.is_ban_evasion())
+    {
+        if !permissions.admin_ban_account {
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+        let banned_account = state.get_internal_id(report.creator).await?;
+        let target = state.get_internal_id(report.target).await?;
+        accounts_to_ban.push((banned_account, target));
+    }
+
+    let audit_log_entries: Vec<_> = data
+        .values
+        .iter()
+        .map(|report| {
+            NewAdminAuditLogEntry::new(
+                moderator_id,
+                address.ip(),
+                AdminAuditLogAction::ProcessReport,
+            )
+            .target_account(report.target)
+            .parameters(report)
+        })
+        .collect();
+
     let reporters_to_ban = db_write!(state, move |cmds| {
-        cmds.common_admin()
+        let reporters_to_ban = cmds
+            .common_admin()
             .report()
             .process_reports_and_get_report_spammers(moderator_id, data.values)
-            .await
+            .await?;
+        for entry in audit_log_entries {
+            cmds.common_history()
+                .write_admin_audit_log_entry(entry)
+                .await;
+        }
+        Ok(reporters_to_ban)
     })?;
 
     state
@@ -92,6 +133,11 @@ pub async fn post_process_reports(
         .auto_ban_spam_reporters(reporters_to_ban)
         .await?;
 
+    state
+        .data_all_access()
+        .ban_accounts_linked_to_banned_accounts(moderator_id, accounts_to_ban)
+        .await?;
+
     Ok(())
 }
 
@@ -1,8 +1,9 @@
 use std::{net::SocketAddr, time::Duration};
 
 use axum::{
-    body::Body,
+    body::{Body, Bytes},
     extract::{ConnectInfo, FromRequest, State, rejection::JsonRejection},
+    http::header::RETRY_AFTER,
     middleware::Next,
     response::{IntoResponse, Response},
 };
@@ -10,12 +11,17 @@ use axum_extra::TypedHeader;
 use headers::{
     Authorization, CacheControl, ETag, HeaderMapExt, IfNoneMatch, authorization::Bearer,
 };
+use http_body_util::LengthLimitError;
 use hyper::Request;
 use model::AccessToken;
 use serde::Serialize;
 use server_data::{app::ReadData, read::GetReadCommandsCommon};
 pub use server_state::utils::StatusCode;
-use server_state::{StateForRouterCreation, app::GetAccessTokens};
+use server_state::{
+    S, StateForRouterCreation,
+    app::{GetAccessTokens, GetConfig},
+    ip_rate_limit::IpRateLimitRouteGroup,
+};
 use simple_backend::create_counters;
 use simple_backend_config::RUNNING_IN_DEBUG_MODE;
 use utoipa::{
@@ -31,7 +37,8 @@ use utoipa::{
 ///
 /// Adds `Permissions` extension to request, so that adding
 /// "Extension(api_caller_permissions): Extension<Permissions>"
-/// to handlers is possible.
+/// to handlers is possible. The permissions are empty if the server
+/// requires TOTP verification and the login session is not verified.
 ///
 /// Adds `AccountState` extension to request, so that adding
 /// "Extension(api_caller_account_state): Extension<AccountState>"
@@ -69,12 +76,41 @@ pub async fn authenticate_with_access_token(
     }
 }
 
+/// Middleware for per IP address rate limiting of unauthenticated routes.
+///
+/// Returns `429 Too Many Requests` with `Retry-After` header when
+/// the limit is reached.
+pub async fn rate_limit_with_ip_address(
+    State((state, group)): State<(S, IpRateLimitRouteGroup)>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    match state
+        .ip_rate_limiter()
+        .check(group, addr.ip(), state.config())
+        .await
+    {
+        Ok(()) => next.run(req).await,
+        Err(wait_time) => {
+            API.ip_rate_limit_reached.incr();
+            let retry_after_seconds = wait_time.as_secs() + u64::from(wait_time.subsec_nanos() > 0);
+            (
+                hyper::StatusCode::TOO_MANY_REQUESTS,
+                [(RETRY_AFTER, retry_after_seconds.to_string())],
+            )
+                .into_response()
+        }
+    }
+}
+
 create_counters!(
     ApiCounters,
     API,
     API_COUNTERS_LIST,
     access_token_found,
     access_token_not_found,
+    ip_rate_limit_reached,
 );
 
 /// Utoipa API doc security config
@@ -143,6 +179,20 @@ impl IntoResponse for ApiError {
     }
 }
 
+/// Read request body to memory.
+///
+/// Returns [StatusCode::PAYLOAD_TOO_LARGE] if the body is larger
+/// than the limit.
+pub async fn body_to_bytes(body: Body, limit: usize) -> Result<Bytes, StatusCode> {
+    axum::body::to_bytes(body, limit).await.map_err(|e| {
+        if e.into_inner().downcast_ref::<LengthLimitError>().is_some() {
+            StatusCode::PAYLOAD_TOO_LARGE
+        } else {
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    })
+}
+
 pub fn cache_control_for_images() -> CacheControl {
     const MONTH_SECONDS: u64 = 60 * 60 * 24 * 30;
     CacheControl::new()
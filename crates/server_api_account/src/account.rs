@@ -13,6 +13,7 @@ pub mod report;
 pub mod settings;
 pub mod sign_in_with;
 pub mod state;
+pub mod totp;
 pub mod verification;
 
 pub use association::*;
@@ -30,4 +31,5 @@ pub use report::*;
 pub use settings::*;
 pub use sign_in_with::*;
 pub use state::*;
+pub use totp::*;
 pub use verification::*;
@@ -1,14 +1,19 @@
+use std::net::SocketAddr;
+
 use axum::{
     Extension,
-    extract::{Path, State},
+    extract::{ConnectInfo, Path, State},
+};
+use model::{
+    AccountId, AccountIdInternal, AdminAuditLogAction, NewAdminAuditLogEntry, Permissions,
 };
-use model::{AccountId, AccountIdInternal, Permissions};
 use model_account::GetAccountBanTimeResult;
 use server_api::{
     S,
-    app::{GetAccounts, ReadData},
-    create_open_api_router,
+    app::{GetAccounts, ReadData, WriteData},
+    create_open_api_router, db_write,
 };
+use server_data::write::GetWriteCommandsCommon;
 use server_data_account::read::GetReadCommandsAccount;
 use simple_backend::create_counters;
 
@@ -36,16 +41,33 @@ pub async fn get_account_ban_time(
     State(state): State<S>,
     Extension(api_caller): Extension<AccountIdInternal>,
     Extension(permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Path(account): Path<AccountId>,
 ) -> Result<Json<GetAccountBanTimeResult>, StatusCode> {
     ACCOUNT.get_account_ban_time.incr();
 
-    if account != api_caller.as_id() && !permissions.admin_ban_account {
+    let admin_access = account != api_caller.as_id();
+    if admin_access && !permissions.admin_ban_account {
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
     let internal_id = state.get_internal_id(account).await?;
 
+    if admin_access {
+        let audit_log_entry = NewAdminAuditLogEntry::new(
+            api_caller,
+            address.ip(),
+            AdminAuditLogAction::GetAccountBanTime,
+        )
+        .target_account(account);
+        db_write!(state, move |cmds| {
+            cmds.common_history()
+                .write_admin_audit_log_entry(audit_log_entry)
+                .await;
+            Ok(())
+        })?;
+    }
+
     let result = state.read().account().ban().ban_time(internal_id).await?;
 
     Ok(result.into())
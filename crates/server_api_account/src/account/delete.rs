@@ -1,14 +1,19 @@
+use std::net::SocketAddr;
+
 use axum::{
     Extension,
-    extract::{Path, State},
+    extract::{ConnectInfo, Path, State},
+};
+use model::{
+    AccountId, AccountIdInternal, AdminAuditLogAction, NewAdminAuditLogEntry, Permissions,
 };
-use model::{AccountId, AccountIdInternal, Permissions};
 use model_account::{BooleanSetting, GetAccountDeletionRequestResult};
 use server_api::{
     S,
     app::{GetAccounts, ReadData, WriteData},
     create_open_api_router, db_write,
 };
+use server_data::write::GetWriteCommandsCommon;
 use server_data_account::{read::GetReadCommandsAccount, write::GetWriteCommandsAccount};
 use simple_backend::create_counters;
 
@@ -38,22 +43,39 @@ pub async fn post_set_account_deletion_request_state(
     State(state): State<S>,
     Extension(api_caller): Extension<AccountIdInternal>,
     Extension(permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Path(account): Path<AccountId>,
     Json(value): Json<BooleanSetting>,
 ) -> Result<(), StatusCode> {
     ACCOUNT.post_set_account_deletion_request_state.incr();
 
-    if account != api_caller.as_id() && !permissions.admin_request_account_deletion {
+    let admin_access = account != api_caller.as_id();
+    if admin_access && !permissions.admin_request_account_deletion {
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
     let internal_id = state.get_internal_id(account).await?;
+    let audit_log_entry = admin_access.then(|| {
+        NewAdminAuditLogEntry::new(
+            api_caller,
+            address.ip(),
+            AdminAuditLogAction::SetAccountDeletionRequestState,
+        )
+        .target_account(account)
+        .parameters(&value)
+    });
 
     db_write!(state, move |cmds| {
         cmds.account()
             .delete()
             .set_account_deletion_request_state(internal_id, value.value)
-            .await
+            .await?;
+        if let Some(entry) = audit_log_entry {
+            cmds.common_history()
+                .write_admin_audit_log_entry(entry)
+                .await;
+        }
+        Ok(())
     })?;
 
     Ok(())
@@ -82,16 +104,33 @@ pub async fn get_account_deletion_request_state(
     State(state): State<S>,
     Extension(api_caller): Extension<AccountIdInternal>,
     Extension(permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Path(account): Path<AccountId>,
 ) -> Result<Json<GetAccountDeletionRequestResult>, StatusCode> {
     ACCOUNT.get_account_deletion_request_state.incr();
 
-    if account != api_caller.as_id() && !permissions.admin_request_account_deletion {
+    let admin_access = account != api_caller.as_id();
+    if admin_access && !permissions.admin_request_account_deletion {
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
     let internal_id = state.get_internal_id(account).await?;
 
+    if admin_access {
+        let audit_log_entry = NewAdminAuditLogEntry::new(
+            api_caller,
+            address.ip(),
+            AdminAuditLogAction::GetAccountDeletionRequestState,
+        )
+        .target_account(account);
+        db_write!(state, move |cmds| {
+            cmds.common_history()
+                .write_admin_audit_log_entry(audit_log_entry)
+                .await;
+            Ok(())
+        })?;
+    }
+
     let result = state
         .read()
         .account()
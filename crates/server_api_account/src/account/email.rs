@@ -1,14 +1,15 @@
-use std::{collections::HashMap, time::Duration};
+use std::{collections::HashMap, net::SocketAddr, time::Duration};
 
 use axum::{
     Extension,
     body::Bytes,
-    extract::{Form, Query, State},
+    extract::{ConnectInfo, Form, Query, State},
     http::StatusCode,
 };
 use axum_extra::{TypedHeader, headers::ContentType};
 use model::{
-    AccessToken, AccountIdInternal, AccountState, EventToClientInternal, Permissions, UnixTime,
+    AccessToken, AccountIdInternal, AccountState, AdminAuditLogAction, EventToClientInternal,
+    NewAdminAuditLogEntry, Permissions, UnixTime,
 };
 use model_account::{
     EmailAddressState, InitEmailChange, InitEmailChangeResult, SendVerifyEmailMessageResult,
@@ -21,7 +22,10 @@ use server_api::{
     create_open_api_router, db_write,
     utils::Json,
 };
-use server_data::{app::GetConfig, email::EmailSendingHandle, read::GetReadCommandsCommon};
+use server_data::{
+    app::GetConfig, email::EmailSendingHandle, read::GetReadCommandsCommon,
+    write::GetWriteCommandsCommon,
+};
 use server_data_account::{
     read::GetReadCommandsAccount,
     write::{GetWriteCommandsAccount, account::email::TokenCheckResult},
@@ -678,6 +682,7 @@ pub async fn post_set_email_login_enabled(
     State(state): State<S>,
     Extension(api_caller_account_id): Extension<AccountIdInternal>,
     Extension(api_caller_permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(request): Json<SetEmailLoginEnabled>,
 ) -> Result<(), crate::utils::StatusCode> {
     ACCOUNT.post_set_email_login_enabled.incr();
@@ -691,6 +696,16 @@ pub async fn post_set_email_login_enabled(
         return Err(crate::utils::StatusCode::INTERNAL_SERVER_ERROR);
     }
 
+    let audit_log_entry = (!is_own_account).then(|| {
+        NewAdminAuditLogEntry::new(
+            api_caller_account_id,
+            address.ip(),
+            AdminAuditLogAction::SetEmailLoginEnabled,
+        )
+        .target_account(request.aid)
+        .parameters(&request)
+    });
+
     db_write!(state, move |cmds| {
         cmds.account()
             .email()
@@ -706,6 +721,12 @@ pub async fn post_set_email_login_enabled(
                 .await?;
         }
 
+        if let Some(entry) = audit_log_entry {
+            cmds.common_history()
+                .write_admin_audit_log_entry(entry)
+                .await;
+        }
+
         Ok(())
     })?;
 
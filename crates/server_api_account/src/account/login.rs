@@ -9,7 +9,7 @@ use base64::Engine;
 use model::{AccountIdInternal, ClientType, EmailLoginToken, UnixTime};
 use model_account::{
     AccessToken, AccountId, AppleAccountId, AuthPair, EmailAddress, EmailLogin, GoogleAccountId,
-    LoginResult, RefreshToken, RequestEmailLoginToken, RequestEmailLoginTokenResult,
+    LoginResult, OidcAccountId, RefreshToken, RequestEmailLoginToken, RequestEmailLoginTokenResult,
     SignInWithInfo, SignInWithLoginInfo,
 };
 use server_api::{
@@ -26,7 +26,7 @@ use simple_backend::{
     app::{AppAttestationProvider, MaxMindDbDataProvider, SignInWith},
     app_attestation::AppAttestationError,
     create_counters,
-    sign_in_with::{apple::AppleAccountInfo, google::GoogleAccountInfo},
+    sign_in_with::{apple::AppleAccountInfo, google::GoogleAccountInfo, oidc::OidcAccountInfo},
 };
 use simple_backend_model::AppAttestation;
 use tokio::time::{Duration, timeout};
@@ -73,6 +73,13 @@ pub async fn login_impl(
             .init_login_session(id.into(), tokens_clone, address, false)
             .await
             .into_error()?;
+        cmds.cache()
+            .write_cache_common(id, |entry| {
+                entry.reset_totp_verification();
+                Ok(())
+            })
+            .await
+            .into_error()?;
         Ok(())
     })?;
 
@@ -154,6 +161,37 @@ impl SignInWithInfoTrait for AppleAccountInfo {
     }
 }
 
+impl SignInWithInfoTrait for OidcAccountInfo {
+    fn email(&self) -> String {
+        self.email.clone()
+    }
+
+    fn email_verified(&self) -> bool {
+        self.email_verified
+    }
+
+    fn sign_in_with_info(&self) -> SignInWithInfo {
+        SignInWithInfo {
+            oidc_provider: Some(self.provider.clone()),
+            oidc_account_id: Some(OidcAccountId(self.id.clone())),
+            ..Default::default()
+        }
+    }
+
+    async fn already_existing_account(
+        &self,
+        state: &S,
+    ) -> Result<Option<AccountIdInternal>, StatusCode> {
+        let already_existing_account = state
+            .read()
+            .account()
+            .oidc_account_id_to_account_id(self.provider.clone(), OidcAccountId(self.id.clone()))
+            .await?;
+
+        Ok(already_existing_account)
+    }
+}
+
 async fn validate_registration_platform(
     state: &S,
     client_type: ClientType,
@@ -299,7 +337,7 @@ pub(super) fn validate_app_attestation(
         })
 }
 
-/// Start new session with sign in with Apple or Google.
+/// Start new session with sign in with Apple, Google or OpenID Connect.
 ///
 /// Registers new account if it does not exist, when registration is enabled
 /// for the current client platform in dynamic server config.
@@ -354,6 +392,15 @@ pub async fn post_sign_in_with_login(
             .validate_google_token(google.token, nonce_bytes)
             .await?;
         handle_sign_in_with_info(&state, address, tokens.client_info.client_type, info).await
+    } else if let Some(oidc) = tokens.oidc {
+        let nonce_bytes = base64::engine::general_purpose::URL_SAFE
+            .decode(oidc.nonce)
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        let info = state
+            .sign_in_with_manager()
+            .validate_oidc_token(&oidc.provider, oidc.token, nonce_bytes)
+            .await?;
+        handle_sign_in_with_info(&state, address, tokens.client_info.client_type, info).await
     } else {
         Err(StatusCode::INTERNAL_SERVER_ERROR)
     }?;
@@ -2,8 +2,8 @@ use axum::{Extension, extract::State};
 use base64::Engine;
 use model::AccountIdInternal;
 use model_account::{
-    AppleAccountId, GoogleAccountId, PutSignInWithApple, PutSignInWithGoogle, PutSignInWithResult,
-    SignInWithState,
+    AppleAccountId, GoogleAccountId, OidcAccountId, PutSignInWithApple, PutSignInWithGoogle,
+    PutSignInWithOidc, PutSignInWithResult, SignInWithState,
 };
 use server_api::{S, create_open_api_router, db_write};
 use server_data::{DataError, app::GetConfig, write_commands::WriteCmds};
@@ -17,7 +17,7 @@ use crate::{
 
 const PATH_GET_SIGN_IN_WITH_INFO: &str = "/account_api/sign_in_with_info";
 
-/// Get current sign in with Apple and Google state.
+/// Get current sign in with Apple, Google and OpenID Connect state.
 #[utoipa::path(
     get,
     path = PATH_GET_SIGN_IN_WITH_INFO,
@@ -43,6 +43,9 @@ pub async fn get_sign_in_with_info(
     Ok(SignInWithState {
         apple: info.apple_account_id.is_some(),
         google: info.google_account_id.is_some(),
+        oidc: info
+            .oidc_provider
+            .filter(|_| info.oidc_account_id.is_some()),
     }
     .into())
 }
@@ -165,6 +168,67 @@ pub async fn put_sign_in_with_google(
     Ok(result.into())
 }
 
+const PATH_PUT_SIGN_IN_WITH_OIDC: &str = "/account_api/sign_in_with_oidc";
+
+/// Associate or disassociate OpenID Connect sign in with account.
+///
+/// Only one OpenID Connect provider can be associated with the account.
+#[utoipa::path(
+    put,
+    path = PATH_PUT_SIGN_IN_WITH_OIDC,
+    request_body = PutSignInWithOidc,
+    responses(
+        (status = 200, description = "Successful.", body = PutSignInWithResult),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn put_sign_in_with_oidc(
+    State(state): State<S>,
+    Extension(account_id): Extension<AccountIdInternal>,
+    Json(body): Json<PutSignInWithOidc>,
+) -> Result<Json<PutSignInWithResult>, StatusCode> {
+    ACCOUNT.put_sign_in_with_oidc.incr();
+
+    let result = if let Some(oidc) = body.oidc {
+        let nonce_bytes = base64::engine::general_purpose::URL_SAFE
+            .decode(oidc.nonce)
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        let info = state
+            .sign_in_with_manager()
+            .validate_oidc_token(&oidc.provider, oidc.token, nonce_bytes)
+            .await?;
+
+        if !info.email_verified {
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+
+        db_write!(state, move |cmds| {
+            if check_sign_in_with_history_limit(&cmds, account_id).await? {
+                return Ok(PutSignInWithResult::error_history_limit_reached());
+            }
+
+            cmds.account()
+                .sign_in_with()
+                .update_oidc_account_id(account_id, Some((info.provider, OidcAccountId(info.id))))
+                .await?;
+
+            Ok(PutSignInWithResult::ok())
+        })?
+    } else {
+        db_write!(state, move |cmds| {
+            cmds.account()
+                .sign_in_with()
+                .update_oidc_account_id(account_id, None)
+                .await?;
+            Ok(PutSignInWithResult::ok())
+        })?
+    };
+
+    Ok(result.into())
+}
+
 async fn check_sign_in_with_history_limit(
     cmds: &WriteCmds,
     account_id: AccountIdInternal,
@@ -187,6 +251,7 @@ create_open_api_router!(
     get_sign_in_with_info,
     put_sign_in_with_apple,
     put_sign_in_with_google,
+    put_sign_in_with_oidc,
 );
 
 create_counters!(
@@ -196,4 +261,5 @@ create_counters!(
     get_sign_in_with_info,
     put_sign_in_with_apple,
     put_sign_in_with_google,
+    put_sign_in_with_oidc,
 );
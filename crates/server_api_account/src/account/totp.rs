@@ -0,0 +1,274 @@
+use axum::{Extension, extract::State};
+use model_account::{AccountIdInternal, TotpCode, TotpCodeResult, TotpEnrollmentResult, TotpState};
+use server_api::{
+    S,
+    app::{ApiLimitsProvider, ReadData, WriteData},
+    create_open_api_router, db_write,
+};
+use server_data_account::{read::GetReadCommandsAccount, write::GetWriteCommandsAccount};
+use simple_backend::create_counters;
+
+use crate::utils::{Json, StatusCode};
+
+const PATH_GET_TOTP_STATE: &str = "/account_api/totp_state";
+
+/// Get TOTP state of the account and the current login session.
+#[utoipa::path(
+    get,
+    path = PATH_GET_TOTP_STATE,
+    responses(
+        (status = 200, description = "Successful.", body = TotpState),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn get_totp_state(
+    State(state): State<S>,
+    Extension(account_id): Extension<AccountIdInternal>,
+) -> Result<Json<TotpState>, StatusCode> {
+    ACCOUNT.get_totp_state.incr();
+    let totp_state = state.read().account().totp().totp_state(account_id).await?;
+    Ok(totp_state.into())
+}
+
+const PATH_POST_START_TOTP_ENROLLMENT: &str = "/account_api/start_totp_enrollment";
+
+/// Start TOTP enrollment.
+///
+/// Creates a new TOTP secret which is enabled after confirming it with
+/// a valid code. Possible previous unconfirmed secret is replaced.
+///
+/// If the server requires TOTP for admin permissions, enrollment is
+/// not possible when the account has admin permissions.
+#[utoipa::path(
+    post,
+    path = PATH_POST_START_TOTP_ENROLLMENT,
+    responses(
+        (status = 200, description = "Successful.", body = TotpEnrollmentResult),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_start_totp_enrollment(
+    State(state): State<S>,
+    Extension(account_id): Extension<AccountIdInternal>,
+) -> Result<Json<TotpEnrollmentResult>, StatusCode> {
+    ACCOUNT.post_start_totp_enrollment.incr();
+
+    let result = db_write!(state, move |cmds| {
+        cmds.account()
+            .totp()
+            .start_totp_enrollment(account_id)
+            .await
+    })?;
+
+    Ok(result.into())
+}
+
+const PATH_POST_CONFIRM_TOTP_ENROLLMENT: &str = "/account_api/confirm_totp_enrollment";
+
+/// Confirm TOTP enrollment with a TOTP code.
+///
+/// If the code is valid, TOTP is enabled, the current login session is
+/// marked as verified and recovery codes are returned.
+#[utoipa::path(
+    post,
+    path = PATH_POST_CONFIRM_TOTP_ENROLLMENT,
+    request_body = TotpCode,
+    responses(
+        (status = 200, description = "Successful.", body = TotpCodeResult),
+        (status = 401, description = "Unauthorized."),
+        (status = 429, description = "Too many requests."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_confirm_totp_enrollment(
+    State(state): State<S>,
+    Extension(account_id): Extension<AccountIdInternal>,
+    Json(code): Json<TotpCode>,
+) -> Result<Json<TotpCodeResult>, StatusCode> {
+    ACCOUNT.post_confirm_totp_enrollment.incr();
+
+    state
+        .api_limits(account_id)
+        .account()
+        .totp_code_attempt()
+        .await?;
+
+    let recovery_codes = db_write!(state, move |cmds| {
+        cmds.account()
+            .totp()
+            .confirm_totp_enrollment(account_id, code.code)
+            .await
+    })?;
+
+    let result = match recovery_codes {
+        Some(codes) => TotpCodeResult::ok_with_recovery_codes(codes),
+        None => TotpCodeResult::error_invalid_code(),
+    };
+
+    Ok(result.into())
+}
+
+const PATH_POST_VERIFY_TOTP: &str = "/account_api/verify_totp";
+
+/// Verify the current login session with a TOTP code or recovery code.
+///
+/// Admin permissions are usable only from verified login sessions
+/// if the server requires that. Verification is valid for
+/// a server configured time and new login removes the verification.
+#[utoipa::path(
+    post,
+    path = PATH_POST_VERIFY_TOTP,
+    request_body = TotpCode,
+    responses(
+        (status = 200, description = "Successful.", body = TotpCodeResult),
+        (status = 401, description = "Unauthorized."),
+        (status = 429, description = "Too many requests."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_verify_totp(
+    State(state): State<S>,
+    Extension(account_id): Extension<AccountIdInternal>,
+    Json(code): Json<TotpCode>,
+) -> Result<Json<TotpCodeResult>, StatusCode> {
+    ACCOUNT.post_verify_totp.incr();
+
+    state
+        .api_limits(account_id)
+        .account()
+        .totp_code_attempt()
+        .await?;
+
+    let valid = db_write!(state, move |cmds| {
+        cmds.account()
+            .totp()
+            .verify_totp(account_id, code.code)
+            .await
+    })?;
+
+    let result = if valid {
+        TotpCodeResult::ok()
+    } else {
+        TotpCodeResult::error_invalid_code()
+    };
+
+    Ok(result.into())
+}
+
+const PATH_POST_DISABLE_TOTP: &str = "/account_api/disable_totp";
+
+/// Disable TOTP with a TOTP code or recovery code.
+#[utoipa::path(
+    post,
+    path = PATH_POST_DISABLE_TOTP,
+    request_body = TotpCode,
+    responses(
+        (status = 200, description = "Successful.", body = TotpCodeResult),
+        (status = 401, description = "Unauthorized."),
+        (status = 429, description = "Too many requests."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_disable_totp(
+    State(state): State<S>,
+    Extension(account_id): Extension<AccountIdInternal>,
+    Json(code): Json<TotpCode>,
+) -> Result<Json<TotpCodeResult>, StatusCode> {
+    ACCOUNT.post_disable_totp.incr();
+
+    state
+        .api_limits(account_id)
+        .account()
+        .totp_code_attempt()
+        .await?;
+
+    let valid = db_write!(state, move |cmds| {
+        cmds.account()
+            .totp()
+            .disable_totp(account_id, code.code)
+            .await
+    })?;
+
+    let result = if valid {
+        TotpCodeResult::ok()
+    } else {
+        TotpCodeResult::error_invalid_code()
+    };
+
+    Ok(result.into())
+}
+
+const PATH_POST_REGENERATE_TOTP_RECOVERY_CODES: &str =
+    "/account_api/regenerate_totp_recovery_codes";
+
+/// Replace TOTP recovery codes with new codes.
+///
+/// Requires a TOTP code or recovery code.
+#[utoipa::path(
+    post,
+    path = PATH_POST_REGENERATE_TOTP_RECOVERY_CODES,
+    request_body = TotpCode,
+    responses(
+        (status = 200, description = "Successful.", body = TotpCodeResult),
+        (status = 401, description = "Unauthorized."),
+        (status = 429, description = "Too many requests."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_regenerate_totp_recovery_codes(
+    State(state): State<S>,
+    Extension(account_id): Extension<AccountIdInternal>,
+    Json(code): Json<TotpCode>,
+) -> Result<Json<TotpCodeResult>, StatusCode> {
+    ACCOUNT.post_regenerate_totp_recovery_codes.incr();
+
+    state
+        .api_limits(account_id)
+        .account()
+        .totp_code_attempt()
+        .await?;
+
+    let recovery_codes = db_write!(state, move |cmds| {
+        cmds.account()
+            .totp()
+            .regenerate_totp_recovery_codes(account_id, code.code)
+            .await
+    })?;
+
+    let result = match recovery_codes {
+        Some(codes) => TotpCodeResult::ok_with_recovery_codes(codes),
+        None => TotpCodeResult::error_invalid_code(),
+    };
+
+    Ok(result.into())
+}
+
+create_open_api_router!(
+    fn router_totp,
+    get_totp_state,
+    post_start_totp_enrollment,
+    post_confirm_totp_enrollment,
+    post_verify_totp,
+    post_disable_totp,
+    post_regenerate_totp_recovery_codes,
+);
+
+create_counters!(
+    AccountCounters,
+    ACCOUNT,
+    ACCOUNT_TOTP_COUNTERS_LIST,
+    get_totp_state,
+    post_start_totp_enrollment,
+    post_confirm_totp_enrollment,
+    post_verify_totp,
+    post_disable_totp,
+    post_regenerate_totp_recovery_codes,
+);
@@ -1,5 +1,12 @@
-use axum::{Extension, extract::State};
-use model::{AccountId, AccountIdInternal, Permissions};
+use std::net::SocketAddr;
+
+use axum::{
+    Extension,
+    extract::{ConnectInfo, State},
+};
+use model::{
+    AccountId, AccountIdInternal, AdminAuditLogAction, NewAdminAuditLogEntry, Permissions,
+};
 use model_account::{
     AssociationMembersPage, GetAssociationMember, GetAssociationMembersPage,
     ManualAssociationMembershipRegistry, ManualAssociationMembershipRegistryInput,
@@ -10,6 +17,7 @@ use server_api::{
     app::{GetAccounts, GetConfig, ReadData},
     create_open_api_router, db_write,
 };
+use server_data::write::GetWriteCommandsCommon;
 use server_data_account::{read::GetReadCommandsAccount, write::GetWriteCommandsAccount};
 use simple_backend::create_counters;
 
@@ -79,7 +87,9 @@ const PATH_POST_MANUAL_ASSOCIATION_MEMBERSHIP_REGISTRY: &str =
 )]
 pub async fn post_manual_association_membership_registry(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(data): Json<ManualAssociationMembershipRegistryInput>,
 ) -> Result<(), StatusCode> {
     ACCOUNT_ADMIN
@@ -90,11 +100,22 @@ pub async fn post_manual_association_membership_registry(
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        api_caller_id,
+        address.ip(),
+        AdminAuditLogAction::ManualAssociationMembershipRegistry,
+    )
+    .parameters(&data);
+
     db_write!(state, move |cmds| {
         cmds.account_admin()
             .association()
             .upsert_manual_registry(data.registry)
-            .await
+            .await?;
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
+        Ok(())
     })?;
 
     Ok(())
@@ -159,7 +180,9 @@ const PATH_POST_DELETE_ASSOCIATION_MEMBERSHIP: &str = "/account_api/delete_assoc
 )]
 pub async fn post_delete_association_membership(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(member): Json<AccountId>,
 ) -> Result<(), StatusCode> {
     ACCOUNT_ADMIN.post_delete_association_membership.incr();
@@ -168,13 +191,23 @@ pub async fn post_delete_association_membership(
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        api_caller_id,
+        address.ip(),
+        AdminAuditLogAction::DeleteAssociationMembership,
+    )
+    .target_account(member);
     let member = state.get_internal_id(member).await?;
 
     db_write!(state, move |cmds| {
         cmds.account_admin()
             .association()
             .delete_entry(member)
-            .await
+            .await?;
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
+        Ok(())
     })?;
 
     Ok(())
@@ -203,6 +236,7 @@ pub async fn post_update_association_membership_type(
     State(state): State<S>,
     Extension(permissions): Extension<Permissions>,
     Extension(account_id): Extension<AccountIdInternal>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(data): Json<UpdateAssociationMembershipType>,
 ) -> Result<(), StatusCode> {
     ACCOUNT_ADMIN.post_update_association_membership_type.incr();
@@ -225,12 +259,23 @@ pub async fn post_update_association_membership_type(
         .ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
 
     let member = state.get_internal_id(data.member).await?;
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        account_id,
+        address.ip(),
+        AdminAuditLogAction::UpdateAssociationMembershipType,
+    )
+    .target_account(data.member)
+    .parameters(&data);
 
     db_write!(state, move |cmds| {
         cmds.account_admin()
             .association()
             .update_membership_type(member, account_id, data.membership_type)
-            .await
+            .await?;
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
+        Ok(())
     })?;
 
     Ok(())
@@ -1,11 +1,17 @@
-use axum::{Extension, extract::State};
-use model::{AccountIdInternal, Permissions};
+use std::net::SocketAddr;
+
+use axum::{
+    Extension,
+    extract::{ConnectInfo, State},
+};
+use model::{AccountIdInternal, AdminAuditLogAction, NewAdminAuditLogEntry, Permissions};
 use model_account::SetAccountBanState;
 use server_api::{
     S,
     app::{GetAccounts, WriteData},
     create_open_api_router, db_write,
 };
+use server_data::write::GetWriteCommandsCommon;
 use server_data_account::write::{GetWriteCommandsAccount, account_admin::SetAccountBanStateMode};
 use simple_backend::create_counters;
 
@@ -33,6 +39,7 @@ pub async fn post_set_ban_state(
     State(state): State<S>,
     Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(ban_info): Json<SetAccountBanState>,
 ) -> Result<(), StatusCode> {
     ACCOUNT_ADMIN.post_set_ban_state.incr();
@@ -42,6 +49,13 @@ pub async fn post_set_ban_state(
     }
 
     let internal_id = state.get_internal_id(ban_info.account).await?;
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        api_caller_id,
+        address.ip(),
+        AdminAuditLogAction::SetBanState,
+    )
+    .target_account(ban_info.account)
+    .parameters(&ban_info);
 
     db_write!(state, move |cmds| {
         cmds.account_admin()
@@ -56,6 +70,9 @@ pub async fn post_set_ban_state(
                 },
             )
             .await?;
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
 
         Ok(())
     })?;
@@ -1,7 +1,16 @@
-use axum::{Extension, extract::State};
-use model::{EventToClientInternal, Permissions};
+use std::net::SocketAddr;
+
+use axum::{
+    Extension,
+    extract::{ConnectInfo, State},
+};
+use model::{
+    AccountIdInternal, AdminAuditLogAction, EventToClientInternal, NewAdminAuditLogEntry,
+    Permissions,
+};
 use model_account::SaveInfoBanners;
 use server_api::{S, create_open_api_router, db_write};
+use server_data::write::GetWriteCommandsCommon;
 use server_data_account::write::{GetWriteCommandsAccount, account_admin::SaveInfoBannersResult};
 use simple_backend::create_counters;
 
@@ -34,7 +43,9 @@ const PATH_POST_SAVE_INFO_BANNERS: &str = "/account_api/save_info_banners";
 )]
 pub async fn post_save_info_banners(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(request): Json<SaveInfoBanners>,
 ) -> Result<(), StatusCode> {
     ACCOUNT_ADMIN.post_save_info_banners.incr();
@@ -43,6 +54,13 @@ pub async fn post_save_info_banners(
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        api_caller_id,
+        address.ip(),
+        AdminAuditLogAction::SaveInfoBanners,
+    )
+    .parameters(&request);
+
     let result = db_write!(state, move |cmds| {
         let result = cmds
             .account_admin()
@@ -51,6 +69,10 @@ pub async fn post_save_info_banners(
             .await?;
 
         if result == SaveInfoBannersResult::Updated {
+            cmds.common_history()
+                .write_admin_audit_log_entry(audit_log_entry)
+                .await;
+
             cmds.events()
                 .send_connected_event_to_logged_in_clients(
                     EventToClientInternal::ClientConfigChanged,
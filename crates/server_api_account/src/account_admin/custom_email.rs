@@ -1,13 +1,16 @@
+use std::net::SocketAddr;
+
 use axum::{
     Extension,
-    extract::{Query, State},
+    extract::{ConnectInfo, Query, State},
 };
+use model::{AdminAuditLogAction, NewAdminAuditLogEntry};
 use model_account::{
     AccountIdInternal, CustomEmailId, CustomEmailTargetGroup, GetCustomEmailConfig,
     GetCustomEmailListParams, Permissions, SendCustomEmail, UpdateCustomEmail,
 };
 use server_api::{S, app::GetConfig, create_open_api_router, db_write};
-use server_data::read::GetReadCommandsCommon;
+use server_data::{read::GetReadCommandsCommon, write::GetWriteCommandsCommon};
 use server_data_account::{read::GetReadCommandsAccount, write::GetWriteCommandsAccount};
 use simple_backend::create_counters;
 
@@ -95,6 +98,7 @@ pub async fn post_create_custom_email(
     State(state): State<S>,
     Extension(account_id): Extension<AccountIdInternal>,
     Extension(permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
 ) -> Result<Json<CustomEmailId>, StatusCode> {
     ACCOUNT.post_create_custom_email.incr();
 
@@ -102,11 +106,23 @@ pub async fn post_create_custom_email(
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
-    let email_id = db_write!(state, move |cmds| cmds
-        .account_admin()
-        .custom_email()
-        .create_custom_email(account_id)
-        .await)?;
+    let email_id = db_write!(state, move |cmds| {
+        let email_id = cmds
+            .account_admin()
+            .custom_email()
+            .create_custom_email(account_id)
+            .await?;
+        let audit_log_entry = NewAdminAuditLogEntry::new(
+            account_id,
+            address.ip(),
+            AdminAuditLogAction::CreateCustomEmail,
+        )
+        .parameters(&email_id);
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
+        Ok(email_id)
+    })?;
     Ok(email_id.into())
 }
 
@@ -129,7 +145,9 @@ const PATH_POST_UPDATE_CUSTOM_EMAIL: &str = "/account_api/update_custom_email";
 )]
 pub async fn post_update_custom_email(
     State(state): State<S>,
+    Extension(account_id): Extension<AccountIdInternal>,
     Extension(permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(data): Json<UpdateCustomEmail>,
 ) -> Result<(), StatusCode> {
     ACCOUNT.post_update_custom_email.incr();
@@ -138,11 +156,22 @@ pub async fn post_update_custom_email(
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        account_id,
+        address.ip(),
+        AdminAuditLogAction::UpdateCustomEmail,
+    )
+    .parameters(&data);
+
     db_write!(state, move |cmds| {
         cmds.account_admin()
             .custom_email()
             .update_custom_email(data)
-            .await
+            .await?;
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
+        Ok(())
     })?;
 
     Ok(())
@@ -165,7 +194,9 @@ const PATH_POST_SEND_CUSTOM_EMAIL_TO_ALL_ACCOUNTS: &str =
 )]
 pub async fn post_send_custom_email_to_all_accounts(
     State(state): State<S>,
+    Extension(account_id): Extension<AccountIdInternal>,
     Extension(permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(data): Json<SendCustomEmail>,
 ) -> Result<(), StatusCode> {
     ACCOUNT.post_send_custom_email_to_all_accounts.incr();
@@ -187,11 +218,25 @@ pub async fn post_send_custom_email_to_all_accounts(
                 .await?
         }
     };
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        account_id,
+        address.ip(),
+        AdminAuditLogAction::SendCustomEmailToAllAccounts,
+    )
+    .parameters(&data);
+
     let limit_reached = db_write!(state, move |cmds| {
-        cmds.account_admin()
+        let limit_reached = cmds
+            .account_admin()
             .custom_email()
             .send_custom_email(data.email_id, account_ids)
-            .await
+            .await?;
+        if limit_reached.is_none() {
+            cmds.common_history()
+                .write_admin_audit_log_entry(audit_log_entry)
+                .await;
+        }
+        Ok(limit_reached)
     })?;
 
     if limit_reached.is_some() {
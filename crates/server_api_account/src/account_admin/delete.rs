@@ -1,13 +1,18 @@
+use std::net::SocketAddr;
+
 use axum::{
     Extension,
-    extract::{Path, State},
+    extract::{ConnectInfo, Path, State},
+};
+use model::{
+    AccountId, AccountIdInternal, AdminAuditLogAction, NewAdminAuditLogEntry, Permissions,
 };
-use model::{AccountId, Permissions};
 use server_api::{
     S,
     app::{GetAccounts, WriteData},
     create_open_api_router, db_write,
 };
+use server_data::write::GetWriteCommandsCommon;
 use server_data_account::write::GetWriteCommandsAccount;
 use simple_backend::create_counters;
 
@@ -33,7 +38,9 @@ const PATH_POST_DELETE_ACCOUNT: &str = "/account_api/delete_account/{aid}";
 )]
 pub async fn post_delete_account(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Path(account): Path<AccountId>,
 ) -> Result<(), StatusCode> {
     ACCOUNT_ADMIN.post_delete_account.incr();
@@ -43,9 +50,18 @@ pub async fn post_delete_account(
     }
 
     let internal_id = state.get_internal_id(account).await?;
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        api_caller_id,
+        address.ip(),
+        AdminAuditLogAction::DeleteAccount,
+    )
+    .target_account(account);
 
     db_write!(state, move |cmds| {
         cmds.account().delete().delete_account(internal_id).await?;
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
         Ok(())
     })?;
 
@@ -1,14 +1,20 @@
+use std::net::SocketAddr;
+
 use axum::{
     Extension,
-    extract::{Path, State},
+    extract::{ConnectInfo, Path, State},
+};
+use model::{
+    AccountId, AccountIdInternal, AdminAuditLogAction, EventToClientInternal,
+    NewAdminAuditLogEntry, Permissions,
 };
-use model::{AccountId, EventToClientInternal, Permissions};
 use model_account::{EmailAddressStateAdmin, InitEmailChangeAdmin, InitEmailChangeResult};
 use server_api::{
     S,
     app::{EventManagerProvider, ReadData, WriteData},
     create_open_api_router, db_write,
 };
+use server_data::write::GetWriteCommandsCommon;
 use server_data_account::{read::GetReadCommandsAccount, write::GetWriteCommandsAccount};
 use simple_backend::create_counters;
 
@@ -84,7 +90,9 @@ pub const PATH_POST_ADMIN_CANCEL_EMAIL_CHANGE: &str =
 )]
 pub async fn post_admin_cancel_email_change(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(api_caller_permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Path(target_account): Path<AccountId>,
 ) -> Result<(), StatusCode> {
     ACCOUNT_ADMIN.post_admin_cancel_email_change.incr();
@@ -93,6 +101,12 @@ pub async fn post_admin_cancel_email_change(
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        api_caller_id,
+        address.ip(),
+        AdminAuditLogAction::CancelEmailChange,
+    )
+    .target_account(target_account);
     let target_account = state.get_internal_id(target_account).await?;
 
     db_write!(state, move |cmds| {
@@ -100,6 +114,9 @@ pub async fn post_admin_cancel_email_change(
             .email()
             .cancel_email_change(target_account)
             .await?;
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
 
         cmds.events()
             .send_connected_event(
@@ -136,7 +153,9 @@ pub const PATH_POST_ADMIN_INIT_EMAIL_CHANGE: &str = "/account_api/admin_init_ema
 )]
 pub async fn post_admin_init_email_change(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(api_caller_permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(request): Json<InitEmailChangeAdmin>,
 ) -> Result<Json<InitEmailChangeResult>, StatusCode> {
     ACCOUNT_ADMIN.post_admin_init_email_change.incr();
@@ -146,9 +165,23 @@ pub async fn post_admin_init_email_change(
     }
 
     let target_account = state.get_internal_id(request.account_id).await?;
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        api_caller_id,
+        address.ip(),
+        AdminAuditLogAction::InitEmailChange,
+    )
+    .target_account(request.account_id)
+    .parameters(&request);
 
     let result = init_email_change_impl(&state, target_account, request.new_email).await?;
 
+    db_write!(state, move |cmds| {
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
+        Ok(())
+    })?;
+
     state
         .event_manager()
         .send_connected_event(
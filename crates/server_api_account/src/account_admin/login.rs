@@ -1,14 +1,19 @@
+use std::net::SocketAddr;
+
 use axum::{
     Extension,
-    extract::{Path, State},
+    extract::{ConnectInfo, Path, State},
+};
+use model::{
+    AccountId, AccountIdInternal, AdminAuditLogAction, NewAdminAuditLogEntry, Permissions,
 };
-use model::{AccountId, Permissions};
 use model_account::AccountLockedState;
 use server_api::{
     S,
     app::{GetAccounts, ReadData, WriteData},
     create_open_api_router, db_write,
 };
+use server_data::write::GetWriteCommandsCommon;
 use server_data_account::{read::GetReadCommandsAccount, write::GetWriteCommandsAccount};
 use simple_backend::create_counters;
 
@@ -76,7 +81,9 @@ const PATH_POST_SET_ACCOUNT_LOCKED_STATE: &str = "/account_api/set_account_locke
 )]
 pub async fn post_set_account_locked_state(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Path(account_id): Path<AccountId>,
     Json(locked_state): Json<AccountLockedState>,
 ) -> Result<(), StatusCode> {
@@ -87,12 +94,23 @@ pub async fn post_set_account_locked_state(
     }
 
     let internal_id = state.get_internal_id(account_id).await?;
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        api_caller_id,
+        address.ip(),
+        AdminAuditLogAction::SetAccountLockedState,
+    )
+    .target_account(account_id)
+    .parameters(&locked_state);
 
     db_write!(state, move |cmds| {
         cmds.account_admin()
             .login()
             .set_locked_state(internal_id, locked_state.locked)
-            .await
+            .await?;
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
+        Ok(())
     })?;
 
     Ok(())
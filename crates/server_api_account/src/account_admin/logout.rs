@@ -1,8 +1,12 @@
+use std::net::SocketAddr;
+
 use axum::{
     Extension,
-    extract::{Path, State},
+    extract::{ConnectInfo, Path, State},
+};
+use model::{
+    AccountId, AccountIdInternal, AdminAuditLogAction, NewAdminAuditLogEntry, Permissions,
 };
-use model::{AccountId, Permissions};
 use server_api::{
     S,
     app::{GetAccounts, WriteData},
@@ -33,7 +37,9 @@ const PATH_POST_ADMIN_LOGOUT: &str = "/account_api/admin_logout/{aid}";
 )]
 pub async fn post_admin_logout(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Path(account_id): Path<AccountId>,
 ) -> Result<(), StatusCode> {
     ACCOUNT_ADMIN.post_admin_logout.incr();
@@ -43,9 +49,16 @@ pub async fn post_admin_logout(
     }
 
     let internal_id = state.get_internal_id(account_id).await?;
+    let audit_log_entry =
+        NewAdminAuditLogEntry::new(api_caller_id, address.ip(), AdminAuditLogAction::Logout)
+            .target_account(account_id);
 
     db_write!(state, move |cmds| {
-        cmds.common().logout(internal_id).await
+        cmds.common().logout(internal_id).await?;
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
+        Ok(())
     })?;
 
     Ok(())
@@ -1,12 +1,16 @@
+use std::net::SocketAddr;
+
 use axum::{
     Extension,
-    extract::{Path, State},
+    extract::{ConnectInfo, Path, State},
 };
+use model::{AdminAuditLogAction, NewAdminAuditLogEntry};
 use model_account::{
     AccountIdInternal, BooleanSetting, NewsId, NewsLocale, Permissions, UpdateNewsTranslation,
     UpdateNewsTranslationResult,
 };
 use server_api::{DataError, S, create_open_api_router, db_write, result::WrappedContextExt};
+use server_data::write::GetWriteCommandsCommon;
 use server_data_account::{read::GetReadCommandsAccount, write::GetWriteCommandsAccount};
 use simple_backend::create_counters;
 
@@ -29,6 +33,7 @@ pub async fn post_create_news_item(
     State(state): State<S>,
     Extension(account_id): Extension<AccountIdInternal>,
     Extension(permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
 ) -> Result<Json<NewsId>, StatusCode> {
     ACCOUNT.post_create_news_item.incr();
 
@@ -36,11 +41,23 @@ pub async fn post_create_news_item(
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
-    let news_id = db_write!(state, move |cmds| cmds
-        .account_admin()
-        .news()
-        .create_news_item(account_id)
-        .await)?;
+    let news_id = db_write!(state, move |cmds| {
+        let news_id = cmds
+            .account_admin()
+            .news()
+            .create_news_item(account_id)
+            .await?;
+        let audit_log_entry = NewAdminAuditLogEntry::new(
+            account_id,
+            address.ip(),
+            AdminAuditLogAction::CreateNewsItem,
+        )
+        .parameters(&news_id);
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
+        Ok(news_id)
+    })?;
     Ok(news_id.into())
 }
 
@@ -61,6 +78,7 @@ pub async fn delete_news_item(
     State(state): State<S>,
     Extension(account_id): Extension<AccountIdInternal>,
     Extension(permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Path(nid): Path<NewsId>,
 ) -> Result<(), StatusCode> {
     ACCOUNT.delete_news_item.incr();
@@ -80,11 +98,20 @@ pub async fn delete_news_item(
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
-    db_write!(state, move |cmds| cmds
-        .account_admin()
-        .news()
-        .delete_news_item(nid)
-        .await)?;
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        account_id,
+        address.ip(),
+        AdminAuditLogAction::DeleteNewsItem,
+    )
+    .parameters(&nid);
+
+    db_write!(state, move |cmds| {
+        cmds.account_admin().news().delete_news_item(nid).await?;
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
+        Ok(())
+    })?;
 
     Ok(())
 }
@@ -108,6 +135,7 @@ pub async fn post_update_news_translation(
     State(state): State<S>,
     Extension(account_id): Extension<AccountIdInternal>,
     Extension(permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Path(nid): Path<NewsId>,
     Path(locale): Path<NewsLocale>,
     Json(news_translation): Json<UpdateNewsTranslation>,
@@ -118,6 +146,17 @@ pub async fn post_update_news_translation(
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        account_id,
+        address.ip(),
+        AdminAuditLogAction::UpdateNewsTranslation,
+    )
+    .parameters(&serde_json::json!({
+        "nid": nid,
+        "locale": locale,
+        "translation": news_translation,
+    }));
+
     let result = db_write!(state, move |cmds| {
         let item = cmds
             .read()
@@ -143,6 +182,9 @@ pub async fn post_update_news_translation(
             .news()
             .upsert_news_translation(account_id, nid, locale, news_translation)
             .await?;
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
 
         Ok(UpdateNewsTranslationResult::success())
     })?;
@@ -167,6 +209,7 @@ pub async fn delete_news_translation(
     State(state): State<S>,
     Extension(account_id): Extension<AccountIdInternal>,
     Extension(permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Path(nid): Path<NewsId>,
     Path(locale): Path<NewsLocale>,
 ) -> Result<(), StatusCode> {
@@ -187,11 +230,26 @@ pub async fn delete_news_translation(
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
-    db_write!(state, move |cmds| cmds
-        .account_admin()
-        .news()
-        .delete_news_translation(nid, locale)
-        .await)?;
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        account_id,
+        address.ip(),
+        AdminAuditLogAction::DeleteNewsTranslation,
+    )
+    .parameters(&serde_json::json!({
+        "nid": nid,
+        "locale": locale,
+    }));
+
+    db_write!(state, move |cmds| {
+        cmds.account_admin()
+            .news()
+            .delete_news_translation(nid, locale)
+            .await?;
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
+        Ok(())
+    })?;
 
     Ok(())
 }
@@ -214,6 +272,7 @@ pub async fn post_set_news_publicity(
     State(state): State<S>,
     Extension(account_id): Extension<AccountIdInternal>,
     Extension(permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Path(nid): Path<NewsId>,
     Json(publicity): Json<BooleanSetting>,
 ) -> Result<(), StatusCode> {
@@ -223,6 +282,16 @@ pub async fn post_set_news_publicity(
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        account_id,
+        address.ip(),
+        AdminAuditLogAction::SetNewsPublicity,
+    )
+    .parameters(&serde_json::json!({
+        "nid": nid,
+        "public": publicity.value,
+    }));
+
     db_write!(state, move |cmds| {
         let item = cmds
             .read()
@@ -243,6 +312,9 @@ pub async fn post_set_news_publicity(
             .news()
             .set_news_publicity(nid, publicity.value)
             .await?;
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
 
         Ok(())
     })?;
@@ -1,15 +1,20 @@
+use std::net::SocketAddr;
+
 use axum::{
     Extension,
-    extract::{Path, State},
+    extract::{ConnectInfo, Path, State},
+};
+use model::{
+    AccountId, AccountIdInternal, AdminAuditLogAction, EventToClientInternal,
+    NewAdminAuditLogEntry, Permissions,
 };
-use model::{AccountId, EventToClientInternal, Permissions};
-use model_account::GetAllAdminsResult;
+use model_account::{GetAllAdminsResult, SetPermissionsResult};
 use server_api::{
     S,
     app::{GetAccounts, ReadData, WriteData},
     create_open_api_router, db_write,
 };
-use server_data::read::GetReadCommandsCommon;
+use server_data::{read::GetReadCommandsCommon, write::GetWriteCommandsCommon};
 use server_data_account::{read::GetReadCommandsAccount, write::GetWriteCommandsAccount};
 use simple_backend::create_counters;
 
@@ -92,6 +97,9 @@ const PATH_POST_SET_PERMISSIONS: &str = "/account_api/set_permissions/{aid}";
 
 /// Set permissions for account
 ///
+/// If the server requires TOTP for admin permissions, permissions
+/// can be granted only to accounts which have TOTP enabled.
+///
 /// # Access
 ///
 /// Permission [model_account::Permissions::admin_edit_permissions] is required.
@@ -101,7 +109,7 @@ const PATH_POST_SET_PERMISSIONS: &str = "/account_api/set_permissions/{aid}";
     params(AccountId),
     request_body = Permissions,
     responses(
-        (status = 200, description = "Successfull."),
+        (status = 200, description = "Successfull.", body = SetPermissionsResult),
         (status = 401, description = "Unauthorized."),
         (status = 500, description = "Internal server error."),
     ),
@@ -109,10 +117,12 @@ const PATH_POST_SET_PERMISSIONS: &str = "/account_api/set_permissions/{aid}";
 )]
 pub async fn post_set_permissions(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(api_caller_permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Path(account): Path<AccountId>,
     Json(new_permissions): Json<Permissions>,
-) -> Result<(), StatusCode> {
+) -> Result<Json<SetPermissionsResult>, StatusCode> {
     ACCOUNT_ADMIN.post_set_permissions.incr();
 
     if !api_caller_permissions.admin_edit_permissions {
@@ -120,21 +130,42 @@ pub async fn post_set_permissions(
     }
 
     let internal_id = state.get_internal_id(account).await?;
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        api_caller_id,
+        address.ip(),
+        AdminAuditLogAction::SetPermissions,
+    )
+    .target_account(account)
+    .parameters(&new_permissions);
+
+    let result = db_write!(state, move |cmds| {
+        if new_permissions != Permissions::default()
+            && !cmds
+                .read()
+                .account()
+                .totp()
+                .admin_permissions_can_be_granted(internal_id)
+                .await?
+        {
+            return Ok(SetPermissionsResult::error_totp_not_enabled());
+        }
 
-    db_write!(state, move |cmds| {
         cmds.account_admin()
             .permissions()
             .set_permissions(internal_id, new_permissions)
             .await?;
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
 
         cmds.events()
             .send_connected_event(internal_id.uuid, EventToClientInternal::AccountStateChanged)
             .await?;
 
-        Ok(())
+        Ok(SetPermissionsResult::ok())
     })?;
 
-    Ok(())
+    Ok(result.into())
 }
 
 create_open_api_router!(fn router_admin_permissions, get_permissions, get_all_admins, post_set_permissions,);
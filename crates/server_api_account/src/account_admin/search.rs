@@ -3,7 +3,10 @@ use axum::{
     extract::{Path, State},
 };
 use model::Permissions;
-use model_account::{GetAccountIdFromEmailParams, GetAccountIdFromEmailResult};
+use model_account::{
+    AdminAccountSearchQuery, AdminAccountSearchResult, GetAccountIdFromEmailParams,
+    GetAccountIdFromEmailResult,
+};
 use server_api::{S, app::ReadData, create_open_api_router};
 use server_data_account::read::GetReadCommandsAccount;
 use simple_backend::create_counters;
@@ -53,11 +56,72 @@ pub async fn get_account_id_from_email(
     Ok(r.into())
 }
 
-create_open_api_router!(fn router_admin_search, get_account_id_from_email,);
+const PATH_POST_SEARCH_ACCOUNTS: &str = "/account_api/search_accounts";
+
+/// Search accounts. Results are paged and ordered by account creation
+/// order.
+///
+/// Email and profile name filters find case-insensitive partial matches.
+/// Page numbers start from 0.
+///
+/// # Access
+///
+/// Permission [model_account::Permissions::admin_find_account_by_email_address] is required.
+///
+/// Additionally IP address filter requires permission
+/// [model_account::Permissions::admin_view_account_ip_address_usage] and
+/// permissions filter requires permission
+/// [model_account::Permissions::admin_view_permissions].
+#[utoipa::path(
+    post,
+    path = PATH_POST_SEARCH_ACCOUNTS,
+    request_body = AdminAccountSearchQuery,
+    responses(
+        (status = 200, description = "Successfull.", body = AdminAccountSearchResult),
+        (status = 400, description = "Negative page number."),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_search_accounts(
+    State(state): State<S>,
+    Extension(permissions): Extension<Permissions>,
+    Json(query): Json<AdminAccountSearchQuery>,
+) -> Result<Json<AdminAccountSearchResult>, StatusCode> {
+    ACCOUNT_ADMIN.post_search_accounts.incr();
+
+    if !permissions.admin_find_account_by_email_address
+        || (query.ip_address.is_some() && !permissions.admin_view_account_ip_address_usage)
+        || (query.permissions.is_some() && !permissions.admin_view_permissions)
+    {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    if query.page < 0 {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let r = state
+        .read()
+        .account_admin()
+        .search()
+        .search_accounts(query)
+        .await?;
+
+    Ok(r.into())
+}
+
+create_open_api_router!(
+    fn router_admin_search,
+    get_account_id_from_email,
+    post_search_accounts,
+);
 
 create_counters!(
     AccountCounters,
     ACCOUNT_ADMIN,
     ACCOUNT_ADMIN_SEARCH_COUNTERS_LIST,
     get_account_id_from_email,
+    post_search_accounts,
 );
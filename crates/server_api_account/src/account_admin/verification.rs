@@ -1,16 +1,20 @@
-use std::sync::OnceLock;
+use std::{net::SocketAddr, sync::OnceLock};
 
-use axum::{Extension, extract::State};
-use model::{AccountIdInternal, Permissions, UnixTime};
+use axum::{
+    Extension,
+    extract::{ConnectInfo, State},
+};
+use model::{AccountIdInternal, AdminAuditLogAction, NewAdminAuditLogEntry, Permissions, UnixTime};
 use model_account::{
     AccountVerificationQueueAdminItem, GetAccountVerificationQueueNextItemResult,
     PostAccountVerificationQueueRemoveNextItem,
 };
 use server_api::{
     S,
-    app::{AccountVerificationQueueProvider, EventManagerProvider, GetAccounts},
-    create_open_api_router,
+    app::{AccountVerificationQueueProvider, EventManagerProvider, GetAccounts, WriteData},
+    create_open_api_router, db_write,
 };
+use server_data::write::GetWriteCommandsCommon;
 use simple_backend::create_counters;
 
 use crate::utils::{Json, StatusCode};
@@ -97,6 +101,7 @@ pub async fn post_account_verification_queue_remove_next_item(
     State(state): State<S>,
     Extension(permissions): Extension<Permissions>,
     Extension(moderator_id): Extension<AccountIdInternal>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(data): Json<PostAccountVerificationQueueRemoveNextItem>,
 ) -> Result<(), StatusCode> {
     ACCOUNT_ADMIN
@@ -111,6 +116,14 @@ pub async fn post_account_verification_queue_remove_next_item(
         .lock()
         .await;
 
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        moderator_id,
+        address.ip(),
+        AdminAuditLogAction::RemoveAccountVerificationQueueItem,
+    )
+    .target_account(data.account_id)
+    .parameters(&data);
+
     let PostAccountVerificationQueueRemoveNextItem {
         account_id,
         verification_error_flags,
@@ -141,6 +154,13 @@ pub async fn post_account_verification_queue_remove_next_item(
         )
         .await?;
 
+    db_write!(state, move |cmds| {
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
+        Ok(())
+    })?;
+
     let _removed_item = state
         .account_verification_queue()
         .remove_next_item(account_id, &state.event_manager())
@@ -1,3 +1,4 @@
+pub mod attachment;
 pub mod block;
 pub mod like;
 pub mod match_routes;
@@ -9,6 +10,7 @@ pub mod report;
 pub mod transfer;
 pub mod video_call;
 
+pub use attachment::*;
 pub use block::*;
 pub use like::*;
 pub use match_routes::*;
@@ -0,0 +1,133 @@
+use axum::{
+    Extension,
+    body::Body,
+    extract::{Query, State},
+};
+use axum_extra::TypedHeader;
+use headers::ContentType;
+use model_chat::{
+    AccountIdInternal, GetMessageAttachmentParams, UploadMessageAttachmentParams,
+    UploadMessageAttachmentResult,
+};
+use server_api::{S, app::GetConfig, create_open_api_router};
+use server_data_chat::{read::GetReadChatCommands, write::GetWriteCommandsChat};
+use simple_backend::create_counters;
+
+use super::super::utils::{Json, StatusCode, body_to_bytes};
+use crate::{
+    app::{GetAccounts, ReadData, WriteData},
+    db_write,
+};
+
+const PATH_POST_UPLOAD_MESSAGE_ATTACHMENT: &str = "/chat_api/upload_message_attachment";
+
+/// Upload end-to-end encrypted attachment for a sent message.
+///
+/// The message must be sent before uploading the attachment and
+/// sender acknowledgement for the message must be done only after
+/// the attachment upload. Upload fails if the recipient has already
+/// acknowledged the message. Only one attachment per message is possible.
+///
+/// The attachment is removed from server when both sender and
+/// recipient have acknowledged the message.
+///
+/// Server config file defines max size for an attachment and max
+/// count for attachments waiting for message acknowledgements.
+/// Attachment bytes are counted to the yearly chat backup transfer
+/// budget.
+#[utoipa::path(
+    post,
+    path = PATH_POST_UPLOAD_MESSAGE_ATTACHMENT,
+    params(UploadMessageAttachmentParams),
+    request_body(content = inline(model::BinaryData), content_type = "application/octet-stream"),
+    responses(
+        (status = 200, description = "Success.", body = UploadMessageAttachmentResult),
+        (status = 401, description = "Unauthorized."),
+        (status = 413, description = "Attachment is larger than the max size."),
+        (status = 500, description = "Internal server error or attachment data related error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_upload_message_attachment(
+    State(state): State<S>,
+    Extension(id): Extension<AccountIdInternal>,
+    Query(query_params): Query<UploadMessageAttachmentParams>,
+    attachment_bytes: Body,
+) -> Result<Json<UploadMessageAttachmentResult>, StatusCode> {
+    CHAT.post_upload_message_attachment.incr();
+
+    let max_bytes = state
+        .config()
+        .limits_chat()
+        .message_attachment_max_bytes
+        .bytes()
+        .try_into()
+        .unwrap_or(usize::MAX);
+    let bytes = body_to_bytes(attachment_bytes, max_bytes).await?;
+
+    let result = db_write!(state, move |cmds| {
+        cmds.chat()
+            .attachment()
+            .upload_attachment(id, query_params.message_id, bytes.into())
+            .await
+    })?;
+
+    Ok(result.into())
+}
+
+const PATH_GET_MESSAGE_ATTACHMENT: &str = "/chat_api/message_attachment";
+
+/// Download end-to-end encrypted attachment for a received message.
+///
+/// The attachment is available until both sender and recipient have
+/// acknowledged the message. Sender and recipient must be a match
+/// and no blocks must exist.
+#[utoipa::path(
+    get,
+    path = PATH_GET_MESSAGE_ATTACHMENT,
+    params(GetMessageAttachmentParams),
+    responses(
+        (status = 200, description = "Success.", body = inline(model::BinaryData), content_type = "application/octet-stream"),
+        (status = 401, description = "Unauthorized."),
+        (status = 404, description = "Attachment is not available."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn get_message_attachment(
+    State(state): State<S>,
+    Extension(id): Extension<AccountIdInternal>,
+    Query(query_params): Query<GetMessageAttachmentParams>,
+) -> Result<(TypedHeader<ContentType>, Vec<u8>), StatusCode> {
+    CHAT.get_message_attachment.incr();
+
+    let Some(sender) = state.get_internal_id_optional(query_params.sender).await else {
+        return Err(StatusCode::NOT_FOUND);
+    };
+
+    let attachment = state
+        .read()
+        .chat()
+        .attachment()
+        .received_message_attachment(id, sender, query_params.message_id)
+        .await?;
+
+    match attachment {
+        Some(attachment) => Ok((TypedHeader(ContentType::octet_stream()), attachment)),
+        None => Err(StatusCode::NOT_FOUND),
+    }
+}
+
+create_open_api_router!(
+        fn router_attachment,
+        post_upload_message_attachment,
+        get_message_attachment,
+);
+
+create_counters!(
+    ChatCounters,
+    CHAT,
+    CHAT_ATTACHMENT_COUNTERS_LIST,
+    post_upload_message_attachment,
+    get_message_attachment,
+);
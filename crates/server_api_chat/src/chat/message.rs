@@ -5,21 +5,19 @@ use axum::{
 };
 use axum_extra::TypedHeader;
 use headers::ContentType;
-use model::{GetConversationId, MessageId, NotificationEvent};
+use model::{DAILY_MESSAGES_REMAINING_WARNING_THRESHOLD, GetConversationId, MessageId};
 use model_chat::{
-    AccountId, AccountIdInternal, EventToClientInternal, GetSentMessage, LatestSeenMessageInfoList,
+    AccountId, AccountIdInternal, GetSentMessage, LatestSeenMessageInfoList,
     MessageDeliveryInfoIdList, MessageDeliveryInfoList, PendingMessageAcknowledgementList,
     SeenMessage, SendMessageResult, SendMessageToAccountParams, SentMessageIdList, add_minimal_i64,
 };
 use server_api::{
     S,
     app::{ApiLimitsProvider, ApiUsageTrackerProvider, DataSignerProvider, GetConfig},
+    common::send_new_message_events,
     create_open_api_router,
 };
-use server_data_chat::{
-    read::GetReadChatCommands,
-    write::{GetWriteCommandsChat, chat::PushNotificationAllowed},
-};
+use server_data_chat::{read::GetReadChatCommands, write::GetWriteCommandsChat};
 use simple_backend::create_counters;
 use tracing::error;
 
@@ -121,7 +119,6 @@ pub async fn post_add_recipient_acknowledgement(
 }
 
 const PATH_POST_SEND_MESSAGE: &str = "/chat_api/send_message";
-const DAILY_MESSAGES_REMAINING_WARNING_THRESHOLD: u16 = 50;
 
 /// Send message to a match.
 ///
@@ -132,6 +129,10 @@ const DAILY_MESSAGES_REMAINING_WARNING_THRESHOLD: u16 = 50;
 ///
 /// Only the latest public key for sender and recipient can be used when
 /// sending a message.
+///
+/// Connected recipient receives the message with WebSocket `PendingMessage`
+/// message. The same functionality is also available using WebSocket
+/// `SendMessage` message.
 #[utoipa::path(
     post,
     path = PATH_POST_SEND_MESSAGE,
@@ -171,7 +172,7 @@ pub async fn post_send_message(
 
     let keys = state.data_signer().keys().await?;
     let result = db_write!(state, move |cmds| {
-        let (result, push_notification_allowed) = cmds
+        let (result, new_message) = cmds
             .chat()
             .insert_pending_message_if_match_and_not_blocked(
                 id,
@@ -184,26 +185,8 @@ pub async fn post_send_message(
             )
             .await?;
 
-        if !result.is_err() {
-            cmds.events()
-                .send_connected_event(
-                    message_recipient,
-                    EventToClientInternal::PendingChatNotificationsChanged,
-                )
-                .await
-                .ignore_and_log_error();
-
-            cmds.events()
-                .send_connected_event(message_recipient, EventToClientInternal::NewMessageReceived)
-                .await
-                .ignore_and_log_error();
-
-            if let Some(PushNotificationAllowed) = push_notification_allowed {
-                cmds.events()
-                    .send_notification(message_recipient, NotificationEvent::NewMessageReceived)
-                    .await
-                    .ignore_and_log_error();
-            }
+        if let Some(new_message) = new_message {
+            send_new_message_events(cmds.events(), message_recipient, new_message).await;
         }
 
         Ok(result)
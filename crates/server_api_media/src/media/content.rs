@@ -1,13 +1,17 @@
+use std::net::SocketAddr;
+
 use axum::{
     Extension,
     body::Body,
-    extract::{Path, Query, State},
+    extract::{ConnectInfo, Path, Query, State},
+    http::{HeaderMap, HeaderValue, header},
     response::{IntoResponse, Response},
 };
 use axum_extra::TypedHeader;
-use headers::{ContentLength, ContentType, IfNoneMatch};
+use headers::{ContentLength, IfNoneMatch};
 use model::{
-    ContentQualityVariant, EventToClientInternal, GetContentProcessingState, NotificationEvent,
+    AdminAuditLogAction, ContentFileVariant, ContentImageFormat, ContentQualityVariant,
+    EventToClientInternal, GetContentProcessingState, NewAdminAuditLogEntry, NotificationEvent,
     PendingAppNotificationInternal,
 };
 use model_media::{
@@ -77,12 +81,22 @@ const PATH_GET_CONTENT: &str = "/media_api/content/{aid}/{cid}";
 /// For any other case preferred quality is used if API has not
 /// too much concurrent access.
 ///
+/// # Content format
+///
+/// The format is selected using query parameter `f` or if it is not set,
+/// using the `Accept` header. JPEG is returned if the selected
+/// format is not available for the content.
+///
 #[utoipa::path(
     get,
     path = PATH_GET_CONTENT,
     params(AccountId, ContentId, GetContentQueryParams),
     responses(
-        (status = 200, description = "Get content file.", body = inline(model::BinaryData), content_type = "application/octet-stream"),
+        (status = 200, description = "Get content file.", content(
+            ("image/jpeg" = inline(model::BinaryData)),
+            ("image/webp" = inline(model::BinaryData)),
+            ("image/avif" = inline(model::BinaryData)),
+        )),
         (status = 401, description = "Unauthorized."),
         (status = 500),
     ),
@@ -97,6 +111,7 @@ pub async fn get_content(
     Path(requested_profile): Path<AccountId>,
     Path(requested_content_id): Path<ContentId>,
     Query(params): Query<GetContentQueryParams>,
+    headers: HeaderMap,
     browser_etag: Option<TypedHeader<IfNoneMatch>>,
 ) -> Result<Response, StatusCode> {
     MEDIA.get_content.incr();
@@ -118,11 +133,24 @@ pub async fn get_content(
         })
         .unwrap_or(ContentQualityVariant::High);
 
+    let preferred_format = params
+        .f
+        .as_deref()
+        .and_then(ContentImageFormat::from_query_value)
+        .unwrap_or_else(|| {
+            headers
+                .get(header::ACCEPT)
+                .and_then(|v| v.to_str().ok())
+                .map(ContentImageFormat::from_accept_header)
+                .unwrap_or(ContentImageFormat::Jpeg)
+        });
+
     if account_id.as_id() == requested_profile {
         return send_content(
             &state,
             ContentQualityVariant::High,
             ContentQualityVariant::High,
+            preferred_format,
             requested_profile,
             requested_content_id,
             browser_etag,
@@ -141,6 +169,7 @@ pub async fn get_content(
             &state,
             ContentQualityVariant::High,
             ContentQualityVariant::High,
+            preferred_format,
             requested_profile,
             requested_content_id,
             browser_etag,
@@ -227,6 +256,7 @@ pub async fn get_content(
         &state,
         preferred_quality,
         actual_quality,
+        preferred_format,
         requested_profile,
         requested_content_id,
         browser_etag,
@@ -234,36 +264,49 @@ pub async fn get_content(
     .await
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn send_content(
     state: &S,
     preferred_quality: ContentQualityVariant,
     actual_quality: ContentQualityVariant,
+    preferred_format: ContentImageFormat,
     requested_profile: AccountId,
     requested_content_id: ContentId,
     browser_etag: Option<TypedHeader<IfNoneMatch>>,
 ) -> Result<Response, StatusCode> {
-    let etag = state.etag_utils().immutable_content_variant(actual_quality);
+    let jpeg_data = state.read().media().content_data_variant(
+        requested_profile,
+        requested_content_id,
+        actual_quality,
+    )?;
+    let preferred_data = jpeg_data.format_variant(preferred_format);
+    let (actual_format, data) =
+        if preferred_format != ContentImageFormat::Jpeg && preferred_data.exists() {
+            (preferred_format, preferred_data)
+        } else {
+            (ContentImageFormat::Jpeg, jpeg_data)
+        };
+
+    let etag = state
+        .etag_utils()
+        .immutable_content_variant(ContentFileVariant::new(actual_quality, actual_format));
     let cache_control = if actual_quality == preferred_quality {
         cache_control_for_images()
     } else {
         cache_control_for_images_with_downgraded_quality()
     };
+    let vary = [(header::VARY, HeaderValue::from_static("accept"))];
 
     if browser_etag.matches(etag) {
         return Ok((
             axum::http::StatusCode::NOT_MODIFIED,
             TypedHeader(etag.clone()),
             TypedHeader(cache_control),
+            vary,
         )
             .into_response());
     }
 
-    let data = state.read().media().content_data_variant(
-        requested_profile,
-        requested_content_id,
-        actual_quality,
-    )?;
-
     let (length, stream) = data
         .byte_count_and_read_stream()
         .await
@@ -274,7 +317,11 @@ async fn send_content(
     Ok((
         TypedHeader(etag.clone()),
         TypedHeader(cache_control),
-        TypedHeader(ContentType::octet_stream()),
+        vary,
+        [(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(actual_format.mime_type()),
+        )],
         TypedHeader(ContentLength(length)),
         Body::from_stream(stream),
     )
@@ -486,6 +533,7 @@ pub async fn delete_content(
     State(state): State<S>,
     Extension(api_caller_account_id): Extension<AccountIdInternal>,
     Extension(permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Path(content_owner_account_id): Path<AccountId>,
     Path(content_id): Path<ContentId>,
 ) -> Result<(), StatusCode> {
@@ -524,15 +572,30 @@ pub async fn delete_content(
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
-    if owner_deleting_content {
+    let audit_log_entry = if owner_deleting_content {
         MEDIA.delete_content_for_content_owner.incr();
+        None
     } else {
         MEDIA.delete_content_for_admin.incr();
-    }
+        let entry = NewAdminAuditLogEntry::new(
+            api_caller_account_id,
+            address.ip(),
+            AdminAuditLogAction::DeleteMediaContent,
+        )
+        .target_account(content_id.account_id())
+        .target_content(content_id.content_id());
+        Some(entry)
+    };
 
     db_write!(state, move |cmds| {
         let r = cmds.media().delete_content(content_id).await?;
 
+        if let Some(entry) = audit_log_entry {
+            cmds.common_history()
+                .write_admin_audit_log_entry(entry)
+                .await;
+        }
+
         if r.current_media_content_refresh_needed {
             cmds.events()
                 .send_connected_event(
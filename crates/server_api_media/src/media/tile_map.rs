@@ -1,11 +1,11 @@
 use axum::{
     body::Body,
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode, header},
     response::{IntoResponse, Response},
 };
 use axum_extra::TypedHeader;
-use headers::{ContentLength, ContentType, IfNoneMatch};
+use headers::{ContentLength, IfNoneMatch};
 use model_media::{MapTileVersion, MapTileX, MapTileY, MapTileZ};
 use server_api::{
     S,
@@ -13,20 +13,30 @@ use server_api::{
     create_open_api_router,
     utils::{IfNoneMatchExtensions, cache_control_for_images},
 };
-use simple_backend::{app::GetTileMap, create_counters};
+use simple_backend::{app::GetTileMap, create_counters, map::MapTileData};
 use tracing::error;
 
 const PATH_GET_MAP_TILE: &str = "/media_api/map_tile/{z}/{x}/{y}";
 
-/// Get map tile PNG file.
+/// Get map tile file.
 ///
-/// Returns a .png even if the URL does not have it.
+/// The tile format depends on server configuration. Tile directory
+/// contains only PNG files. MBTiles and PMTiles archives can also contain
+/// JPEG, WebP, AVIF or vector tiles (`pbf`). Gzip compressed tiles are
+/// decompressed if the Accept-Encoding header does not contain gzip.
+/// The URL file extension is ignored.
 #[utoipa::path(
     get,
     path = PATH_GET_MAP_TILE,
     params(MapTileZ, MapTileX, MapTileY, MapTileVersion),
     responses(
-        (status = 200, description = "Get map tile PNG file.", body = inline(model::BinaryData), content_type = "image/png"),
+        (status = 200, description = "Get map tile file.", content(
+            ("image/png" = inline(model::BinaryData)),
+            ("image/jpeg" = inline(model::BinaryData)),
+            ("image/webp" = inline(model::BinaryData)),
+            ("image/avif" = inline(model::BinaryData)),
+            ("application/vnd.mapbox-vector-tile" = inline(model::BinaryData)),
+        )),
         (status = 401, description = "Unauthorized."),
         (status = 404, description = "Not found."),
         (status = 500),
@@ -40,6 +50,7 @@ pub async fn get_map_tile(
     Path(y): Path<MapTileY>,
     Query(v): Query<MapTileVersion>,
     browser_etag: Option<TypedHeader<IfNoneMatch>>,
+    headers: HeaderMap,
 ) -> Result<Response, StatusCode> {
     MEDIA.get_map_tile.incr();
 
@@ -52,38 +63,46 @@ pub async fn get_map_tile(
         return Err(StatusCode::NOT_FOUND);
     }
 
-    let y_string = y.y.trim_end_matches(".png");
+    let y_string = y.y.split_once('.').map(|(y, _)| y).unwrap_or(&y.y);
     let y = y_string
         .parse::<u32>()
         .map_err(|_| StatusCode::NOT_ACCEPTABLE)?;
 
-    let byte_count_and_data_stream = state
+    let tile = state
         .tile_map()
-        .map_tile_byte_count_and_byte_stream(z.z, x.x, y)
+        .map_tile(z.z, x.x, y, accepts_gzip(&headers))
         .await
         .map_err(|e| {
             error!("{:?}", e);
             StatusCode::INTERNAL_SERVER_ERROR
         })?;
 
-    match byte_count_and_data_stream {
-        Some((byte_count, data_stream)) => {
-            let etag = state.etag_utils().immutable_content().clone();
+    match tile {
+        Some(tile) => {
+            let etag = state.tile_map().etag(&tile).clone();
             let cache_control = cache_control_for_images();
-            if browser_etag.matches(state.etag_utils().immutable_content()) {
+            let vary = [(header::VARY, "accept-encoding")];
+            if browser_etag.matches(&etag) {
                 Ok((
                     StatusCode::NOT_MODIFIED,
                     TypedHeader(etag),
                     TypedHeader(cache_control),
+                    vary,
                 )
                     .into_response())
             } else {
+                let body = match tile.data {
+                    MapTileData::File(data_stream) => Body::from_stream(data_stream),
+                    MapTileData::Bytes(data) => Body::from(data),
+                };
                 Ok((
                     TypedHeader(etag),
                     TypedHeader(cache_control),
-                    TypedHeader(ContentType::png()),
-                    TypedHeader(ContentLength(byte_count)),
-                    Body::from_stream(data_stream),
+                    vary,
+                    TypedHeader(tile.content_type),
+                    tile.content_encoding.map(TypedHeader),
+                    TypedHeader(ContentLength(tile.byte_count)),
+                    body,
                 )
                     .into_response())
             }
@@ -92,6 +111,24 @@ pub async fn get_map_tile(
     }
 }
 
+fn accepts_gzip(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(header::ACCEPT_ENCODING)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .any(|coding| {
+            let mut parts = coding.split(';').map(|v| v.trim());
+            let name = parts.next().unwrap_or_default();
+            let disabled = parts.any(|v| {
+                v.strip_prefix("q=")
+                    .and_then(|q| q.parse::<f32>().ok())
+                    .is_some_and(|q| q == 0.0)
+            });
+            (name.eq_ignore_ascii_case("gzip") || name == "*") && !disabled
+        })
+}
+
 create_open_api_router!(fn router_tile_map, get_map_tile,);
 
 create_counters!(
@@ -1,5 +1,10 @@
-use axum::{Extension, extract::State};
-use model::AdminBotNotificationTypes;
+use std::net::SocketAddr;
+
+use axum::{
+    Extension,
+    extract::{ConnectInfo, State},
+};
+use model::{AdminAuditLogAction, AdminBotNotificationTypes, NewAdminAuditLogEntry};
 use model_media::{
     AccountIdInternal, GetMediaContentFaceVerifiedNullList, Permissions,
     PostMediaContentFaceDetectedValue, PostMediaContentFaceVerifiedValue,
@@ -10,6 +15,7 @@ use server_api::{
     create_open_api_router,
     result::WrappedContextExt,
 };
+use server_data::write::GetWriteCommandsCommon;
 use server_data_media::{read::GetReadMediaCommands, write::GetWriteCommandsMedia};
 use simple_backend::create_counters;
 
@@ -42,7 +48,9 @@ const PATH_POST_MEDIA_CONTENT_FACE_DETECTED_VALUE: &str =
 )]
 pub async fn post_media_content_face_detected_value(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(data): Json<PostMediaContentFaceDetectedValue>,
 ) -> Result<(), StatusCode> {
     MEDIA_ADMIN.post_media_content_face_detected_value.incr();
@@ -52,6 +60,14 @@ pub async fn post_media_content_face_detected_value(
     }
 
     let content_owner = state.get_internal_id(data.account_id).await?;
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        api_caller_id,
+        address.ip(),
+        AdminAuditLogAction::SetMediaContentFaceDetectedValue,
+    )
+    .target_account(data.account_id)
+    .target_content(data.content_id)
+    .parameters(&data);
 
     let changed = db_write!(state, move |cmds| {
         let content_id = cmds
@@ -59,10 +75,15 @@ pub async fn post_media_content_face_detected_value(
             .media()
             .content_id_internal(content_owner, data.content_id)
             .await?;
-        cmds.media_admin()
+        let changed = cmds
+            .media_admin()
             .content()
             .change_face_detected_value(content_id, data.value)
-            .await
+            .await?;
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
+        Ok(changed)
     })?;
 
     if changed {
@@ -142,6 +163,7 @@ pub async fn post_media_content_face_verified_value(
     State(state): State<S>,
     Extension(permissions): Extension<Permissions>,
     Extension(moderator_id): Extension<AccountIdInternal>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(data): Json<PostMediaContentFaceVerifiedValue>,
 ) -> Result<(), StatusCode> {
     MEDIA_ADMIN.post_media_content_face_verified_value.incr();
@@ -155,6 +177,14 @@ pub async fn post_media_content_face_verified_value(
     }
 
     let content_owner = state.get_internal_id(data.account_id).await?;
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        moderator_id,
+        address.ip(),
+        AdminAuditLogAction::SetMediaContentFaceVerifiedValue,
+    )
+    .target_account(data.account_id)
+    .target_content(data.security_content)
+    .parameters(&data);
 
     db_write!(state, move |cmds| {
         let current_security_content = cmds
@@ -183,6 +213,9 @@ pub async fn post_media_content_face_verified_value(
             .content()
             .change_face_verified_values(moderator_id, values)
             .await?;
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
 
         Ok(())
     })?;
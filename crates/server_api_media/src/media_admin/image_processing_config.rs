@@ -1,6 +1,15 @@
-use axum::{Extension, extract::State};
-use model::{ImageProcessingDynamicConfig, ImageProcessingWarnings, Permissions};
+use std::net::SocketAddr;
+
+use axum::{
+    Extension,
+    extract::{ConnectInfo, State},
+};
+use model::{
+    AccountIdInternal, AdminAuditLogAction, ImageProcessingDynamicConfig, ImageProcessingWarnings,
+    NewAdminAuditLogEntry, Permissions,
+};
 use server_api::{S, app::ReadData, create_open_api_router, utils::StatusCode};
+use server_data::write::GetWriteCommandsCommon;
 use server_data_media::{read::GetReadMediaCommands, write::GetWriteCommandsMedia};
 use simple_backend::{app::GetSimpleBackendConfig, create_counters, image::ImageProcess};
 
@@ -50,19 +59,31 @@ pub async fn get_image_processing_config(
 )]
 pub async fn post_image_processing_config(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(api_caller_permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(config): Json<ImageProcessingDynamicConfig>,
 ) -> Result<(), StatusCode> {
     if !api_caller_permissions.admin_server_edit_image_processing_config {
         return Err(StatusCode::UNAUTHORIZED);
     }
 
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        api_caller_id,
+        address.ip(),
+        AdminAuditLogAction::EditImageProcessingConfig,
+    )
+    .parameters(&config);
+
     let config_clone = config.clone();
     db_write!(state, move |cmds| {
         cmds.media_admin()
             .image_processing_config()
             .upsert_image_processing_config(&config_clone)
             .await?;
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
 
         Ok(())
     })?;
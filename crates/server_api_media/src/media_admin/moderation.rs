@@ -1,8 +1,13 @@
+use std::net::SocketAddr;
+
 use axum::{
     Extension,
-    extract::{Query, State},
+    extract::{ConnectInfo, Query, State},
+};
+use model::{
+    AdminAuditLogAction, AdminNotificationTypes, NewAdminAuditLogEntry, NotificationEvent,
+    PendingAppNotificationInternal,
 };
-use model::{AdminNotificationTypes, NotificationEvent, PendingAppNotificationInternal};
 use model_media::{
     AccountIdInternal, EventToClientInternal, GetMediaContentModerationQueuePageParams,
     MediaContentModerationQueuePage, Permissions, PostModerateMediaContent,
@@ -94,6 +99,7 @@ pub async fn post_moderate_media_content(
     State(state): State<S>,
     Extension(permissions): Extension<Permissions>,
     Extension(moderator_id): Extension<AccountIdInternal>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(data): Json<PostModerateMediaContent>,
 ) -> Result<(), StatusCode> {
     MEDIA_ADMIN.post_moderate_media_content.incr();
@@ -107,6 +113,14 @@ pub async fn post_moderate_media_content(
     }
 
     let content_owner = state.get_internal_id(data.account_id).await?;
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        moderator_id,
+        address.ip(),
+        AdminAuditLogAction::ModerateMediaContent,
+    )
+    .target_account(data.account_id)
+    .target_content(data.content_id)
+    .parameters(&data);
 
     let mode = if data.move_to_human.unwrap_or_default() {
         ContentModerationMode::MoveToHumanModeration {
@@ -132,6 +146,9 @@ pub async fn post_moderate_media_content(
             .content()
             .moderate_media_content(mode, content_id)
             .await?;
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
 
         cmds.events()
             .send_connected_event(
@@ -1,17 +1,23 @@
+use std::net::SocketAddr;
+
 use axum::{
     Extension,
-    extract::{Path, State},
+    extract::{ConnectInfo, Path, State},
+};
+use model::{
+    AdminAuditLogAction, EditVerificationSecurityContent, EditVerificationValues,
+    NewAdminAuditLogEntry,
 };
-use model::{EditVerificationSecurityContent, EditVerificationValues};
 use model_media::{
     AccountId, AccountIdInternal, Permissions, PostSecurityContentVerifiedValue,
     SecurityContentAdminInfo,
 };
 use server_api::{
     S,
-    app::{GetAccounts, ReadData},
-    create_open_api_router,
+    app::{GetAccounts, ReadData, WriteData},
+    create_open_api_router, db_write,
 };
+use server_data::write::GetWriteCommandsCommon;
 use server_data_media::read::GetReadMediaCommands;
 use simple_backend::create_counters;
 
@@ -93,6 +99,7 @@ pub async fn post_security_content_verified_value(
     State(state): State<S>,
     Extension(permissions): Extension<Permissions>,
     Extension(moderator_id): Extension<AccountIdInternal>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(data): Json<PostSecurityContentVerifiedValue>,
 ) -> Result<(), StatusCode> {
     MEDIA_ADMIN.post_security_content_verified_value.incr();
@@ -102,6 +109,14 @@ pub async fn post_security_content_verified_value(
     }
 
     let profile_owner_id = state.get_internal_id(data.account_id).await?;
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        moderator_id,
+        address.ip(),
+        AdminAuditLogAction::SetSecurityContentVerifiedValue,
+    )
+    .target_account(data.account_id)
+    .target_content(data.security_content)
+    .parameters(&data);
 
     state
         .data_all_access()
@@ -119,6 +134,13 @@ pub async fn post_security_content_verified_value(
         )
         .await?;
 
+    db_write!(state, move |cmds| {
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
+        Ok(())
+    })?;
+
     Ok(())
 }
 
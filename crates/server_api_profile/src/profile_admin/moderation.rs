@@ -1,8 +1,13 @@
+use std::net::SocketAddr;
+
 use axum::{
     Extension,
-    extract::{Path, Query, State},
+    extract::{ConnectInfo, Path, Query, State},
+};
+use model::{
+    AccountId, AdminAuditLogAction, AdminNotificationTypes, NewAdminAuditLogEntry,
+    NotificationEvent, PendingAppNotificationInternal,
 };
-use model::{AccountId, AdminNotificationTypes, NotificationEvent, PendingAppNotificationInternal};
 use model_profile::{
     AccountIdInternal, EventToClientInternal, GetProfileStringModerationQueuePageParams,
     GetProfileStringState, GetProfileStringStateParams, Permissions, PostModerateProfileString,
@@ -109,6 +114,7 @@ pub async fn post_moderate_profile_string(
     State(state): State<S>,
     Extension(permissions): Extension<Permissions>,
     Extension(moderator_id): Extension<AccountIdInternal>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(data): Json<PostModerateProfileString>,
 ) -> Result<(), StatusCode> {
     PROFILE.post_moderate_profile_string.incr();
@@ -131,6 +137,13 @@ pub async fn post_moderate_profile_string(
     }
 
     let string_owner_id = state.get_internal_id(data.id).await?;
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        moderator_id,
+        address.ip(),
+        AdminAuditLogAction::ModerateProfileString,
+    )
+    .target_account(data.id)
+    .parameters(&data);
 
     let mode = if data.move_to_human.unwrap_or_default() {
         ModerateProfileValueMode::MoveToHumanModeration {
@@ -185,6 +198,10 @@ pub async fn post_moderate_profile_string(
                 .await?;
         }
 
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
+
         Ok(())
     })?;
 
@@ -1,7 +1,13 @@
-use axum::{Extension, extract::State};
-use model::Permissions;
+use std::net::SocketAddr;
+
+use axum::{
+    Extension,
+    extract::{ConnectInfo, State},
+};
+use model::{AccountIdInternal, AdminAuditLogAction, NewAdminAuditLogEntry, Permissions};
 use model_profile::{ProfileAttributesSchemaExport, UpdateProfileAttributesSchema};
 use server_api::{S, create_open_api_router, db_write};
+use server_data::write::GetWriteCommandsCommon;
 use server_data_profile::{read::GetReadProfileCommands, write::GetWriteCommandsProfile};
 use simple_backend::create_counters;
 
@@ -67,7 +73,9 @@ pub async fn get_profile_attributes_schema(
 )]
 pub async fn put_profile_attributes_schema(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(request): Json<UpdateProfileAttributesSchema>,
 ) -> Result<(), StatusCode> {
     PROFILE.put_profile_attributes_schema.incr();
@@ -78,11 +86,22 @@ pub async fn put_profile_attributes_schema(
 
     let has_content_permission = permissions.admin_edit_profile_attributes_schema_visible_content;
 
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        api_caller_id,
+        address.ip(),
+        AdminAuditLogAction::EditProfileAttributesSchema,
+    )
+    .parameters(&request);
+
     db_write!(state, move |cmds| {
         cmds.profile_admin()
             .attribute_schema()
             .update_schema(request, has_content_permission)
-            .await
+            .await?;
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
+        Ok(())
     })?;
 
     Ok(())
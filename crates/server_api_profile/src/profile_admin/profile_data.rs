@@ -1,15 +1,20 @@
+use std::net::SocketAddr;
+
 use axum::{
     Extension,
-    extract::{Path, State},
+    extract::{ConnectInfo, Path, State},
+};
+use model::{
+    AccountId, AccountIdInternal, AdminAuditLogAction, AdminNotificationTypes,
+    EventToClientInternal, NewAdminAuditLogEntry,
 };
-use model::{AccountId, AdminNotificationTypes, EventToClientInternal};
 use model_profile::{GetProfileAgeAndName, Permissions, ProfileUpdateInternal, SetProfileName};
 use server_api::{
     DataError, S,
     app::{AdminNotificationProvider, GetAccounts, GetConfig},
     create_open_api_router, db_write,
 };
-use server_data::read::GetReadCommandsCommon;
+use server_data::{read::GetReadCommandsCommon, write::GetWriteCommandsCommon};
 use server_data_profile::{read::GetReadProfileCommands, write::GetWriteCommandsProfile};
 use simple_backend::create_counters;
 use simple_backend_utils::IntoReportFromString;
@@ -104,7 +109,9 @@ const PATH_POST_SET_PROFILE_NAME: &str = "/profile_api/set_profile_name";
 )]
 pub async fn post_set_profile_name(
     State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
     Extension(permissions): Extension<Permissions>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(info): Json<SetProfileName>,
 ) -> Result<(), StatusCode> {
     PROFILE.post_set_profile_name.incr();
@@ -116,6 +123,13 @@ pub async fn post_set_profile_name(
     }
 
     let profile_owner_id = state.get_internal_id(info.account).await?;
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        api_caller_id,
+        address.ip(),
+        AdminAuditLogAction::SetProfileName,
+    )
+    .target_account(info.account)
+    .parameters(&info);
 
     db_write!(state, move |cmds| {
         let profile = cmds
@@ -153,6 +167,10 @@ pub async fn post_set_profile_name(
             .send_connected_event(profile_owner_id, EventToClientInternal::ProfileChanged)
             .await?;
 
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
+
         Ok(())
     })?;
 
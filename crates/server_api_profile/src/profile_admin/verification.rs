@@ -1,15 +1,23 @@
+use std::net::SocketAddr;
+
 use axum::{
     Extension,
-    extract::{Path, State},
+    extract::{ConnectInfo, Path, State},
 };
 use model::{
-    AccountId, EditVerificationProfileAgeRange, EditVerificationProfileName, EditVerificationValues,
+    AccountId, AdminAuditLogAction, EditVerificationProfileAgeRange, EditVerificationProfileName,
+    EditVerificationValues, NewAdminAuditLogEntry,
 };
 use model_profile::{
     Permissions, PostProfileAgeRangeVerifiedValue, PostProfileNameVerifiedValue,
     ProfileAgeRangeVerificationAdminInfo, ProfileNameVerificationAdminInfo,
 };
-use server_api::{S, app::GetAccounts, create_open_api_router};
+use server_api::{
+    S,
+    app::{GetAccounts, WriteData},
+    create_open_api_router, db_write,
+};
+use server_data::write::GetWriteCommandsCommon;
 use server_data_profile::read::GetReadProfileCommands;
 use simple_backend::create_counters;
 
@@ -82,6 +90,7 @@ pub async fn post_profile_age_range_verified_value(
     State(state): State<S>,
     Extension(permissions): Extension<Permissions>,
     Extension(moderator_id): Extension<model_profile::AccountIdInternal>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(data): Json<PostProfileAgeRangeVerifiedValue>,
 ) -> Result<(), StatusCode> {
     PROFILE.post_profile_age_range_verified_value.incr();
@@ -91,6 +100,13 @@ pub async fn post_profile_age_range_verified_value(
     }
 
     let profile_owner_id = state.get_internal_id(data.account_id).await?;
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        moderator_id,
+        address.ip(),
+        AdminAuditLogAction::SetProfileAgeRangeVerifiedValue,
+    )
+    .target_account(data.account_id)
+    .parameters(&data);
 
     state
         .data_all_access()
@@ -108,6 +124,13 @@ pub async fn post_profile_age_range_verified_value(
         )
         .await?;
 
+    db_write!(state, move |cmds| {
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
+        Ok(())
+    })?;
+
     Ok(())
 }
 
@@ -174,6 +197,7 @@ pub async fn post_profile_name_verified_value(
     State(state): State<S>,
     Extension(permissions): Extension<Permissions>,
     Extension(moderator_id): Extension<model_profile::AccountIdInternal>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(data): Json<PostProfileNameVerifiedValue>,
 ) -> Result<(), StatusCode> {
     PROFILE.post_profile_name_verified_value.incr();
@@ -183,6 +207,13 @@ pub async fn post_profile_name_verified_value(
     }
 
     let profile_owner_id = state.get_internal_id(data.account_id).await?;
+    let audit_log_entry = NewAdminAuditLogEntry::new(
+        moderator_id,
+        address.ip(),
+        AdminAuditLogAction::SetProfileNameVerifiedValue,
+    )
+    .target_account(data.account_id)
+    .parameters(&data);
 
     state
         .data_all_access()
@@ -200,6 +231,13 @@ pub async fn post_profile_name_verified_value(
         )
         .await?;
 
+    db_write!(state, move |cmds| {
+        cmds.common_history()
+            .write_admin_audit_log_entry(audit_log_entry)
+            .await;
+        Ok(())
+    })?;
+
     Ok(())
 }
 
@@ -3,7 +3,8 @@ use std::{future::Future, sync::Arc};
 use config::Config;
 use error_stack::ResultExt;
 use model::{
-    AccountIdInternal, ClientType, PushNotificationSendingInfo, PushNotificationsToMarkAsSent,
+    AccountIdInternal, ClientType, PushNotificationProvider, PushNotificationSendingInfo,
+    PushNotificationsToMarkAsSent,
 };
 use simple_backend::ServerQuitWatcher;
 use simple_backend_utils::{ContextExt, Result, consts::MIB_IN_BYTES};
@@ -16,13 +17,17 @@ use tracing::{error, warn};
 use crate::push_notifications::{
     apns::{ApnsManager, ApnsManagerQuitHandle},
     fcm::{FcmManager, FcmManagerQuitHandle},
+    unified_push::{UnifiedPushManager, UnifiedPushManagerQuitHandle},
     web::{WebPushManager, WebPushManagerQuitHandle},
 };
 
 mod apns;
 mod fcm;
+mod unified_push;
 mod web;
 
+pub use unified_push::{UnifiedPushEndpointError, validate_unified_push_endpoint};
+
 const PRIMARY_BUFFER_SIZE: usize = MIB_IN_BYTES;
 const SECONDARY_BUFFER_SIZE: usize = MIB_IN_BYTES / 2;
 
@@ -44,6 +49,8 @@ pub enum PushNotificationError {
     GetClientType,
     #[error("Client type not found")]
     ClientTypeNotFound,
+    #[error("Getting push notification provider failed")]
+    GetPushNotificationProvider,
     #[error("Saving notification flags to database failed")]
     SaveToDatabaseFailed,
     #[error("Serializing error")]
@@ -143,6 +150,11 @@ pub trait PushNotificationStateProvider {
         &self,
         account_id: AccountIdInternal,
     ) -> impl Future<Output = Result<Option<ClientType>, PushNotificationError>> + Send;
+
+    fn push_notification_provider(
+        &self,
+        account_id: AccountIdInternal,
+    ) -> impl Future<Output = Result<PushNotificationProvider, PushNotificationError>> + Send;
 }
 
 pub fn channel() -> (PushNotificationSender, PushNotificationReceiver) {
@@ -170,9 +182,11 @@ pub struct PushNotificationManager<T> {
     fcm_sender: Sender<SendPushNotification>,
     apns_sender: Sender<SendPushNotification>,
     web_sender: Sender<SendPushNotification>,
+    unified_push_sender: Sender<SendPushNotification>,
     fcm_quit_handle: FcmManagerQuitHandle,
     apns_quit_handle: ApnsManagerQuitHandle,
     web_quit_handle: WebPushManagerQuitHandle,
+    unified_push_quit_handle: UnifiedPushManagerQuitHandle,
     receiver: PushNotificationReceiver,
     state: T,
 }
@@ -211,13 +225,25 @@ impl<T: PushNotificationStateProvider + Clone + Send + Sync + 'static> PushNotif
         )
         .await;
 
+        let (unified_push_sender, unified_push_receiver) =
+            tokio::sync::mpsc::channel(SECONDARY_BUFFER_SIZE);
+        let unified_push_quit_handle = UnifiedPushManager::new_manager(
+            &config,
+            unified_push_receiver,
+            state.clone(),
+            quit_notification.resubscribe(),
+        )
+        .await;
+
         let manager = PushNotificationManager {
             fcm_sender,
             apns_sender,
             web_sender,
+            unified_push_sender,
             fcm_quit_handle,
             apns_quit_handle,
             web_quit_handle,
+            unified_push_quit_handle,
             receiver,
             state,
         };
@@ -267,6 +293,7 @@ impl<T: PushNotificationStateProvider + Clone + Send + Sync + 'static> PushNotif
         self.fcm_quit_handle.wait_quit().await;
         self.apns_quit_handle.wait_quit().await;
         self.web_quit_handle.wait_quit().await;
+        self.unified_push_quit_handle.wait_quit().await;
 
         // There might be unhandled or failed notifications, so save those
         // from cache to database.
@@ -292,6 +319,20 @@ impl<T: PushNotificationStateProvider + Clone + Send + Sync + 'static> PushNotif
             return Err(PushNotificationError::ClientTypeNotFound.report());
         };
 
+        if client_type != ClientType::Bot {
+            let provider = self
+                .state
+                .push_notification_provider(send_push_notification.account_id)
+                .await?;
+            if provider == PushNotificationProvider::UnifiedPush {
+                return self
+                    .unified_push_sender
+                    .send(send_push_notification)
+                    .await
+                    .change_context(PushNotificationError::NotificationRoutingFailed);
+            }
+        }
+
         match client_type {
             ClientType::Android => self
                 .fcm_sender
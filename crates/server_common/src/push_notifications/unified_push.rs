@@ -0,0 +1,686 @@
+use std::{
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+    time::Duration,
+};
+
+use aes_gcm::{AeadCore, Aes128Gcm, KeyInit, aead::Aead};
+use base64::Engine;
+use config::Config;
+use error_stack::{Report, ResultExt};
+use model::{PushNotification, PushNotificationDeviceToken, PushNotificationSendingInfo};
+use rand::rngs::OsRng;
+use reqwest::{
+    Client, StatusCode,
+    dns::{Addrs, Name, Resolve, Resolving},
+    header::{CONTENT_TYPE, RETRY_AFTER},
+};
+use simple_backend::ServerQuitWatcher;
+use simple_backend_config::file::UnifiedPushConfig;
+use simple_backend_utils::Result;
+use tokio::{sync::mpsc::Receiver, task::JoinHandle};
+use tracing::{error, info, warn};
+use url::{Host, Url};
+
+use crate::push_notifications::{
+    PushNotificationError, PushNotificationStateProvider, SendPushNotification,
+};
+
+/// UnifiedPush distributors must accept at least 4096 bytes
+const MAX_MESSAGE_SIZE: usize = 4096;
+/// Max wait time for HTTP 429 and 503 responses
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum UnifiedPushEndpointError {
+    #[error("Invalid endpoint URL")]
+    InvalidUrl,
+    #[error("Endpoint URL scheme is not allowed")]
+    SchemeNotAllowed,
+    #[error("Endpoint host address resolving failed")]
+    Resolve,
+    #[error("Endpoint host IP address is not allowed")]
+    IpAddressNotAllowed,
+}
+
+/// Check that the endpoint URL scheme is allowed and that the endpoint
+/// host resolves only to public IP addresses.
+pub async fn validate_unified_push_endpoint(
+    endpoint: &str,
+    config: &UnifiedPushConfig,
+) -> std::result::Result<(), UnifiedPushEndpointError> {
+    let url = parse_endpoint_url(endpoint, config)?;
+    if config.allow_private_ip_addresses {
+        return Ok(());
+    }
+    match url.host() {
+        Some(Host::Domain(domain)) => {
+            let port = url
+                .port_or_known_default()
+                .ok_or(UnifiedPushEndpointError::InvalidUrl)?;
+            resolve_public_addresses(domain, port).await?;
+            Ok(())
+        }
+        Some(Host::Ipv4(ip)) => check_ip_address(ip.into()),
+        Some(Host::Ipv6(ip)) => check_ip_address(ip.into()),
+        None => Err(UnifiedPushEndpointError::InvalidUrl),
+    }
+}
+
+/// Check URL scheme and IP address if the host is an IP address.
+/// Domain names are checked when the HTTP client resolves them.
+fn parse_endpoint_url(
+    endpoint: &str,
+    config: &UnifiedPushConfig,
+) -> std::result::Result<Url, UnifiedPushEndpointError> {
+    let url = Url::parse(endpoint).map_err(|_| UnifiedPushEndpointError::InvalidUrl)?;
+    let scheme_allowed = url.scheme() == "https" || (config.allow_http && url.scheme() == "http");
+    if !scheme_allowed {
+        return Err(UnifiedPushEndpointError::SchemeNotAllowed);
+    }
+    if !config.allow_private_ip_addresses {
+        match url.host() {
+            Some(Host::Ipv4(ip)) => check_ip_address(ip.into())?,
+            Some(Host::Ipv6(ip)) => check_ip_address(ip.into())?,
+            Some(Host::Domain(_)) => (),
+            None => return Err(UnifiedPushEndpointError::InvalidUrl),
+        }
+    }
+    Ok(url)
+}
+
+async fn resolve_public_addresses(
+    host: &str,
+    port: u16,
+) -> std::result::Result<Vec<SocketAddr>, UnifiedPushEndpointError> {
+    let addresses: Vec<SocketAddr> = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(|_| UnifiedPushEndpointError::Resolve)?
+        .collect();
+    if addresses.is_empty() {
+        return Err(UnifiedPushEndpointError::Resolve);
+    }
+    for a in &addresses {
+        check_ip_address(a.ip())?;
+    }
+    Ok(addresses)
+}
+
+fn check_ip_address(ip: IpAddr) -> std::result::Result<(), UnifiedPushEndpointError> {
+    if is_public_ip_address(ip) {
+        Ok(())
+    } else {
+        Err(UnifiedPushEndpointError::IpAddressNotAllowed)
+    }
+}
+
+fn is_public_ip_address(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            // Carrier-grade NAT 100.64.0.0/10
+            let shared = ip.octets()[0] == 100 && (ip.octets()[1] & 0xC0) == 64;
+            !(ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_multicast()
+                || ip.is_documentation()
+                || shared)
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_ip_address(ip.into()),
+            None => {
+                !(ip.is_loopback()
+                    || ip.is_unspecified()
+                    || ip.is_unique_local()
+                    || ip.is_unicast_link_local()
+                    || ip.is_multicast())
+            }
+        },
+    }
+}
+
+/// DNS resolver which prevents connecting to private IP addresses
+/// if endpoint's DNS record changes after the endpoint is validated.
+struct PublicAddressResolver;
+
+impl Resolve for PublicAddressResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addresses = resolve_public_addresses(name.as_str(), 0).await?;
+            let addresses: Addrs = Box::new(addresses.into_iter());
+            Ok(addresses)
+        })
+    }
+}
+
+pub struct UnifiedPushManager<T> {
+    client: Option<Client>,
+    sending_logic: UnifiedPushSendingLogic,
+    receiver: Receiver<SendPushNotification>,
+    state: T,
+}
+
+pub struct UnifiedPushManagerQuitHandle {
+    task: JoinHandle<()>,
+}
+
+impl UnifiedPushManagerQuitHandle {
+    pub async fn wait_quit(self) {
+        match self.task.await {
+            Ok(()) => (),
+            Err(e) => {
+                warn!("UnifiedPushManager quit failed. Error: {e:?}");
+            }
+        }
+    }
+}
+
+impl<T: PushNotificationStateProvider + Send + Sync + 'static> UnifiedPushManager<T> {
+    pub async fn new_manager(
+        config: &Config,
+        receiver: Receiver<SendPushNotification>,
+        state: T,
+        quit_notification: ServerQuitWatcher,
+    ) -> UnifiedPushManagerQuitHandle {
+        let unified_push_config = config.simple_backend().unified_push_config().cloned();
+
+        let client = if let Some(config) = &unified_push_config {
+            match create_client(config) {
+                Ok(client) => Some(client),
+                Err(e) => {
+                    error!("Creating UnifiedPush client failed: {}", e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        let sending_logic = UnifiedPushSendingLogic::new(unified_push_config.unwrap_or_default());
+
+        let mut manager = Self {
+            client,
+            sending_logic,
+            receiver,
+            state,
+        };
+
+        let task = tokio::spawn(async move {
+            manager.run(quit_notification).await;
+        });
+
+        UnifiedPushManagerQuitHandle { task }
+    }
+
+    pub async fn run(&mut self, mut quit_notification: ServerQuitWatcher) {
+        loop {
+            tokio::select! {
+                notification = self.receiver.recv() => {
+                    match notification {
+                        Some(notification) => match self.handle_notification(notification).await {
+                            Ok(()) => (),
+                            Err(e) => {
+                                error!("UnifiedPush notification handling failed: {e:?}");
+                            }
+                        },
+                        None => {
+                            warn!("UnifiedPush notification channel is broken");
+                            return;
+                        }
+                    }
+                }
+                _ = quit_notification.recv() => {
+                    return;
+                }
+            }
+        }
+    }
+
+    pub async fn handle_notification(
+        &mut self,
+        send_push_notification: SendPushNotification,
+    ) -> Result<(), PushNotificationError> {
+        let client = if let Some(client) = &self.client {
+            client
+        } else {
+            return Ok(());
+        };
+
+        let info = self
+            .state
+            .get_and_reset_push_notifications(send_push_notification.account_id)
+            .await
+            .change_context(PushNotificationError::ReadingNotificationSentStatusFailed)?;
+
+        let PushNotificationSendingInfo {
+            db_state,
+            notifications,
+            notifications_to_mark_as_sent,
+        } = info;
+
+        let Some(token) = db_state.device_token else {
+            return Ok(());
+        };
+
+        let Some(encryption_key) = db_state.encryption_key else {
+            return Ok(());
+        };
+
+        let encryption_key_bytes = base64::engine::general_purpose::STANDARD
+            .decode(encryption_key.as_str())
+            .change_context(PushNotificationError::EncryptionFailed)?;
+
+        for n in notifications {
+            let message = create_message(&n, &encryption_key_bytes)?;
+
+            match self
+                .sending_logic
+                .send_push_notification(client, &token, message, n.is_visible())
+                .await
+            {
+                Ok(()) => (),
+                Err(action) => match action {
+                    UnusualAction::RemoveDeviceToken => {
+                        return self
+                            .state
+                            .remove_device_token(send_push_notification.account_id)
+                            .await
+                            .change_context(PushNotificationError::RemoveDeviceTokenFailed);
+                    }
+                },
+            }
+        }
+
+        self.state
+            .mark_push_notifications_as_sent(
+                send_push_notification.account_id,
+                notifications_to_mark_as_sent,
+            )
+            .await
+            .change_context(PushNotificationError::MarkNotificationsAsSentFailed)?;
+
+        Ok(())
+    }
+}
+
+fn create_client(config: &UnifiedPushConfig) -> reqwest::Result<Client> {
+    let builder = Client::builder()
+        .timeout(Duration::from_secs(20))
+        .redirect(reqwest::redirect::Policy::none());
+    let builder = if config.allow_private_ip_addresses {
+        builder
+    } else {
+        builder.dns_resolver(Arc::new(PublicAddressResolver))
+    };
+    builder.build()
+}
+
+/// Create message which has the same JSON object which
+/// FCM notification data contains.
+fn create_message(
+    notification: &PushNotification,
+    encryption_key_bytes: &[u8],
+) -> Result<String, PushNotificationError> {
+    let notification_content = if let Some(body) = notification.body() {
+        serde_json::json!({
+            "title": notification.title(),
+            "body": body,
+        })
+    } else {
+        serde_json::json!({
+            "title": notification.title(),
+        })
+    };
+
+    let content_json = serde_json::to_string(&notification_content)
+        .change_context(PushNotificationError::Serialize)?;
+
+    let cipher = Aes128Gcm::new_from_slice(encryption_key_bytes)
+        .change_context(PushNotificationError::EncryptionFailed)?;
+
+    let nonce = Aes128Gcm::generate_nonce(OsRng);
+
+    let encrypted = cipher
+        .encrypt(&nonce, content_json.as_bytes())
+        .map_err(|_| {
+            Report::new(PushNotificationError::EncryptionFailed)
+                .attach("Failed to encrypt notification content")
+        })?;
+
+    let encrypted_base64 = base64::engine::general_purpose::STANDARD.encode(&encrypted);
+    let nonce_base64 = base64::engine::general_purpose::STANDARD.encode(nonce.as_slice());
+
+    let mut data = serde_json::Map::new();
+    data.insert("id".to_string(), serde_json::json!(notification.id()));
+    if let Some(channel) = notification.channel() {
+        data.insert("channel".to_string(), serde_json::json!(channel));
+    }
+    data.insert("encrypted".to_string(), serde_json::json!(encrypted_base64));
+    data.insert("nonce".to_string(), serde_json::json!(nonce_base64));
+
+    let message = serde_json::to_string(&serde_json::Value::Object(data))
+        .change_context(PushNotificationError::Serialize)?;
+
+    if message.len() > MAX_MESSAGE_SIZE {
+        return Err(
+            Report::new(PushNotificationError::NotificationBuildingFailed)
+                .attach(format!("Message size {} is too large", message.len())),
+        );
+    }
+
+    Ok(message)
+}
+
+struct UnifiedPushSendingLogic {
+    config: UnifiedPushConfig,
+}
+
+impl UnifiedPushSendingLogic {
+    pub fn new(config: UnifiedPushConfig) -> Self {
+        Self { config }
+    }
+
+    pub async fn send_push_notification(
+        &mut self,
+        client: &Client,
+        token: &PushNotificationDeviceToken,
+        message: String,
+        is_visible: bool,
+    ) -> std::result::Result<(), UnusualAction> {
+        let mut retry_once_done = false;
+        loop {
+            match self
+                .send_push_notification_internal(client, token, &message, is_visible)
+                .await
+            {
+                Ok(()) => return Ok(()),
+                Err(Action::RemoveDeviceToken) => return Err(UnusualAction::RemoveDeviceToken),
+                Err(Action::Retry) => (),
+                Err(Action::RetryOnce) => {
+                    if retry_once_done {
+                        return Ok(());
+                    }
+                    retry_once_done = true;
+                }
+            }
+        }
+    }
+
+    async fn send_push_notification_internal(
+        &mut self,
+        client: &Client,
+        token: &PushNotificationDeviceToken,
+        message: &str,
+        is_visible: bool,
+    ) -> std::result::Result<(), Action> {
+        // Endpoint was validated when it was set but the config might
+        // have changed after that.
+        if let Err(e) = parse_endpoint_url(token.as_str(), &self.config) {
+            error!("Invalid UnifiedPush endpoint: {e}");
+            return Err(Action::RemoveDeviceToken);
+        }
+
+        let urgency = if is_visible { "high" } else { "normal" };
+
+        let response = client
+            .post(token.as_str())
+            .header(CONTENT_TYPE, "application/json")
+            .header("TTL", (60 * 60 * 24 * 14).to_string())
+            .header("Urgency", urgency)
+            .body(message.to_string())
+            .send()
+            .await;
+
+        let response = match response {
+            Ok(response) => response,
+            Err(e) if e.is_builder() => {
+                error!("Invalid UnifiedPush endpoint: {e}");
+                return Err(Action::RemoveDeviceToken);
+            }
+            Err(e) => {
+                error!("UnifiedPush send failed: {e}");
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                return Err(Action::RetryOnce);
+            }
+        };
+
+        let status = response.status();
+        if status.is_success() {
+            if self.config.debug_logging {
+                info!("UnifiedPush send successful");
+            }
+            return Ok(());
+        }
+
+        if self.config.debug_logging {
+            error!("UnifiedPush error detected, status: {status}");
+        }
+
+        match status {
+            StatusCode::NOT_FOUND | StatusCode::GONE => Err(Action::RemoveDeviceToken),
+            StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE => {
+                let retry_after = response
+                    .headers()
+                    .get(RETRY_AFTER)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.parse::<u64>().ok())
+                    .map(Duration::from_secs);
+                if let Some(retry_after) = retry_after
+                    && retry_after <= MAX_RETRY_AFTER
+                {
+                    tokio::time::sleep(retry_after).await;
+                    Err(Action::Retry)
+                } else {
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                    Err(Action::RetryOnce)
+                }
+            }
+            StatusCode::PAYLOAD_TOO_LARGE => {
+                error!("UnifiedPush error: {status}");
+                // Ignore error
+                Ok(())
+            }
+            _ if status.is_server_error() => {
+                error!("UnifiedPush server error: {status}");
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                Err(Action::RetryOnce)
+            }
+            _ => {
+                // Distributor does not accept messages to the endpoint
+                error!("UnifiedPush error: {status}, removing device token");
+                Err(Action::RemoveDeviceToken)
+            }
+        }
+    }
+}
+
+pub enum UnusualAction {
+    RemoveDeviceToken,
+}
+
+pub enum Action {
+    RemoveDeviceToken,
+    Retry,
+    RetryOnce,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use model::PushNotificationId;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    fn config(allow_http: bool, allow_private_ip_addresses: bool) -> UnifiedPushConfig {
+        UnifiedPushConfig {
+            allow_http,
+            allow_private_ip_addresses,
+            debug_logging: false,
+        }
+    }
+
+    /// Minimal ntfy compatible distributor which accepts one message
+    /// and returns the request body.
+    async fn ntfy_stand_in() -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let endpoint = format!("http://{address}/up_test_topic?up=1");
+        let handle = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = vec![];
+            let mut buffer = [0u8; 1024];
+            let (headers_end, content_length) = loop {
+                let n = stream.read(&mut buffer).await.unwrap();
+                assert!(n > 0);
+                request.extend_from_slice(&buffer[..n]);
+                if let Some(i) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                    let headers = String::from_utf8_lossy(&request[..i]).to_lowercase();
+                    assert!(headers.starts_with("post /up_test_topic?up=1 "));
+                    assert!(headers.contains("content-type: application/json"));
+                    let content_length = headers
+                        .lines()
+                        .find_map(|l| l.strip_prefix("content-length:"))
+                        .and_then(|v| v.trim().parse::<usize>().ok())
+                        .unwrap();
+                    break (i + 4, content_length);
+                }
+            };
+            while request.len() < headers_end + content_length {
+                let n = stream.read(&mut buffer).await.unwrap();
+                assert!(n > 0);
+                request.extend_from_slice(&buffer[..n]);
+            }
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+                .await
+                .unwrap();
+            String::from_utf8(request[headers_end..].to_vec()).unwrap()
+        });
+        (endpoint, handle)
+    }
+
+    #[tokio::test]
+    async fn send_and_decrypt_notification() {
+        let (endpoint, distributor) = ntfy_stand_in().await;
+        let config = config(true, true);
+        let client = create_client(&config).unwrap();
+        let mut sending_logic = UnifiedPushSendingLogic::new(config);
+
+        let key = [7u8; 16];
+        let notification = PushNotification::new_with_body(
+            PushNotificationId::AdminNotification,
+            "Title".to_string(),
+            "Body".to_string(),
+        );
+        let message = create_message(&notification, &key).unwrap();
+        let token = PushNotificationDeviceToken::new(endpoint);
+        assert!(
+            sending_logic
+                .send_push_notification(&client, &token, message, true)
+                .await
+                .is_ok()
+        );
+
+        let body = distributor.await.unwrap();
+        let data: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(data["id"], serde_json::json!(notification.id()));
+        let encrypted = base64::engine::general_purpose::STANDARD
+            .decode(data["encrypted"].as_str().unwrap())
+            .unwrap();
+        let nonce = base64::engine::general_purpose::STANDARD
+            .decode(data["nonce"].as_str().unwrap())
+            .unwrap();
+        let cipher = Aes128Gcm::new_from_slice(&key).unwrap();
+        let decrypted = cipher
+            .decrypt(aes_gcm::Nonce::from_slice(&nonce), encrypted.as_slice())
+            .unwrap();
+        let content: serde_json::Value = serde_json::from_slice(&decrypted).unwrap();
+        assert_eq!(
+            content,
+            serde_json::json!({
+                "title": "Title",
+                "body": "Body",
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn private_endpoint_is_not_sent_to() {
+        let config = config(true, false);
+        let client = create_client(&config).unwrap();
+        let mut sending_logic = UnifiedPushSendingLogic::new(config);
+        let token = PushNotificationDeviceToken::new("http://127.0.0.1:1/topic".to_string());
+        assert!(matches!(
+            sending_logic
+                .send_push_notification(&client, &token, "{}".to_string(), true)
+                .await,
+            Err(UnusualAction::RemoveDeviceToken)
+        ));
+    }
+
+    #[test]
+    fn non_public_ip_addresses() {
+        let not_public: [IpAddr; 12] = [
+            Ipv4Addr::LOCALHOST.into(),
+            Ipv4Addr::UNSPECIFIED.into(),
+            Ipv4Addr::BROADCAST.into(),
+            Ipv4Addr::new(10, 1, 2, 3).into(),
+            Ipv4Addr::new(172, 16, 0, 1).into(),
+            Ipv4Addr::new(192, 168, 1, 1).into(),
+            Ipv4Addr::new(169, 254, 169, 254).into(),
+            Ipv4Addr::new(100, 64, 0, 1).into(),
+            Ipv6Addr::LOCALHOST.into(),
+            Ipv6Addr::UNSPECIFIED.into(),
+            "fd00::1".parse().unwrap(),
+            "::ffff:127.0.0.1".parse().unwrap(),
+        ];
+        for ip in not_public {
+            assert!(!is_public_ip_address(ip), "{ip}");
+        }
+        assert!(!is_public_ip_address("fe80::1".parse().unwrap()));
+        assert!(is_public_ip_address(Ipv4Addr::new(1, 1, 1, 1).into()));
+        assert!(is_public_ip_address(
+            "2606:4700:4700::1111".parse().unwrap()
+        ));
+    }
+
+    #[tokio::test]
+    async fn endpoint_validation() {
+        let strict = config(false, false);
+        assert_eq!(
+            validate_unified_push_endpoint("not a url", &strict).await,
+            Err(UnifiedPushEndpointError::InvalidUrl)
+        );
+        assert_eq!(
+            validate_unified_push_endpoint("http://1.1.1.1/topic", &strict).await,
+            Err(UnifiedPushEndpointError::SchemeNotAllowed)
+        );
+        assert_eq!(
+            validate_unified_push_endpoint("https://127.0.0.1/topic", &strict).await,
+            Err(UnifiedPushEndpointError::IpAddressNotAllowed)
+        );
+        assert_eq!(
+            validate_unified_push_endpoint("https://[::1]/topic", &strict).await,
+            Err(UnifiedPushEndpointError::IpAddressNotAllowed)
+        );
+        assert_eq!(
+            validate_unified_push_endpoint("https://localhost/topic", &strict).await,
+            Err(UnifiedPushEndpointError::IpAddressNotAllowed)
+        );
+        assert_eq!(
+            validate_unified_push_endpoint("https://1.1.1.1/topic", &strict).await,
+            Ok(())
+        );
+        assert_eq!(
+            validate_unified_push_endpoint("http://127.0.0.1/topic", &config(true, true)).await,
+            Ok(())
+        );
+    }
+}
@@ -5,16 +5,19 @@ use config::{Config, file::EmailAddress};
 use futures::future::BoxFuture;
 use model::{
     AccountId, AccountIdInternal, AccountVerificationErrorFlagsValue, ClientMessageForDataAllCrate,
-    EditVerificationValues, UnixTime, VerificationMethod,
+    EditVerificationValues, MessageId, NewPendingMessage, PendingMessageId, PublicKeyId,
+    ResponseSendMessage, UnixTime, VerificationMethod,
 };
 use model_server_data::SignInWithInfo;
 pub use server_common::app::*;
 use server_common::websocket::WebSocketError;
 use simple_backend::manager_client::ManagerApiClient;
+use utils::encrypt::ParsedKeys;
 
 use crate::{
     DataError,
     data_export::{DataExportCmd, DataExportManagerData},
+    data_import::{DataImportProfileContent, DataImportResult},
     db_manager::{InternalWriting, RouterDatabaseReadHandle},
     email::EmailChannelSender,
     event::EventManagerWithCacheReference,
@@ -175,6 +178,37 @@ pub trait DataAllUtils: Send + Sync + 'static {
         write_handle: &'a WriteCommandRunnerHandle,
     ) -> BoxFuture<'a, server_common::result::Result<(), DataError>>;
 
+    #[allow(clippy::too_many_arguments)]
+    fn send_message<'a>(
+        &self,
+        write_handle: &'a WriteCommandRunnerHandle,
+        sender: AccountIdInternal,
+        recipient: AccountIdInternal,
+        message: Vec<u8>,
+        sender_public_key: PublicKeyId,
+        recipient_public_key: PublicKeyId,
+        message_id: MessageId,
+        keys: Arc<ParsedKeys>,
+    ) -> BoxFuture<
+        'a,
+        server_common::result::Result<(ResponseSendMessage, Option<NewPendingMessage>), DataError>,
+    >;
+
+    fn add_recipient_acknowledgement<'a>(
+        &self,
+        write_handle: &'a WriteCommandRunnerHandle,
+        recipient: AccountIdInternal,
+        messages: Vec<PendingMessageId>,
+        delivery_failed: bool,
+    ) -> BoxFuture<'a, server_common::result::Result<(), DataError>>;
+
+    fn add_sender_acknowledgement<'a>(
+        &self,
+        write_handle: &'a WriteCommandRunnerHandle,
+        sender: AccountIdInternal,
+        messages: Vec<MessageId>,
+    ) -> BoxFuture<'a, server_common::result::Result<(), DataError>>;
+
     fn data_export<'a>(
         &self,
         write_handle: &'a WriteCommandRunnerHandle,
@@ -182,6 +216,27 @@ pub trait DataAllUtils: Send + Sync + 'static {
         cmd: DataExportCmd,
     ) -> BoxFuture<'a, server_common::result::Result<(), DataError>>;
 
+    fn import_account_data<'a>(
+        &self,
+        write_handle: &'a WriteCommandRunnerHandle,
+        archive: Vec<u8>,
+    ) -> BoxFuture<'a, server_common::result::Result<DataImportResult, DataError>>;
+
+    fn cancel_account_data_import<'a>(
+        &self,
+        write_handle: &'a WriteCommandRunnerHandle,
+        id: AccountIdInternal,
+    ) -> BoxFuture<'a, server_common::result::Result<(), DataError>>;
+
+    fn complete_account_data_import<'a>(
+        &self,
+        config: &'a Config,
+        read_handle: &'a RouterDatabaseReadHandle,
+        write_handle: &'a WriteCommandRunnerHandle,
+        id: AccountIdInternal,
+        content: DataImportProfileContent,
+    ) -> BoxFuture<'a, server_common::result::Result<(), DataError>>;
+
     fn edit_verification_values<'a>(
         &self,
         write_command_runner: &'a WriteCommandRunnerHandle,
@@ -196,6 +251,15 @@ pub trait DataAllUtils: Send + Sync + 'static {
         reporters_to_ban: Vec<AccountIdInternal>,
     ) -> BoxFuture<'a, server_common::result::Result<(), DataError>>;
 
+    /// Ban accounts from accepted ban evasion reports.
+    /// Vec items are `(banned_account, target)` pairs.
+    fn ban_accounts_linked_to_banned_accounts<'a>(
+        &self,
+        write_command_runner: &'a WriteCommandRunnerHandle,
+        moderator_id: AccountIdInternal,
+        accounts: Vec<(AccountIdInternal, AccountIdInternal)>,
+    ) -> BoxFuture<'a, server_common::result::Result<(), DataError>>;
+
     #[allow(clippy::too_many_arguments)]
     fn process_account_verification_queue_item<'a>(
         &self,
@@ -8,6 +8,7 @@ use std::{
 use account::CacheAccount;
 use chat::CacheChat;
 use common::CacheCommon;
+use config::file::AdminTotpConfig;
 use media::CacheMedia;
 use model::{
     AccessToken, AccessTokenType, AccountId, AccountIdInternal, AccountState,
@@ -168,10 +169,13 @@ impl DatabaseCache {
         })
     }
 
+    /// See [CacheCommon::login_session_permissions] for
+    /// `totp_config` documentation.
     pub async fn access_token_and_ip_is_valid(
         &self,
         access_token: &AccessToken,
         connection: SocketAddr,
+        totp_config: &AdminTotpConfig,
     ) -> Option<(AccountIdInternal, Permissions, AccountState)> {
         let tokens = self.access_tokens.read().await;
         if let Some(entry) = tokens.get(access_token) {
@@ -185,7 +189,7 @@ impl DatabaseCache {
             if is_valid {
                 Some((
                     entry.account_entry.account_id_internal,
-                    r.common.account.permissions(),
+                    r.common.login_session_permissions(totp_config),
                     r.common.account.state(),
                 ))
             } else {
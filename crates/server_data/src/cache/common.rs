@@ -1,8 +1,10 @@
+use config::file::AdminTotpConfig;
 use model::{
     AccessToken, AccessTokenType, AccessTokenUnixTime, Account, IpAddressInternal, LoginSession,
-    OtherSharedState, PushNotificationFlags, RefreshToken,
+    OtherSharedState, Permissions, PushNotificationFlags, RefreshToken, UnixTime,
 };
 use model_server_data::{AppNotificationSettingsInternal, AuthPair};
+use simple_backend_utils::time::DurationValue;
 
 use super::ConnectionInfo;
 use crate::{cache::api_limits::AllApiLimits, event::EventSender};
@@ -14,6 +16,9 @@ pub struct CacheCommon {
     pub current_connection: Option<ConnectionInfo>,
     login_session: Option<LoginSession>,
     login_session_changed: bool,
+    /// Time when the current login session was verified with TOTP.
+    /// This is not saved to database.
+    totp_verification_unix_time: Option<UnixTime>,
     /// The cached pending push notification flags indicates not yet handled
     /// notification which PushNotificationManager will handle as soon as
     /// possible.
@@ -53,6 +58,32 @@ impl CacheCommon {
     pub fn logout(&mut self) {
         self.login_session = None;
         self.login_session_changed = true;
+        self.totp_verification_unix_time = None;
+    }
+
+    pub fn set_totp_verified(&mut self) {
+        self.totp_verification_unix_time = Some(UnixTime::current_time());
+    }
+
+    pub fn reset_totp_verification(&mut self) {
+        self.totp_verification_unix_time = None;
+    }
+
+    pub fn totp_verification_is_valid(&self, validity: DurationValue) -> bool {
+        self.totp_verification_unix_time
+            .map(|time| !time.duration_value_elapsed(validity))
+            .unwrap_or_default()
+    }
+
+    /// Permissions which the current login session can use.
+    ///
+    /// If TOTP is required for this account, admin permissions
+    /// require valid TOTP verification.
+    pub fn login_session_permissions(&self, totp_config: &AdminTotpConfig) -> Permissions {
+        match totp_config.required_verification_validity(self.other_shared_state.is_bot()) {
+            Some(validity) if !self.totp_verification_is_valid(validity) => Permissions::default(),
+            _ => self.account.permissions(),
+        }
     }
 
     pub fn get_tokens_if_save_needed(&mut self) -> Option<Option<LoginSession>> {
@@ -116,6 +147,7 @@ impl Default for CacheCommon {
             current_connection: None,
             login_session: None,
             login_session_changed: false,
+            totp_verification_unix_time: None,
             pending_push_notification_flags: PushNotificationFlags::empty(),
             app_notification_settings: AppNotificationSettingsInternal::default(),
             api_limits: AllApiLimits::default(),
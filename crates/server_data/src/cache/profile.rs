@@ -19,7 +19,7 @@ use simple_backend_utils::Result;
 
 use crate::{
     db_manager::InternalWriting,
-    index::{coordinates::LocationIndexArea, read::LocationIndexIteratorState},
+    index::region::{LocationIndexRegionsArea, LocationIndexRegionsIteratorState},
 };
 
 #[derive(Debug)]
@@ -139,15 +139,15 @@ impl CacheProfile {
 
 #[derive(Debug, Clone)]
 pub struct LocationData {
-    pub current_position: LocationIndexArea,
-    pub current_iterator: LocationIndexIteratorState,
+    pub current_position: LocationIndexRegionsArea,
+    pub current_iterator: LocationIndexRegionsIteratorState,
 }
 
 impl Default for LocationData {
     fn default() -> Self {
         Self {
-            current_position: LocationIndexArea::default(),
-            current_iterator: LocationIndexIteratorState::completed(),
+            current_position: LocationIndexRegionsArea::default(),
+            current_iterator: LocationIndexRegionsIteratorState::completed(),
         }
     }
 }
@@ -185,7 +185,7 @@ impl<I: InternalWriting> UpdateLocationCacheState for I {
 
 #[derive(Debug)]
 pub struct AutomaticProfileSearch {
-    pub current_iterator: LocationIndexIteratorState,
+    pub current_iterator: LocationIndexRegionsIteratorState,
     pub iterator_session_id: Option<AutomaticProfileSearchIteratorSessionIdInternal>,
     pub iterator_session_id_storage: NextNumberStorage,
     last_seen_unix_time: Option<AutomaticProfileSearchLastSeenUnixTime>,
@@ -198,7 +198,7 @@ impl AutomaticProfileSearch {
         settings: AutomaticProfileSearchSettings,
     ) -> Self {
         Self {
-            current_iterator: LocationIndexIteratorState::completed(),
+            current_iterator: LocationIndexRegionsIteratorState::completed(),
             iterator_session_id: None,
             iterator_session_id_storage: NextNumberStorage::default(),
             last_seen_unix_time,
@@ -0,0 +1,59 @@
+use model::{AccountIdInternal, ContentId};
+
+/// Image from user data export archive. The image must be processed
+/// using content processing before it can be used.
+#[derive(Debug, Clone)]
+pub struct DataImportImage {
+    pub source_content_id: ContentId,
+    pub secure_capture: bool,
+    pub data: Vec<u8>,
+}
+
+/// Profile content from user data export archive. Content IDs are
+/// source account content IDs until [Self::to_imported] converts
+/// those to imported account content IDs.
+#[derive(Debug, Clone)]
+pub struct DataImportProfileContent {
+    pub content: Vec<ContentId>,
+    pub security_content: Option<ContentId>,
+    pub grid_crop_size: f32,
+    pub grid_crop_x: f32,
+    pub grid_crop_y: f32,
+}
+
+impl DataImportProfileContent {
+    /// Convert source account content IDs to imported account content IDs.
+    /// Content which was not imported is removed. Security content
+    /// is removed if face was not detected from it.
+    ///
+    /// The closure returns new content ID and face detected value.
+    pub fn to_imported(
+        &self,
+        imported: impl Fn(ContentId) -> Option<(ContentId, bool)>,
+    ) -> Self {
+        Self {
+            content: self
+                .content
+                .iter()
+                .filter_map(|c| imported(*c).map(|(c, _)| c))
+                .collect(),
+            security_content: self
+                .security_content
+                .and_then(&imported)
+                .and_then(|(c, face_detected)| face_detected.then_some(c)),
+            grid_crop_size: self.grid_crop_size,
+            grid_crop_x: self.grid_crop_x,
+            grid_crop_y: self.grid_crop_y,
+        }
+    }
+}
+
+pub enum DataImportResult {
+    Ok {
+        id: AccountIdInternal,
+        images: Vec<DataImportImage>,
+        profile_content: DataImportProfileContent,
+    },
+    InvalidArchive,
+    EmailAlreadyExists,
+}
@@ -491,6 +491,10 @@ impl RouterDatabaseReadHandle {
     pub fn read_handle_raw(&self) -> &CurrentReadHandle {
         &self.current_read_handle
     }
+
+    pub fn history_read_handle_raw(&self) -> &HistoryReadHandle {
+        &self.history_read_handle
+    }
 }
 
 pub struct ReadAdapter<'a> {
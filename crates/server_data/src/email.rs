@@ -1,7 +1,7 @@
 use error_stack::IntoReport;
 use model::ClientLanguage;
 use model_server_data::EmailAddress;
-pub use simple_backend::email::EmailError;
+pub use simple_backend::email::{EmailBody, EmailError};
 use simple_backend_utils::consts::MIB_IN_BYTES;
 use tokio::sync::{
     mpsc::{Receiver, Sender, error::TrySendError},
@@ -18,8 +18,7 @@ const CUSTOM_EMAIL_CHANNEL_BUFFER_SIZE: usize = 16;
 pub struct EmailData {
     pub email_address: String,
     pub subject: String,
-    pub body: String,
-    pub body_is_html: bool,
+    pub body: EmailBody,
 }
 
 pub struct NormalEmailMsg {
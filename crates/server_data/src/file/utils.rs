@@ -3,7 +3,7 @@ use std::path::{Path, PathBuf};
 use axum::body::BodyDataStream;
 use config::Config;
 use error_stack::ResultExt;
-use model::{AccountId, ContentId, ContentQualityVariant};
+use model::{AccountId, ContentFileVariant, ContentId, ContentImageFormat, ContentQualityVariant};
 use server_common::data::DataError;
 use simple_backend_database::data::create_dirs_and_get_files_dir_path;
 use simple_backend_utils::{
@@ -66,6 +66,16 @@ impl FileDir {
             .media_content_variant(content_id, variant)
     }
 
+    pub fn media_content_file_variant(
+        &self,
+        id: AccountId,
+        content_id: ContentId,
+        variant: ContentFileVariant,
+    ) -> ContentFile {
+        self.media_content_variant(id, content_id, variant.quality)
+            .format_variant(variant.format)
+    }
+
     pub fn account_dir(&self, id: AccountId) -> AccountDir {
         let mut dir = self.dir.clone();
         dir.push(id.to_string());
@@ -212,6 +222,18 @@ impl ContentFile {
         self.path.as_path()
     }
 
+    /// Get file for other image format. The current file must be
+    /// the JPEG file.
+    pub fn format_variant(&self, format: ContentImageFormat) -> ContentFile {
+        ContentFile {
+            path: self.path.format_variant(format),
+        }
+    }
+
+    pub fn exists(&self) -> bool {
+        self.path.as_path().exists()
+    }
+
     pub async fn overwrite_and_remove_if_exists(self) -> Result<(), FileError> {
         self.path.overwrite_and_remove_if_exists().await
     }
@@ -237,6 +259,18 @@ pub struct TmpContentFile {
 }
 
 impl TmpContentFile {
+    /// Get file for other image format. The current file must be
+    /// the JPEG file.
+    pub fn format_variant(&self, format: ContentImageFormat) -> TmpContentFile {
+        TmpContentFile {
+            path: self.path.format_variant(format),
+        }
+    }
+
+    pub fn exists(&self) -> bool {
+        self.path.as_path().exists()
+    }
+
     pub async fn save_stream_with_cancel(
         &self,
         stream: BodyDataStream,
@@ -245,6 +279,10 @@ impl TmpContentFile {
         self.path.save_stream_with_cancel(stream, cancel).await
     }
 
+    pub async fn read_all(&self) -> Result<Vec<u8>, FileError> {
+        self.path.read_all().await
+    }
+
     pub async fn move_to(self, new_location: &ContentFile) -> Result<(), FileError> {
         self.path.move_to(&new_location.path).await
     }
@@ -297,6 +335,12 @@ impl PathToFile {
         &self.path
     }
 
+    fn format_variant(&self, format: ContentImageFormat) -> PathToFile {
+        PathToFile {
+            path: format.file_path_from_jpeg_path(&self.path),
+        }
+    }
+
     pub async fn create_parent_dirs(&self) -> Result<(), FileError> {
         if let Some(parent_dir) = self.path.parent() {
             if !parent_dir.exists() {
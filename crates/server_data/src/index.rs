@@ -3,35 +3,33 @@
 //! Allow iterating profiles from nearest to farthest starting from
 //! some location.
 //!
-//! The first part of the index is a matrix [LocationIndexManager::index]
+//! The first part of the index is a matrix [data::LocationIndex]
 //! where each cell has jump info to next profile for each direction.
 //! The cell also contains info is profile available at this location.
 //!
 //! After finding a cell with profiles, the cell coordinate info is
-//! used to check available profiles from [LocationIndexManager::profiles].
+//! used to check available profiles from [profiles::ProfilesAtLocation].
 //!
 //! The profile index supports multiple readers and one writer.
+//!
+//! The index is split to regions [region::LocationIndexRegion] which have
+//! their own matrix and profile storage. Iterating continues to
+//! other regions when max distance filter reaches them.
 
-use std::{collections::HashMap, sync::Arc};
+use std::sync::Arc;
 
 use config::Config;
-use coordinates::{CoordinateManager, LocationIndexArea};
-use data::IndexSize;
-use error_stack::ResultExt;
-use info::LocationIndexInfoCreator;
 use model::{AccountId, ProfileLink, UnixTime};
 use model_server_data::{
-    Location, LocationIndexKey, LocationIndexProfileData, MaxDistanceKm, MinDistanceKm,
-    ProfileAttributesInternal, ProfileQueryMakerDetails,
+    Location, LocationIndexProfileData, MaxDistanceKm, MinDistanceKm, ProfileAttributesInternal,
+    ProfileQueryMakerDetails,
+};
+use region::{
+    IteratorResultInternal, LocationIndexRegionKey, LocationIndexRegions, LocationIndexRegionsArea,
+    LocationIndexRegionsIteratorState,
 };
-use profiles::ProfilesAtLocation;
-use read::LocationIndexIteratorState;
 use server_common::data::index::IndexError;
-use tokio::sync::RwLock;
-use tracing::info;
-use write::IndexUpdater;
 
-use self::data::LocationIndex;
 use crate::db_manager::InternalWriting;
 
 pub mod coordinates;
@@ -39,6 +37,7 @@ pub mod data;
 pub mod info;
 pub mod profiles;
 pub mod read;
+pub mod region;
 pub mod write;
 
 pub trait LocationWrite {
@@ -63,34 +62,16 @@ impl<I: InternalWriting> LocationWrite for I {
 
 #[derive(Debug)]
 pub struct LocationIndexManager {
-    index: Arc<LocationIndex>,
-    profiles: RwLock<HashMap<LocationIndexKey, ProfilesAtLocation>>,
-    coordinates: CoordinateManager,
+    regions: LocationIndexRegions,
 }
 
 impl LocationIndexManager {
     pub fn new(config: Arc<Config>) -> Self {
-        let coordinates = CoordinateManager::new(config.location().clone());
         // Create index also if profile features are disabled.
         // This way accidential index access will not crash the server.
         // The default index should not consume memory that much.
-        let (width, height) = (
-            coordinates.width().try_into().unwrap(),
-            coordinates.height().try_into().unwrap(),
-        );
-
-        let index = LocationIndex::new(IndexSize::new(width), IndexSize::new(height)).into();
-
-        info!(
-            "{}",
-            LocationIndexInfoCreator::new(config.location().clone())
-                .create_one(config.location().index_cell_square_km),
-        );
-
         Self {
-            index,
-            coordinates,
-            profiles: RwLock::new(HashMap::new()),
+            regions: LocationIndexRegions::new(config.location_regions()),
         }
     }
 
@@ -99,51 +80,50 @@ impl LocationIndexManager {
         location: Location,
         min_distance: Option<MinDistanceKm>,
         max_distance: Option<MaxDistanceKm>,
-    ) -> LocationIndexArea {
-        self.coordinates
-            .to_index_area(location.into(), min_distance, max_distance, &self.index)
+    ) -> LocationIndexRegionsArea {
+        self.regions
+            .to_area(location.into(), min_distance, max_distance)
     }
-}
 
-enum IteratorResultInternal {
-    NoProfiles,
-    TryAgain,
-    MatchingProfilesFound { profiles: Vec<ProfileLink> },
+    pub fn regions(&self) -> &LocationIndexRegions {
+        &self.regions
+    }
 }
 
 #[derive(Debug)]
 pub struct LocationIndexIteratorHandle<'a> {
-    index: &'a Arc<LocationIndex>,
-    profiles: &'a RwLock<HashMap<LocationIndexKey, ProfilesAtLocation>>,
+    regions: &'a LocationIndexRegions,
 }
 
 impl<'a> LocationIndexIteratorHandle<'a> {
     pub fn new(manager: &'a LocationIndexManager) -> Self {
         Self {
-            index: &manager.index,
-            profiles: &manager.profiles,
+            regions: &manager.regions,
         }
     }
 
     pub fn next_profiles(
         &self,
-        previous_iterator_state: LocationIndexIteratorState,
+        previous_iterator_state: LocationIndexRegionsIteratorState,
         query_maker_details: &ProfileQueryMakerDetails,
         profile_attributes: &ProfileAttributesInternal,
-    ) -> (LocationIndexIteratorState, Option<Vec<ProfileLink>>) {
+    ) -> (LocationIndexRegionsIteratorState, Option<Vec<ProfileLink>>) {
         let current_time = UnixTime::current_time();
         let mut iterator_state = previous_iterator_state;
         loop {
-            let (new_state, result) = self.next_profiles_internal(
-                iterator_state,
+            let result = self.next_profiles_internal(
+                &mut iterator_state,
                 query_maker_details,
                 profile_attributes,
                 &current_time,
             );
-            iterator_state = new_state;
             match result {
                 IteratorResultInternal::NoProfiles => {
-                    return (iterator_state, None);
+                    if iterator_state.next_region(self.regions) {
+                        continue;
+                    } else {
+                        return (iterator_state, None);
+                    }
                 }
                 IteratorResultInternal::MatchingProfilesFound { profiles } => {
                     return (iterator_state, Some(profiles));
@@ -155,71 +135,51 @@ impl<'a> LocationIndexIteratorHandle<'a> {
         }
     }
 
-    /// Iterate to next index cell which has profiles and get all matching
-    /// profiles.
+    /// Iterate to next index cell of current region which has profiles and
+    /// get all matching profiles.
     fn next_profiles_internal(
         &self,
-        previous_iterator_state: LocationIndexIteratorState,
+        iterator_state: &mut LocationIndexRegionsIteratorState,
         query_maker_details: &ProfileQueryMakerDetails,
         profile_attributes: &ProfileAttributesInternal,
         current_time: &UnixTime,
-    ) -> (LocationIndexIteratorState, IteratorResultInternal) {
-        let index = self.index.clone();
-        let (iterator, key) = {
-            let mut iterator = previous_iterator_state.into_iterator(index);
-            let key = iterator.next();
-            (iterator, key)
-        };
-        let result = match key {
-            None => IteratorResultInternal::NoProfiles,
-            Some(key) => match self.profiles.blocking_read().get(&key) {
-                // Possible data race occurred where profile was removed
-                // from the data storage when iterating the index.
-                None => IteratorResultInternal::TryAgain,
-                // TODO(perf): Currently all profiles in one index cell are
-                // sent to client, which might cause issues if everyone will
-                // set profile to same location.
-                Some(profiles) => {
-                    let matches: Vec<ProfileLink> = profiles.find_profiles(
-                        query_maker_details,
-                        profile_attributes,
-                        current_time,
-                    );
-                    if matches.is_empty() {
-                        IteratorResultInternal::TryAgain
-                    } else {
-                        IteratorResultInternal::MatchingProfilesFound { profiles: matches }
-                    }
-                }
-            },
+    ) -> IteratorResultInternal {
+        let Some(region) = iterator_state.current_region() else {
+            return IteratorResultInternal::NoProfiles;
         };
-        (iterator.into(), result)
+        let (new_state, result) = self.regions.get(region).next_profiles_internal(
+            iterator_state.take_current(),
+            query_maker_details,
+            profile_attributes,
+            current_time,
+        );
+        iterator_state.set_current(new_state);
+        result
     }
 
     pub fn new_iterator_state(
         &self,
-        area: &LocationIndexArea,
+        area: &LocationIndexRegionsArea,
         random: bool,
-    ) -> LocationIndexIteratorState {
-        LocationIndexIteratorState::new(area, random, &self.index)
+    ) -> LocationIndexRegionsIteratorState {
+        self.regions.new_iterator_state(area, random)
     }
 
-    pub fn index(&self) -> &LocationIndex {
-        self.index
+    /// Area without distance limits
+    pub fn with_max_area(&self, area: &LocationIndexRegionsArea) -> LocationIndexRegionsArea {
+        area.with_max_area(self.regions)
     }
 }
 
 #[derive(Debug)]
 pub struct LocationIndexWriteHandle<'a> {
-    index: &'a Arc<LocationIndex>,
-    profiles: &'a RwLock<HashMap<LocationIndexKey, ProfilesAtLocation>>,
+    regions: &'a LocationIndexRegions,
 }
 
 impl<'a> LocationIndexWriteHandle<'a> {
     pub fn new(manager: &'a LocationIndexManager) -> Self {
         Self {
-            index: &manager.index,
-            profiles: &manager.profiles,
+            regions: &manager.regions,
         }
     }
 
@@ -227,65 +187,28 @@ impl<'a> LocationIndexWriteHandle<'a> {
     pub async fn update_profile_location(
         &self,
         account_id: AccountId,
-        previous_key: LocationIndexKey,
-        new_key: LocationIndexKey,
+        previous_key: LocationIndexRegionKey,
+        new_key: LocationIndexRegionKey,
     ) -> simple_backend_utils::Result<(), IndexError> {
-        if previous_key == new_key {
-            // No update needed. If return would not be here then
-            // if new_size == 0 check would make profile disappear.
-            return Ok(());
+        if previous_key.region == new_key.region {
+            return self
+                .regions
+                .get(new_key.region)
+                .update_profile_location(account_id, previous_key.key, new_key.key)
+                .await;
         }
 
-        let mut profiles = self.profiles.write().await;
-        let data = match profiles.get_mut(&previous_key) {
-            Some(p) => {
-                let current_profile = p.remove(&account_id);
-                Some((current_profile, p.len()))
-            }
-            None => None,
-        };
-
-        if let Some((current_profile, new_size)) = data {
-            let mut updater = IndexUpdater::new(self.index.clone());
-
-            if let Some(profile) = current_profile {
-                match profiles.get_mut(&new_key) {
-                    Some(some_other_profiles_also) => {
-                        let update_index = some_other_profiles_also.is_empty();
-                        some_other_profiles_also.insert(account_id, profile);
-                        if update_index {
-                            drop(profiles);
-                            tokio::task::spawn_blocking(move || {
-                                updater.flag_cell_to_have_profiles(new_key)
-                            })
-                            .await
-                            .change_context(IndexError::ProfileIndex)?;
-                        }
-                    }
-                    None => {
-                        profiles.insert(new_key, ProfilesAtLocation::new(account_id, profile));
-                        drop(profiles);
-                        tokio::task::spawn_blocking(move || {
-                            updater.flag_cell_to_have_profiles(new_key)
-                        })
-                        .await
-                        .change_context(IndexError::ProfileIndex)?;
-                    }
-                }
-            } else {
-                // Drop before calling remove_profile_flag_from_cell, so
-                // reading will not be blocked that much.
-                drop(profiles);
-            }
-
-            if new_size == 0 {
-                let mut updater = IndexUpdater::new(self.index.clone());
-                tokio::task::spawn_blocking(move || {
-                    updater.remove_profile_flag_from_cell(previous_key);
-                })
-                .await
-                .change_context(IndexError::ProfileIndex)?;
-            }
+        let removed = self
+            .regions
+            .get(previous_key.region)
+            .remove_profile_data(account_id, previous_key.key)
+            .await?;
+
+        if let Some(profile_data) = removed {
+            self.regions
+                .get(new_key.region)
+                .update_profile_data(account_id, profile_data, new_key.key)
+                .await?;
         }
 
         Ok(())
@@ -296,52 +219,24 @@ impl<'a> LocationIndexWriteHandle<'a> {
         &self,
         account_id: AccountId,
         profile_data: LocationIndexProfileData,
-        key: LocationIndexKey,
+        key: LocationIndexRegionKey,
     ) -> simple_backend_utils::Result<(), IndexError> {
-        let mut profiles = self.profiles.write().await;
-        match profiles.get_mut(&key) {
-            Some(some_other_profiles_also) => {
-                let update_index = some_other_profiles_also.is_empty();
-                some_other_profiles_also.insert(account_id, profile_data);
-                if update_index {
-                    drop(profiles);
-                    let mut updater = IndexUpdater::new(self.index.clone());
-                    tokio::task::spawn_blocking(move || updater.flag_cell_to_have_profiles(key))
-                        .await
-                        .change_context(IndexError::ProfileIndex)?;
-                }
-            }
-            None => {
-                profiles.insert(key, ProfilesAtLocation::new(account_id, profile_data));
-                drop(profiles);
-                let mut updater = IndexUpdater::new(self.index.clone());
-                tokio::task::spawn_blocking(move || updater.flag_cell_to_have_profiles(key))
-                    .await
-                    .change_context(IndexError::ProfileIndex)?;
-            }
-        }
-        Ok(())
+        self.regions
+            .get(key.region)
+            .update_profile_data(account_id, profile_data, key.key)
+            .await
     }
 
     /// Remove LocationIndexProfileData from specific index location
     pub async fn remove_profile_data(
         &self,
         account_id: AccountId,
-        key: LocationIndexKey,
+        key: LocationIndexRegionKey,
     ) -> simple_backend_utils::Result<(), IndexError> {
-        let mut profiles = self.profiles.write().await;
-        if let Some(some_other_profiles_also) = profiles.get_mut(&key) {
-            let removed = some_other_profiles_also.remove(&account_id);
-
-            if removed.is_some() && some_other_profiles_also.is_empty() {
-                profiles.remove(&key);
-                drop(profiles);
-                let mut updater = IndexUpdater::new(self.index.clone());
-                tokio::task::spawn_blocking(move || updater.remove_profile_flag_from_cell(key))
-                    .await
-                    .change_context(IndexError::ProfileIndex)?;
-            }
-        }
+        self.regions
+            .get(key.region)
+            .remove_profile_data(account_id, key.key)
+            .await?;
         Ok(())
     }
 }
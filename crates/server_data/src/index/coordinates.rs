@@ -6,50 +6,51 @@ use rand::Rng;
 
 use super::data::{LocationIndex, ReadIndex};
 
-/// Use middle point of Finland to approximate longitude.
-/// That probably makes the index squares practically larger in north and
-/// smaller in south. Or other way around.
-fn calculate_longitude_one_km_in_degrees() -> f64 {
-    1.0 / (111.320 * f64::cos(LATITUDE_FOR_LONGITUDE_CORRECTION.to_radians()).to_degrees())
+/// Earth equatorial circumference in kilometers. Web Mercator projection
+/// uses the equatorial radius.
+const EARTH_CIRCUMFERENCE_KM: f64 = 40075.016686;
+
+/// Supported OpenStreetMap zoom levels. Zoom level 6 tile side length is
+/// about 626 km at the equator and zoom level 17 tile side length is about
+/// 0.3 km at the equator.
+pub const SUPPORTED_ZOOM_LEVELS: std::ops::RangeInclusive<u8> = 6..=17;
+
+/// Index width or height must be at least this many tiles as
+/// index border is reserved to be empty.
+const MIN_INDEX_SIDE_TILES: u32 = 3;
+
+/// Max index width or height. See [super::data::IndexSize].
+const MAX_INDEX_SIDE_TILES: u32 = 0x8000;
+
+/// Web Mercator tiles are squares so tile width and height in kilometers are
+/// equal, but the length depends on the latitude.
+pub fn calculate_tile_side_length_km(latitude_deg: f64, zoom_level: u8) -> f64 {
+    let n = 2.0_f64.powi(zoom_level as i32);
+    EARTH_CIRCUMFERENCE_KM * latitude_deg.to_radians().cos() / n
 }
 
-/// Latitude value for longitude correction.
-/// Hanko + (Nuorgam - Hanko) / 2
-const LATITUDE_FOR_LONGITUDE_CORRECTION: f64 = 59.8 + (70.1 - 59.8) / 2.0;
-
-/// OpenStreetMap zoom levels and map tile side length in kilometers.
-/// Data is from GitHub Codepilot.
-pub const ZOOM_LEVEL_AND_TILE_LENGTH: &[(u8, f64)] = &[
-    (9, 305.0),
-    (10, 153.0),
-    (11, 76.5),
-    (12, 38.2),
-    (13, 19.1),
-    (14, 9.55),
-    (15, 4.77),
-    (16, 2.39),
-    (17, 1.19),
-];
-
-fn find_nearest_zoom_level(square_km: NonZeroU8) -> (u8, f64) {
+/// Find zoom level which tile side length at latitude `reference_latitude`
+/// is nearest to `square_km`.
+fn find_nearest_zoom_level(square_km: NonZeroU8, reference_latitude: f64) -> u8 {
     let square_km = square_km.get() as f64;
-    let (mut nearest_zoom_level, mut nearest_distance) = ZOOM_LEVEL_AND_TILE_LENGTH[0];
-    let mut nearest_tile_length = nearest_distance;
-    for (zoom_level, tile_length) in ZOOM_LEVEL_AND_TILE_LENGTH {
+    let mut nearest_zoom_level = *SUPPORTED_ZOOM_LEVELS.start();
+    let mut nearest_distance = f64::MAX;
+    for zoom_level in SUPPORTED_ZOOM_LEVELS {
+        let tile_length = calculate_tile_side_length_km(reference_latitude, zoom_level);
         let distance = (square_km - tile_length).abs();
         if distance < nearest_distance {
             nearest_distance = distance;
-            nearest_zoom_level = *zoom_level;
-            nearest_tile_length = *tile_length;
+            nearest_zoom_level = zoom_level;
         }
     }
-    (nearest_zoom_level, nearest_tile_length)
+    nearest_zoom_level
 }
 
 // https://wiki.openstreetmap.org/wiki/Slippy_map_tilenames#Lon./lat._to_tile_numbers
 // n = 2 ^ zoom
 // xtile = n * ((lon_deg + 180) / 360)
 // ytile = n * (1 - (log(tan(lat_rad) + sec(lat_rad)) / π)) / 2
+// lat_rad = arctan(sinh(π * (1 - 2 * ytile / n)))
 
 fn calculate_tile_x(longitude_deg: f64, zoom_level: u8) -> u32 {
     let n = 2.0_f64.powi(zoom_level as i32);
@@ -59,39 +60,107 @@ fn calculate_tile_x(longitude_deg: f64, zoom_level: u8) -> u32 {
 
 fn calculate_tile_y(latitude_deg: f64, zoom_level: u8) -> u32 {
     let n = 2.0_f64.powi(zoom_level as i32);
-    let latitude_rad = latitude_deg.to_radians();
+    let latitude_rad = latitude_deg
+        .clamp(-LocationConfig::MAX_LATITUDE, LocationConfig::MAX_LATITUDE)
+        .to_radians();
     let y = n
         * (1.0 - (latitude_rad.tan() + (1.0 / latitude_rad.cos())).ln() / std::f64::consts::PI)
         / 2.0;
     y as u32
 }
 
+fn calculate_tile_y_latitude(tile_y: f64, zoom_level: u8) -> f64 {
+    let n = 2.0_f64.powi(zoom_level as i32);
+    (std::f64::consts::PI * (1.0 - 2.0 * tile_y / n))
+        .sinh()
+        .atan()
+        .to_degrees()
+}
+
+/// Location index row info
+#[derive(Debug, Clone, Copy)]
+pub struct IndexRowInfo {
+    /// Row index
+    pub y: u16,
+    /// Latitude of the row center
+    pub latitude: f64,
+    /// Index cell width and height in kilometers
+    pub cell_side_length_km: f64,
+}
+
+/// Converts coordinates to location index keys.
+///
+/// Index cells are OpenStreetMap (Web Mercator) tiles, so the cells are
+/// squares but the cell side length in kilometers depends on the row
+/// latitude. Distances are converted to index keys using the latitude of the
+/// location which makes the distance filters work in every region.
 #[derive(Debug)]
 pub struct CoordinateManager {
     pub config: LocationConfig,
-    pub longitude_one_km_in_degrees: f64,
     pub zoom_level: u8,
-    pub tile_side_length_km: f64,
 }
 
 impl CoordinateManager {
     pub fn new(config: LocationConfig) -> Self {
-        let (zoom_level, tile_side_length_km) =
-            find_nearest_zoom_level(config.index_cell_square_km);
-        Self {
-            zoom_level,
-            tile_side_length_km,
-            config,
-            longitude_one_km_in_degrees: calculate_longitude_one_km_in_degrees(),
+        let zoom_level =
+            find_nearest_zoom_level(config.index_cell_square_km, config.reference_latitude());
+        Self::new_with_zoom_level(config, zoom_level)
+    }
+
+    /// Zoom level is decreased or increased if the index area would be too
+    /// large or small with the requested zoom level.
+    pub fn new_with_zoom_level(config: LocationConfig, zoom_level: u8) -> Self {
+        let mut manager = Self { config, zoom_level };
+        while manager.zoom_level > *SUPPORTED_ZOOM_LEVELS.start()
+            && (manager.height_in_tiles() > MAX_INDEX_SIDE_TILES
+                || manager.width_in_tiles() > MAX_INDEX_SIDE_TILES)
+        {
+            manager.zoom_level -= 1;
         }
+        while manager.zoom_level < *SUPPORTED_ZOOM_LEVELS.end()
+            && (manager.height_in_tiles() < MIN_INDEX_SIDE_TILES
+                || manager.width_in_tiles() < MIN_INDEX_SIDE_TILES)
+        {
+            manager.zoom_level += 1;
+        }
+        manager
     }
 
     pub fn zoom_level(&self) -> u8 {
         self.zoom_level
     }
 
-    pub fn tile_side_length_km(&self) -> f64 {
-        self.tile_side_length_km
+    /// Index cell side length in kilometers at specific index row.
+    pub fn row_info(&self, y: u16) -> IndexRowInfo {
+        let y_start = calculate_tile_y(self.config.latitude_top_left, self.zoom_level);
+        // Index row 0 contains two tile rows, so use the second one as
+        // the row center location.
+        let tile_y_center = (y_start + 1 + y as u32) as f64 + 0.5;
+        let latitude = calculate_tile_y_latitude(tile_y_center, self.zoom_level);
+        IndexRowInfo {
+            y,
+            latitude,
+            cell_side_length_km: calculate_tile_side_length_km(latitude, self.zoom_level),
+        }
+    }
+
+    /// Info for every index row from top to bottom.
+    pub fn row_infos(&self) -> impl Iterator<Item = IndexRowInfo> + '_ {
+        (0..self.height()).map(|y| self.row_info(y))
+    }
+
+    /// Min and max index cell side length in kilometers.
+    pub fn cell_side_length_range_km(&self) -> (f64, f64) {
+        let first = self.row_info(0).cell_side_length_km;
+        let last = self.row_info(self.height() - 1).cell_side_length_km;
+        let mut min = first.min(last);
+        let mut max = first.max(last);
+        // Index might contain the equator
+        if self.config.latitude_top_left > 0.0 && self.config.latitude_bottom_right < 0.0 {
+            max = calculate_tile_side_length_km(0.0, self.zoom_level);
+        }
+        min = min.min(max);
+        (min, max)
     }
 
     // Max y tile number of the index area.
@@ -104,14 +173,22 @@ impl CoordinateManager {
         calculate_tile_x(self.config.longitude_bottom_right, self.zoom_level)
     }
 
-    pub fn height(&self) -> u16 {
+    fn height_in_tiles(&self) -> u32 {
         let y_start = calculate_tile_y(self.config.latitude_top_left, self.zoom_level);
-        u32::max(1, self.y_max_tile() - y_start) as u16
+        u32::max(1, self.y_max_tile().saturating_sub(y_start))
     }
 
-    pub fn width(&self) -> u16 {
+    fn width_in_tiles(&self) -> u32 {
         let x_start = calculate_tile_x(self.config.longitude_top_left, self.zoom_level);
-        u32::max(1, self.x_max_tile() - x_start) as u16
+        u32::max(1, self.x_max_tile().saturating_sub(x_start))
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height_in_tiles() as u16
+    }
+
+    pub fn width(&self) -> u16 {
+        self.width_in_tiles() as u16
     }
 
     pub fn to_index_area(
@@ -123,11 +200,12 @@ impl CoordinateManager {
     ) -> LocationIndexArea {
         let profile_location = self.location_to_index_key(location);
 
-        let area_inner = min_distance
-            .map(|min_distance| self.create_index_area(location, min_distance.value, index));
+        let area_inner = min_distance.map(|min_distance| {
+            self.create_index_area(location, min_distance.value, DistanceLimit::Min, index)
+        });
 
         let area_outer = if let Some(max_distance) = max_distance {
-            self.create_index_area(location, max_distance.value, index)
+            self.create_index_area(location, max_distance.value, DistanceLimit::Max, index)
         } else {
             IndexArea::max_area(index)
         };
@@ -139,12 +217,33 @@ impl CoordinateManager {
         &self,
         location: LocationInternal,
         distance: i16,
+        limit: DistanceLimit,
         index: &LocationIndex,
     ) -> IndexArea {
         let distance = distance as f64;
-        let top_left = self.location_to_index_key(location.move_kilometers(distance, -distance));
+        let top = location.move_kilometers(distance, 0.0);
+        let bottom = location.move_kilometers(-distance, 0.0);
+        let (nearest_to_pole, nearest_to_equator) =
+            if top.latitude().abs() > bottom.latitude().abs() {
+                (top, bottom)
+            } else {
+                (bottom, top)
+            };
+        // Longitude degrees per kilometer depends on latitude.
+        // Max distance area uses the row nearest to the pole, so that
+        // the area contains every location inside the distance. Min distance
+        // area uses the row nearest to the equator, so that the area
+        // does not contain locations outside the distance.
+        let width_row = match limit {
+            DistanceLimit::Min => nearest_to_equator,
+            DistanceLimit::Max => nearest_to_pole,
+        };
+        let left = width_row.move_kilometers(0.0, -distance);
+        let right = width_row.move_kilometers(0.0, distance);
+        let top_left =
+            self.location_to_index_key(LocationInternal::new(top.latitude(), left.longitude()));
         let bottom_right =
-            self.location_to_index_key(location.move_kilometers(-distance, distance));
+            self.location_to_index_key(LocationInternal::new(bottom.latitude(), right.longitude()));
         IndexArea::new(top_left, bottom_right, index)
     }
 
@@ -194,6 +293,12 @@ impl CoordinateManager {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+enum DistanceLimit {
+    Min,
+    Max,
+}
+
 /// The area is not located on the index border.
 #[derive(Debug, Clone, Default)]
 pub struct IndexArea {
@@ -345,4 +450,37 @@ mod test {
         let manager = manager();
         assert_eq!(manager.calculate_index_x_key(10.0), manager.width() - 1);
     }
+
+    #[test]
+    fn cell_side_length_is_smaller_near_pole() {
+        let manager = CoordinateManager::new(LocationConfig {
+            latitude_top_left: 70.0,
+            longitude_top_left: 0.0,
+            latitude_bottom_right: 30.0,
+            longitude_bottom_right: 10.0,
+            index_cell_square_km: NonZeroU8::new(10).unwrap(),
+        });
+        let top = manager.row_info(0);
+        let bottom = manager.row_info(manager.height() - 1);
+        assert!(top.latitude > bottom.latitude);
+        assert!(top.cell_side_length_km < bottom.cell_side_length_km);
+    }
+
+    #[test]
+    fn zoom_level_selection_uses_region_latitude() {
+        let config = |latitude_top_left, latitude_bottom_right| LocationConfig {
+            latitude_top_left,
+            longitude_top_left: 0.0,
+            latitude_bottom_right,
+            longitude_bottom_right: 10.0,
+            index_cell_square_km: NonZeroU8::new(10).unwrap(),
+        };
+        let equator = CoordinateManager::new(config(5.0, -5.0));
+        let north = CoordinateManager::new(config(70.0, 65.0));
+        assert!(equator.zoom_level() > north.zoom_level());
+        let (_, equator_max) = equator.cell_side_length_range_km();
+        let (_, north_max) = north.cell_side_length_range_km();
+        assert!((equator_max - 10.0).abs() < 5.0);
+        assert!((north_max - 10.0).abs() < 5.0);
+    }
 }
@@ -4,7 +4,7 @@ use config::file::LocationConfig;
 use model_server_data::CellData;
 use simple_backend_utils::consts::KIB_IN_BYTES;
 
-use super::coordinates::{CoordinateManager, ZOOM_LEVEL_AND_TILE_LENGTH};
+use super::coordinates::{CoordinateManager, SUPPORTED_ZOOM_LEVELS};
 
 pub struct LocationIndexInfoCreator {
     config: LocationConfig,
@@ -16,18 +16,13 @@ impl LocationIndexInfoCreator {
     }
 
     pub fn create_one(&self, index_cell_square_km: NonZeroU8) -> String {
-        self.create_one_internal(index_cell_square_km, false)
+        let mut location = self.config.clone();
+        location.index_cell_square_km = index_cell_square_km;
+        Self::create_info(&CoordinateManager::new(location), false)
     }
 
     #[allow(clippy::format_in_format_args)]
-    fn create_one_internal(
-        &self,
-        index_cell_square_km: NonZeroU8,
-        whitespace_padding: bool,
-    ) -> String {
-        let mut location = self.config.clone();
-        location.index_cell_square_km = index_cell_square_km;
-        let coordinates = CoordinateManager::new(location);
+    fn create_info(coordinates: &CoordinateManager, whitespace_padding: bool) -> String {
         let (width, height): (NonZeroU16, NonZeroU16) = (
             coordinates.width().try_into().unwrap(),
             coordinates.height().try_into().unwrap(),
@@ -36,10 +31,8 @@ impl LocationIndexInfoCreator {
         let size = format!("Location index size: {width}x{height}, ");
         let bytes = format!("bytes: {}, ", format_size_in_bytes(byte_count));
         let zoom = format!("zoom: {}, ", coordinates.zoom_level());
-        let len = format!(
-            "tile side length: {:.2} km",
-            coordinates.tile_side_length_km()
-        );
+        let (min, max) = coordinates.cell_side_length_range_km();
+        let len = format!("cell side length: {min:.2}-{max:.2} km");
         if whitespace_padding {
             format!("{size:<35}{bytes:<20}{zoom:<10}{len}",)
         } else {
@@ -47,19 +40,18 @@ impl LocationIndexInfoCreator {
         }
     }
 
+    /// Info for every supported zoom level
     pub fn create_all(&self) -> String {
         let mut info = String::new();
-        for (_, tile_length) in ZOOM_LEVEL_AND_TILE_LENGTH {
-            let tile_length_u64 = *tile_length as u64;
-            let converted =
-                TryInto::<u8>::try_into(tile_length_u64).and_then(TryInto::<NonZeroU8>::try_into);
-            match converted {
-                Ok(length) => {
-                    info.push_str(&self.create_one_internal(length, true));
-                    info.push('\n');
-                }
-                Err(_) => continue,
+        for zoom_level in SUPPORTED_ZOOM_LEVELS {
+            let coordinates =
+                CoordinateManager::new_with_zoom_level(self.config.clone(), zoom_level);
+            if coordinates.zoom_level() != zoom_level {
+                // Index area is too small for the zoom level
+                continue;
             }
+            info.push_str(&Self::create_info(&coordinates, true));
+            info.push('\n');
         }
 
         // Pop final newline
@@ -67,6 +59,20 @@ impl LocationIndexInfoCreator {
 
         info
     }
+
+    /// Cell side length for every row of the configured location index
+    pub fn create_rows(&self) -> String {
+        let coordinates = CoordinateManager::new(self.config.clone());
+        let mut info = Self::create_info(&coordinates, false);
+        for row in coordinates.row_infos() {
+            info.push('\n');
+            info.push_str(&format!(
+                "row: {:<6} latitude: {:<8.3} cell side length: {:.2} km",
+                row.y, row.latitude, row.cell_side_length_km,
+            ));
+        }
+        info
+    }
 }
 
 fn format_size_in_bytes(size: usize) -> String {
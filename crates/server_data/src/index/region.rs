@@ -0,0 +1,559 @@
+//! Location index regions
+//!
+//! Every region has its own location index matrix and profile storage.
+//! Profiles are stored to the region which contains the profile location
+//! or if there is no such region, then to the nearest region.
+
+use std::{collections::HashMap, sync::Arc};
+
+use config::file::{LocationConfig, LocationRegionConfig};
+use error_stack::ResultExt;
+use model::{AccountId, ProfileLink, UnixTime};
+use model_server_data::{
+    LocationIndexKey, LocationIndexProfileData, LocationInternal, MaxDistanceKm, MinDistanceKm,
+    ProfileAttributesInternal, ProfileQueryMakerDetails,
+};
+use server_common::data::index::IndexError;
+use tokio::sync::RwLock;
+use tracing::info;
+
+use super::{
+    coordinates::{CoordinateManager, LocationIndexArea},
+    data::{IndexSize, LocationIndex},
+    info::LocationIndexInfoCreator,
+    profiles::ProfilesAtLocation,
+    read::LocationIndexIteratorState,
+    write::IndexUpdater,
+};
+
+/// Location index region ID. Value is the region position in the
+/// region config list.
+#[derive(Debug, Hash, PartialEq, Clone, Copy, Default, Eq)]
+pub struct LocationIndexRegionId(u8);
+
+impl LocationIndexRegionId {
+    fn index(&self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Location index key which also contains the region.
+#[derive(Debug, Hash, PartialEq, Clone, Copy, Default, Eq)]
+pub struct LocationIndexRegionKey {
+    pub region: LocationIndexRegionId,
+    pub key: LocationIndexKey,
+}
+
+pub(super) enum IteratorResultInternal {
+    NoProfiles,
+    TryAgain,
+    MatchingProfilesFound { profiles: Vec<ProfileLink> },
+}
+
+#[derive(Debug)]
+pub struct LocationIndexRegion {
+    name: String,
+    index: Arc<LocationIndex>,
+    profiles: RwLock<HashMap<LocationIndexKey, ProfilesAtLocation>>,
+    coordinates: CoordinateManager,
+}
+
+impl LocationIndexRegion {
+    fn new(config: LocationRegionConfig) -> Self {
+        let coordinates = CoordinateManager::new(config.location.clone());
+        let (width, height) = (
+            coordinates.width().try_into().unwrap(),
+            coordinates.height().try_into().unwrap(),
+        );
+
+        let index = LocationIndex::new(IndexSize::new(width), IndexSize::new(height)).into();
+
+        info!(
+            "Region {}: {}",
+            config.name,
+            LocationIndexInfoCreator::new(config.location.clone())
+                .create_one(config.location.index_cell_square_km),
+        );
+
+        Self {
+            name: config.name,
+            index,
+            coordinates,
+            profiles: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn index(&self) -> &Arc<LocationIndex> {
+        &self.index
+    }
+
+    fn config(&self) -> &LocationConfig {
+        &self.coordinates.config
+    }
+
+    fn contains(&self, location: LocationInternal) -> bool {
+        let c = self.config();
+        (c.latitude_bottom_right..=c.latitude_top_left).contains(&location.latitude())
+            && (c.longitude_top_left..=c.longitude_bottom_right).contains(&location.longitude())
+    }
+
+    /// Distance from location to the nearest point of the region.
+    fn distance_km(&self, location: LocationInternal) -> f64 {
+        let c = self.config();
+        let nearest_point = LocationInternal::new(
+            location
+                .latitude()
+                .clamp(c.latitude_bottom_right, c.latitude_top_left),
+            location
+                .longitude()
+                .clamp(c.longitude_top_left, c.longitude_bottom_right),
+        );
+        location.distance_km(&nearest_point)
+    }
+
+    fn to_index_area(
+        &self,
+        location: LocationInternal,
+        min_distance: Option<MinDistanceKm>,
+        max_distance: Option<MaxDistanceKm>,
+    ) -> LocationIndexArea {
+        self.coordinates
+            .to_index_area(location, min_distance, max_distance, &self.index)
+    }
+
+    /// Iterate to next index cell which has profiles and get all matching
+    /// profiles.
+    pub(super) fn next_profiles_internal(
+        &self,
+        previous_iterator_state: LocationIndexIteratorState,
+        query_maker_details: &ProfileQueryMakerDetails,
+        profile_attributes: &ProfileAttributesInternal,
+        current_time: &UnixTime,
+    ) -> (LocationIndexIteratorState, IteratorResultInternal) {
+        let index = self.index.clone();
+        let (iterator, key) = {
+            let mut iterator = previous_iterator_state.into_iterator(index);
+            let key = iterator.next();
+            (iterator, key)
+        };
+        let result = match key {
+            None => IteratorResultInternal::NoProfiles,
+            Some(key) => match self.profiles.blocking_read().get(&key) {
+                // Possible data race occurred where profile was removed
+                // from the data storage when iterating the index.
+                None => IteratorResultInternal::TryAgain,
+                // TODO(perf): Currently all profiles in one index cell are
+                // sent to client, which might cause issues if everyone will
+                // set profile to same location.
+                Some(profiles) => {
+                    let matches: Vec<ProfileLink> = profiles.find_profiles(
+                        query_maker_details,
+                        profile_attributes,
+                        current_time,
+                    );
+                    if matches.is_empty() {
+                        IteratorResultInternal::TryAgain
+                    } else {
+                        IteratorResultInternal::MatchingProfilesFound { profiles: matches }
+                    }
+                }
+            },
+        };
+        (iterator.into(), result)
+    }
+
+    /// Move LocationIndexProfileData to another index location
+    pub(super) async fn update_profile_location(
+        &self,
+        account_id: AccountId,
+        previous_key: LocationIndexKey,
+        new_key: LocationIndexKey,
+    ) -> simple_backend_utils::Result<(), IndexError> {
+        if previous_key == new_key {
+            // No update needed. If return would not be here then
+            // if new_size == 0 check would make profile disappear.
+            return Ok(());
+        }
+
+        let mut profiles = self.profiles.write().await;
+        let data = match profiles.get_mut(&previous_key) {
+            Some(p) => {
+                let current_profile = p.remove(&account_id);
+                Some((current_profile, p.len()))
+            }
+            None => None,
+        };
+
+        if let Some((current_profile, new_size)) = data {
+            let mut updater = IndexUpdater::new(self.index.clone());
+
+            if let Some(profile) = current_profile {
+                match profiles.get_mut(&new_key) {
+                    Some(some_other_profiles_also) => {
+                        let update_index = some_other_profiles_also.is_empty();
+                        some_other_profiles_also.insert(account_id, profile);
+                        if update_index {
+                            drop(profiles);
+                            tokio::task::spawn_blocking(move || {
+                                updater.flag_cell_to_have_profiles(new_key)
+                            })
+                            .await
+                            .change_context(IndexError::ProfileIndex)?;
+                        }
+                    }
+                    None => {
+                        profiles.insert(new_key, ProfilesAtLocation::new(account_id, profile));
+                        drop(profiles);
+                        tokio::task::spawn_blocking(move || {
+                            updater.flag_cell_to_have_profiles(new_key)
+                        })
+                        .await
+                        .change_context(IndexError::ProfileIndex)?;
+                    }
+                }
+            } else {
+                // Drop before calling remove_profile_flag_from_cell, so
+                // reading will not be blocked that much.
+                drop(profiles);
+            }
+
+            if new_size == 0 {
+                let mut updater = IndexUpdater::new(self.index.clone());
+                tokio::task::spawn_blocking(move || {
+                    updater.remove_profile_flag_from_cell(previous_key);
+                })
+                .await
+                .change_context(IndexError::ProfileIndex)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Set LocationIndexProfileData to specific index location
+    pub(super) async fn update_profile_data(
+        &self,
+        account_id: AccountId,
+        profile_data: LocationIndexProfileData,
+        key: LocationIndexKey,
+    ) -> simple_backend_utils::Result<(), IndexError> {
+        let mut profiles = self.profiles.write().await;
+        match profiles.get_mut(&key) {
+            Some(some_other_profiles_also) => {
+                let update_index = some_other_profiles_also.is_empty();
+                some_other_profiles_also.insert(account_id, profile_data);
+                if update_index {
+                    drop(profiles);
+                    let mut updater = IndexUpdater::new(self.index.clone());
+                    tokio::task::spawn_blocking(move || updater.flag_cell_to_have_profiles(key))
+                        .await
+                        .change_context(IndexError::ProfileIndex)?;
+                }
+            }
+            None => {
+                profiles.insert(key, ProfilesAtLocation::new(account_id, profile_data));
+                drop(profiles);
+                let mut updater = IndexUpdater::new(self.index.clone());
+                tokio::task::spawn_blocking(move || updater.flag_cell_to_have_profiles(key))
+                    .await
+                    .change_context(IndexError::ProfileIndex)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove LocationIndexProfileData from specific index location
+    pub(super) async fn remove_profile_data(
+        &self,
+        account_id: AccountId,
+        key: LocationIndexKey,
+    ) -> simple_backend_utils::Result<Option<LocationIndexProfileData>, IndexError> {
+        let mut profiles = self.profiles.write().await;
+        let Some(some_other_profiles_also) = profiles.get_mut(&key) else {
+            return Ok(None);
+        };
+
+        let removed = some_other_profiles_also.remove(&account_id);
+
+        if removed.is_some() && some_other_profiles_also.is_empty() {
+            profiles.remove(&key);
+            drop(profiles);
+            let mut updater = IndexUpdater::new(self.index.clone());
+            tokio::task::spawn_blocking(move || updater.remove_profile_flag_from_cell(key))
+                .await
+                .change_context(IndexError::ProfileIndex)?;
+        }
+
+        Ok(removed)
+    }
+}
+
+/// All location index regions
+#[derive(Debug)]
+pub struct LocationIndexRegions {
+    regions: Vec<LocationIndexRegion>,
+}
+
+impl LocationIndexRegions {
+    /// Panics if region count is zero or larger than 256.
+    pub fn new(config: Vec<LocationRegionConfig>) -> Self {
+        if config.is_empty() || config.len() > LocationRegionConfig::MAX_REGION_COUNT {
+            panic!("Invalid location index region count {}", config.len());
+        }
+        Self {
+            regions: config.into_iter().map(LocationIndexRegion::new).collect(),
+        }
+    }
+
+    pub fn get(&self, id: LocationIndexRegionId) -> &LocationIndexRegion {
+        // Region ID is always valid as it is created from the region list
+        &self.regions[id.index()]
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (LocationIndexRegionId, &LocationIndexRegion)> {
+        self.regions
+            .iter()
+            .enumerate()
+            .map(|(i, r)| (LocationIndexRegionId(i as u8), r))
+    }
+
+    /// Region which contains the location or the nearest region
+    /// if location is not inside any region.
+    fn region_for_location(&self, location: LocationInternal) -> LocationIndexRegionId {
+        if let Some((id, _)) = self.iter().find(|(_, r)| r.contains(location)) {
+            return id;
+        }
+
+        self.iter()
+            .map(|(id, r)| (id, r.distance_km(location)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(id, _)| id)
+            .unwrap_or_default()
+    }
+
+    pub fn to_area(
+        &self,
+        location: LocationInternal,
+        min_distance: Option<MinDistanceKm>,
+        max_distance: Option<MaxDistanceKm>,
+    ) -> LocationIndexRegionsArea {
+        let profile_region = self.region_for_location(location);
+        let profile_area =
+            self.get(profile_region)
+                .to_index_area(location, min_distance, max_distance);
+
+        let mut other_areas: Vec<(f64, LocationIndexRegionArea)> = self
+            .iter()
+            .filter(|(id, _)| *id != profile_region)
+            .map(|(id, r)| (id, r, r.distance_km(location)))
+            .filter(|(_, _, distance)| {
+                max_distance
+                    .map(|max| *distance <= max.value as f64)
+                    .unwrap_or(true)
+            })
+            .map(|(id, r, distance)| {
+                let area = LocationIndexRegionArea {
+                    region: id,
+                    area: r.to_index_area(location, min_distance, max_distance),
+                };
+                (distance, area)
+            })
+            .collect();
+        other_areas.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut areas = vec![LocationIndexRegionArea {
+            region: profile_region,
+            area: profile_area,
+        }];
+        areas.extend(other_areas.into_iter().map(|(_, area)| area));
+
+        LocationIndexRegionsArea { location, areas }
+    }
+
+    pub fn new_iterator_state(
+        &self,
+        area: &LocationIndexRegionsArea,
+        random: bool,
+    ) -> LocationIndexRegionsIteratorState {
+        let Some(first) = area.areas.first() else {
+            return LocationIndexRegionsIteratorState::completed();
+        };
+        LocationIndexRegionsIteratorState {
+            areas: area.areas.clone(),
+            current_area: 0,
+            current: LocationIndexIteratorState::new(
+                &first.area,
+                random,
+                self.get(first.region).index(),
+            ),
+            random,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LocationIndexRegionArea {
+    region: LocationIndexRegionId,
+    area: LocationIndexArea,
+}
+
+/// Index areas from every region which is inside the max distance.
+#[derive(Debug, Clone)]
+pub struct LocationIndexRegionsArea {
+    location: LocationInternal,
+    /// The first area is from the region where the profile is located.
+    /// Other areas are sorted by region distance.
+    areas: Vec<LocationIndexRegionArea>,
+}
+
+impl LocationIndexRegionsArea {
+    /// Profile location in the index.
+    pub fn profile_location(&self) -> LocationIndexRegionKey {
+        self.areas
+            .first()
+            .map(|a| LocationIndexRegionKey {
+                region: a.region,
+                key: a.area.profile_location(),
+            })
+            .unwrap_or_default()
+    }
+
+    /// Area without distance limits
+    pub fn with_max_area(&self, regions: &LocationIndexRegions) -> Self {
+        regions.to_area(self.location, None, None)
+    }
+}
+
+impl Default for LocationIndexRegionsArea {
+    fn default() -> Self {
+        Self {
+            location: LocationInternal::new(0.0, 0.0),
+            areas: vec![],
+        }
+    }
+}
+
+/// Iterator state for iterating the profile location region and then
+/// other regions which are inside the max distance.
+#[derive(Debug, Clone)]
+pub struct LocationIndexRegionsIteratorState {
+    areas: Vec<LocationIndexRegionArea>,
+    current_area: usize,
+    current: LocationIndexIteratorState,
+    random: bool,
+}
+
+impl LocationIndexRegionsIteratorState {
+    pub fn completed() -> Self {
+        Self {
+            areas: vec![],
+            current_area: 0,
+            current: LocationIndexIteratorState::completed(),
+            random: false,
+        }
+    }
+
+    pub(super) fn current_region(&self) -> Option<LocationIndexRegionId> {
+        self.areas.get(self.current_area).map(|a| a.region)
+    }
+
+    pub(super) fn take_current(&mut self) -> LocationIndexIteratorState {
+        std::mem::replace(&mut self.current, LocationIndexIteratorState::completed())
+    }
+
+    pub(super) fn set_current(&mut self, state: LocationIndexIteratorState) {
+        self.current = state;
+    }
+
+    /// Move to next region. Returns false if all regions are iterated.
+    pub(super) fn next_region(&mut self, regions: &LocationIndexRegions) -> bool {
+        self.current_area = self.current_area.saturating_add(1);
+        match self.areas.get(self.current_area) {
+            Some(next) => {
+                self.current = LocationIndexIteratorState::new(
+                    &next.area,
+                    self.random,
+                    regions.get(next.region).index(),
+                );
+                true
+            }
+            None => {
+                self.current = LocationIndexIteratorState::completed();
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU8;
+
+    use super::*;
+
+    fn region(name: &str, latitude_top_left: f64, longitude_top_left: f64) -> LocationRegionConfig {
+        LocationRegionConfig {
+            name: name.to_string(),
+            location: LocationConfig {
+                latitude_top_left,
+                longitude_top_left,
+                latitude_bottom_right: latitude_top_left - 5.0,
+                longitude_bottom_right: longitude_top_left + 5.0,
+                index_cell_square_km: NonZeroU8::new(50).unwrap(),
+            },
+        }
+    }
+
+    /// Regions are next to each other.
+    fn regions() -> LocationIndexRegions {
+        LocationIndexRegions::new(vec![
+            region("first", 60.0, 10.0),
+            region("second", 60.0, 15.0),
+            region("far", 20.0, 100.0),
+        ])
+    }
+
+    #[test]
+    fn location_inside_region() {
+        let regions = regions();
+        let location = LocationInternal::new(57.0, 17.0);
+        assert_eq!(
+            regions.region_for_location(location),
+            LocationIndexRegionId(1)
+        );
+    }
+
+    #[test]
+    fn location_outside_regions_uses_nearest_region() {
+        let regions = regions();
+        let location = LocationInternal::new(10.0, 101.0);
+        assert_eq!(
+            regions.region_for_location(location),
+            LocationIndexRegionId(2)
+        );
+    }
+
+    #[test]
+    fn max_distance_limits_regions() {
+        let regions = regions();
+        let location = LocationInternal::new(57.0, 14.9);
+        let area = regions.to_area(location, None, Some(MaxDistanceKm { value: 100 }));
+        let region_ids: Vec<_> = area.areas.iter().map(|a| a.region).collect();
+        assert_eq!(
+            region_ids,
+            vec![LocationIndexRegionId(0), LocationIndexRegionId(1)]
+        );
+    }
+
+    #[test]
+    fn without_max_distance_all_regions_are_included() {
+        let regions = regions();
+        let location = LocationInternal::new(57.0, 14.9);
+        let area = regions.to_area(location, None, None);
+        assert_eq!(area.areas.len(), 3);
+    }
+}
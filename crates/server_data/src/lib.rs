@@ -15,6 +15,7 @@ pub mod app;
 pub mod cache;
 pub mod content_processing;
 pub mod data_export;
+pub mod data_import;
 pub mod data_reset;
 pub mod db_manager;
 pub mod demo;
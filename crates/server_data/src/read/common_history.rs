@@ -1,5 +1,8 @@
 use database::history::read::GetDbHistoryReadCommandsCommon;
-use model::{GetIpCountryStatisticsResult, GetIpCountryStatisticsSettings};
+use model::{
+    AdminAuditLogPage, AdminAuditLogQuery, GetIpCountryStatisticsResult,
+    GetIpCountryStatisticsSettings,
+};
 use server_common::data::IntoDataError;
 
 use crate::{
@@ -29,4 +32,13 @@ impl ReadCommandsCommonHistory<'_> {
         .await
         .into_error()
     }
+
+    pub async fn admin_audit_log_page(
+        &self,
+        query: AdminAuditLogQuery,
+    ) -> Result<AdminAuditLogPage, DataError> {
+        self.db_read_history(move |mut cmds| cmds.common_history().admin_audit_log_page(query))
+            .await
+            .into_error()
+    }
 }
@@ -1,5 +1,6 @@
 use std::net::SocketAddr;
 
+use config::file::AdminTotpConfig;
 use model::{
     AccessToken, AccessTokenType, AccountId, AccountIdInternal, AccountState, Permissions,
 };
@@ -30,9 +31,10 @@ impl<'a> AccessTokenManager<'a> {
         &self,
         token: &AccessToken,
         connection: SocketAddr,
+        totp_config: &AdminTotpConfig,
     ) -> Option<(AccountIdInternal, Permissions, AccountState)> {
         self.cache
-            .access_token_and_ip_is_valid(token, connection)
+            .access_token_and_ip_is_valid(token, connection, totp_config)
             .await
     }
 }
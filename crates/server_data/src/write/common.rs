@@ -215,7 +215,7 @@ impl<I: InternalWriting> UpdateLocationIndexVisibility for I {
 
                 Ok::<
                     (
-                        model_server_data::LocationIndexKey,
+                        crate::index::region::LocationIndexRegionKey,
                         model_server_data::LocationIndexProfileData,
                     ),
                     error_stack::Report<CacheError>,
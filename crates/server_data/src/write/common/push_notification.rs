@@ -1,5 +1,8 @@
 use database::current::{read::GetDbReadCommandsCommon, write::GetDbWriteCommandsCommon};
-use model::{AccountIdInternal, PushNotificationDeviceToken, PushNotificationEncryptionKey};
+use model::{
+    AccountIdInternal, PushNotificationDeviceToken, PushNotificationEncryptionKey,
+    PushNotificationProvider,
+};
 use tracing::info;
 
 use crate::{
@@ -40,6 +43,7 @@ impl WriteCommandsCommonPushNotification<'_> {
         &self,
         id: AccountIdInternal,
         token: PushNotificationDeviceToken,
+        provider: PushNotificationProvider,
     ) -> Result<PushNotificationEncryptionKey, DataError> {
         let token_clone = token.clone();
         let token = db_transaction!(self, move |mut cmds| {
@@ -48,6 +52,7 @@ impl WriteCommandsCommonPushNotification<'_> {
                 .update_push_notification_device_token_and_generate_new_notification_token(
                     id,
                     token_clone,
+                    provider,
                 )
         })?;
 
@@ -1,5 +1,7 @@
 use database::current::{read::GetDbReadCommandsCommon, write::GetDbWriteCommandsCommon};
-use model::{AccountIdInternal, ProcessReport, ReportContent, ReportType, ReportTypeInternal};
+use model::{
+    AccountIdInternal, ProcessReport, ReportContent, ReportType, ReportTypeInternal, UnixTime,
+};
 use simple_backend_utils::IntoReportFromString;
 
 use crate::{
@@ -59,7 +61,9 @@ impl WriteCommandsCommonAdminReport<'_> {
             std::collections::HashSet::new();
         for report in &reports {
             let creator = self.to_account_id_internal(report.creator).await?;
-            creators_set.insert(creator);
+            if !report.report_type.is_ban_evasion() {
+                creators_set.insert(creator);
+            }
             let target = self.to_account_id_internal(report.target).await?;
             self.process_single_report(
                 moderator_id,
@@ -96,4 +100,52 @@ impl WriteCommandsCommonAdminReport<'_> {
 
         Ok(reporters_to_ban)
     }
+
+    /// Create ban evasion reports for accounts which are created after
+    /// `created_after` and are linked to some banned account.
+    /// Only one report is created for each account pair.
+    ///
+    /// Returns true if new reports were created.
+    pub async fn report_ban_evasion_candidates(
+        &self,
+        created_after: UnixTime,
+    ) -> Result<bool, DataError> {
+        let config = self.config_arc();
+        let candidates = self
+            .db_read(move |mut cmds| {
+                cmds.common_admin()
+                    .ban_evasion()
+                    .find_ban_evasion_candidates(created_after, &config)
+            })
+            .await?;
+
+        let mut new_reports = false;
+        for candidate in candidates {
+            let banned_account = candidate.banned_account;
+            let target = candidate.target;
+            let current_reports = self
+                .db_read(move |mut cmds| {
+                    cmds.common().report().get_all_detailed_reports(
+                        banned_account,
+                        target,
+                        ReportTypeInternal::BanEvasion,
+                    )
+                })
+                .await?;
+            if !current_reports.is_empty() {
+                continue;
+            }
+
+            db_transaction!(self, move |mut cmds| {
+                cmds.common_admin().report().insert_ban_evasion_report(
+                    banned_account,
+                    target,
+                    &candidate.evidence,
+                )
+            })?;
+            new_reports = true;
+        }
+
+        Ok(new_reports)
+    }
 }
@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 
 use database::history::write::GetDbHistoryWriteCommandsCommon;
+use model::NewAdminAuditLogEntry;
 use simple_backend_model::{IpCountryCounters, IpCountryKey, MetricKey, PerfMetricValueArea};
+use tracing::error;
 
 use crate::{
     DataError, db_transaction_history, define_cmd_wrapper_write, result::Result,
@@ -28,4 +30,16 @@ impl WriteCommandsCommonHistory<'_> {
             cmds.common_history().write_ip_country_data(data)
         })
     }
+
+    /// The audit log is in the history database, so the entry can't be
+    /// written in the same transaction as the admin action. The action
+    /// is already done when this runs, so errors are only logged.
+    pub async fn write_admin_audit_log_entry(&self, entry: NewAdminAuditLogEntry) {
+        let result: Result<(), DataError> = db_transaction_history!(self, move |mut cmds| {
+            cmds.common_history().write_admin_audit_log_entry(entry)
+        });
+        if let Err(e) = result {
+            error!("Writing admin audit log entry failed: {:?}", e);
+        }
+    }
 }
@@ -9,7 +9,7 @@ use std::{
 
 use axum::body::BodyDataStream;
 use futures::Future;
-use model::{AccountId, AccountIdInternal, ContentQualityVariant, ProfileLink};
+use model::{AccountId, AccountIdInternal, ContentImageFormat, ContentQualityVariant, ProfileLink};
 use model_server_data::{
     AutomaticProfileSearchIteratorSessionId, AutomaticProfileSearchIteratorSessionIdInternal,
     ProfileIteratorSessionId, ProfileIteratorSessionIdInternal,
@@ -306,6 +306,23 @@ impl<'a> WriteCommandsConcurrent<'a> {
         if let Err(e) = tmp_img_very_low.overwrite_and_remove_if_exists().await {
             warn!("tmp_img_very_low removing failed {:?}", e)
         }
+        for tmp_img in [
+            &tmp_img_high,
+            &tmp_img_medium,
+            &tmp_img_low,
+            &tmp_img_lower,
+            &tmp_img_very_low,
+        ] {
+            for format in [ContentImageFormat::Webp, ContentImageFormat::Avif] {
+                if let Err(e) = tmp_img
+                    .format_variant(format)
+                    .overwrite_and_remove_if_exists()
+                    .await
+                {
+                    warn!("tmp_img {:?} removing failed {:?}", format, e)
+                }
+            }
+        }
 
         Ok(UploadInfo {
             tmp_raw_img,
@@ -483,9 +500,7 @@ impl<'a> WriteCommandsConcurrent<'a> {
                 let area = if distance_filters_enabled {
                     &p.location.current_position
                 } else {
-                    &p.location
-                        .current_position
-                        .with_max_area(self.location.index())
+                    &self.location.with_max_area(&p.location.current_position)
                 };
                 let next_state = self.location.new_iterator_state(area, false);
                 p.automatic_profile_search.current_iterator = next_state;
@@ -4,8 +4,8 @@ use model::UnixTime;
 use model_account::{
     AccountGlobalState, AccountId, AccountIdInternal, AccountVerificationDataInternal,
     AppleAccountId, BotAccount, EmailAddress, EmailAddressState, EmailAddressStateInternal,
-    EmailChange, EmailLoginTokens, GetBotsResult, GoogleAccountId, SignInWithHistoryEntry,
-    SignInWithInfo,
+    EmailChange, EmailLoginTokens, GetBotsResult, GoogleAccountId, OidcAccountId,
+    SignInWithHistoryEntry, SignInWithInfo,
 };
 use model_server_state::DemoAccountId;
 use server_data::{
@@ -18,6 +18,7 @@ pub mod delete;
 pub mod email;
 pub mod news;
 pub mod notification;
+pub mod totp;
 
 define_cmd_wrapper_read!(ReadCommandsAccount);
 
@@ -45,6 +46,10 @@ impl<'a> ReadCommandsAccount<'a> {
     pub fn notification(self) -> notification::ReadCommandsAccountNotification<'a> {
         notification::ReadCommandsAccountNotification::new(self.0)
     }
+
+    pub fn totp(self) -> totp::ReadCommandsAccountTotp<'a> {
+        totp::ReadCommandsAccountTotp::new(self.0)
+    }
 }
 
 impl ReadCommandsAccount<'_> {
@@ -182,6 +187,20 @@ impl ReadCommandsAccount<'_> {
         .into_error()
     }
 
+    pub async fn oidc_account_id_to_account_id(
+        &self,
+        provider: String,
+        id: OidcAccountId,
+    ) -> Result<Option<AccountIdInternal>, DataError> {
+        self.db_read(move |mut cmds| {
+            cmds.account()
+                .sign_in_with()
+                .oidc_account_id_to_account_id(&provider, id)
+        })
+        .await
+        .into_error()
+    }
+
     pub async fn demo_account_owned_account_ids(
         &self,
         id: DemoAccountId,
@@ -0,0 +1,79 @@
+use database_account::current::read::GetDbReadCommandsAccount;
+use model::Permissions;
+use model_account::{AccountIdInternal, TotpState};
+use server_data::{
+    DataError, IntoDataError, app::GetConfig, cache::CacheReadCommon, define_cmd_wrapper_read,
+    read::DbRead, result::Result,
+};
+
+define_cmd_wrapper_read!(ReadCommandsAccountTotp);
+
+impl ReadCommandsAccountTotp<'_> {
+    pub async fn totp_state(&self, id: AccountIdInternal) -> Result<TotpState, DataError> {
+        let (enabled, recovery_codes_left) = self
+            .db_read(move |mut cmds| {
+                let enabled = cmds
+                    .account()
+                    .totp()
+                    .totp(id)?
+                    .map(|v| v.enabled)
+                    .unwrap_or_default();
+                let recovery_codes_left = cmds.account().totp().totp_recovery_code_count(id)?;
+                Ok((enabled, recovery_codes_left))
+            })
+            .await?;
+
+        let config = self.config().admin_totp();
+        let (verification_required, verified) = self
+            .read_cache_common(id, |entry| {
+                let verification_required = config
+                    .required_verification_validity(entry.other_shared_state.is_bot())
+                    .is_some()
+                    && entry.account.permissions() != Permissions::default();
+                let verified =
+                    entry.totp_verification_is_valid(config.verification_validity_duration);
+                Ok((verification_required, verified))
+            })
+            .await
+            .into_error()?;
+
+        Ok(TotpState {
+            enabled,
+            recovery_codes_left,
+            verification_required,
+            verified,
+        })
+    }
+
+    /// Returns `false` if the server requires TOTP verification for
+    /// the account's admin permissions and TOTP is not enabled.
+    pub async fn admin_permissions_can_be_granted(
+        &self,
+        id: AccountIdInternal,
+    ) -> Result<bool, DataError> {
+        let config = self.config().admin_totp();
+        let totp_required = self
+            .read_cache_common(id, |entry| {
+                Ok(config
+                    .required_verification_validity(entry.other_shared_state.is_bot())
+                    .is_some())
+            })
+            .await
+            .into_error()?;
+        if !totp_required {
+            return Ok(true);
+        }
+
+        self.db_read(move |mut cmds| {
+            let enabled = cmds
+                .account()
+                .totp()
+                .totp(id)?
+                .map(|v| v.enabled)
+                .unwrap_or_default();
+            Ok(enabled)
+        })
+        .await
+        .into_error()
+    }
+}
@@ -5,6 +5,7 @@ pub mod custom_email;
 pub mod login;
 pub mod news;
 pub mod permissions;
+pub mod search;
 define_cmd_wrapper_read!(ReadCommandsAccountAdmin);
 
 impl<'a> ReadCommandsAccountAdmin<'a> {
@@ -27,4 +28,8 @@ impl<'a> ReadCommandsAccountAdmin<'a> {
     pub fn permissions(self) -> permissions::ReadCommandsAccountPermissionsAdmin<'a> {
         permissions::ReadCommandsAccountPermissionsAdmin::new(self.0)
     }
+
+    pub fn search(self) -> search::ReadCommandsAccountSearchAdmin<'a> {
+        search::ReadCommandsAccountSearchAdmin::new(self.0)
+    }
 }
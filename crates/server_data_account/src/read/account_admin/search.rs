@@ -0,0 +1,18 @@
+use database_account::current::read::GetDbReadCommandsAccount;
+use model_account::{AdminAccountSearchQuery, AdminAccountSearchResult};
+use server_data::{
+    DataError, IntoDataError, define_cmd_wrapper_read, read::DbRead, result::Result,
+};
+
+define_cmd_wrapper_read!(ReadCommandsAccountSearchAdmin);
+
+impl ReadCommandsAccountSearchAdmin<'_> {
+    pub async fn search_accounts(
+        &self,
+        query: AdminAccountSearchQuery,
+    ) -> Result<AdminAccountSearchResult, DataError> {
+        self.db_read(move |mut cmds| cmds.account_admin().search().search_accounts(&query))
+            .await
+            .into_error()
+    }
+}
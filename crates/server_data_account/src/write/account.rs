@@ -6,7 +6,9 @@ use database_account::current::write::GetDbWriteCommandsAccount;
 use delete::WriteCommandsAccountDelete;
 use email::WriteCommandsAccountEmail;
 use model::UnixTime;
-use model_account::{AccountIdInternal, AccountVerificationErrorFlagsValue, VerificationMethod};
+use model_account::{
+    AccountId, AccountIdInternal, AccountVerificationErrorFlagsValue, VerificationMethod,
+};
 use model_server_state::DemoAccountId;
 use news::WriteCommandsAccountNews;
 use server_data::{
@@ -24,6 +26,7 @@ pub mod news;
 pub mod notification;
 pub mod report;
 pub mod sign_in_with;
+pub mod totp;
 
 #[derive(Debug, Clone, Copy)]
 pub struct IncrementAdminAccessGrantedCount;
@@ -62,6 +65,10 @@ impl<'a> WriteCommandsAccount<'a> {
     pub fn sign_in_with(self) -> sign_in_with::WriteCommandsAccountSignInWith<'a> {
         sign_in_with::WriteCommandsAccountSignInWith::new(self.0)
     }
+
+    pub fn totp(self) -> totp::WriteCommandsAccountTotp<'a> {
+        totp::WriteCommandsAccountTotp::new(self.0)
+    }
 }
 
 impl WriteCommandsAccount<'_> {
@@ -152,6 +159,20 @@ impl WriteCommandsAccount<'_> {
         })
     }
 
+    /// Use the preferred account ID if it is not already used.
+    pub async fn get_next_unique_account_id_with_preferred_id(
+        &self,
+        preferred: AccountId,
+    ) -> Result<AccountIdInternal, DataError> {
+        db_transaction!(self, move |mut cmds| {
+            if let Some(id) = cmds.account().data().new_account_id_if_unused(preferred)? {
+                Ok(id)
+            } else {
+                cmds.account().data().new_unique_account_id()
+            }
+        })
+    }
+
     pub async fn set_account_verification_data(
         &self,
         id: AccountIdInternal,
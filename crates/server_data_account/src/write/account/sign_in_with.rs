@@ -1,6 +1,6 @@
 use database_account::current::write::GetDbWriteCommandsAccount;
 use model::{AccountIdInternal, UnixTime};
-use model_account::{AppleAccountId, GoogleAccountId};
+use model_account::{AppleAccountId, GoogleAccountId, OidcAccountId};
 use server_data::{
     DataError, db_transaction, define_cmd_wrapper_write, result::Result, write::DbTransaction,
 };
@@ -32,6 +32,19 @@ impl WriteCommandsAccountSignInWith<'_> {
         })
     }
 
+    /// The `oidc_id` tuple contains the provider name and the account ID.
+    pub async fn update_oidc_account_id(
+        &self,
+        id: AccountIdInternal,
+        oidc_id: Option<(String, OidcAccountId)>,
+    ) -> Result<(), DataError> {
+        db_transaction!(self, move |mut cmds| {
+            cmds.account()
+                .sign_in_with()
+                .update_oidc_account_id(id, oidc_id)
+        })
+    }
+
     pub async fn prune_sign_in_with_history(
         &self,
         retention_unix_time: UnixTime,
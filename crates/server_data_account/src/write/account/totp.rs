@@ -0,0 +1,225 @@
+use database::{DbWriteMode, DieselDatabaseError};
+use database_account::current::{read::GetDbReadCommandsAccount, write::GetDbWriteCommandsAccount};
+use model::{Permissions, UnixTime};
+use model_account::{AccountIdInternal, TotpEnrollmentResult};
+use server_data::{
+    DataError, IntoDataError,
+    app::GetConfig,
+    cache::{CacheReadCommon, CacheWriteCommon},
+    db_transaction, define_cmd_wrapper_write,
+    result::Result,
+    write::DbTransaction,
+};
+use utils::totp::{
+    RECOVERY_CODE_COUNT, generate_recovery_code, generate_totp_secret, recovery_code_hash,
+    totp_secret_to_base32, verify_totp_code,
+};
+
+define_cmd_wrapper_write!(WriteCommandsAccountTotp);
+
+impl WriteCommandsAccountTotp<'_> {
+    /// Creates a new TOTP secret which is enabled after
+    /// [Self::confirm_totp_enrollment].
+    ///
+    /// Enrollment is not possible for accounts which have admin
+    /// permissions if TOTP is required for admin permissions.
+    /// Otherwise the confirmation would verify the login session
+    /// without any previous verification.
+    pub async fn start_totp_enrollment(
+        &self,
+        id: AccountIdInternal,
+    ) -> Result<TotpEnrollmentResult, DataError> {
+        let has_admin_permissions_requiring_totp =
+            self.has_admin_permissions_requiring_totp(id).await?;
+
+        db_transaction!(self, move |mut cmds| {
+            let enabled = cmds
+                .read()
+                .account()
+                .totp()
+                .totp(id)?
+                .map(|v| v.enabled)
+                .unwrap_or_default();
+            if enabled {
+                return Ok(TotpEnrollmentResult::error_already_enabled());
+            }
+            if has_admin_permissions_requiring_totp {
+                return Ok(TotpEnrollmentResult::error_admin_permissions());
+            }
+            let secret = generate_totp_secret();
+            cmds.account()
+                .totp()
+                .upsert_pending_totp_secret(id, secret.clone())?;
+            Ok(TotpEnrollmentResult::ok(totp_secret_to_base32(&secret)))
+        })
+    }
+
+    /// Enables TOTP and marks the current login session as verified
+    /// if the code is valid.
+    ///
+    /// Returns new recovery codes if the code is valid. Enrollment
+    /// started before admin permissions were granted is not confirmed.
+    pub async fn confirm_totp_enrollment(
+        &self,
+        id: AccountIdInternal,
+        code: String,
+    ) -> Result<Option<Vec<String>>, DataError> {
+        if self.has_admin_permissions_requiring_totp(id).await? {
+            return Ok(None);
+        }
+
+        let recovery_codes = db_transaction!(self, move |mut cmds| {
+            let Some(totp) = cmds.read().account().totp().totp(id)? else {
+                return Ok(None);
+            };
+            if totp.enabled {
+                return Ok(None);
+            }
+            let Some(time_step) = verify_totp_code(
+                &totp.secret,
+                &code,
+                UnixTime::current_time().ut,
+                totp.last_used_time_step,
+            ) else {
+                return Ok(None);
+            };
+            cmds.account()
+                .totp()
+                .update_totp_enabled_and_last_used_time_step(id, true, time_step)?;
+            replace_recovery_codes(&mut cmds, id).map(Some)
+        })?;
+
+        if recovery_codes.is_some() {
+            self.write_cache_common(id, |entry| {
+                entry.set_totp_verified();
+                Ok(())
+            })
+            .await
+            .into_error()?;
+        }
+
+        Ok(recovery_codes)
+    }
+
+    /// Marks the current login session as verified if the code is
+    /// valid TOTP code or recovery code.
+    pub async fn verify_totp(
+        &self,
+        id: AccountIdInternal,
+        code: String,
+    ) -> Result<bool, DataError> {
+        let valid = db_transaction!(self, move |mut cmds| {
+            use_totp_code_or_recovery_code(&mut cmds, id, &code)
+        })?;
+
+        if valid {
+            self.write_cache_common(id, |entry| {
+                entry.set_totp_verified();
+                Ok(())
+            })
+            .await
+            .into_error()?;
+        }
+
+        Ok(valid)
+    }
+
+    /// Disables TOTP if the code is valid TOTP code or recovery code.
+    pub async fn disable_totp(
+        &self,
+        id: AccountIdInternal,
+        code: String,
+    ) -> Result<bool, DataError> {
+        let valid = db_transaction!(self, move |mut cmds| {
+            let valid = use_totp_code_or_recovery_code(&mut cmds, id, &code)?;
+            if valid {
+                cmds.account().totp().delete_totp(id)?;
+            }
+            Ok(valid)
+        })?;
+
+        if valid {
+            self.write_cache_common(id, |entry| {
+                entry.reset_totp_verification();
+                Ok(())
+            })
+            .await
+            .into_error()?;
+        }
+
+        Ok(valid)
+    }
+
+    /// Returns new recovery codes if the code is valid TOTP code or
+    /// recovery code.
+    pub async fn regenerate_totp_recovery_codes(
+        &self,
+        id: AccountIdInternal,
+        code: String,
+    ) -> Result<Option<Vec<String>>, DataError> {
+        db_transaction!(self, move |mut cmds| {
+            if !use_totp_code_or_recovery_code(&mut cmds, id, &code)? {
+                return Ok(None);
+            }
+            replace_recovery_codes(&mut cmds, id).map(Some)
+        })
+    }
+
+    async fn has_admin_permissions_requiring_totp(
+        &self,
+        id: AccountIdInternal,
+    ) -> Result<bool, DataError> {
+        let config = self.config().admin_totp();
+        self.read_cache_common(id, |entry| {
+            Ok(config
+                .required_verification_validity(entry.other_shared_state.is_bot())
+                .is_some()
+                && entry.account.permissions() != Permissions::default())
+        })
+        .await
+        .into_error()
+    }
+}
+
+/// Used recovery code is deleted and TOTP code reuse is prevented.
+fn use_totp_code_or_recovery_code(
+    cmds: &mut DbWriteMode<'_>,
+    id: AccountIdInternal,
+    code: &str,
+) -> simple_backend_utils::Result<bool, DieselDatabaseError> {
+    let Some(totp) = cmds.read().account().totp().totp(id)? else {
+        return Ok(false);
+    };
+    if !totp.enabled {
+        return Ok(false);
+    }
+
+    if let Some(time_step) = verify_totp_code(
+        &totp.secret,
+        code,
+        UnixTime::current_time().ut,
+        totp.last_used_time_step,
+    ) {
+        cmds.account()
+            .totp()
+            .update_totp_enabled_and_last_used_time_step(id, true, time_step)?;
+        return Ok(true);
+    }
+
+    cmds.account()
+        .totp()
+        .delete_totp_recovery_code(id, recovery_code_hash(code))
+}
+
+fn replace_recovery_codes(
+    cmds: &mut DbWriteMode<'_>,
+    id: AccountIdInternal,
+) -> simple_backend_utils::Result<Vec<String>, DieselDatabaseError> {
+    let codes: Vec<String> = (0..RECOVERY_CODE_COUNT)
+        .map(|_| generate_recovery_code())
+        .collect();
+    cmds.account()
+        .totp()
+        .replace_totp_recovery_codes(id, codes.iter().map(|c| recovery_code_hash(c)).collect())?;
+    Ok(codes)
+}
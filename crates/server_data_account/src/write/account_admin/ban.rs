@@ -133,4 +133,32 @@ impl WriteCommandsAccountBan<'_> {
 
         Ok(())
     }
+
+    /// Ban `target` until the current ban of `banned_account` ends.
+    /// Does nothing if `banned_account` is not banned anymore.
+    pub async fn ban_linked_account(
+        &self,
+        admin_id: AccountIdInternal,
+        banned_account: AccountIdInternal,
+        target: AccountIdInternal,
+    ) -> Result<(), DataError> {
+        let ban_state = self
+            .db_read(move |mut cmds| cmds.account().ban().account_ban_time(banned_account))
+            .await?;
+
+        let Some(banned_until) = ban_state.banned_until else {
+            return Ok(());
+        };
+
+        self.set_account_ban_state(
+            target,
+            SetAccountBanStateMode::BanOrUnban {
+                admin_id,
+                banned_until: Some(banned_until),
+                reason_category: Some(AccountBanReasonCategory::BAN_EVASION),
+                reason_details: None,
+            },
+        )
+        .await
+    }
 }
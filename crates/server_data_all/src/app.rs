@@ -1,11 +1,12 @@
-use std::net::IpAddr;
+use std::{net::IpAddr, sync::Arc};
 
 use axum::extract::ws::WebSocket;
 use config::Config;
 use futures::{FutureExt, future::BoxFuture};
 use model::{
     AccountIdInternal, AccountVerificationErrorFlags, AccountVerificationErrorFlagsValue,
-    ClientMessageForDataAllCrate, EditVerificationValues, EmailMessages, UnixTime,
+    ClientMessageForDataAllCrate, EditVerificationValues, EmailMessages, MessageId,
+    NewPendingMessage, PendingMessageId, PublicKeyId, ResponseSendMessage, UnixTime,
     VerificationMethod,
 };
 use model_account::{
@@ -16,6 +17,7 @@ use server_data::{
     DataError,
     app::{DataAllUtils, RegisterImplResult},
     data_export::DataExportCmd,
+    data_import::{DataImportProfileContent, DataImportResult},
     data_reset::BACKEND_DATA_RESET_STATE,
     db_manager::RouterDatabaseReadHandle,
     result::WrappedContextExt,
@@ -25,9 +27,10 @@ use server_data_account::{
     read::GetReadCommandsAccount,
     write::{GetWriteCommandsAccount, account_admin::SetAccountBanStateMode},
 };
-use server_data_chat::read::GetReadChatCommands;
+use server_data_chat::{read::GetReadChatCommands, write::GetWriteCommandsChat};
 use simple_backend::manager_client::ManagerApiClient;
 use simple_backend_model::NonEmptyString;
+use utils::encrypt::ParsedKeys;
 
 use crate::{register::RegisterAccount, unlimited_likes::UnlimitedLikesUpdate};
 
@@ -81,7 +84,7 @@ impl DataAllUtils for DataAllUtilsImpl {
 
                     let id = cmds.account().get_next_unique_account_id().await?;
                     RegisterAccount::new(cmds.write_handle())
-                        .register(id, sign_in_with, email.clone(), ip)
+                        .register(id, sign_in_with, email.clone(), Some(ip))
                         .await?;
 
                     if email.is_some() {
@@ -168,6 +171,84 @@ impl DataAllUtils for DataAllUtilsImpl {
         .boxed()
     }
 
+    fn send_message<'a>(
+        &self,
+        write_command_runner: &'a WriteCommandRunnerHandle,
+        sender: AccountIdInternal,
+        recipient: AccountIdInternal,
+        message: Vec<u8>,
+        sender_public_key: PublicKeyId,
+        recipient_public_key: PublicKeyId,
+        message_id: MessageId,
+        keys: Arc<ParsedKeys>,
+    ) -> BoxFuture<
+        'a,
+        server_common::result::Result<(ResponseSendMessage, Option<NewPendingMessage>), DataError>,
+    > {
+        async move {
+            let (result, new_message) = write_command_runner
+                .write(move |cmds| async move {
+                    cmds.chat()
+                        .insert_pending_message_if_match_and_not_blocked(
+                            sender,
+                            recipient,
+                            message,
+                            sender_public_key,
+                            recipient_public_key,
+                            message_id,
+                            keys,
+                        )
+                        .await
+                })
+                .await?;
+            Ok((result.to_websocket_response(), new_message))
+        }
+        .boxed()
+    }
+
+    fn add_recipient_acknowledgement<'a>(
+        &self,
+        write_command_runner: &'a WriteCommandRunnerHandle,
+        recipient: AccountIdInternal,
+        messages: Vec<PendingMessageId>,
+        delivery_failed: bool,
+    ) -> BoxFuture<'a, server_common::result::Result<(), DataError>> {
+        async move {
+            write_command_runner
+                .write(move |cmds| async move {
+                    cmds.chat()
+                        .add_recipient_acknowledgement_and_delete_if_also_sender_has_acknowledged(
+                            recipient,
+                            messages,
+                            delivery_failed,
+                        )
+                        .await
+                })
+                .await
+        }
+        .boxed()
+    }
+
+    fn add_sender_acknowledgement<'a>(
+        &self,
+        write_command_runner: &'a WriteCommandRunnerHandle,
+        sender: AccountIdInternal,
+        messages: Vec<MessageId>,
+    ) -> BoxFuture<'a, server_common::result::Result<(), DataError>> {
+        async move {
+            write_command_runner
+                .write(move |cmds| async move {
+                    cmds.chat()
+                        .add_sender_acknowledgement_and_delete_if_also_recipient_has_acknowledged(
+                            sender, messages,
+                        )
+                        .await
+                })
+                .await
+        }
+        .boxed()
+    }
+
     fn data_export<'a>(
         &self,
         write_command_runner: &'a WriteCommandRunnerHandle,
@@ -181,6 +262,40 @@ impl DataAllUtils for DataAllUtilsImpl {
         .boxed()
     }
 
+    fn import_account_data<'a>(
+        &self,
+        write_command_runner: &'a WriteCommandRunnerHandle,
+        archive: Vec<u8>,
+    ) -> BoxFuture<'a, server_common::result::Result<DataImportResult, DataError>> {
+        crate::data_import::import_account_data(write_command_runner, archive).boxed()
+    }
+
+    fn cancel_account_data_import<'a>(
+        &self,
+        write_handle: &'a WriteCommandRunnerHandle,
+        id: AccountIdInternal,
+    ) -> BoxFuture<'a, server_common::result::Result<(), DataError>> {
+        crate::data_import::cancel_account_data_import(write_handle, id).boxed()
+    }
+
+    fn complete_account_data_import<'a>(
+        &self,
+        config: &'a Config,
+        read_handle: &'a RouterDatabaseReadHandle,
+        write_handle: &'a WriteCommandRunnerHandle,
+        id: AccountIdInternal,
+        content: DataImportProfileContent,
+    ) -> BoxFuture<'a, server_common::result::Result<(), DataError>> {
+        crate::data_import::complete_account_data_import(
+            config,
+            read_handle,
+            write_handle,
+            id,
+            content,
+        )
+        .boxed()
+    }
+
     fn edit_verification_values<'a>(
         &self,
         write_command_runner: &'a WriteCommandRunnerHandle,
@@ -288,4 +403,30 @@ impl DataAllUtils for DataAllUtilsImpl {
         }
         .boxed()
     }
+
+    fn ban_accounts_linked_to_banned_accounts<'a>(
+        &self,
+        write_command_runner: &'a WriteCommandRunnerHandle,
+        moderator_id: AccountIdInternal,
+        accounts: Vec<(AccountIdInternal, AccountIdInternal)>,
+    ) -> BoxFuture<'a, server_common::result::Result<(), DataError>> {
+        async move {
+            if accounts.is_empty() {
+                return Ok(());
+            }
+
+            write_command_runner
+                .write(move |cmds| async move {
+                    for (banned_account, target) in accounts {
+                        cmds.account_admin()
+                            .ban()
+                            .ban_linked_account(moderator_id, banned_account, target)
+                            .await?;
+                    }
+                    Ok(())
+                })
+                .await
+        }
+        .boxed()
+    }
 }
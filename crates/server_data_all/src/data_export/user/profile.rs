@@ -1,6 +1,6 @@
 use database::{DbReadMode, DieselDatabaseError};
 use database_profile::current::read::GetDbReadCommandsProfile;
-use model::UnixTime;
+use model::{AccountId, UnixTime};
 use model_chat::{
     AutomaticProfileSearchLastSeenUnixTime, AutomaticProfileSearchSettings, LastSeenUnixTime,
     Location, ProfileAppNotificationSettings, ProfileEditedTime,
@@ -23,6 +23,7 @@ pub struct UserDataExportJsonProfile {
     last_seen_unix_time: LastSeenUnixTime,
     my_profile: GetMyProfileResult,
     favorite_added_time_list: Vec<UnixTime>,
+    favorites: Vec<AccountId>,
     profile_name_allowlist: Vec<String>,
     profile_app_notification_settings: ProfileAppNotificationSettings,
     automatic_profile_search_settings: AutomaticProfileSearchSettings,
@@ -47,6 +48,13 @@ impl UserDataExportJsonProfile {
             last_seen_unix_time: current.profile().data().profile_last_seen_time(id)?,
             my_profile: current.profile().data().my_profile(id)?,
             favorite_added_time_list: current.profile().favorite().favorite_added_time_list(id)?,
+            favorites: current
+                .profile()
+                .favorite()
+                .favorites(id)?
+                .into_iter()
+                .map(|v| v.as_id())
+                .collect(),
             profile_name_allowlist: current
                 .profile()
                 .moderation()
@@ -0,0 +1,461 @@
+use std::{
+    collections::HashSet,
+    io::{Cursor, Read, Seek},
+};
+
+use config::Config;
+use model::{AccountId, AccountIdInternal, ContentId, ContentQualityVariant, ProfileAge};
+use model_account::{EmailAddressState, SignInWithInfo};
+use model_chat::{
+    AccountAppNotificationSettings, AutomaticProfileSearchSettings, ChatAppNotificationSettings,
+    Location, MediaAppNotificationSettings, ProfileAppNotificationSettings,
+};
+use model_media::{
+    ContentInfoDetailed, MyProfileContent, SecurityContentUserDataExport, SetProfileContent,
+};
+use model_profile::{
+    ProfileAttributeValueUpdate, ProfileFiltersUpdate, ProfileUpdateInternal, SearchAgeRange,
+    SearchAgeRangeValidated, SearchGroups, ValidatedSearchGroups,
+};
+use serde::{Deserialize, de::DeserializeOwned};
+use server_data::{
+    DataError,
+    data_import::{DataImportImage, DataImportProfileContent, DataImportResult},
+    data_reset::BACKEND_DATA_RESET_STATE,
+    db_manager::{InternalReading, RouterDatabaseReadHandle},
+    result::{Result, WrappedContextExt},
+    write_commands::WriteCommandRunnerHandle,
+};
+use server_data_account::{read::GetReadCommandsAccount, write::GetWriteCommandsAccount};
+use server_data_chat::write::GetWriteCommandsChat;
+use server_data_media::{read::GetReadMediaCommands, write::GetWriteCommandsMedia};
+use server_data_profile::write::GetWriteCommandsProfile;
+use simple_backend_model::NonEmptyString;
+use tracing::{error, warn};
+
+use crate::register::RegisterAccount;
+
+/// Same as the max size for content uploads
+const MAX_IMAGE_SIZE: u64 = 1024 * 1024 * 10;
+/// Max size for one JSON file
+const MAX_JSON_SIZE: u64 = 1024 * 1024 * 10;
+
+#[derive(Deserialize)]
+struct ImportAccountId {
+    uuid: AccountId,
+}
+
+#[derive(Deserialize)]
+struct ImportJsonCommon {
+    id: ImportAccountId,
+}
+
+#[derive(Deserialize)]
+struct ImportJsonAccount {
+    email_address_state: EmailAddressState,
+    account_notification_settings: AccountAppNotificationSettings,
+}
+
+#[derive(Deserialize)]
+struct ImportProfile {
+    name: Option<NonEmptyString>,
+    ptext: Option<NonEmptyString>,
+    age: ProfileAge,
+    #[serde(default)]
+    attributes: Vec<ProfileAttributeValueUpdate>,
+}
+
+#[derive(Deserialize)]
+struct ImportMyProfile {
+    profile: ImportProfile,
+}
+
+#[derive(Deserialize)]
+struct ImportJsonProfile {
+    profile_filters: ProfileFiltersUpdate,
+    search_groups: SearchGroups,
+    location: Location,
+    search_age_range: SearchAgeRange,
+    my_profile: ImportMyProfile,
+    /// Older exports do not have this field.
+    #[serde(default)]
+    favorites: Vec<AccountId>,
+    profile_app_notification_settings: ProfileAppNotificationSettings,
+    automatic_profile_search_settings: AutomaticProfileSearchSettings,
+}
+
+#[derive(Deserialize)]
+struct ImportJsonMedia {
+    security_content: SecurityContentUserDataExport,
+    profile_content: MyProfileContent,
+    content: Vec<ContentInfoDetailed>,
+    media_app_notification_settings: MediaAppNotificationSettings,
+}
+
+#[derive(Deserialize)]
+struct ImportJsonChat {
+    chat_app_notification_settings: ChatAppNotificationSettings,
+}
+
+struct ParsedArchive {
+    common: ImportJsonCommon,
+    account: ImportJsonAccount,
+    profile: ImportJsonProfile,
+    media: ImportJsonMedia,
+    chat: ImportJsonChat,
+    images: Vec<DataImportImage>,
+    profile_content: DataImportProfileContent,
+}
+
+impl ParsedArchive {
+    fn parse(archive: Vec<u8>) -> std::result::Result<Self, String> {
+        let mut archive = zip::ZipArchive::new(Cursor::new(archive)).map_err(|e| e.to_string())?;
+
+        let common: ImportJsonCommon = read_user_json(&mut archive, "common")?;
+        let account: ImportJsonAccount = read_user_json(&mut archive, "account")?;
+        let profile: ImportJsonProfile = read_user_json(&mut archive, "profile")?;
+        let media: ImportJsonMedia = read_user_json(&mut archive, "media")?;
+        let chat: ImportJsonChat = read_user_json(&mut archive, "chat")?;
+
+        let security_content = media
+            .security_content
+            .content
+            .as_ref()
+            .map(|c| c.cid)
+            .filter(|cid| {
+                media
+                    .content
+                    .iter()
+                    .any(|c| c.cid == *cid && c.secure_capture)
+            });
+        let profile_content = DataImportProfileContent {
+            content: media
+                .profile_content
+                .content
+                .iter()
+                .take(6)
+                .map(|c| c.cid)
+                .collect(),
+            security_content,
+            grid_crop_size: media.profile_content.grid_crop_size,
+            grid_crop_x: media.profile_content.grid_crop_x,
+            grid_crop_y: media.profile_content.grid_crop_y,
+        };
+
+        let mut images = vec![];
+        let mut handled = HashSet::new();
+        for cid in profile_content
+            .content
+            .iter()
+            .chain(profile_content.security_content.iter())
+        {
+            if !handled.insert(*cid) {
+                continue;
+            }
+            let secure_capture = media
+                .content
+                .iter()
+                .find(|c| c.cid == *cid)
+                .map(|c| c.secure_capture)
+                .ok_or_else(|| format!("Content info missing for {}", cid.cid))?;
+            images.push(DataImportImage {
+                source_content_id: *cid,
+                secure_capture,
+                data: read_image(&mut archive, *cid)?,
+            });
+        }
+
+        Ok(Self {
+            common,
+            account,
+            profile,
+            media,
+            chat,
+            images,
+            profile_content,
+        })
+    }
+}
+
+fn find_file_name<R: Read + Seek>(
+    archive: &zip::ZipArchive<R>,
+    path_suffix: &str,
+) -> std::result::Result<String, String> {
+    archive
+        .file_names()
+        .find(|name| name.ends_with(path_suffix))
+        .map(|name| name.to_string())
+        .ok_or_else(|| format!("File {path_suffix} missing"))
+}
+
+fn read_file<R: Read + Seek>(
+    archive: &mut zip::ZipArchive<R>,
+    path_suffix: &str,
+    max_size: u64,
+) -> std::result::Result<Vec<u8>, String> {
+    let name = find_file_name(archive, path_suffix)?;
+    let file = archive.by_name(&name).map_err(|e| e.to_string())?;
+    if file.size() > max_size {
+        return Err(format!("File {path_suffix} is too large"));
+    }
+    let mut data = vec![];
+    file.take(max_size)
+        .read_to_end(&mut data)
+        .map_err(|e| e.to_string())?;
+    Ok(data)
+}
+
+fn read_user_json<R: Read + Seek, T: DeserializeOwned>(
+    archive: &mut zip::ZipArchive<R>,
+    json_name: &str,
+) -> std::result::Result<T, String> {
+    let path_suffix = format!("/user/{json_name}.json");
+    let data = read_file(archive, &path_suffix, MAX_JSON_SIZE)?;
+    serde_json::from_slice(&data).map_err(|e| format!("File {path_suffix}: {e}"))
+}
+
+fn read_image<R: Read + Seek>(
+    archive: &mut zip::ZipArchive<R>,
+    cid: ContentId,
+) -> std::result::Result<Vec<u8>, String> {
+    let path_suffix = format!(
+        "/media/{}.jpg",
+        cid.content_file_name_variant(ContentQualityVariant::High)
+    );
+    read_file(archive, &path_suffix, MAX_IMAGE_SIZE)
+}
+
+/// Create new account from user data export archive.
+///
+/// Data which refers to other accounts is not imported except favorites
+/// which point to existing accounts. The account ID from the archive is
+/// used if it is not already used on this server.
+///
+/// Images are not imported. Caller must process [DataImportResult::Ok]
+/// images and then complete the import with
+/// [complete_account_data_import]. If that is not possible, the import
+/// must be cancelled with [cancel_account_data_import].
+pub async fn import_account_data(
+    write_handle: &WriteCommandRunnerHandle,
+    archive: Vec<u8>,
+) -> Result<DataImportResult, DataError> {
+    let data = match ParsedArchive::parse(archive) {
+        Ok(data) => data,
+        Err(e) => {
+            warn!("Invalid data import archive: {e}");
+            return Ok(DataImportResult::InvalidArchive);
+        }
+    };
+
+    write_handle
+        .write(move |cmds| async move {
+            if BACKEND_DATA_RESET_STATE.is_ongoing() {
+                return Err(DataError::NotAllowed.report());
+            }
+
+            let ParsedArchive {
+                common,
+                account,
+                profile,
+                media,
+                chat,
+                images,
+                profile_content,
+            } = data;
+
+            let attribute_info = cmds.profile_attributes().read().await;
+            let profile_update = ProfileUpdateInternal {
+                ptext: profile.my_profile.profile.ptext,
+                name: profile.my_profile.profile.name,
+                age: profile.my_profile.profile.age,
+                attributes: profile
+                    .my_profile
+                    .profile
+                    .attributes
+                    .into_iter()
+                    .filter(|a| attribute_info.get_attribute(a.id).is_some())
+                    .collect(),
+            }
+            .validate_without_age_range(
+                &attribute_info,
+                cmds.write_handle().config().profile_name_regex(),
+                false,
+            );
+            let filters = profile
+                .profile_filters
+                .from_data_export(&attribute_info)
+                .validate(&attribute_info);
+            drop(attribute_info);
+            let search_groups: std::result::Result<ValidatedSearchGroups, _> =
+                profile.search_groups.try_into();
+            let search_age_range: std::result::Result<SearchAgeRangeValidated, _> =
+                profile.search_age_range.try_into();
+
+            let (profile_update, filters, search_groups, search_age_range) =
+                match (profile_update, filters, search_groups, search_age_range) {
+                    (Ok(p), Ok(f), Ok(g), Ok(r)) if p.name.is_some() => (p, f, g, r),
+                    _ => {
+                        warn!("Invalid data import archive: profile validation failed");
+                        return Ok(DataImportResult::InvalidArchive);
+                    }
+                };
+
+            let email = account.email_address_state.email;
+            if let Some(email) = email.clone() {
+                let account_found = cmds
+                    .read()
+                    .account()
+                    .email()
+                    .account_id_from_email(email)
+                    .await?;
+
+                if account_found.is_some() {
+                    return Ok(DataImportResult::EmailAlreadyExists);
+                }
+            }
+
+            let id = cmds
+                .account()
+                .get_next_unique_account_id_with_preferred_id(common.id.uuid)
+                .await?;
+            RegisterAccount::new(cmds.write_handle())
+                .register(id, SignInWithInfo::default(), email, None)
+                .await?;
+
+            // Account creation and data importing are not in the same
+            // transaction, so delete the account if importing fails.
+            let result: Result<(), DataError> = async {
+                // Account
+
+                cmds.account()
+                    .notification()
+                    .upsert_app_notification_settings(id, account.account_notification_settings)
+                    .await?;
+
+                // Profile
+
+                cmds.profile().profile(id, profile_update).await?;
+                cmds.profile()
+                    .update_search_groups(id, search_groups)
+                    .await?;
+                cmds.profile()
+                    .update_search_age_range(id, search_age_range)
+                    .await?;
+                cmds.profile().update_profile_filters(id, filters).await?;
+                cmds.profile()
+                    .profile_update_location(id, profile.location)
+                    .await?;
+                cmds.profile()
+                    .search()
+                    .upsert_automatic_profile_search_settings(
+                        id,
+                        profile.automatic_profile_search_settings,
+                    )
+                    .await?;
+                cmds.profile()
+                    .notification()
+                    .upsert_app_notification_settings(id, profile.profile_app_notification_settings)
+                    .await?;
+                for favorite in profile.favorites {
+                    let Some(favorite) = cmds
+                        .read()
+                        .cache()
+                        .to_account_id_internal_optional(favorite)
+                        .await
+                    else {
+                        // The account does not exist on this server
+                        continue;
+                    };
+                    if favorite == id {
+                        continue;
+                    }
+                    cmds.profile().insert_favorite_profile(id, favorite).await?;
+                }
+
+                // Media
+
+                cmds.media()
+                    .notification()
+                    .upsert_app_notification_settings(id, media.media_app_notification_settings)
+                    .await?;
+
+                // Chat
+
+                cmds.chat()
+                    .notification()
+                    .upsert_app_notification_settings(id, chat.chat_app_notification_settings)
+                    .await?;
+
+                Ok(())
+            }
+            .await;
+
+            if let Err(e) = result {
+                if let Err(delete_error) = cmds.account().delete().delete_account(id).await {
+                    error!("Deleting partially imported account failed: {delete_error:?}");
+                }
+                return Err(e);
+            }
+
+            Ok(DataImportResult::Ok {
+                id,
+                images,
+                profile_content,
+            })
+        })
+        .await
+}
+
+/// Delete imported account if image processing or
+/// [complete_account_data_import] fails.
+pub async fn cancel_account_data_import(
+    write_handle: &WriteCommandRunnerHandle,
+    id: AccountIdInternal,
+) -> Result<(), DataError> {
+    write_handle
+        .write(move |cmds| async move { cmds.account().delete().delete_account(id).await })
+        .await
+}
+
+/// Set profile and security content for imported account and
+/// complete the initial setup.
+pub async fn complete_account_data_import(
+    config: &Config,
+    read_handle: &RouterDatabaseReadHandle,
+    write_handle: &WriteCommandRunnerHandle,
+    id: AccountIdInternal,
+    content: DataImportProfileContent,
+) -> Result<(), DataError> {
+    write_handle
+        .write(move |cmds| async move {
+            if !content.content.is_empty() {
+                cmds.media()
+                    .update_profile_content(
+                        id,
+                        SetProfileContent {
+                            content: content.content,
+                            grid_crop_size: content.grid_crop_size,
+                            grid_crop_x: content.grid_crop_x,
+                            grid_crop_y: content.grid_crop_y,
+                        },
+                    )
+                    .await?;
+            }
+
+            if let Some(security_content) = content.security_content {
+                let security_content = cmds
+                    .read()
+                    .media()
+                    .content_id_internal(id, security_content)
+                    .await?;
+                cmds.media()
+                    .update_security_content(security_content)
+                    .await?;
+            }
+
+            Ok(())
+        })
+        .await?;
+
+    crate::initial_setup::complete_initial_setup(config, read_handle, write_handle, id).await
+}
@@ -79,7 +79,20 @@ pub async fn complete_initial_setup(
                 && (global_state.admin_access_granted_count == 0
                     || grant_admin_access_more_than_once)
             {
-                Some(IncrementAdminAccessGrantedCount)
+                if cmds
+                    .read()
+                    .account()
+                    .totp()
+                    .admin_permissions_can_be_granted(id)
+                    .await?
+                {
+                    Some(IncrementAdminAccessGrantedCount)
+                } else {
+                    warn!(
+                        "Account detected as admin account but TOTP is not enabled. Permissions are not enabled"
+                    );
+                    None
+                }
             } else {
                 None
             };
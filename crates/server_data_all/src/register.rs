@@ -31,7 +31,7 @@ impl RegisterAccount<'_> {
         account_id: AccountIdInternal,
         sign_in_with_info: SignInWithInfo,
         email: Option<EmailAddress>,
-        ip: IpAddr,
+        ip: Option<IpAddr>,
     ) -> Result<(), DataError> {
         let config = self.config_arc().clone();
         self.db_transaction(move |current| {
@@ -63,7 +63,7 @@ impl RegisterAccount<'_> {
         id: AccountIdInternal,
         sign_in_with_info: SignInWithInfo,
         email: Option<EmailAddress>,
-        ip: IpAddr,
+        ip: Option<IpAddr>,
         mut current: DbWriteMode,
     ) -> simple_backend_utils::Result<AccountIdInternal, DieselDatabaseError> {
         // Common
@@ -129,12 +129,14 @@ impl RegisterAccount<'_> {
         }
 
         // Save the IP address used at registration time.
-        let mut ip_data = HashMap::new();
-        ip_data.insert(AccountIdDb::from(id), IpAddressStorage::new(ip.into()));
-        current
-            .common_admin()
-            .statistics()
-            .save_ip_address_data(ip_data)?;
+        if let Some(ip) = ip {
+            let mut ip_data = HashMap::new();
+            ip_data.insert(AccountIdDb::from(id), IpAddressStorage::new(ip.into()));
+            current
+                .common_admin()
+                .statistics()
+                .save_ip_address_data(ip_data)?;
+        }
 
         Ok(id)
     }
@@ -10,6 +10,7 @@ use server_data::{
     define_cmd_wrapper_read, read::DbRead, result::Result,
 };
 
+mod attachment;
 mod limits;
 mod notification;
 mod privacy;
@@ -19,6 +20,9 @@ mod transfer;
 define_cmd_wrapper_read!(ReadCommandsChat);
 
 impl<'a> ReadCommandsChat<'a> {
+    pub fn attachment(self) -> attachment::ReadCommandsChatAttachment<'a> {
+        attachment::ReadCommandsChatAttachment::new(self.0)
+    }
     pub fn public_key(self) -> public_key::ReadCommandsChatPublicKey<'a> {
         public_key::ReadCommandsChatPublicKey::new(self.0)
     }
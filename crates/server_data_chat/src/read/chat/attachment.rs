@@ -0,0 +1,37 @@
+use database_chat::current::read::GetDbReadCommandsChat;
+use model::MessageId;
+use model_chat::AccountIdInternal;
+use server_data::{
+    DataError, IntoDataError, define_cmd_wrapper_read, read::DbRead, result::Result,
+};
+
+define_cmd_wrapper_read!(ReadCommandsChatAttachment);
+
+impl ReadCommandsChatAttachment<'_> {
+    /// Get attachment for received message. Returns `None` if the
+    /// attachment does not exist, the message is already acknowledged
+    /// or sender and recipient are not a match anymore.
+    pub async fn received_message_attachment(
+        &self,
+        recipient: AccountIdInternal,
+        sender: AccountIdInternal,
+        message: MessageId,
+    ) -> Result<Option<Vec<u8>>, DataError> {
+        self.db_read(move |mut cmds| {
+            let interaction = cmds
+                .chat()
+                .interaction()
+                .account_interaction(recipient, sender)?;
+            match interaction {
+                Some(interaction) if interaction.is_match() && !interaction.is_blocked() => (),
+                _ => return Ok(None),
+            }
+
+            cmds.chat()
+                .attachment()
+                .received_message_attachment(recipient, sender, message)
+        })
+        .await
+        .into_error()
+    }
+}
@@ -1,3 +1,4 @@
+mod attachment;
 mod limits;
 mod notification;
 mod privacy;
@@ -18,7 +19,10 @@ use database_chat::current::{
     },
 };
 use error_stack::ResultExt;
-use model::{NewReceivedLikesCountResult, PendingAppNotificationInternal, ReceivedLikeId};
+use model::{
+    NewPendingMessage, NewReceivedLikesCountResult, PendingAppNotificationInternal,
+    PushNotificationAllowed, ReceivedLikeId,
+};
 use model_chat::{
     AccountIdInternal, AddPublicKeyResult, ChatStateRaw, DeliveryInfoType, LatestSeenMessageInfo,
     MessageId, NewReceivedLikesCount, PendingMessageId, PublicKeyId, ReceivedLikesIteratorState,
@@ -41,6 +45,9 @@ use crate::{event::EventManagerChatMethods, read::GetReadChatCommands};
 define_cmd_wrapper_write!(WriteCommandsChat);
 
 impl<'a> WriteCommandsChat<'a> {
+    pub fn attachment(self) -> attachment::WriteCommandsChatAttachment<'a> {
+        attachment::WriteCommandsChatAttachment::new(self.0)
+    }
     pub fn report(self) -> report::WriteCommandsChatReport<'a> {
         report::WriteCommandsChatReport::new(self.0)
     }
@@ -431,7 +438,7 @@ impl WriteCommandsChat<'_> {
         recipient_public_key_from_client: PublicKeyId,
         message_id_value: MessageId,
         keys: Arc<ParsedKeys>,
-    ) -> Result<(SendMessageResult, Option<PushNotificationAllowed>), DataError> {
+    ) -> Result<(SendMessageResult, Option<NewPendingMessage>), DataError> {
         let chat_limits = self.config().limits_chat();
         let conversation_pending_messages_max_count =
             i64::from(chat_limits.conversation_pending_messages_max_count);
@@ -549,9 +556,14 @@ impl WriteCommandsChat<'_> {
                 None
             };
 
+            let new_message = NewPendingMessage {
+                signed_message: message_values.clone(),
+                push_notification_allowed: push_notification_allowd,
+            };
+
             Ok((
                 SendMessageResult::successful(message_values, remaining_conversation_messages),
-                push_notification_allowd,
+                Some(new_message),
             ))
         })
     }
@@ -683,9 +695,3 @@ pub struct SenderAndRecipientStateChanges {
     pub sender: ChatStateChanges,
     pub recipient: ChatStateChanges,
 }
-
-/// Message push notification is allowed to be sent if recipient side
-/// of acknowledgement queue is empty when sending a new message.
-/// This avoids sending multiple push notifications if client is running
-/// in background and can receive push notifications.
-pub struct PushNotificationAllowed;
@@ -0,0 +1,89 @@
+use database::current::read::GetDbReadCommandsCommon;
+use database_chat::current::{
+    read::GetDbReadCommandsChat,
+    write::{GetDbWriteCommandsChat, chat::transfer::TransferBudgetCheckResult},
+};
+use model::MessageId;
+use model_chat::{AccountIdInternal, UploadMessageAttachmentResult};
+use server_data::{
+    DataError, app::GetConfig, db_transaction, define_cmd_wrapper_write, result::Result,
+    write::DbTransaction,
+};
+
+define_cmd_wrapper_write!(WriteCommandsChatAttachment);
+
+impl WriteCommandsChatAttachment<'_> {
+    /// Store encrypted attachment for sent message. The message must
+    /// be waiting for sender and recipient acknowledgement and sender and
+    /// recipient must still be a match.
+    ///
+    /// Attachment bytes are counted to the sender's yearly backup
+    /// transfer budget.
+    pub async fn upload_attachment(
+        &self,
+        sender: AccountIdInternal,
+        message: MessageId,
+        attachment: Vec<u8>,
+    ) -> Result<UploadMessageAttachmentResult, DataError> {
+        let chat_limits = self.config().limits_chat();
+        let pending_attachments_max_count =
+            i64::from(chat_limits.message_attachment_pending_max_count);
+        let yearly_limit_bytes = chat_limits.backup_transfer_yearly_max_bytes.bytes();
+
+        db_transaction!(self, move |mut cmds| {
+            let Some(pending_message) = cmds
+                .read()
+                .chat()
+                .attachment()
+                .pending_message_for_attachment_upload(sender, message)?
+            else {
+                return Ok(UploadMessageAttachmentResult::message_not_found());
+            };
+
+            if pending_message.attachment_exists {
+                return Ok(UploadMessageAttachmentResult::attachment_already_exists());
+            }
+
+            let recipient = cmds
+                .read()
+                .common()
+                .db_id_to_internal_id(pending_message.recipient)?;
+            let interaction = cmds
+                .read()
+                .chat()
+                .interaction()
+                .account_interaction(sender, recipient)?;
+            match interaction {
+                Some(interaction) if interaction.is_match() && !interaction.is_blocked() => (),
+                _ => return Ok(UploadMessageAttachmentResult::message_not_found()),
+            }
+
+            let pending_attachments = cmds
+                .read()
+                .chat()
+                .attachment()
+                .pending_attachment_count(sender)?;
+            if pending_attachments >= pending_attachments_max_count {
+                return Ok(UploadMessageAttachmentResult::too_many_pending_attachments());
+            }
+
+            let attachment_size: i64 = attachment.len().try_into().unwrap_or(i64::MAX);
+            let budget_check_result = cmds.chat().transfer().update_transfer_budget(
+                sender,
+                attachment_size,
+                yearly_limit_bytes,
+            )?;
+            if let TransferBudgetCheckResult::ExceedsLimit = budget_check_result {
+                return Ok(UploadMessageAttachmentResult::transfer_budget_exceeded());
+            }
+
+            cmds.chat().attachment().insert_pending_message_attachment(
+                sender,
+                pending_message.pending_message_id,
+                attachment,
+            )?;
+
+            Ok(UploadMessageAttachmentResult::successful())
+        })
+    }
+}
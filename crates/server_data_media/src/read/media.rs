@@ -1,5 +1,5 @@
 use database_media::current::read::GetDbReadCommandsMedia;
-use model::{ContentIdInternal, ContentQualityVariant};
+use model::{ContentFileVariant, ContentIdInternal, ContentQualityVariant};
 use model_media::{
     AccountId, AccountIdInternal, ContentId, CurrentAccountMediaInternal, MediaContentRaw,
     MediaContentSyncVersion,
@@ -37,6 +37,18 @@ impl ReadCommandsMedia<'_> {
         Ok(c)
     }
 
+    pub fn content_data_file_variant(
+        &self,
+        account_id: AccountId,
+        content_id: ContentId,
+        variant: ContentFileVariant,
+    ) -> Result<ContentFile, DataError> {
+        let c = self
+            .files()
+            .media_content_file_variant(account_id, content_id, variant);
+        Ok(c)
+    }
+
     pub fn content_data(
         &self,
         account_id: AccountId,
@@ -3,7 +3,10 @@ use std::collections::HashSet;
 use database::current::read::GetDbReadCommandsCommon;
 use database_media::current::{read::GetDbReadCommandsMedia, write::GetDbWriteCommandsMedia};
 use error_stack::ResultExt;
-use model::{AccountState, ContentIdInternal, ContentQualityVariant, EventToClientInternal};
+use model::{
+    AccountState, ContentFileVariant, ContentIdInternal, ContentImageFormat, ContentQualityVariant,
+    EventToClientInternal,
+};
 use model_media::{
     AccountIdInternal, ContentId, ContentIdDb, ContentSlot, CurrentAccountMediaInternal,
     NewContentParams, ProfileContent, ProfileContentModificationMetadata, SetProfileContent,
@@ -56,6 +59,7 @@ impl WriteCommandsMedia<'_> {
         new_content_params: NewContentParams,
         face_detected: bool,
         face_detected_manual: Option<bool>,
+        raw_content_sha256: Vec<u8>,
     ) -> Result<ContentId, DataError> {
         let account = self
             .db_read(move |mut cmds| cmds.common().account(id))
@@ -78,51 +82,15 @@ impl WriteCommandsMedia<'_> {
 
             if let Some(content) = current_content_in_slot {
                 let content_id = content.content_id();
-                let path_h = self.files().media_content_variant(
-                    id.as_id(),
-                    content_id,
-                    ContentQualityVariant::High,
-                );
-                path_h
-                    .overwrite_and_remove_if_exists()
-                    .await
-                    .change_context(DataError::File)?;
-                let path_m = self.files().media_content_variant(
-                    id.as_id(),
-                    content_id,
-                    ContentQualityVariant::Medium,
-                );
-                path_m
-                    .overwrite_and_remove_if_exists()
-                    .await
-                    .change_context(DataError::File)?;
-                let path_l = self.files().media_content_variant(
-                    id.as_id(),
-                    content_id,
-                    ContentQualityVariant::Low,
-                );
-                path_l
-                    .overwrite_and_remove_if_exists()
-                    .await
-                    .change_context(DataError::File)?;
-                let path_lo = self.files().media_content_variant(
-                    id.as_id(),
-                    content_id,
-                    ContentQualityVariant::Lower,
-                );
-                path_lo
-                    .overwrite_and_remove_if_exists()
-                    .await
-                    .change_context(DataError::File)?;
-                let path_vl = self.files().media_content_variant(
-                    id.as_id(),
-                    content_id,
-                    ContentQualityVariant::VeryLow,
-                );
-                path_vl
-                    .overwrite_and_remove_if_exists()
-                    .await
-                    .change_context(DataError::File)?;
+                for variant in
+                    ContentFileVariant::all_variants_for_formats(ContentImageFormat::all_formats())
+                {
+                    self.files()
+                        .media_content_file_variant(id.as_id(), content_id, variant)
+                        .overwrite_and_remove_if_exists()
+                        .await
+                        .change_context(DataError::File)?;
+                }
                 self.db_transaction(move |mut cmds| {
                     cmds.media()
                         .media_content()
@@ -169,24 +137,30 @@ impl WriteCommandsMedia<'_> {
                     new_content_params,
                     face_detected,
                     face_detected_manual,
+                    raw_content_sha256,
                 )?;
 
                 // Move content from tmp dir to content dir
-                tmp_img_high
-                    .move_to_blocking(&processed_content_path_high)
-                    .map_err(|e| e.change_context(DieselDatabaseError::File))?;
-                tmp_img_medium
-                    .move_to_blocking(&processed_content_path_medium)
-                    .map_err(|e| e.change_context(DieselDatabaseError::File))?;
-                tmp_img_low
-                    .move_to_blocking(&processed_content_path_low)
-                    .map_err(|e| e.change_context(DieselDatabaseError::File))?;
-                tmp_img_lower
-                    .move_to_blocking(&processed_content_path_lower)
-                    .map_err(|e| e.change_context(DieselDatabaseError::File))?;
-                tmp_img_very_low
-                    .move_to_blocking(&processed_content_path_very_low)
-                    .map_err(|e| e.change_context(DieselDatabaseError::File))?;
+                for (tmp_img, processed_content_path) in [
+                    (tmp_img_high, processed_content_path_high),
+                    (tmp_img_medium, processed_content_path_medium),
+                    (tmp_img_low, processed_content_path_low),
+                    (tmp_img_lower, processed_content_path_lower),
+                    (tmp_img_very_low, processed_content_path_very_low),
+                ] {
+                    // Other image formats than JPEG are optional
+                    for format in [ContentImageFormat::Webp, ContentImageFormat::Avif] {
+                        let tmp_img_format = tmp_img.format_variant(format);
+                        if tmp_img_format.exists() {
+                            tmp_img_format
+                                .move_to_blocking(&processed_content_path.format_variant(format))
+                                .map_err(|e| e.change_context(DieselDatabaseError::File))?;
+                        }
+                    }
+                    tmp_img
+                        .move_to_blocking(&processed_content_path)
+                        .map_err(|e| e.change_context(DieselDatabaseError::File))?;
+                }
                 // If moving fails, diesel rollbacks the transaction.
 
                 Ok(content_id)
@@ -368,26 +342,14 @@ impl WriteCommandsMedia<'_> {
 
         let account_id = content_id.content_owner().into();
         let cid = content_id.content_id();
-        self.files()
-            .media_content_variant(account_id, cid, ContentQualityVariant::High)
-            .overwrite_and_remove_if_exists()
-            .await?;
-        self.files()
-            .media_content_variant(account_id, cid, ContentQualityVariant::Medium)
-            .overwrite_and_remove_if_exists()
-            .await?;
-        self.files()
-            .media_content_variant(account_id, cid, ContentQualityVariant::Low)
-            .overwrite_and_remove_if_exists()
-            .await?;
-        self.files()
-            .media_content_variant(account_id, cid, ContentQualityVariant::Lower)
-            .overwrite_and_remove_if_exists()
-            .await?;
-        self.files()
-            .media_content_variant(account_id, cid, ContentQualityVariant::VeryLow)
-            .overwrite_and_remove_if_exists()
-            .await?;
+        for variant in
+            ContentFileVariant::all_variants_for_formats(ContentImageFormat::all_formats())
+        {
+            self.files()
+                .media_content_file_variant(account_id, cid, variant)
+                .overwrite_and_remove_if_exists()
+                .await?;
+        }
 
         Ok(r)
     }
@@ -74,12 +74,12 @@ fn attribute_content_changed(old: &Attribute, new: &Attribute) -> Result<bool, D
     Ok(false)
 }
 
-/// Key and mode are immutable
+/// Key, mode and numeric range are immutable
 fn ensure_values_not_removed_or_immutable_changed(
     old: &Attribute,
     new: &Attribute,
 ) -> Result<(), DataError> {
-    if old.key != new.key || old.mode != new.mode {
+    if old.key != new.key || old.mode != new.mode || old.numeric != new.numeric {
         return Err(DataError::NotAllowed.report());
     }
 
@@ -4,12 +4,12 @@
 #![warn(unused_crate_dependencies)]
 
 use axum::{
-    Router,
+    Router, middleware,
     routing::{any, get, post},
 };
 use private::PrivateRoutes;
 use server_api::app::GetConfig;
-use server_state::StateForRouterCreation;
+use server_state::{StateForRouterCreation, ip_rate_limit::IpRateLimitRouteGroup};
 
 mod api;
 mod private;
@@ -70,7 +70,7 @@ pub struct AccountRoutes;
 
 impl AccountRoutes {
     pub fn routes_without_obfuscation_support(state: StateForRouterCreation) -> Router {
-        Router::new()
+        let sign_in_with = Router::new()
             .route(
                 api::account::PATH_SIGN_IN_WITH_LOGIN,
                 post(api::account::post_sign_in_with_login),
@@ -79,6 +79,12 @@ impl AccountRoutes {
                 api::account::PATH_SIGN_IN_WITH_APPLE_REDIRECT_TO_APP,
                 post(api::account::post_sign_in_with_apple_redirect_to_app),
             )
+            .route_layer(middleware::from_fn_with_state(
+                (state.s.clone(), IpRateLimitRouteGroup::SignInWith),
+                api::utils::rate_limit_with_ip_address,
+            ));
+
+        let email_login = Router::new()
             .route(
                 api::account::PATH_POST_REQUEST_EMAIL_LOGIN_TOKEN,
                 post(api::account::post_request_email_login_token),
@@ -87,6 +93,14 @@ impl AccountRoutes {
                 api::account::PATH_POST_EMAIL_LOGIN_WITH_TOKEN,
                 post(api::account::post_email_login_with_token),
             )
+            .route_layer(middleware::from_fn_with_state(
+                (state.s.clone(), IpRateLimitRouteGroup::EmailLogin),
+                api::utils::rate_limit_with_ip_address,
+            ));
+
+        Router::new()
+            .merge(sign_in_with)
+            .merge(email_login)
             .route(
                 api::account::PATH_GET_VERIFY_EMAIL,
                 get(api::account::get_verify_email),
@@ -109,7 +123,13 @@ impl AccountRoutes {
     pub fn routes_with_obfuscation_support(state: StateForRouterCreation) -> Router {
         let public = Router::new();
         let public = if state.s.config().demo_account_config().is_some() {
-            public.merge(api::account::router_demo(state.clone()))
+            let demo = api::account::router_demo(state.clone()).route_layer(
+                middleware::from_fn_with_state(
+                    (state.s.clone(), IpRateLimitRouteGroup::DemoAccount),
+                    api::utils::rate_limit_with_ip_address,
+                ),
+            );
+            public.merge(demo)
         } else {
             public
         };
@@ -27,6 +27,8 @@ impl PrivateRoutes {
             .merge(api::common_admin::router_statistics(self.state.clone()))
             .merge(api::common_admin::router_report(self.state.clone()))
             .merge(api::common_admin::router_notification(self.state.clone()))
+            .merge(api::common_admin::router_audit_log(self.state.clone()))
+            .merge(api::common_admin::router_data_import(self.state.clone()))
             .route_layer({
                 middleware::from_fn_with_state(
                     self.state.clone(),
@@ -43,6 +45,7 @@ impl PrivateRoutes {
             .merge(api::account::router_ban(self.state.clone()))
             .merge(api::account::router_delete(self.state.clone()))
             .merge(api::account::router_sign_in_with(self.state.clone()))
+            .merge(api::account::router_totp(self.state.clone()))
             .merge(api::account::router_settings(self.state.clone()))
             .merge(api::account::router_state(self.state.clone()))
             .merge(api::account::router_news(self.state.clone()))
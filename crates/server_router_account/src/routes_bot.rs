@@ -1,5 +1,5 @@
-use axum::{Router, routing::post};
-use server_state::S;
+use axum::{Router, middleware, routing::post};
+use server_state::{S, ip_rate_limit::IpRateLimitRouteGroup};
 
 use crate::api;
 
@@ -7,11 +7,18 @@ pub struct RemoteBotApiRoutes;
 
 impl RemoteBotApiRoutes {
     pub fn router(state: S) -> Router {
-        Router::new()
+        let login = Router::new()
             .route(
                 api::account_bot::PATH_REMOTE_BOT_LOGIN,
                 post(api::account_bot::post_remote_bot_login),
             )
+            .route_layer(middleware::from_fn_with_state(
+                (state.clone(), IpRateLimitRouteGroup::RemoteBotLogin),
+                api::utils::rate_limit_with_ip_address,
+            ));
+
+        Router::new()
+            .merge(login)
             .route(
                 api::account_bot::PATH_REMOTE_GET_BOTS,
                 post(api::account_bot::post_remote_get_bots),
@@ -19,6 +19,7 @@ impl PrivateRoutes {
             .merge(api::chat::block::router_block(self.state.clone()))
             .merge(api::chat::match_routes::router_match(self.state.clone()))
             .merge(api::chat::message::router_message(self.state.clone()))
+            .merge(api::chat::attachment::router_attachment(self.state.clone()))
             .merge(api::chat::public_key::router_public_key(self.state.clone()))
             .merge(api::chat::report::router_chat_report(self.state.clone()))
             .merge(api::chat::notification::router_notification(
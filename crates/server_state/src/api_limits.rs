@@ -154,6 +154,18 @@ impl AccountApiLimits<'_> {
             })
             .await
     }
+
+    pub async fn totp_code_attempt(&self) -> Result<(), ApiLimitError> {
+        self.limits
+            .check(|state, config| {
+                state
+                    .totp_code_attempts
+                    .increment_and_check_is_limit_reached(
+                        config.limits_account().totp_code_attempts_daily_max_count,
+                    )
+            })
+            .await
+    }
 }
 
 pub struct ProfileApiLimits<'a> {
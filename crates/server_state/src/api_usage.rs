@@ -7,7 +7,7 @@ use std::{
     },
 };
 
-use model::{AccountIdDb, ApiUsage};
+use model::{AccountIdDb, ApiUsage, ApiUsageValue};
 use tokio::sync::RwLock;
 
 struct State {
@@ -16,6 +16,8 @@ struct State {
 
 pub struct ApiUsageTracker {
     state: Arc<RwLock<State>>,
+    /// Usage of all accounts since server start. This is not reset.
+    totals: Arc<ApiUsage>,
 }
 
 impl Debug for ApiUsageTracker {
@@ -28,6 +30,7 @@ impl Clone for ApiUsageTracker {
     fn clone(&self) -> Self {
         Self {
             state: self.state.clone(),
+            totals: self.totals.clone(),
         }
     }
 }
@@ -38,9 +41,14 @@ impl ApiUsageTracker {
             state: Arc::new(RwLock::new(State {
                 api_usage: HashMap::new(),
             })),
+            totals: Arc::new(ApiUsage::default()),
         }
     }
 
+    pub fn totals(&self) -> impl Iterator<Item = ApiUsageValue> {
+        self.totals.values()
+    }
+
     pub async fn get_current_state_and_reset(&self) -> HashMap<AccountIdDb, ApiUsage> {
         let mut lock = self.state.write().await;
         std::mem::take(&mut lock.api_usage)
@@ -49,8 +57,10 @@ impl ApiUsageTracker {
     pub async fn incr(
         &self,
         account: impl Into<AccountIdDb>,
-        api_getter: impl FnOnce(&ApiUsage) -> &AtomicU32,
+        api_getter: impl Fn(&ApiUsage) -> &AtomicU32,
     ) {
+        api_getter(&self.totals).fetch_add(1, Ordering::Relaxed);
+
         let account = account.into();
         {
             let lock = self.state.read().await;
@@ -0,0 +1,273 @@
+use std::{
+    collections::HashMap,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use config::{
+    Config,
+    file::{IpRateLimitConfig, TokenBucketConfig},
+};
+use tokio::sync::Mutex;
+
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(60 * 10);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IpRateLimitRouteGroup {
+    SignInWith,
+    EmailLogin,
+    DemoAccount,
+    RemoteBotLogin,
+}
+
+impl IpRateLimitRouteGroup {
+    fn bucket_config(&self, config: &IpRateLimitConfig) -> TokenBucketConfig {
+        match self {
+            Self::SignInWith => config.sign_in_with,
+            Self::EmailLogin => config.email_login,
+            Self::DemoAccount => config.demo_account,
+            Self::RemoteBotLogin => config.remote_bot_login,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TokenBucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    fn new(capacity: u16, now: Instant) -> Self {
+        Self {
+            tokens: capacity.into(),
+            updated: now,
+        }
+    }
+
+    fn refill(&mut self, config: TokenBucketConfig, now: Instant) {
+        let refill_duration = refill_duration(config);
+        let elapsed = now.saturating_duration_since(self.updated);
+        let new_tokens = elapsed.as_secs_f64() / refill_duration.as_secs_f64();
+        self.tokens = (self.tokens + new_tokens).min(config.capacity.into());
+        self.updated = now;
+    }
+
+    /// Returns wait time until next request is possible if the bucket
+    /// is empty.
+    fn take(&mut self, config: TokenBucketConfig, now: Instant) -> Result<(), Duration> {
+        self.refill(config, now);
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            let missing = 1.0 - self.tokens;
+            Err(refill_duration(config).mul_f64(missing))
+        }
+    }
+
+    fn is_full(&self, config: TokenBucketConfig, now: Instant) -> bool {
+        let mut bucket = *self;
+        bucket.refill(config, now);
+        bucket.tokens >= config.capacity.into()
+    }
+}
+
+fn refill_duration(config: TokenBucketConfig) -> Duration {
+    Duration::from_secs(config.refill_duration.seconds.max(1).into())
+}
+
+/// Convert IP address to network address if network prefix is configured.
+fn rate_limit_key(ip: IpAddr, config: &IpRateLimitConfig) -> IpAddr {
+    match ip {
+        IpAddr::V4(ip) => {
+            let prefix = config.ipv4_prefix_length.min(32);
+            let mask = u32::MAX.checked_shl(32 - u32::from(prefix)).unwrap_or(0);
+            Ipv4Addr::from_bits(ip.to_bits() & mask).into()
+        }
+        IpAddr::V6(ip) => {
+            let prefix = config.ipv6_prefix_length.min(128);
+            let mask = u128::MAX.checked_shl(128 - u32::from(prefix)).unwrap_or(0);
+            Ipv6Addr::from_bits(ip.to_bits() & mask).into()
+        }
+    }
+}
+
+struct IpRateLimiterInner {
+    buckets: HashMap<(IpRateLimitRouteGroup, IpAddr), TokenBucket>,
+    last_cleanup: Instant,
+}
+
+impl IpRateLimiterInner {
+    fn take(
+        &mut self,
+        key: (IpRateLimitRouteGroup, IpAddr),
+        config: &IpRateLimitConfig,
+        now: Instant,
+    ) -> Result<(), Duration> {
+        let bucket_config = key.0.bucket_config(config);
+
+        if now.saturating_duration_since(self.last_cleanup) >= CLEANUP_INTERVAL {
+            self.remove_full_buckets(config, now);
+        }
+
+        if !self.buckets.contains_key(&key) {
+            let max_count = usize::try_from(config.max_bucket_count).unwrap_or(usize::MAX);
+            if self.buckets.len() >= max_count {
+                self.remove_full_buckets(config, now);
+            }
+            if self.buckets.len() >= max_count {
+                return Err(refill_duration(bucket_config));
+            }
+        }
+
+        self.buckets
+            .entry(key)
+            .or_insert_with(|| TokenBucket::new(bucket_config.capacity, now))
+            .take(bucket_config, now)
+    }
+
+    fn remove_full_buckets(&mut self, config: &IpRateLimitConfig, now: Instant) {
+        self.buckets
+            .retain(|(group, _), bucket| !bucket.is_full(group.bucket_config(config), now));
+        self.last_cleanup = now;
+    }
+}
+
+/// Token bucket rate limiter for unauthenticated routes
+pub struct IpRateLimiter {
+    inner: Arc<Mutex<IpRateLimiterInner>>,
+}
+
+impl Default for IpRateLimiter {
+    fn default() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(IpRateLimiterInner {
+                buckets: HashMap::new(),
+                last_cleanup: Instant::now(),
+            })),
+        }
+    }
+}
+
+impl Clone for IpRateLimiter {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl IpRateLimiter {
+    /// Returns `Err` with wait time if the limit is reached.
+    pub async fn check(
+        &self,
+        group: IpRateLimitRouteGroup,
+        ip: IpAddr,
+        config: &Config,
+    ) -> Result<(), Duration> {
+        let rate_limit_config = config.limits_ip_rate_limit();
+        let bucket_config = group.bucket_config(&rate_limit_config);
+
+        if bucket_config.capacity == 0 || config.api().debug_disable_api_limits {
+            return Ok(());
+        }
+
+        let is_exempted = config.simple_backend().ip_lists().iter().any(|list| {
+            rate_limit_config
+                .exempted_ip_lists
+                .iter()
+                .any(|name| name == list.name())
+                && list.contains(ip)
+        });
+        if is_exempted {
+            return Ok(());
+        }
+
+        let key = (group, rate_limit_key(ip, &rate_limit_config));
+        self.inner
+            .lock()
+            .await
+            .take(key, &rate_limit_config, Instant::now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use simple_backend_utils::time::DurationValue;
+
+    use super::*;
+
+    const CONFIG: TokenBucketConfig = TokenBucketConfig {
+        capacity: 2,
+        refill_duration: DurationValue::from_seconds(10),
+    };
+
+    #[test]
+    fn token_bucket_refills_over_time() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(CONFIG.capacity, start);
+        assert_eq!(bucket.take(CONFIG, start), Ok(()));
+        assert_eq!(bucket.take(CONFIG, start), Ok(()));
+        assert_eq!(bucket.take(CONFIG, start), Err(Duration::from_secs(10)));
+        let later = start + Duration::from_secs(5);
+        assert_eq!(bucket.take(CONFIG, later), Err(Duration::from_secs(5)));
+        let later = start + Duration::from_secs(10);
+        assert_eq!(bucket.take(CONFIG, later), Ok(()));
+        assert!(bucket.is_full(CONFIG, later + Duration::from_secs(20)));
+    }
+
+    #[test]
+    fn network_prefix_is_used_as_key() {
+        let config = IpRateLimitConfig {
+            ipv4_prefix_length: 24,
+            ipv6_prefix_length: 64,
+            ..Default::default()
+        };
+        assert_eq!(
+            rate_limit_key("192.168.1.123".parse().unwrap(), &config),
+            "192.168.1.0".parse::<IpAddr>().unwrap(),
+        );
+        assert_eq!(
+            rate_limit_key("2001:db8:1:2:3:4:5:6".parse().unwrap(), &config),
+            "2001:db8:1:2::".parse::<IpAddr>().unwrap(),
+        );
+        let config = IpRateLimitConfig::default();
+        assert_eq!(
+            rate_limit_key("192.168.1.123".parse().unwrap(), &config),
+            "192.168.1.123".parse::<IpAddr>().unwrap(),
+        );
+        assert_eq!(
+            rate_limit_key("2001:db8:1:2:3:4:5:6".parse().unwrap(), &config),
+            "2001:db8:1:2::".parse::<IpAddr>().unwrap(),
+        );
+    }
+
+    #[test]
+    fn bucket_count_is_limited() {
+        let config = IpRateLimitConfig {
+            max_bucket_count: 1,
+            sign_in_with: CONFIG,
+            ..Default::default()
+        };
+        let group = IpRateLimitRouteGroup::SignInWith;
+        let ip1: IpAddr = "192.168.1.1".parse().unwrap();
+        let ip2: IpAddr = "192.168.1.2".parse().unwrap();
+        let start = Instant::now();
+        let mut limiter = IpRateLimiterInner {
+            buckets: HashMap::new(),
+            last_cleanup: start,
+        };
+
+        assert_eq!(limiter.take((group, ip1), &config, start), Ok(()));
+        assert_eq!(
+            limiter.take((group, ip2), &config, start),
+            Err(Duration::from_secs(10)),
+        );
+        // The first bucket is full again, so it can be removed
+        let later = start + Duration::from_secs(10);
+        assert_eq!(limiter.take((group, ip2), &config, later), Ok(()));
+        assert_eq!(limiter.buckets.len(), 1);
+    }
+}
@@ -14,7 +14,8 @@ use data_signer::DataSigner;
 use ip_address::IpAddressUsageTracker;
 use model::{
     AccountIdInternal, AccountVerificationErrorFlagsValue, ClientMessageForDataAllCrate,
-    EditVerificationValues, UnixTime, VerificationMethod,
+    EditVerificationValues, MessageId, NewPendingMessage, PendingMessageId, PublicKeyId,
+    ResponseSendMessage, UnixTime, VerificationMethod,
 };
 use model_server_data::{EmailAddress, SignInWithInfo};
 use server_common::{push_notifications::PushNotificationSender, websocket::WebSocketError};
@@ -22,12 +23,14 @@ use server_data::{
     app::{DataAllUtils, GetConfig, RegisterImplResult},
     content_processing::ContentProcessingManagerData,
     data_export::{DataExportCmd, DataExportManagerData},
+    data_import::{DataImportProfileContent, DataImportResult},
     db_manager::RouterDatabaseReadHandle,
     email::EmailChannelSender,
     statistics::ProfileStatisticsCache,
     write_commands::WriteCommandRunnerHandle,
 };
 use simple_backend::app::SimpleBackendAppState;
+use utils::encrypt::ParsedKeys;
 
 use crate::{
     account_verification_queue::AccountVerificationQueueData,
@@ -36,6 +39,7 @@ use crate::{
     demo::DemoAccountManager,
     dynamic_config::DynamicConfigManagerData,
     email_registration::{EmailRegistrationTokenStore, limit::EmailRegistrationRateLimiter},
+    ip_rate_limit::IpRateLimiter,
     utils::ETagUtils,
 };
 
@@ -51,6 +55,7 @@ pub mod demo;
 pub mod dynamic_config;
 pub mod email_registration;
 pub mod ip_address;
+pub mod ip_rate_limit;
 pub mod state_impl;
 pub mod utils;
 
@@ -87,6 +92,7 @@ struct AppStateInternal {
     etag_utils: ETagUtils,
     email_registration_tokens: EmailRegistrationTokenStore,
     email_registration_rate_limiter: EmailRegistrationRateLimiter,
+    ip_rate_limiter: IpRateLimiter,
     email_channel_sender: EmailChannelSender,
 }
 
@@ -132,6 +138,7 @@ impl AppState {
             etag_utils: ETagUtils::new(),
             email_registration_tokens: EmailRegistrationTokenStore::default(),
             email_registration_rate_limiter: EmailRegistrationRateLimiter::default(),
+            ip_rate_limiter: IpRateLimiter::default(),
             email_channel_sender,
         };
 
@@ -156,6 +163,10 @@ impl AppState {
         &self.state.email_registration_rate_limiter
     }
 
+    pub fn ip_rate_limiter(&self) -> &IpRateLimiter {
+        &self.state.ip_rate_limiter
+    }
+
     pub fn email_channel_sender(&self) -> &EmailChannelSender {
         &self.state.email_channel_sender
     }
@@ -250,6 +261,57 @@ impl DataAllAccess<'_> {
         cmd.await
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub async fn send_message(
+        &self,
+        sender: AccountIdInternal,
+        recipient: AccountIdInternal,
+        message: Vec<u8>,
+        sender_public_key: PublicKeyId,
+        recipient_public_key: PublicKeyId,
+        message_id: MessageId,
+        keys: Arc<ParsedKeys>,
+    ) -> server_common::result::Result<(ResponseSendMessage, Option<NewPendingMessage>), DataError>
+    {
+        let cmd = self.utils().send_message(
+            self.write(),
+            sender,
+            recipient,
+            message,
+            sender_public_key,
+            recipient_public_key,
+            message_id,
+            keys,
+        );
+        cmd.await
+    }
+
+    pub async fn add_recipient_acknowledgement(
+        &self,
+        recipient: AccountIdInternal,
+        messages: Vec<PendingMessageId>,
+        delivery_failed: bool,
+    ) -> server_common::result::Result<(), DataError> {
+        let cmd = self.utils().add_recipient_acknowledgement(
+            self.write(),
+            recipient,
+            messages,
+            delivery_failed,
+        );
+        cmd.await
+    }
+
+    pub async fn add_sender_acknowledgement(
+        &self,
+        sender: AccountIdInternal,
+        messages: Vec<MessageId>,
+    ) -> server_common::result::Result<(), DataError> {
+        let cmd = self
+            .utils()
+            .add_sender_acknowledgement(self.write(), sender, messages);
+        cmd.await
+    }
+
     pub async fn data_export(
         &self,
         zip_main_directory_name: String,
@@ -261,6 +323,37 @@ impl DataAllAccess<'_> {
         cmd.await
     }
 
+    pub async fn import_account_data(
+        &self,
+        archive: Vec<u8>,
+    ) -> server_common::result::Result<DataImportResult, DataError> {
+        let cmd = self.utils().import_account_data(self.write(), archive);
+        cmd.await
+    }
+
+    pub async fn cancel_account_data_import(
+        &self,
+        id: AccountIdInternal,
+    ) -> server_common::result::Result<(), DataError> {
+        let cmd = self.utils().cancel_account_data_import(self.write(), id);
+        cmd.await
+    }
+
+    pub async fn complete_account_data_import(
+        &self,
+        id: AccountIdInternal,
+        content: DataImportProfileContent,
+    ) -> server_common::result::Result<(), DataError> {
+        let cmd = self.utils().complete_account_data_import(
+            self.config(),
+            self.read(),
+            self.write(),
+            id,
+            content,
+        );
+        cmd.await
+    }
+
     pub async fn edit_verification_values(
         &self,
         moderator_id: AccountIdInternal,
@@ -306,6 +399,20 @@ impl DataAllAccess<'_> {
             .auto_ban_spam_reporters(self.write(), reporters_to_ban);
         cmd.await
     }
+
+    /// Vec items are `(banned_account, target)` pairs.
+    pub async fn ban_accounts_linked_to_banned_accounts(
+        &self,
+        moderator_id: AccountIdInternal,
+        accounts: Vec<(AccountIdInternal, AccountIdInternal)>,
+    ) -> server_common::result::Result<(), DataError> {
+        let cmd = self.utils().ban_accounts_linked_to_banned_accounts(
+            self.write(),
+            moderator_id,
+            accounts,
+        );
+        cmd.await
+    }
 }
 
 /// Macro for writing data with simpler syntax.
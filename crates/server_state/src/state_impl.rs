@@ -20,9 +20,9 @@ use server_data::{
 };
 use simple_backend::{
     app::{
-        AppAttestationProvider, FilePackageProvider, GetManagerApi, GetSimpleBackendConfig,
-        GetTileMap, IpCountryTrackerProvider, JitsiMeetUrlCreatorProvider, MaxMindDbDataProvider,
-        PerfCounterDataProvider, SignInWith,
+        AppAttestationProvider, AppMetricsProvider, FilePackageProvider, GetManagerApi,
+        GetSimpleBackendConfig, GetTileMap, IpCountryTrackerProvider, JitsiMeetUrlCreatorProvider,
+        MaxMindDbDataProvider, PerfCounterDataProvider, SignInWith,
     },
     app_attestation::AppAttestationManager,
     file_package::FilePackageManager,
@@ -30,7 +30,7 @@ use simple_backend::{
     jitsi_meet::JitsiMeetUrlCreator,
     manager_client::{ManagerApiClient, ManagerEventHandler},
     map::TileMapManager,
-    perf::PerfMetricsManagerData,
+    perf::{PerfMetricsManagerData, open_metrics::OpenMetrics},
     sign_in_with::SignInWithManager,
 };
 use simple_backend_config::SimpleBackendConfig;
@@ -259,7 +259,7 @@ impl GetAccessTokens for S {
         self.state
             .database
             .access_token_manager()
-            .access_token_and_ip_is_valid(token, connection)
+            .access_token_and_ip_is_valid(token, connection, self.config().admin_totp())
             .await
     }
 }
@@ -366,6 +366,51 @@ impl PerfCounterDataProvider for S {
     }
 }
 
+impl AppMetricsProvider for S {
+    async fn app_metrics(&self, metrics: &mut OpenMetrics) {
+        let mut api_usage = metrics.counter("api_usage", "API usage of all accounts");
+        for v in self.state.api_usage_tracker.totals() {
+            api_usage.value_with_labels(&[("api", v.name)], v.value.into());
+        }
+
+        let pools = [
+            (
+                "current",
+                self.state.database.read_handle_raw().0.pool_status(),
+            ),
+            (
+                "history",
+                self.state
+                    .database
+                    .history_read_handle_raw()
+                    .0
+                    .pool_status(),
+            ),
+        ];
+        let mut connections = metrics.gauge(
+            "database_read_pool_connections",
+            "Database read pool connections",
+        );
+        for (database, status) in pools {
+            for (state, count) in [
+                ("max", status.max_size),
+                ("open", status.size),
+                ("available", status.available),
+            ] {
+                connections
+                    .value_with_labels(&[("database", database), ("state", state)], count as u64);
+            }
+        }
+        let mut waiting = metrics.gauge(
+            "database_read_pool_waiting_tasks",
+            "Tasks waiting for a database read connection",
+        );
+        for (database, status) in pools {
+            waiting.value_with_labels(&[("database", database)], status.waiting as u64);
+        }
+    }
+}
+
 impl FilePackageProvider for S {
     fn file_package(&self) -> &FilePackageManager {
         &self.state.simple_backend_state.file_packages
@@ -2,12 +2,14 @@ use axum::response::{IntoResponse, Response};
 use config::file::ConfigFileError;
 use headers::ETag;
 use manager_api::ClientError;
-use model::{ContentQualityVariant, UnixTime};
+use model::{ContentFileVariant, ContentImageFormat, ContentQualityVariant, UnixTime};
 use server_common::data::cache::CacheError;
 use server_data::{data_export::DataExportError, event::EventError};
 use simple_backend::{
     jitsi_meet::JitsiMeetUrlCreatorError,
-    sign_in_with::{apple::SignInWithAppleError, google::SignInWithGoogleError},
+    sign_in_with::{
+        apple::SignInWithAppleError, google::SignInWithGoogleError, oidc::SignInWithOidcError,
+    },
 };
 
 use crate::{DataError, api_limits::ApiLimitError, data_signer::DataSignerError};
@@ -30,6 +32,8 @@ pub enum StatusCode {
     TOO_MANY_REQUESTS,
     /// 304
     NOT_MODIFIED,
+    /// 413
+    PAYLOAD_TOO_LARGE,
 }
 
 impl From<StatusCode> for hyper::StatusCode {
@@ -43,6 +47,7 @@ impl From<StatusCode> for hyper::StatusCode {
             StatusCode::NOT_FOUND => hyper::StatusCode::NOT_FOUND,
             StatusCode::TOO_MANY_REQUESTS => hyper::StatusCode::TOO_MANY_REQUESTS,
             StatusCode::NOT_MODIFIED => hyper::StatusCode::NOT_MODIFIED,
+            StatusCode::PAYLOAD_TOO_LARGE => hyper::StatusCode::PAYLOAD_TOO_LARGE,
         }
     }
 }
@@ -64,6 +69,8 @@ enum RequestError {
     SignInWithGoogle,
     #[error("Sign in with Apple error")]
     SignInWithApple,
+    #[error("Sign in with OpenID Connect error")]
+    SignInWithOidc,
     #[error("Manager client error")]
     ManagerClientError,
     #[error("Config file error")]
@@ -137,6 +144,7 @@ impl_error_to_status_code!(DataError, RequestError::Data);
 impl_error_to_status_code!(CacheError, RequestError::Cache);
 impl_error_to_status_code!(SignInWithGoogleError, RequestError::SignInWithGoogle);
 impl_error_to_status_code!(SignInWithAppleError, RequestError::SignInWithApple);
+impl_error_to_status_code!(SignInWithOidcError, RequestError::SignInWithOidc);
 impl_error_to_status_code!(ClientError, RequestError::ManagerClientError);
 impl_error_to_status_code!(ConfigFileError, RequestError::ConfigFileError);
 impl_error_to_status_code!(EventError, RequestError::EventError);
@@ -153,13 +161,49 @@ impl From<crate::result::WrappedReport<error_stack::Report<ApiLimitError>>> for
     }
 }
 
+struct QualityVariantETags {
+    high: ETag,
+    medium: ETag,
+    low: ETag,
+    lower: ETag,
+    very_low: ETag,
+}
+
+impl QualityVariantETags {
+    fn new(format: ContentImageFormat) -> Self {
+        let etag = |quality: ContentQualityVariant| -> ETag {
+            format!(
+                "\"{}\"",
+                ContentFileVariant::new(quality, format).file_name_suffix()
+            )
+            .parse()
+            .unwrap()
+        };
+        Self {
+            high: etag(ContentQualityVariant::High),
+            medium: etag(ContentQualityVariant::Medium),
+            low: etag(ContentQualityVariant::Low),
+            lower: etag(ContentQualityVariant::Lower),
+            very_low: etag(ContentQualityVariant::VeryLow),
+        }
+    }
+
+    fn get(&self, variant: ContentQualityVariant) -> &ETag {
+        match variant {
+            ContentQualityVariant::High => &self.high,
+            ContentQualityVariant::Medium => &self.medium,
+            ContentQualityVariant::Low => &self.low,
+            ContentQualityVariant::Lower => &self.lower,
+            ContentQualityVariant::VeryLow => &self.very_low,
+        }
+    }
+}
+
 pub struct ETagUtils {
     immutable_content: ETag,
-    immutable_content_high: ETag,
-    immutable_content_medium: ETag,
-    immutable_content_low: ETag,
-    immutable_content_lower: ETag,
-    immutable_content_very_low: ETag,
+    immutable_content_jpeg: QualityVariantETags,
+    immutable_content_webp: QualityVariantETags,
+    immutable_content_avif: QualityVariantETags,
     server_start_time: ETag,
 }
 
@@ -167,11 +211,9 @@ impl ETagUtils {
     pub(crate) fn new() -> Self {
         Self {
             immutable_content: "\"i\"".to_string().parse().unwrap(),
-            immutable_content_high: "\"6\"".parse().unwrap(),
-            immutable_content_medium: "\"5\"".parse().unwrap(),
-            immutable_content_low: "\"4\"".parse().unwrap(),
-            immutable_content_lower: "\"3\"".parse().unwrap(),
-            immutable_content_very_low: "\"2\"".parse().unwrap(),
+            immutable_content_jpeg: QualityVariantETags::new(ContentImageFormat::Jpeg),
+            immutable_content_webp: QualityVariantETags::new(ContentImageFormat::Webp),
+            immutable_content_avif: QualityVariantETags::new(ContentImageFormat::Avif),
             server_start_time: format!("\"{}\"", UnixTime::current_time().ut)
                 .parse()
                 .unwrap(),
@@ -182,14 +224,13 @@ impl ETagUtils {
         &self.immutable_content
     }
 
-    pub fn immutable_content_variant(&self, variant: ContentQualityVariant) -> &ETag {
-        match variant {
-            ContentQualityVariant::High => &self.immutable_content_high,
-            ContentQualityVariant::Medium => &self.immutable_content_medium,
-            ContentQualityVariant::Low => &self.immutable_content_low,
-            ContentQualityVariant::Lower => &self.immutable_content_lower,
-            ContentQualityVariant::VeryLow => &self.immutable_content_very_low,
-        }
+    pub fn immutable_content_variant(&self, variant: ContentFileVariant) -> &ETag {
+        let etags = match variant.format {
+            ContentImageFormat::Jpeg => &self.immutable_content_jpeg,
+            ContentImageFormat::Webp => &self.immutable_content_webp,
+            ContentImageFormat::Avif => &self.immutable_content_avif,
+        };
+        etags.get(variant.quality)
     }
 
     pub fn server_start_time(&self) -> &ETag {
@@ -7,9 +7,13 @@ use simple_backend_config::SimpleBackendConfig;
 
 use super::manager_client::ManagerApiClient;
 use crate::{
-    app_attestation::AppAttestationManager, file_package::FilePackageManager,
-    ip_country::IpCountryTracker, jitsi_meet::JitsiMeetUrlCreator, map::TileMapManager,
-    maxmind_db::MaxMindDbManagerData, perf::PerfMetricsManagerData,
+    app_attestation::AppAttestationManager,
+    file_package::FilePackageManager,
+    ip_country::IpCountryTracker,
+    jitsi_meet::JitsiMeetUrlCreator,
+    map::TileMapManager,
+    maxmind_db::MaxMindDbManagerData,
+    perf::{PerfMetricsManagerData, open_metrics::OpenMetrics},
     sign_in_with::SignInWithManager,
 };
 
@@ -17,6 +21,8 @@ use crate::{
 pub enum AppStateCreationError {
     #[error("File package manager error")]
     FilePackageManagerError,
+    #[error("Tile map manager error")]
+    TileMapManagerError,
 }
 
 #[derive(Clone)]
@@ -43,7 +49,10 @@ impl SimpleBackendAppState {
     ) -> simple_backend_utils::Result<Self, AppStateCreationError> {
         Ok(SimpleBackendAppState {
             reqwest_client: reqwest_client.clone(),
-            tile_map: TileMapManager::new(&config).into(),
+            tile_map: TileMapManager::new(&config)
+                .await
+                .change_context(AppStateCreationError::TileMapManagerError)?
+                .into(),
             sign_in_with: SignInWithManager::new(config.clone(), reqwest_client).into(),
             app_attestation: AppAttestationManager::new(config.clone()).into(),
             file_packages: FilePackageManager::new(&config)
@@ -99,6 +108,13 @@ pub trait PerfCounterDataProvider {
     fn perf_counter_data_arc(&self) -> Arc<PerfMetricsManagerData>;
 }
 
+pub trait AppMetricsProvider {
+    /// Add application specific metrics to metrics API response.
+    fn app_metrics(&self, _metrics: &mut OpenMetrics) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+}
+
 pub trait FilePackageProvider {
     fn file_package(&self) -> &FilePackageManager;
 }
@@ -2,23 +2,25 @@ use std::{num::NonZeroU32, str::FromStr, time::Duration};
 
 use data::EmailLimitStateStorage;
 use error_stack::ResultExt;
-use lettre::{
-    Address, AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
-    message::{Mailbox, header::ContentType},
-    transport::smtp::{PoolConfig, authentication::Credentials},
-};
+use futures::future::BoxFuture;
+use lettre::{Address, message::Mailbox};
 use simple_backend_config::{SimpleBackendConfig, file::EmailSendingConfig};
 use simple_backend_model::UnixTime;
-use simple_backend_utils::{ContextExt, Result};
+pub use simple_backend_model::{EmailBody, EmailInlineImage};
+use simple_backend_utils::Result;
 use tokio::sync::Mutex;
 use tracing::{error, info};
 
-use crate::email::data::Counter;
+use crate::email::{data::Counter, http::HttpEmailSender, smtp::SmtpEmailSender};
 
 mod data;
+mod http;
+mod smtp;
 
 #[derive(thiserror::Error, Debug)]
 pub enum EmailError {
+    #[error("Email sender creating failed")]
+    SenderCreatingFailed,
     #[error("Email sending failed")]
     SendingFailed,
     #[error("Email sending response not positive")]
@@ -41,32 +43,41 @@ pub enum EmailError {
     SavingStateFailed,
 }
 
-pub struct SmtpClient {
+pub struct EmailMessage<'a> {
+    pub from: &'a Mailbox,
+    pub to: Address,
+    pub subject: &'a str,
+    pub body: &'a EmailBody,
+}
+
+/// Email sending backend
+pub trait EmailSender: Send + Sync {
+    /// Send email. Returns message ID from email provider if it
+    /// is available.
+    fn send<'a>(
+        &'a self,
+        message: &'a EmailMessage<'a>,
+    ) -> BoxFuture<'a, Result<Option<String>, EmailError>>;
+}
+
+pub struct EmailClient {
     sending_logic: Option<Mutex<EmailSendingLogic>>,
 }
 
-impl SmtpClient {
+impl EmailClient {
     pub async fn new(simple_backend_config: &SimpleBackendConfig) -> Self {
         let data = if let Some(config) = simple_backend_config.email_sending() {
-            let transport = if config.use_starttls_instead_of_smtps {
-                AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.smtp_server_address)
-            } else {
-                AsyncSmtpTransport::<Tokio1Executor>::relay(&config.smtp_server_address)
-            }
-            .map(|builder| {
-                builder
-                    .credentials(Credentials::new(
-                        config.username.clone(),
-                        config.password.clone(),
-                    ))
-                    .pool_config(PoolConfig::new().max_size(1))
-                    .build()
-            });
-
-            match transport {
+            let sender: Result<Box<dyn EmailSender>, EmailError> =
+                if let Some(http_config) = &config.http_api {
+                    HttpEmailSender::new(http_config).map(|v| Box::new(v) as Box<dyn EmailSender>)
+                } else {
+                    SmtpEmailSender::new(config).map(|v| Box::new(v) as Box<dyn EmailSender>)
+                };
+
+            match sender {
                 Ok(sender) => Some((sender, config.clone())),
                 Err(e) => {
-                    error!("Email sender creating failed: {}", e);
+                    error!("Email sender creating failed: {:?}", e);
                     None
                 }
             }
@@ -123,30 +134,24 @@ impl SmtpClient {
     }
 
     /// Might block until email sending is possible
-    pub async fn send(
-        &self,
-        to: &str,
-        subject: &str,
-        body: &str,
-        body_is_html: bool,
-    ) -> Result<(), EmailError> {
+    pub async fn send(&self, to: &str, subject: &str, body: &EmailBody) -> Result<(), EmailError> {
         let mut sender = match &self.sending_logic {
             Some(s) => s.lock().await,
             None => return Ok(()),
         };
-        sender.send(to, subject, body, body_is_html).await
+        sender.send(to, subject, body).await
     }
 }
 
 struct EmailSendingLogic {
-    sender: AsyncSmtpTransport<Tokio1Executor>,
+    sender: Box<dyn EmailSender>,
     config: EmailSendingConfig,
     send_count_per_minute: SendCounter,
     send_count_per_day: SendCounter,
 }
 
 impl EmailSendingLogic {
-    fn new(sender: AsyncSmtpTransport<Tokio1Executor>, config: EmailSendingConfig) -> Self {
+    fn new(sender: Box<dyn EmailSender>, config: EmailSendingConfig) -> Self {
         Self {
             sender,
             config,
@@ -159,37 +164,18 @@ impl EmailSendingLogic {
         &mut self,
         to: &str,
         subject: &str,
-        body: &str,
-        body_is_html: bool,
+        body: &EmailBody,
     ) -> Result<(), EmailError> {
         let address =
             Address::from_str(to).change_context(EmailError::AccountEmailAddressParsingFailed)?;
 
-        let content_type = if body_is_html {
-            ContentType::TEXT_HTML
-        } else {
-            ContentType::TEXT_PLAIN
-        };
-
         if self.config.debug_logging {
             info!(
-                "Sending email:\nTo: {}\nSubject: {}\nBody: {}",
+                "Sending email:\nTo: {}\nSubject: {}\nBody: {:?}",
                 address, subject, body
             );
         }
 
-        let message = Message::builder()
-            .from(self.config.email_from_header.0.clone())
-            .to(Mailbox::new(None, address))
-            .subject(subject.to_string())
-            .header(content_type)
-            .body(body.to_string())
-            .change_context(EmailError::MessageBuildingFailed)?;
-
-        self.send_raw(message).await
-    }
-
-    async fn send_raw(&mut self, message: Message) -> Result<(), EmailError> {
         self.send_count_per_minute
             .wait_until_allowed(self.config.send_limit_per_minute)
             .await;
@@ -202,25 +188,20 @@ impl EmailSendingLogic {
         self.send_count_per_day
             .increment(self.config.send_limit_per_day);
 
-        let response = self
-            .sender
-            .send(message)
-            .await
-            .change_context(EmailError::SendingFailed)?;
+        let message = EmailMessage {
+            from: &self.config.email_from_header.0,
+            to: address,
+            subject,
+            body,
+        };
 
-        if response.is_positive() {
-            Ok(())
-        } else {
-            let response_message = response.message().collect::<Vec<_>>().join(" ");
-            let error = format!(
-                "SMTP response not positive, code: {}, message: {}",
-                response.code(),
-                response_message
-            );
-            Err(EmailError::EmailSendingResponseNotPositive
-                .report()
-                .attach(error))
+        let message_id = self.sender.send(&message).await?;
+
+        if let Some(message_id) = message_id {
+            info!("Email sent, message ID: {}", message_id);
         }
+
+        Ok(())
     }
 }
 
@@ -0,0 +1,406 @@
+use std::time::Duration;
+
+use base64::Engine;
+use error_stack::{Report, ResultExt};
+use futures::{FutureExt, future::BoxFuture};
+use handlebars::Handlebars;
+use reqwest::{
+    Client, StatusCode,
+    header::{HeaderName, HeaderValue, RETRY_AFTER},
+};
+use simple_backend_config::file::EmailHttpApiConfig;
+use simple_backend_utils::{ContextExt, Result};
+use tracing::warn;
+
+use super::{EmailBody, EmailError, EmailMessage, EmailSender};
+
+const BODY_TEMPLATE_NAME: &str = "body";
+const MAX_ATTEMPTS: usize = 3;
+const DEFAULT_RETRY_WAIT_TIME: Duration = Duration::from_secs(1);
+/// Max wait time for HTTP 429 and 503 responses
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+pub struct HttpEmailSender {
+    client: Client,
+    url: reqwest::Url,
+    auth_header_name: HeaderName,
+    auth_header_value: HeaderValue,
+    message_id_json_pointer: Option<String>,
+    handlebars: Handlebars<'static>,
+}
+
+impl HttpEmailSender {
+    pub fn new(config: &EmailHttpApiConfig) -> Result<Self, EmailError> {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(20))
+            .build()
+            .change_context(EmailError::SenderCreatingFailed)?;
+
+        let auth_header_name = HeaderName::try_from(&config.auth_header_name)
+            .change_context(EmailError::SenderCreatingFailed)?;
+        let mut auth_header_value = HeaderValue::try_from(&config.auth_header_value)
+            .change_context(EmailError::SenderCreatingFailed)?;
+        auth_header_value.set_sensitive(true);
+
+        let mut handlebars = Handlebars::new();
+        handlebars.register_escape_fn(json_string_escape);
+        handlebars
+            .register_template_string(BODY_TEMPLATE_NAME, &config.body_template)
+            .change_context(EmailError::SenderCreatingFailed)?;
+
+        let sender = Self {
+            client,
+            url: config.url.clone(),
+            auth_header_name,
+            auth_header_value,
+            message_id_json_pointer: config.message_id_json_pointer.clone(),
+            handlebars,
+        };
+
+        // Validate that the template produces valid JSON
+        let from = "Example <no-reply@example.com>"
+            .parse()
+            .change_context(EmailError::SenderCreatingFailed)?;
+        let body = EmailBody::TextAndHtml {
+            text: "Text \"with quotes\"\nand new line".to_string(),
+            html: "<p>HTML</p>".to_string(),
+            inline_images: vec![],
+        };
+        let message = EmailMessage {
+            from: &from,
+            to: "test@example.com"
+                .parse()
+                .change_context(EmailError::SenderCreatingFailed)?,
+            subject: "Subject",
+            body: &body,
+        };
+        sender
+            .render_request_body(&message)
+            .change_context(EmailError::SenderCreatingFailed)?;
+
+        Ok(sender)
+    }
+
+    fn render_request_body(
+        &self,
+        message: &EmailMessage<'_>,
+    ) -> Result<serde_json::Value, EmailError> {
+        let (text, html, inline_images) = match message.body {
+            EmailBody::Text(text) => (Some(text), None, [].as_slice()),
+            EmailBody::Html {
+                html,
+                inline_images,
+            } => (None, Some(html), inline_images.as_slice()),
+            EmailBody::TextAndHtml {
+                text,
+                html,
+                inline_images,
+            } => (Some(text), Some(html), inline_images.as_slice()),
+        };
+
+        let inline_images: Vec<_> = inline_images
+            .iter()
+            .map(|v| {
+                serde_json::json!({
+                    "content_id": v.content_id,
+                    "content_type": v.content_type,
+                    "data_base64": base64::engine::general_purpose::STANDARD.encode(&v.data),
+                })
+            })
+            .collect();
+
+        let data = serde_json::json!({
+            "from": message.from.to_string(),
+            "to": message.to.to_string(),
+            "subject": message.subject,
+            "text": text,
+            "html": html,
+            "inline_images": inline_images,
+        });
+
+        let body = self
+            .handlebars
+            .render(BODY_TEMPLATE_NAME, &data)
+            .change_context(EmailError::MessageBuildingFailed)?;
+
+        serde_json::from_str(&body)
+            .change_context(EmailError::MessageBuildingFailed)
+            .attach("Rendered body template is not valid JSON")
+    }
+
+    async fn send_request(
+        &self,
+        body: &serde_json::Value,
+    ) -> std::result::Result<Option<String>, Action> {
+        let response = self
+            .client
+            .post(self.url.clone())
+            .header(&self.auth_header_name, &self.auth_header_value)
+            .json(body)
+            .send()
+            .await;
+
+        let response = match response {
+            Ok(response) => response,
+            Err(e) => {
+                return Err(Action::Retry {
+                    error: EmailError::SendingFailed.report().attach(e.to_string()),
+                    wait: DEFAULT_RETRY_WAIT_TIME,
+                });
+            }
+        };
+
+        let status = response.status();
+        if status.is_success() {
+            let Some(pointer) = &self.message_id_json_pointer else {
+                return Ok(None);
+            };
+            let message_id = match response.json::<serde_json::Value>().await {
+                Ok(json) => json.pointer(pointer).map(|v| match v {
+                    serde_json::Value::String(v) => v.clone(),
+                    other => other.to_string(),
+                }),
+                Err(e) => {
+                    warn!("Email HTTP API response parsing failed: {e}");
+                    None
+                }
+            };
+            return Ok(message_id);
+        }
+
+        let retry_after = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok())
+            .map(Duration::from_secs);
+        let response_text = response.text().await.unwrap_or_default();
+        let error = EmailError::EmailSendingResponseNotPositive
+            .report()
+            .attach(format!("HTTP status: {status}, response: {response_text}"));
+
+        match status {
+            StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE => match retry_after {
+                Some(retry_after) if retry_after > MAX_RETRY_AFTER => Err(Action::Failed(error)),
+                Some(retry_after) => Err(Action::Retry {
+                    error,
+                    wait: retry_after,
+                }),
+                None => Err(Action::Retry {
+                    error,
+                    wait: DEFAULT_RETRY_WAIT_TIME,
+                }),
+            },
+            _ if status.is_server_error() => Err(Action::Retry {
+                error,
+                wait: DEFAULT_RETRY_WAIT_TIME,
+            }),
+            _ => Err(Action::Failed(error)),
+        }
+    }
+}
+
+impl EmailSender for HttpEmailSender {
+    fn send<'a>(
+        &'a self,
+        message: &'a EmailMessage<'a>,
+    ) -> BoxFuture<'a, Result<Option<String>, EmailError>> {
+        async move {
+            let body = self.render_request_body(message)?;
+
+            let mut attempt = 1;
+            loop {
+                match self.send_request(&body).await {
+                    Ok(message_id) => return Ok(message_id),
+                    Err(Action::Retry { error, wait }) if attempt < MAX_ATTEMPTS => {
+                        warn!("Email sending failed, retrying: {error:?}");
+                        tokio::time::sleep(wait).await;
+                        attempt += 1;
+                    }
+                    Err(Action::Retry { error, .. } | Action::Failed(error)) => return Err(error),
+                }
+            }
+        }
+        .boxed()
+    }
+}
+
+enum Action {
+    Retry {
+        error: Report<EmailError>,
+        wait: Duration,
+    },
+    Failed(Report<EmailError>),
+}
+
+/// Escape value so that it can be placed inside a JSON string
+fn json_string_escape(value: &str) -> String {
+    let json_string = serde_json::Value::from(value).to_string();
+    json_string[1..json_string.len() - 1].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+        task::JoinHandle,
+    };
+
+    use super::*;
+
+    const BODY_TEMPLATE: &str = r#"{
+        "from": "{{from}}",
+        "to": ["{{to}}"],
+        "subject": "{{subject}}"
+        {{#if text}}, "text": "{{text}}"{{/if}}
+    }"#;
+
+    /// HTTP server which returns the responses in order and
+    /// returns received requests.
+    async fn http_stub(responses: Vec<String>) -> (reqwest::Url, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/send", listener.local_addr().unwrap())
+            .parse()
+            .unwrap();
+        let handle = tokio::spawn(async move {
+            let mut requests = vec![];
+            for response in responses {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut request = vec![];
+                let mut buffer = [0u8; 1024];
+                let mut body_start = None;
+                loop {
+                    if let Some((headers_end, content_length)) = body_start
+                        && request.len() >= headers_end + content_length
+                    {
+                        break;
+                    }
+                    let n = stream.read(&mut buffer).await.unwrap();
+                    assert!(n > 0);
+                    request.extend_from_slice(&buffer[..n]);
+                    if body_start.is_none()
+                        && let Some(i) = request.windows(4).position(|w| w == b"\r\n\r\n")
+                    {
+                        let headers = String::from_utf8_lossy(&request[..i]).to_lowercase();
+                        let content_length = headers
+                            .lines()
+                            .find_map(|l| l.strip_prefix("content-length:"))
+                            .and_then(|v| v.trim().parse::<usize>().ok())
+                            .unwrap_or_default();
+                        body_start = Some((i + 4, content_length));
+                    }
+                }
+                stream.write_all(response.as_bytes()).await.unwrap();
+                requests.push(String::from_utf8(request).unwrap());
+            }
+            requests
+        });
+        (url, handle)
+    }
+
+    fn sender(url: reqwest::Url) -> HttpEmailSender {
+        HttpEmailSender::new(&EmailHttpApiConfig {
+            url,
+            auth_header_name: "Authorization".to_string(),
+            auth_header_value: "Bearer test".to_string(),
+            body_template: BODY_TEMPLATE.to_string(),
+            message_id_json_pointer: Some("/data/id".to_string()),
+        })
+        .unwrap()
+    }
+
+    async fn send(sender: &HttpEmailSender) -> Result<Option<String>, EmailError> {
+        let from = "Example <no-reply@example.com>".parse().unwrap();
+        let body = EmailBody::Text("Text \"with quotes\"".to_string());
+        let message = EmailMessage {
+            from: &from,
+            to: "test@example.com".parse().unwrap(),
+            subject: "Subject",
+            body: &body,
+        };
+        sender.send(&message).await
+    }
+
+    fn response(status: &str, headers: &str, body: &str) -> String {
+        format!(
+            "HTTP/1.1 {status}\r\ncontent-length: {}\r\ncontent-type: application/json\r\nconnection: close\r\n{headers}\r\n{body}",
+            body.len()
+        )
+    }
+
+    #[tokio::test]
+    async fn request_body_is_rendered_and_message_id_is_returned() {
+        let (url, server) =
+            http_stub(vec![response("200 OK", "", r#"{"data":{"id":"abc"}}"#)]).await;
+        let message_id = send(&sender(url)).await.unwrap();
+        assert_eq!(message_id.as_deref(), Some("abc"));
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests.len(), 1);
+        let (headers, body) = requests[0].split_once("\r\n\r\n").unwrap();
+        assert!(
+            headers
+                .to_lowercase()
+                .contains("authorization: bearer test")
+        );
+        let body: serde_json::Value = serde_json::from_str(body).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "from": "Example <no-reply@example.com>",
+                "to": ["test@example.com"],
+                "subject": "Subject",
+                "text": "Text \"with quotes\"",
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn too_many_requests_is_retried_after_retry_after_time() {
+        let (url, server) = http_stub(vec![
+            response("429 Too Many Requests", "retry-after: 1\r\n", ""),
+            response("200 OK", "", r#"{"data":{"id":1}}"#),
+        ])
+        .await;
+        let message_id = send(&sender(url)).await.unwrap();
+        assert_eq!(message_id.as_deref(), Some("1"));
+        assert_eq!(server.await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn too_long_retry_after_time_fails_sending() {
+        let (url, server) = http_stub(vec![response(
+            "429 Too Many Requests",
+            "retry-after: 3600\r\n",
+            "",
+        )])
+        .await;
+        assert!(send(&sender(url)).await.is_err());
+        assert_eq!(server.await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn server_error_is_retried_until_max_attempts() {
+        let (url, server) = http_stub(vec![
+            response("500 Internal Server Error", "", "");
+            MAX_ATTEMPTS
+        ])
+        .await;
+        assert!(send(&sender(url)).await.is_err());
+        assert_eq!(server.await.unwrap().len(), MAX_ATTEMPTS);
+    }
+
+    #[tokio::test]
+    async fn client_error_is_not_retried() {
+        let (url, server) = http_stub(vec![response("400 Bad Request", "", "")]).await;
+        assert!(send(&sender(url)).await.is_err());
+        assert_eq!(server.await.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn json_string_escape_escapes_quotes_and_control_characters() {
+        assert_eq!(json_string_escape("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+        assert_eq!(json_string_escape("<p>"), "<p>");
+    }
+}
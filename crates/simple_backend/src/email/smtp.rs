@@ -0,0 +1,127 @@
+use error_stack::ResultExt;
+use futures::{FutureExt, future::BoxFuture};
+use lettre::{
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+    message::{Attachment, Mailbox, MultiPart, SinglePart, header::ContentType},
+    transport::smtp::{PoolConfig, authentication::Credentials},
+};
+use simple_backend_config::file::EmailSendingConfig;
+use simple_backend_utils::{ContextExt, Result};
+
+use super::{EmailBody, EmailError, EmailInlineImage, EmailMessage, EmailSender};
+
+pub struct SmtpEmailSender {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl SmtpEmailSender {
+    pub fn new(config: &EmailSendingConfig) -> Result<Self, EmailError> {
+        let Some(smtp_server_address) = &config.smtp_server_address else {
+            return Err(EmailError::SenderCreatingFailed
+                .report()
+                .attach("SMTP server address is not configured"));
+        };
+
+        let builder = if config.use_starttls_instead_of_smtps {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(smtp_server_address)
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::relay(smtp_server_address)
+        }
+        .change_context(EmailError::SenderCreatingFailed)?;
+
+        let builder = if config.username.is_empty() && config.password.is_empty() {
+            builder
+        } else {
+            builder.credentials(Credentials::new(
+                config.username.clone(),
+                config.password.clone(),
+            ))
+        };
+
+        let transport = builder.pool_config(PoolConfig::new().max_size(1)).build();
+
+        Ok(Self { transport })
+    }
+}
+
+impl EmailSender for SmtpEmailSender {
+    /// Returns SMTP server response as message ID. The response
+    /// usually contains the queue ID of the message.
+    fn send<'a>(
+        &'a self,
+        message: &'a EmailMessage<'a>,
+    ) -> BoxFuture<'a, Result<Option<String>, EmailError>> {
+        async move {
+            let message = build_message(message)?;
+
+            let response = self
+                .transport
+                .send(message)
+                .await
+                .change_context(EmailError::SendingFailed)?;
+
+            let response_message = response.message().collect::<Vec<_>>().join(" ");
+            if response.is_positive() {
+                Ok(Some(response_message))
+            } else {
+                let error = format!(
+                    "SMTP response not positive, code: {}, message: {}",
+                    response.code(),
+                    response_message
+                );
+                Err(EmailError::EmailSendingResponseNotPositive
+                    .report()
+                    .attach(error))
+            }
+        }
+        .boxed()
+    }
+}
+
+fn build_message(message: &EmailMessage<'_>) -> Result<Message, EmailError> {
+    let builder = Message::builder()
+        .from(message.from.clone())
+        .to(Mailbox::new(None, message.to.clone()))
+        .subject(message.subject.to_string());
+
+    match message.body {
+        EmailBody::Text(text) => builder.header(ContentType::TEXT_PLAIN).body(text.clone()),
+        EmailBody::Html {
+            html,
+            inline_images,
+        } if inline_images.is_empty() => builder.header(ContentType::TEXT_HTML).body(html.clone()),
+        EmailBody::Html {
+            html,
+            inline_images,
+        } => builder.multipart(html_part(html, inline_images)?),
+        EmailBody::TextAndHtml {
+            text,
+            html,
+            inline_images,
+        } => {
+            let alternative = MultiPart::alternative().singlepart(SinglePart::plain(text.clone()));
+            let alternative = if inline_images.is_empty() {
+                alternative.singlepart(SinglePart::html(html.clone()))
+            } else {
+                alternative.multipart(html_part(html, inline_images)?)
+            };
+            builder.multipart(alternative)
+        }
+    }
+    .change_context(EmailError::MessageBuildingFailed)
+}
+
+/// Create multipart/related part which contains the HTML and
+/// inline images.
+fn html_part(html: &str, inline_images: &[EmailInlineImage]) -> Result<MultiPart, EmailError> {
+    let mut related = MultiPart::related().singlepart(SinglePart::html(html.to_string()));
+    for image in inline_images {
+        let content_type = ContentType::parse(&image.content_type)
+            .change_context(EmailError::MessageBuildingFailed)?;
+        related = related.singlepart(
+            Attachment::new_inline(image.content_id.clone())
+                .body(image.data.to_vec(), content_type),
+        );
+    }
+    Ok(related)
+}
@@ -5,7 +5,10 @@ use std::{
     os::unix::process::CommandExt,
     path::{Path, PathBuf},
     process::Stdio,
-    sync::OnceLock,
+    sync::{
+        OnceLock,
+        atomic::{AtomicU32, Ordering},
+    },
     time::Duration,
 };
 
@@ -14,7 +17,7 @@ use simple_backend_config::{SimpleBackendConfig, image_process::ImageProcessingC
 use simple_backend_image_process::{
     ChangeSettingsCommand, ImageProcessMessage, ImageProcessingInfo, ProcessImageCommand,
 };
-use simple_backend_model::ImageProcessingDynamicConfig;
+use simple_backend_model::{ContentImageFormat, ImageProcessingDynamicConfig};
 use simple_backend_utils::{ContextExt, Result};
 use tokio::{
     io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
@@ -54,15 +57,44 @@ fn get_image_process() -> &'static Mutex<Option<ImageProcessHandle>> {
     IMAGE_PROCESS.get_or_init(|| Mutex::new(None))
 }
 
+/// Count of images waiting for processing or being processed
+static IMAGE_PROCESS_QUEUE_LENGTH: AtomicU32 = AtomicU32::new(0);
+
+struct ImageProcessQueueTracker(());
+
+impl ImageProcessQueueTracker {
+    fn new() -> Self {
+        IMAGE_PROCESS_QUEUE_LENGTH.fetch_add(1, Ordering::Relaxed);
+        Self(())
+    }
+}
+
+impl Drop for ImageProcessQueueTracker {
+    fn drop(&mut self) {
+        IMAGE_PROCESS_QUEUE_LENGTH.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
 pub struct ImageProcessHandle {
     stdin: ChildStdin,
     stdout: ChildStdout,
     child: Child,
     stderr_reader: JoinHandle<()>,
+    read_timeout: Duration,
 }
 
 impl ImageProcessHandle {
     pub async fn start(config: ImageProcessingConfig) -> Result<Self, ImageProcessError> {
+        // AVIF encoding is much slower than JPEG and WebP encoding
+        let read_timeout = if config
+            .additional_formats()
+            .contains(&ContentImageFormat::Avif)
+        {
+            Duration::from_secs(60)
+        } else {
+            Duration::from_secs(10)
+        };
+
         let current_exe = env::current_exe().change_context(ImageProcessError::LaunchCommand)?;
 
         let mut command = std::process::Command::new(current_exe);
@@ -135,6 +167,7 @@ impl ImageProcessHandle {
             stdin,
             stdout,
             child,
+            read_timeout,
         };
 
         // Send initial settings
@@ -204,7 +237,7 @@ impl ImageProcessHandle {
         }
 
         let info = tokio::select! {
-            _ = tokio::time::sleep(Duration::from_secs(10)) => {
+            _ = tokio::time::sleep(self.read_timeout) => {
                 self.close().await;
                 return Err(ImageProcessError::ReadTimeout.into());
             }
@@ -237,7 +270,8 @@ impl ImageProcessHandle {
 ///
 /// The server binary must implement the `image-process` subcommand.
 ///
-/// Outputs JPEG images only.
+/// Outputs JPEG images and optionally also WebP and AVIF images.
+/// See [ProcessImageCommand] for details.
 pub struct ImageProcess;
 
 impl ImageProcess {
@@ -289,6 +323,7 @@ impl ImageProcess {
             output_very_low,
         };
 
+        let _queue_tracker = ImageProcessQueueTracker::new();
         let mut state = get_image_process().lock().await;
 
         let handle = match state.take() {
@@ -306,6 +341,10 @@ impl ImageProcess {
         Ok(info)
     }
 
+    pub fn queue_length() -> u32 {
+        IMAGE_PROCESS_QUEUE_LENGTH.load(Ordering::Relaxed)
+    }
+
     pub async fn update_config_if_process_is_running(
         config: &SimpleBackendConfig,
         dynamic_config: ImageProcessingDynamicConfig,
@@ -34,8 +34,8 @@ use std::{
 };
 
 use app::{
-    GetManagerApi, GetSimpleBackendConfig, GetTileMap, PerfCounterDataProvider, SignInWith,
-    SimpleBackendAppState,
+    AppMetricsProvider, GetManagerApi, GetSimpleBackendConfig, GetTileMap, PerfCounterDataProvider,
+    SignInWith, SimpleBackendAppState,
 };
 use axum::{
     Router,
@@ -82,7 +82,7 @@ use utoipa_swagger_ui::SwaggerUi;
 use self::web_socket::WebSocketManager;
 use crate::{
     ip_country::IpCountryTracker,
-    perf::{PerfMetricsManager, PerfMetricsManagerData},
+    perf::{PerfMetricsManager, PerfMetricsManagerData, metrics_api::create_metrics_api_router},
 };
 
 pub const HTTPS_DEFAULT_PORT: u16 = 443;
@@ -100,6 +100,7 @@ pub trait BusinessLogic: Sized + Send + Sync + 'static {
         + GetSimpleBackendConfig
         + GetTileMap
         + PerfCounterDataProvider
+        + AppMetricsProvider
         + ManagerEventHandler
         + Send
         + Sync
@@ -256,7 +257,7 @@ impl<T: BusinessLogic> SimpleBackend<T> {
             self.config.clone(),
             perf_data,
             manager.clone(),
-            maxmind_db_data,
+            maxmind_db_data.clone(),
         )
         .await
         .expect("State builder init failed");
@@ -320,6 +321,23 @@ impl<T: BusinessLogic> SimpleBackend<T> {
             None
         };
 
+        let metrics_api_server_task = if let Some(config) = &self.config.socket().metrics_api {
+            let router = create_metrics_api_router(state.clone(), maxmind_db_data, config);
+            info!("Metrics API is available on {}", config.address);
+            Some(
+                self.create_server_task_no_tls(
+                    router,
+                    config.address,
+                    "Metrics API",
+                    server_quit_watcher.resubscribe(),
+                    ip_country_tracker.clone(),
+                )
+                .await,
+            )
+        } else {
+            None
+        };
+
         if public_api_server_task.is_none() && local_bot_api_server_task.is_none() {
             warn!("No enabled APIs in config file");
         }
@@ -339,6 +357,9 @@ impl<T: BusinessLogic> SimpleBackend<T> {
         drop(server_quit_handle);
 
         // Wait until all tasks quit
+        if let Some(task) = metrics_api_server_task {
+            task.await.expect("Metrics API server task panic detected");
+        }
         if let Some(task) = local_bot_api_server_task {
             task.await
                 .expect("Local bot API server task panic detected");
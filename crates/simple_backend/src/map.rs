@@ -1,44 +1,226 @@
 //! Slippy map tile server logic.
 
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::UNIX_EPOCH,
+};
+
+use axum::body::Bytes;
 use error_stack::ResultExt;
+use flate2::read::GzDecoder;
+use headers::{ContentEncoding, ContentType, ETag};
 use simple_backend_config::{SimpleBackendConfig, file::TileMapConfig};
-use simple_backend_utils::Result;
+use simple_backend_utils::{ContextExt, Result};
 use tokio_util::io::ReaderStream;
 
+mod mbtiles;
+mod pmtiles;
+
+use mbtiles::MbTilesArchive;
+use pmtiles::PmTilesArchive;
+
 #[derive(thiserror::Error, Debug)]
 pub enum TileMapError {
     #[error("File open failed")]
     IoFileOpen,
     #[error("Getting file metadata failed")]
     IoFileMetadata,
+    #[error("File reading failed")]
+    IoFileRead,
 
     #[error("Missing tile map config")]
     MissingTileMapConfig,
+    #[error("Unknown tile map archive type")]
+    UnknownArchiveType,
+    #[error("Invalid tile map archive")]
+    InvalidArchive,
+    #[error("Unsupported tile map archive")]
+    UnsupportedArchive,
+    #[error("Tile map archive database error")]
+    Database,
+    #[error("Invalid MIME type string in source code")]
+    InvalidMimeType,
+    #[error("Async task failed")]
+    AsyncTask,
+    #[error("Tile decompression failed")]
+    Decompress,
+    #[error("ETag creation failed")]
+    Etag,
 }
 
-pub struct TileMapManager {
-    config: Option<TileMapConfig>,
+/// Limit for decompressed tile size
+const MAX_DECOMPRESSED_TILE_SIZE: u64 = 32 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TileFormat {
+    Png,
+    Jpeg,
+    Webp,
+    Avif,
+    /// Mapbox vector tile
+    Pbf,
 }
 
-impl TileMapManager {
-    pub fn new(config: &SimpleBackendConfig) -> Self {
+impl TileFormat {
+    fn content_type(&self) -> Result<ContentType, TileMapError> {
+        let mime = match self {
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+            Self::Webp => "image/webp",
+            Self::Avif => "image/avif",
+            Self::Pbf => "application/vnd.mapbox-vector-tile",
+        };
+        ContentType::from_str(mime).change_context(TileMapError::InvalidMimeType)
+    }
+}
+
+pub enum MapTileData {
+    File(ReaderStream<tokio::fs::File>),
+    Bytes(Bytes),
+}
+
+pub struct MapTile {
+    pub content_type: ContentType,
+    pub content_encoding: Option<ContentEncoding>,
+    pub byte_count: u64,
+    pub data: MapTileData,
+}
+
+impl MapTile {
+    fn from_archive_data(
+        content_type: ContentType,
+        content_encoding: Option<ContentEncoding>,
+        data: Vec<u8>,
+    ) -> Self {
         Self {
-            config: config.tile_map().cloned(),
+            content_type,
+            content_encoding,
+            byte_count: data.len() as u64,
+            data: MapTileData::Bytes(data.into()),
         }
     }
 
-    pub async fn map_tile_byte_count_and_byte_stream(
+    fn is_gzip_compressed(&self) -> bool {
+        self.content_encoding
+            .as_ref()
+            .is_some_and(|v| v.contains("gzip"))
+    }
+
+    fn decompress_gzip(self) -> Result<Self, TileMapError> {
+        let MapTileData::Bytes(data) = &self.data else {
+            return Err(TileMapError::Decompress.report())
+                .attach("Only archive tiles can be decompressed");
+        };
+        let mut decompressed = vec![];
+        GzDecoder::new(data.as_ref())
+            .take(MAX_DECOMPRESSED_TILE_SIZE)
+            .read_to_end(&mut decompressed)
+            .change_context(TileMapError::Decompress)?;
+        Ok(Self::from_archive_data(
+            self.content_type,
+            None,
+            decompressed,
+        ))
+    }
+}
+
+enum TileSource {
+    Dir(PathBuf),
+    MbTiles(MbTilesArchive),
+    PmTiles(PmTilesArchive),
+}
+
+pub struct TileMapManager {
+    source: Option<TileSource>,
+    etag: ETag,
+    etag_gzip: ETag,
+}
+
+impl TileMapManager {
+    pub async fn new(config: &SimpleBackendConfig) -> Result<Self, TileMapError> {
+        let Some(config) = config.tile_map() else {
+            return Ok(Self {
+                source: None,
+                etag: create_etag("i")?,
+                etag_gzip: create_etag("i-gzip")?,
+            });
+        };
+
+        let (source, etag_base) = match (&config.tile_dir, &config.tile_archive) {
+            // Tile directory changes are handled using tile data version
+            // in the tile URL.
+            (Some(dir), _) => (TileSource::Dir(dir.clone()), "i".to_string()),
+            (None, Some(archive)) => {
+                let source = match archive.extension().and_then(|v| v.to_str()) {
+                    Some(TileMapConfig::MBTILES_FILE_EXTENSION) => {
+                        TileSource::MbTiles(MbTilesArchive::open(archive.clone()).await?)
+                    }
+                    Some(TileMapConfig::PMTILES_FILE_EXTENSION) => {
+                        TileSource::PmTiles(PmTilesArchive::open(archive.clone()).await?)
+                    }
+                    _ => return Err(TileMapError::UnknownArchiveType.report()),
+                };
+                (source, archive_etag_base(archive).await?)
+            }
+            (None, None) => return Err(TileMapError::MissingTileMapConfig.report()),
+        };
+
+        Ok(Self {
+            source: Some(source),
+            etag: create_etag(&etag_base)?,
+            etag_gzip: create_etag(&format!("{etag_base}-gzip"))?,
+        })
+    }
+
+    /// Get map tile.
+    ///
+    /// If `client_accepts_gzip` is false, gzip compressed tiles are
+    /// decompressed.
+    pub async fn map_tile(
         &self,
         z: u32,
         x: u32,
         y: u32,
-    ) -> Result<Option<(u64, ReaderStream<tokio::fs::File>)>, TileMapError> {
-        let config = self
-            .config
+        client_accepts_gzip: bool,
+    ) -> Result<Option<MapTile>, TileMapError> {
+        let source = self
+            .source
             .as_ref()
             .ok_or(TileMapError::MissingTileMapConfig)?;
 
-        let path = config.tile_dir.join(format!("{z}/{x}/{y}.png"));
+        let tile = match source {
+            TileSource::Dir(dir) => Self::map_tile_from_dir(dir, z, x, y).await?,
+            TileSource::MbTiles(archive) => archive.map_tile(z, x, y).await?,
+            TileSource::PmTiles(archive) => archive.map_tile(z, x, y).await?,
+        };
+
+        match tile {
+            Some(tile) if !client_accepts_gzip && tile.is_gzip_compressed() => {
+                tile.decompress_gzip().map(Some)
+            }
+            tile => Ok(tile),
+        }
+    }
+
+    /// ETag for the tile. The ETag changes when the tile archive
+    /// is replaced and it is different for gzip compressed tiles.
+    pub fn etag(&self, tile: &MapTile) -> &ETag {
+        if tile.is_gzip_compressed() {
+            &self.etag_gzip
+        } else {
+            &self.etag
+        }
+    }
+
+    async fn map_tile_from_dir(
+        dir: &std::path::Path,
+        z: u32,
+        x: u32,
+        y: u32,
+    ) -> Result<Option<MapTile>, TileMapError> {
+        let path = dir.join(format!("{z}/{x}/{y}.png"));
 
         if !path.exists() {
             return Ok(None);
@@ -53,6 +235,72 @@ impl TileMapManager {
             .change_context(TileMapError::IoFileMetadata)?;
         let stream = ReaderStream::new(file);
 
-        Ok(Some((metadata.len(), stream)))
+        Ok(Some(MapTile {
+            content_type: ContentType::png(),
+            content_encoding: None,
+            byte_count: metadata.len(),
+            data: MapTileData::File(stream),
+        }))
+    }
+}
+
+/// Archive modification time and size is used for ETag so that
+/// replacing the archive invalidates client caches.
+async fn archive_etag_base(archive: &Path) -> Result<String, TileMapError> {
+    let metadata = tokio::fs::metadata(archive)
+        .await
+        .change_context(TileMapError::IoFileMetadata)?;
+    let modified = metadata
+        .modified()
+        .change_context(TileMapError::IoFileMetadata)?
+        .duration_since(UNIX_EPOCH)
+        .change_context(TileMapError::IoFileMetadata)?;
+    Ok(format!(
+        "a{}-{}-{}",
+        modified.as_secs(),
+        modified.subsec_nanos(),
+        metadata.len()
+    ))
+}
+
+fn create_etag(value: &str) -> Result<ETag, TileMapError> {
+    ETag::from_str(&format!("\"{value}\"")).change_context(TileMapError::Etag)
+}
+
+/// Max tile count on one axis for the zoom level or `None` if
+/// the zoom level is too large.
+fn tile_count_for_zoom(z: u32) -> Option<u32> {
+    1u32.checked_shl(z)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::{Compression, write::GzEncoder};
+
+    use super::*;
+
+    #[test]
+    fn gzip_tile_decompression() {
+        let data = b"vector tile data";
+        let mut encoder = GzEncoder::new(vec![], Compression::default());
+        encoder.write_all(data).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let tile = MapTile::from_archive_data(
+            TileFormat::Pbf.content_type().unwrap(),
+            Some(ContentEncoding::gzip()),
+            compressed,
+        );
+        assert!(tile.is_gzip_compressed());
+
+        let tile = tile.decompress_gzip().unwrap();
+        assert!(!tile.is_gzip_compressed());
+        assert_eq!(tile.byte_count, data.len() as u64);
+        let MapTileData::Bytes(decompressed) = tile.data else {
+            panic!("Unexpected file tile");
+        };
+        assert_eq!(decompressed.as_ref(), data);
     }
 }
@@ -0,0 +1,231 @@
+//! MBTiles (SQLite) archive reading
+
+use std::{path::PathBuf, sync::Arc};
+
+use diesel::{
+    Connection, OptionalExtension, QueryableByName, RunQueryDsl, SqliteConnection, sql_query,
+    sql_types::{Binary, Integer, Text},
+};
+use error_stack::ResultExt;
+use headers::{ContentEncoding, ContentType};
+use simple_backend_utils::{ContextExt, Result};
+use tokio::sync::Mutex;
+
+use super::{MapTile, TileFormat, TileMapError, tile_count_for_zoom};
+
+const GZIP_MAGIC_BYTES: [u8; 2] = [0x1f, 0x8b];
+
+#[derive(QueryableByName)]
+struct MetadataValue {
+    #[diesel(sql_type = Text)]
+    value: String,
+}
+
+#[derive(QueryableByName)]
+struct TileData {
+    #[diesel(sql_type = Binary)]
+    tile_data: Vec<u8>,
+}
+
+pub struct MbTilesArchive {
+    connection: Arc<Mutex<SqliteConnection>>,
+    format: TileFormat,
+    content_type: ContentType,
+}
+
+impl MbTilesArchive {
+    pub async fn open(path: PathBuf) -> Result<Self, TileMapError> {
+        let (connection, format) = tokio::task::spawn_blocking(move || {
+            let path = path.to_str().ok_or(TileMapError::IoFileOpen)?;
+            let mut connection =
+                SqliteConnection::establish(path).change_context(TileMapError::IoFileOpen)?;
+            let format: Option<MetadataValue> =
+                sql_query("SELECT value FROM metadata WHERE name = 'format'")
+                    .get_result(&mut connection)
+                    .optional()
+                    .change_context(TileMapError::Database)?;
+            Ok::<_, error_stack::Report<TileMapError>>((connection, format))
+        })
+        .await
+        .change_context(TileMapError::AsyncTask)??;
+
+        let format = match format.as_ref().map(|v| v.value.as_str()) {
+            Some("png") => TileFormat::Png,
+            Some("jpg") => TileFormat::Jpeg,
+            Some("webp") => TileFormat::Webp,
+            Some("pbf") => TileFormat::Pbf,
+            Some(format) => {
+                return Err(TileMapError::UnsupportedArchive.report())
+                    .attach(format!("Unsupported MBTiles tile format {format}"));
+            }
+            None => {
+                return Err(TileMapError::InvalidArchive.report())
+                    .attach("MBTiles metadata does not contain tile format");
+            }
+        };
+
+        Ok(Self {
+            connection: Arc::new(Mutex::new(connection)),
+            content_type: format.content_type()?,
+            format,
+        })
+    }
+
+    pub async fn map_tile(&self, z: u32, x: u32, y: u32) -> Result<Option<MapTile>, TileMapError> {
+        let Some(tile_count) = tile_count_for_zoom(z) else {
+            return Ok(None);
+        };
+        if x >= tile_count || y >= tile_count {
+            return Ok(None);
+        }
+        // MBTiles uses TMS tile numbering where Y axis is flipped
+        let tile_row = tile_count - 1 - y;
+
+        let (Ok(z), Ok(x), Ok(tile_row)) =
+            (i32::try_from(z), i32::try_from(x), i32::try_from(tile_row))
+        else {
+            return Ok(None);
+        };
+
+        let connection = self.connection.clone();
+        let tile: Option<TileData> = tokio::task::spawn_blocking(move || {
+            let mut connection = connection.blocking_lock();
+            sql_query(
+                "SELECT tile_data FROM tiles WHERE zoom_level = ? AND tile_column = ? AND tile_row = ?",
+            )
+            .bind::<Integer, _>(z)
+            .bind::<Integer, _>(x)
+            .bind::<Integer, _>(tile_row)
+            .get_result(&mut *connection)
+            .optional()
+            .change_context(TileMapError::Database)
+        })
+        .await
+        .change_context(TileMapError::AsyncTask)??;
+
+        let Some(tile) = tile else {
+            return Ok(None);
+        };
+
+        // Vector tiles are usually stored gzip compressed
+        let content_encoding =
+            if self.format == TileFormat::Pbf && tile.tile_data.starts_with(&GZIP_MAGIC_BYTES) {
+                Some(ContentEncoding::gzip())
+            } else {
+                None
+            };
+
+        Ok(Some(MapTile::from_archive_data(
+            self.content_type.clone(),
+            content_encoding,
+            tile.tile_data,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map::MapTileData;
+
+    fn create_archive(name: &str, format: &str, tiles: &[(i32, i32, i32, &[u8])]) -> PathBuf {
+        let path = std::env::temp_dir().join(name);
+        let _ = std::fs::remove_file(&path);
+        let mut connection = SqliteConnection::establish(path.to_str().unwrap()).unwrap();
+        sql_query("CREATE TABLE metadata (name TEXT, value TEXT)")
+            .execute(&mut connection)
+            .unwrap();
+        sql_query(
+            "CREATE TABLE tiles (zoom_level INTEGER, tile_column INTEGER, tile_row INTEGER, tile_data BLOB)",
+        )
+        .execute(&mut connection)
+        .unwrap();
+        sql_query("INSERT INTO metadata (name, value) VALUES ('format', ?)")
+            .bind::<Text, _>(format)
+            .execute(&mut connection)
+            .unwrap();
+        for (z, x, tile_row, data) in tiles {
+            sql_query(
+                "INSERT INTO tiles (zoom_level, tile_column, tile_row, tile_data) VALUES (?, ?, ?, ?)",
+            )
+            .bind::<Integer, _>(z)
+            .bind::<Integer, _>(x)
+            .bind::<Integer, _>(tile_row)
+            .bind::<Binary, _>(*data)
+            .execute(&mut connection)
+            .unwrap();
+        }
+        path
+    }
+
+    fn tile_bytes(tile: MapTile) -> Vec<u8> {
+        match tile.data {
+            MapTileData::Bytes(data) => data.to_vec(),
+            MapTileData::File(_) => panic!("Unexpected file tile"),
+        }
+    }
+
+    #[tokio::test]
+    async fn y_axis_is_flipped() {
+        // Zoom level 1 has two rows so TMS row 0 is XYZ row 1
+        let path = create_archive(
+            "mbtiles_y_axis_is_flipped.mbtiles",
+            "png",
+            &[(1, 0, 0, &[1]), (1, 0, 1, &[2])],
+        );
+        let archive = MbTilesArchive::open(path.clone()).await.unwrap();
+
+        let tile = archive.map_tile(1, 0, 1).await.unwrap().unwrap();
+        assert_eq!(tile.content_type.to_string(), "image/png");
+        assert!(tile.content_encoding.is_none());
+        assert_eq!(tile_bytes(tile), vec![1]);
+
+        let tile = archive.map_tile(1, 0, 0).await.unwrap().unwrap();
+        assert_eq!(tile_bytes(tile), vec![2]);
+
+        drop(archive);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn missing_and_out_of_range_tiles_are_not_found() {
+        let path = create_archive("mbtiles_missing_tiles.mbtiles", "jpg", &[(0, 0, 0, &[1])]);
+        let archive = MbTilesArchive::open(path.clone()).await.unwrap();
+
+        assert!(archive.map_tile(0, 0, 0).await.unwrap().is_some());
+        assert!(archive.map_tile(1, 1, 1).await.unwrap().is_none());
+        assert!(archive.map_tile(0, 1, 0).await.unwrap().is_none());
+        assert!(archive.map_tile(0, 0, 1).await.unwrap().is_none());
+        assert!(archive.map_tile(40, 0, 0).await.unwrap().is_none());
+
+        drop(archive);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn gzip_compressed_vector_tile_has_content_encoding() {
+        let compressed: &[u8] = &[GZIP_MAGIC_BYTES[0], GZIP_MAGIC_BYTES[1], 0];
+        let path = create_archive(
+            "mbtiles_gzip_vector_tile.mbtiles",
+            "pbf",
+            &[(1, 0, 0, compressed), (1, 1, 0, &[1])],
+        );
+        let archive = MbTilesArchive::open(path.clone()).await.unwrap();
+
+        let tile = archive.map_tile(1, 0, 1).await.unwrap().unwrap();
+        assert!(tile.is_gzip_compressed());
+
+        let tile = archive.map_tile(1, 1, 1).await.unwrap().unwrap();
+        assert!(!tile.is_gzip_compressed());
+
+        drop(archive);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn unsupported_format_is_rejected() {
+        let path = create_archive("mbtiles_unsupported_format.mbtiles", "tiff", &[]);
+        assert!(MbTilesArchive::open(path.clone()).await.is_err());
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,507 @@
+//! PMTiles version 3 archive reading
+//!
+//! Header and root directory are kept in memory. Recently used leaf
+//! directories are cached. Other leaf directories and tiles are read
+//! from the archive using the byte ranges stored in directories.
+
+use std::{
+    collections::HashMap, fs::File, io::Read, os::unix::fs::FileExt, path::PathBuf, sync::Arc,
+};
+
+use error_stack::ResultExt;
+use flate2::read::GzDecoder;
+use headers::{ContentEncoding, ContentType};
+use simple_backend_utils::{ContextExt, Result};
+use tokio::sync::Mutex;
+
+use super::{MapTile, TileFormat, TileMapError, tile_count_for_zoom};
+
+const HEADER_SIZE: usize = 127;
+const MAGIC_BYTES: &[u8] = b"PMTiles";
+const SUPPORTED_VERSION: u8 = 3;
+/// Max zoom level which fits to 64-bit tile ID
+const MAX_ZOOM: u32 = 26;
+/// Root directory and leaf directory lookups
+const MAX_DIRECTORY_DEPTH: usize = 4;
+/// Limit for directory and tile reads to prevent large allocations
+/// if archive is invalid.
+const MAX_READ_SIZE: u64 = 32 * 1024 * 1024;
+/// Max count of decompressed leaf directories in memory
+const LEAF_DIRECTORY_CACHE_SIZE: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Compression {
+    None,
+    Gzip,
+}
+
+impl Compression {
+    fn from_header_value(value: u8) -> Result<Self, TileMapError> {
+        match value {
+            // Unknown compression is handled as no compression
+            0 | 1 => Ok(Self::None),
+            2 => Ok(Self::Gzip),
+            _ => Err(TileMapError::UnsupportedArchive.report())
+                .attach(format!("Unsupported PMTiles compression {value}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Header {
+    root_directory_offset: u64,
+    root_directory_length: u64,
+    leaf_directories_offset: u64,
+    tile_data_offset: u64,
+    internal_compression: Compression,
+    tile_compression: Compression,
+    tile_type: u8,
+}
+
+impl Header {
+    fn parse(data: &[u8]) -> Result<Self, TileMapError> {
+        if data.len() < HEADER_SIZE || !data.starts_with(MAGIC_BYTES) {
+            return Err(TileMapError::InvalidArchive.report()).attach("PMTiles header is invalid");
+        }
+
+        let version = data[7];
+        if version != SUPPORTED_VERSION {
+            return Err(TileMapError::UnsupportedArchive.report())
+                .attach(format!("Unsupported PMTiles version {version}"));
+        }
+
+        let u64_at = |i: usize| {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&data[i..i + 8]);
+            u64::from_le_bytes(bytes)
+        };
+
+        Ok(Self {
+            root_directory_offset: u64_at(8),
+            root_directory_length: u64_at(16),
+            leaf_directories_offset: u64_at(40),
+            tile_data_offset: u64_at(56),
+            internal_compression: Compression::from_header_value(data[97])?,
+            tile_compression: Compression::from_header_value(data[98])?,
+            tile_type: data[99],
+        })
+    }
+
+    fn tile_format(&self) -> Result<TileFormat, TileMapError> {
+        match self.tile_type {
+            1 => Ok(TileFormat::Pbf),
+            2 => Ok(TileFormat::Png),
+            3 => Ok(TileFormat::Jpeg),
+            4 => Ok(TileFormat::Webp),
+            5 => Ok(TileFormat::Avif),
+            value => Err(TileMapError::UnsupportedArchive.report())
+                .attach(format!("Unsupported PMTiles tile type {value}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct DirectoryEntry {
+    tile_id: u64,
+    offset: u64,
+    length: u64,
+    /// Zero if entry points to a leaf directory
+    run_length: u32,
+}
+
+struct CachedLeafDirectory {
+    directory: Arc<Vec<DirectoryEntry>>,
+    last_use: u64,
+}
+
+/// Least recently used leaf directory is removed when the cache is full.
+#[derive(Default)]
+struct LeafDirectoryCache {
+    /// Leaf directory offset is the key
+    directories: HashMap<u64, CachedLeafDirectory>,
+    use_counter: u64,
+}
+
+impl LeafDirectoryCache {
+    fn get(&mut self, offset: u64) -> Option<Arc<Vec<DirectoryEntry>>> {
+        self.use_counter += 1;
+        let cached = self.directories.get_mut(&offset)?;
+        cached.last_use = self.use_counter;
+        Some(cached.directory.clone())
+    }
+
+    fn insert(&mut self, offset: u64, directory: Arc<Vec<DirectoryEntry>>) {
+        if self.directories.len() >= LEAF_DIRECTORY_CACHE_SIZE
+            && !self.directories.contains_key(&offset)
+        {
+            let least_recently_used = self
+                .directories
+                .iter()
+                .min_by_key(|(_, cached)| cached.last_use)
+                .map(|(offset, _)| *offset);
+            if let Some(offset) = least_recently_used {
+                self.directories.remove(&offset);
+            }
+        }
+
+        self.use_counter += 1;
+        self.directories.insert(
+            offset,
+            CachedLeafDirectory {
+                directory,
+                last_use: self.use_counter,
+            },
+        );
+    }
+}
+
+pub struct PmTilesArchive {
+    file: Arc<File>,
+    header: Header,
+    root_directory: Vec<DirectoryEntry>,
+    leaf_directory_cache: Mutex<LeafDirectoryCache>,
+    content_type: ContentType,
+}
+
+impl PmTilesArchive {
+    pub async fn open(path: PathBuf) -> Result<Self, TileMapError> {
+        let file = tokio::task::spawn_blocking(move || File::open(path))
+            .await
+            .change_context(TileMapError::AsyncTask)?
+            .change_context(TileMapError::IoFileOpen)?;
+        let file = Arc::new(file);
+
+        let header_data = read_range(&file, 0, HEADER_SIZE as u64).await?;
+        let header = Header::parse(&header_data)?;
+        let content_type = header.tile_format()?.content_type()?;
+
+        let root_directory_data = read_range(
+            &file,
+            header.root_directory_offset,
+            header.root_directory_length,
+        )
+        .await?;
+        let root_directory = parse_directory(&decompress(
+            header.internal_compression,
+            root_directory_data,
+        )?)?;
+
+        Ok(Self {
+            file,
+            header,
+            root_directory,
+            leaf_directory_cache: Mutex::new(LeafDirectoryCache::default()),
+            content_type,
+        })
+    }
+
+    pub async fn map_tile(&self, z: u32, x: u32, y: u32) -> Result<Option<MapTile>, TileMapError> {
+        let Some(tile_id) = zxy_to_tile_id(z, x, y) else {
+            return Ok(None);
+        };
+
+        let mut leaf_directory: Option<Arc<Vec<DirectoryEntry>>> = None;
+        for _ in 0..MAX_DIRECTORY_DEPTH {
+            let directory = leaf_directory
+                .as_deref()
+                .map(Vec::as_slice)
+                .unwrap_or(&self.root_directory);
+            let Some(entry) = find_entry(directory, tile_id) else {
+                return Ok(None);
+            };
+
+            if entry.run_length == 0 {
+                leaf_directory = Some(self.leaf_directory(entry.offset, entry.length).await?);
+            } else {
+                let data = read_range(
+                    &self.file,
+                    self.header.tile_data_offset.saturating_add(entry.offset),
+                    entry.length,
+                )
+                .await?;
+                let content_encoding = match self.header.tile_compression {
+                    Compression::None => None,
+                    Compression::Gzip => Some(ContentEncoding::gzip()),
+                };
+                return Ok(Some(MapTile::from_archive_data(
+                    self.content_type.clone(),
+                    content_encoding,
+                    data,
+                )));
+            }
+        }
+
+        Err(TileMapError::InvalidArchive.report()).attach("PMTiles directory depth is too large")
+    }
+
+    async fn leaf_directory(
+        &self,
+        offset: u64,
+        length: u64,
+    ) -> Result<Arc<Vec<DirectoryEntry>>, TileMapError> {
+        if let Some(directory) = self.leaf_directory_cache.lock().await.get(offset) {
+            return Ok(directory);
+        }
+
+        let data = read_range(
+            &self.file,
+            self.header.leaf_directories_offset.saturating_add(offset),
+            length,
+        )
+        .await?;
+        let compression = self.header.internal_compression;
+        let directory =
+            tokio::task::spawn_blocking(move || parse_directory(&decompress(compression, data)?))
+                .await
+                .change_context(TileMapError::AsyncTask)??;
+        let directory = Arc::new(directory);
+
+        self.leaf_directory_cache
+            .lock()
+            .await
+            .insert(offset, directory.clone());
+
+        Ok(directory)
+    }
+}
+
+/// Positional read which allows concurrent reads using the same file.
+async fn read_range(file: &Arc<File>, offset: u64, length: u64) -> Result<Vec<u8>, TileMapError> {
+    if length > MAX_READ_SIZE {
+        return Err(TileMapError::InvalidArchive.report())
+            .attach("PMTiles archive read size is too large");
+    }
+
+    let file = file.clone();
+    tokio::task::spawn_blocking(move || {
+        let mut data = vec![0; length as usize];
+        file.read_exact_at(&mut data, offset)
+            .change_context(TileMapError::IoFileRead)?;
+        Ok::<_, error_stack::Report<TileMapError>>(data)
+    })
+    .await
+    .change_context(TileMapError::AsyncTask)?
+}
+
+fn decompress(compression: Compression, data: Vec<u8>) -> Result<Vec<u8>, TileMapError> {
+    match compression {
+        Compression::None => Ok(data),
+        Compression::Gzip => {
+            let mut decompressed = vec![];
+            GzDecoder::new(data.as_slice())
+                .take(MAX_READ_SIZE)
+                .read_to_end(&mut decompressed)
+                .change_context(TileMapError::InvalidArchive)?;
+            Ok(decompressed)
+        }
+    }
+}
+
+fn parse_directory(data: &[u8]) -> Result<Vec<DirectoryEntry>, TileMapError> {
+    parse_directory_internal(data)
+        .ok_or(TileMapError::InvalidArchive)
+        .attach("PMTiles directory is invalid")
+}
+
+fn parse_directory_internal(data: &[u8]) -> Option<Vec<DirectoryEntry>> {
+    let mut d = data.iter().copied();
+
+    let count = read_varint(&mut d)?;
+    // Every entry has at least one byte of data
+    if count > data.len() as u64 {
+        return None;
+    }
+
+    let mut entries: Vec<DirectoryEntry> = Vec::with_capacity(count as usize);
+    let mut tile_id: u64 = 0;
+    for _ in 0..count {
+        tile_id = tile_id.checked_add(read_varint(&mut d)?)?;
+        entries.push(DirectoryEntry {
+            tile_id,
+            offset: 0,
+            length: 0,
+            run_length: 0,
+        });
+    }
+
+    for entry in entries.iter_mut() {
+        entry.run_length = read_varint(&mut d)?.try_into().ok()?;
+    }
+
+    for entry in entries.iter_mut() {
+        entry.length = read_varint(&mut d)?;
+    }
+
+    for i in 0..entries.len() {
+        let value = read_varint(&mut d)?;
+        entries[i].offset = if value == 0 && i > 0 {
+            entries[i - 1].offset.checked_add(entries[i - 1].length)?
+        } else {
+            value.checked_sub(1)?
+        };
+    }
+
+    Some(entries)
+}
+
+fn read_varint(d: &mut impl Iterator<Item = u8>) -> Option<u64> {
+    let mut value: u64 = 0;
+    for shift in (0..64).step_by(7) {
+        let byte = d.next()?;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+fn find_entry(entries: &[DirectoryEntry], tile_id: u64) -> Option<&DirectoryEntry> {
+    let index = entries.partition_point(|e| e.tile_id <= tile_id);
+    let entry = entries.get(index.checked_sub(1)?)?;
+    if entry.run_length == 0 || tile_id - entry.tile_id < u64::from(entry.run_length) {
+        Some(entry)
+    } else {
+        None
+    }
+}
+
+/// Convert tile coordinates to PMTiles tile ID which is position of the
+/// tile on a Hilbert curve. Tile IDs of lower zoom levels are before the
+/// tile IDs of the zoom level.
+fn zxy_to_tile_id(z: u32, x: u32, y: u32) -> Option<u64> {
+    if z > MAX_ZOOM {
+        return None;
+    }
+    let n = u64::from(tile_count_for_zoom(z)?);
+    let (mut x, mut y) = (u64::from(x), u64::from(y));
+    if x >= n || y >= n {
+        return None;
+    }
+
+    let previous_zoom_levels_tile_count = ((1u64 << (2 * z)) - 1) / 3;
+
+    let mut d = 0;
+    let mut s = n / 2;
+    while s > 0 {
+        let rx = u64::from(x & s > 0);
+        let ry = u64::from(y & s > 0);
+        d += s * s * ((3 * rx) ^ ry);
+        if ry == 0 {
+            if rx == 1 {
+                x = n - 1 - x;
+                y = n - 1 - y;
+            }
+            std::mem::swap(&mut x, &mut y);
+        }
+        s /= 2;
+    }
+
+    Some(previous_zoom_levels_tile_count + d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_varint(data: &mut Vec<u8>, mut value: u64) {
+        while value >= 0x80 {
+            data.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        data.push(value as u8);
+    }
+
+    #[test]
+    fn tile_id_for_first_zoom_levels() {
+        assert_eq!(zxy_to_tile_id(0, 0, 0), Some(0));
+        assert_eq!(zxy_to_tile_id(1, 0, 0), Some(1));
+        assert_eq!(zxy_to_tile_id(1, 0, 1), Some(2));
+        assert_eq!(zxy_to_tile_id(1, 1, 1), Some(3));
+        assert_eq!(zxy_to_tile_id(1, 1, 0), Some(4));
+        assert_eq!(zxy_to_tile_id(2, 0, 0), Some(5));
+    }
+
+    #[test]
+    fn tile_id_for_invalid_coordinates() {
+        assert_eq!(zxy_to_tile_id(1, 2, 0), None);
+        assert_eq!(zxy_to_tile_id(MAX_ZOOM + 1, 0, 0), None);
+    }
+
+    #[test]
+    fn directory_parsing_and_lookup() {
+        let mut data = vec![];
+        write_varint(&mut data, 3);
+        // Tile IDs as deltas
+        write_varint(&mut data, 0);
+        write_varint(&mut data, 1);
+        write_varint(&mut data, 4);
+        // Run lengths
+        write_varint(&mut data, 1);
+        write_varint(&mut data, 2);
+        write_varint(&mut data, 0);
+        // Lengths
+        write_varint(&mut data, 10);
+        write_varint(&mut data, 20);
+        write_varint(&mut data, 30);
+        // Offsets. Zero means that the data continues after the
+        // previous entry.
+        write_varint(&mut data, 1);
+        write_varint(&mut data, 0);
+        write_varint(&mut data, 1001);
+
+        let entries = parse_directory_internal(&data).unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                DirectoryEntry {
+                    tile_id: 0,
+                    offset: 0,
+                    length: 10,
+                    run_length: 1,
+                },
+                DirectoryEntry {
+                    tile_id: 1,
+                    offset: 10,
+                    length: 20,
+                    run_length: 2,
+                },
+                DirectoryEntry {
+                    tile_id: 5,
+                    offset: 1000,
+                    length: 30,
+                    run_length: 0,
+                },
+            ]
+        );
+
+        assert_eq!(find_entry(&entries, 0), Some(&entries[0]));
+        assert_eq!(find_entry(&entries, 2), Some(&entries[1]));
+        assert_eq!(find_entry(&entries, 3), None);
+        // Leaf directory covers all following tile IDs
+        assert_eq!(find_entry(&entries, 100), Some(&entries[2]));
+    }
+
+    #[test]
+    fn least_recently_used_leaf_directory_is_removed_from_cache() {
+        let mut cache = LeafDirectoryCache::default();
+        for offset in 0..LEAF_DIRECTORY_CACHE_SIZE as u64 {
+            cache.insert(offset, Arc::new(vec![]));
+        }
+        assert!(cache.get(0).is_some());
+
+        cache.insert(1000, Arc::new(vec![]));
+        assert_eq!(cache.directories.len(), LEAF_DIRECTORY_CACHE_SIZE);
+        assert!(cache.get(0).is_some());
+        assert!(cache.get(1).is_none());
+        assert!(cache.get(1000).is_some());
+    }
+
+    #[test]
+    fn truncated_directory_is_invalid() {
+        let mut data = vec![];
+        write_varint(&mut data, 2);
+        write_varint(&mut data, 0);
+        assert_eq!(parse_directory_internal(&data), None);
+    }
+}
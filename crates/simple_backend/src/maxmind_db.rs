@@ -13,7 +13,10 @@ use error_stack::ResultExt;
 use flate2::read::GzDecoder;
 use futures::StreamExt;
 use hyper::StatusCode;
-use simple_backend_config::{SimpleBackendConfig, file::MaxMindDbConfig};
+use simple_backend_config::{
+    SimpleBackendConfig,
+    file::{IpAddressAccessConfig, MaxMindDbConfig},
+};
 use simple_backend_database::data::create_dirs_and_get_simple_backend_dir_path;
 use simple_backend_model::{IpCountry, IpCountryKeyRef, UnixTime};
 use simple_backend_utils::{ContextExt, Result, file::overwrite_and_remove_if_exists};
@@ -114,6 +117,28 @@ impl MaxMindDbManagerData {
         self.db.read().await
     }
 
+    /// Check IP address against IP allowlist and IP country allowlist.
+    pub async fn is_ip_address_accepted(&self, ip: IpAddr, config: &IpAddressAccessConfig) -> bool {
+        if config.allow_all_ip_addresses || config.ip_allowlist.contains(&ip) {
+            return true;
+        }
+
+        if !config.ip_country_allowlist.is_empty() {
+            let ip_db = self.current_db_ref().await;
+            if let Some(ip_db) = ip_db.as_ref()
+                && let Some(country) = ip_db.get_country_ref(ip)
+                && config
+                    .ip_country_allowlist
+                    .iter()
+                    .any(|v| v == country.as_str())
+            {
+                return true;
+            }
+        }
+
+        false
+    }
+
     async fn is_db_loaded(&self) -> bool {
         let lock = self.db.read().await;
         lock.as_ref().is_some()
@@ -20,6 +20,8 @@ use tracing::warn;
 use crate::ServerQuitWatcher;
 
 pub mod counters;
+pub mod metrics_api;
+pub mod open_metrics;
 pub mod system;
 pub mod websocket;
 
@@ -157,6 +159,13 @@ impl PerformanceMetricsHistory {
 
         counter_data
     }
+
+    fn latest_value(&self, key: MetricKey) -> Option<u32> {
+        self.data
+            .front()
+            .and_then(|values| values.metrics.get(&key))
+            .copied()
+    }
 }
 
 #[derive(Debug)]
@@ -177,15 +186,27 @@ impl PerfMetricsManagerQuitHandle {
 
 pub struct PerfMetricsManagerData {
     history: RwLock<PerformanceMetricsHistory>,
+    counters: AllCounters,
 }
 
 impl PerfMetricsManagerData {
     pub fn new(counters: AllCounters) -> Self {
         Self {
             history: RwLock::new(PerformanceMetricsHistory::new(counters)),
+            counters,
         }
     }
 
+    pub fn counters(&self) -> AllCounters {
+        self.counters
+    }
+
+    /// Latest value saved to history. System info is not read directly
+    /// as that takes some time.
+    pub async fn latest_value(&self, key: MetricKey) -> Option<u32> {
+        self.history.read().await.latest_value(key)
+    }
+
     pub async fn get_history(&self, only_latest_hour: bool) -> PerfMetricQueryResult {
         let counter_data = self.history.read().await.get_history(only_latest_hour);
         let mut counters = vec![];
@@ -1,8 +1,10 @@
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 
 pub struct PerfCounter {
     name: &'static str,
     value: AtomicU32,
+    /// Total count since server start. This is not reset.
+    total: AtomicU64,
 }
 
 impl PerfCounter {
@@ -10,12 +12,14 @@ impl PerfCounter {
         Self {
             name,
             value: AtomicU32::new(0),
+            total: AtomicU64::new(0),
         }
     }
 
     /// Increment counter
     pub fn incr(&self) {
         self.value.fetch_add(1, Ordering::Relaxed);
+        self.total.fetch_add(1, Ordering::Relaxed);
     }
 
     pub fn value(&self) -> u32 {
@@ -26,6 +30,10 @@ impl PerfCounter {
         self.value.swap(0, Ordering::Relaxed)
     }
 
+    pub fn total(&self) -> u64 {
+        self.total.load(Ordering::Relaxed)
+    }
+
     pub fn name(&self) -> &'static str {
         self.name
     }
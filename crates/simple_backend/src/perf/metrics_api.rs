@@ -0,0 +1,160 @@
+//! Metrics API for monitoring tools
+
+use std::{net::SocketAddr, sync::Arc};
+
+use axum::{
+    Router,
+    extract::{ConnectInfo, State},
+    http::{StatusCode, header::CONTENT_TYPE},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use simple_backend_config::file::{IpAddressAccessConfig, MetricsApiConfig};
+use simple_backend_model::MetricKey;
+
+use super::{
+    PerfMetricsManagerData,
+    open_metrics::{OPEN_METRICS_CONTENT_TYPE, OpenMetrics},
+    websocket,
+};
+use crate::{
+    app::{AppMetricsProvider, PerfCounterDataProvider},
+    image::ImageProcess,
+    maxmind_db::MaxMindDbManagerData,
+};
+
+pub const PATH_METRICS: &str = "/metrics";
+
+struct MetricsApiState<S> {
+    app: S,
+    maxmind_db: Arc<MaxMindDbManagerData>,
+    access: Arc<IpAddressAccessConfig>,
+}
+
+impl<S: Clone> Clone for MetricsApiState<S> {
+    fn clone(&self) -> Self {
+        Self {
+            app: self.app.clone(),
+            maxmind_db: self.maxmind_db.clone(),
+            access: self.access.clone(),
+        }
+    }
+}
+
+pub fn create_metrics_api_router<
+    S: AppMetricsProvider + PerfCounterDataProvider + Clone + Send + Sync + 'static,
+>(
+    app: S,
+    maxmind_db: Arc<MaxMindDbManagerData>,
+    config: &MetricsApiConfig,
+) -> Router {
+    Router::new()
+        .route(PATH_METRICS, get(get_metrics::<S>))
+        .with_state(MetricsApiState {
+            app,
+            maxmind_db,
+            access: config.access.clone().into(),
+        })
+}
+
+async fn get_metrics<
+    S: AppMetricsProvider + PerfCounterDataProvider + Clone + Send + Sync + 'static,
+>(
+    State(state): State<MetricsApiState<S>>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+) -> Response {
+    if !state
+        .maxmind_db
+        .is_ip_address_accepted(address.ip(), &state.access)
+        .await
+    {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+
+    let mut metrics = OpenMetrics::new();
+    write_simple_backend_metrics(state.app.perf_counter_data(), &mut metrics).await;
+    state.app.app_metrics(&mut metrics).await;
+
+    (
+        [(CONTENT_TYPE, OPEN_METRICS_CONTENT_TYPE)],
+        metrics.finish(),
+    )
+        .into_response()
+}
+
+async fn write_simple_backend_metrics(
+    perf_data: &PerfMetricsManagerData,
+    metrics: &mut OpenMetrics,
+) {
+    let mut counters = metrics.counter("perf_counter", "Performance counter");
+    for category in perf_data.counters() {
+        for counter in category.counter_list() {
+            counters.value_with_labels(
+                &[("category", category.name()), ("counter", counter.name())],
+                counter.total(),
+            );
+        }
+    }
+
+    let connections = [
+        ("user", "all", websocket::Connections::connection_count()),
+        ("user", "men", websocket::ConnectionsMen::connection_count()),
+        (
+            "user",
+            "women",
+            websocket::ConnectionsWomen::connection_count(),
+        ),
+        (
+            "user",
+            "nonbinaries",
+            websocket::ConnectionsNonbinaries::connection_count(),
+        ),
+        ("bot", "all", websocket::BotConnections::connection_count()),
+        (
+            "bot",
+            "men",
+            websocket::BotConnectionsMen::connection_count(),
+        ),
+        (
+            "bot",
+            "women",
+            websocket::BotConnectionsWomen::connection_count(),
+        ),
+        (
+            "bot",
+            "nonbinaries",
+            websocket::BotConnectionsNonbinaries::connection_count(),
+        ),
+        (
+            "admin_bot",
+            "all",
+            websocket::AdminBotConnections::connection_count(),
+        ),
+    ];
+    let mut family = metrics.gauge("websocket_connections", "Current WebSocket connections");
+    for (client, group, count) in connections {
+        family.value_with_labels(&[("client", client), ("group", group)], count.into());
+    }
+
+    metrics
+        .gauge(
+            "image_process_queue_length",
+            "Images waiting for processing or being processed",
+        )
+        .value(ImageProcess::queue_length().into());
+
+    // System info is updated once per minute
+    if let Some(cpu_usage) = perf_data.latest_value(MetricKey::SYSTEM_CPU_USAGE).await {
+        metrics
+            .gauge("system_cpu_usage_percent", "System CPU usage")
+            .value(cpu_usage.into());
+    }
+    if let Some(ram_usage) = perf_data
+        .latest_value(MetricKey::SYSTEM_RAM_USAGE_MIB)
+        .await
+    {
+        metrics
+            .gauge("system_ram_usage_mib", "System RAM usage in MiB")
+            .value(ram_usage.into());
+    }
+}
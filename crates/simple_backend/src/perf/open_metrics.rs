@@ -0,0 +1,127 @@
+//! OpenMetrics text format writer
+
+use std::fmt::Write;
+
+pub const OPEN_METRICS_CONTENT_TYPE: &str =
+    "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MetricType {
+    Counter,
+    Gauge,
+}
+
+impl MetricType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Counter => "counter",
+            Self::Gauge => "gauge",
+        }
+    }
+}
+
+/// Builder for OpenMetrics text format response.
+///
+/// Metric names must match regex `[a-zA-Z_][a-zA-Z0-9_]*`. Counter
+/// names must not have the `_total` suffix as it is added automatically.
+#[derive(Default)]
+pub struct OpenMetrics {
+    text: String,
+}
+
+impl OpenMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn counter(&mut self, name: &str, help: &str) -> MetricFamily<'_> {
+        self.metric_family(name, help, MetricType::Counter)
+    }
+
+    pub fn gauge(&mut self, name: &str, help: &str) -> MetricFamily<'_> {
+        self.metric_family(name, help, MetricType::Gauge)
+    }
+
+    fn metric_family(
+        &mut self,
+        name: &str,
+        help: &str,
+        metric_type: MetricType,
+    ) -> MetricFamily<'_> {
+        let _ = writeln!(self.text, "# TYPE {name} {}", metric_type.as_str());
+        let _ = writeln!(self.text, "# HELP {name} {}", escape_help(help));
+        let sample_name = if metric_type == MetricType::Counter {
+            format!("{name}_total")
+        } else {
+            name.to_string()
+        };
+        MetricFamily {
+            text: &mut self.text,
+            sample_name,
+        }
+    }
+
+    pub fn finish(mut self) -> String {
+        self.text.push_str("# EOF\n");
+        self.text
+    }
+}
+
+pub struct MetricFamily<'a> {
+    text: &'a mut String,
+    sample_name: String,
+}
+
+impl MetricFamily<'_> {
+    pub fn value(&mut self, value: u64) -> &mut Self {
+        self.value_with_labels(&[], value)
+    }
+
+    pub fn value_with_labels(&mut self, labels: &[(&str, &str)], value: u64) -> &mut Self {
+        self.text.push_str(&self.sample_name);
+        if !labels.is_empty() {
+            self.text.push('{');
+            for (i, (name, label_value)) in labels.iter().enumerate() {
+                if i > 0 {
+                    self.text.push(',');
+                }
+                let _ = write!(self.text, "{name}=\"{}\"", escape_label_value(label_value));
+            }
+            self.text.push('}');
+        }
+        let _ = writeln!(self.text, " {value}");
+        self
+    }
+}
+
+fn escape_help(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn escape_label_value(text: &str) -> String {
+    escape_help(text).replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counter_and_gauge_text_format() {
+        let mut metrics = OpenMetrics::new();
+        metrics
+            .counter("requests", "Request count")
+            .value_with_labels(&[("api", "a\"b"), ("type", "x")], 3);
+        metrics.gauge("connections", "Connection count").value(5);
+        assert_eq!(
+            metrics.finish(),
+            "# TYPE requests counter\n\
+             # HELP requests Request count\n\
+             requests_total{api=\"a\\\"b\",type=\"x\"} 3\n\
+             # TYPE connections gauge\n\
+             # HELP connections Connection count\n\
+             connections 5\n\
+             # EOF\n"
+        );
+    }
+}
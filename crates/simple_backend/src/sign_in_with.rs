@@ -7,21 +7,25 @@ use simple_backend_utils::Result;
 use self::{
     apple::{SignInWithAppleError, SignInWithAppleManager},
     google::{GoogleAccountInfo, SignInWithGoogleError, SignInWithGoogleManager},
+    oidc::{OidcAccountInfo, SignInWithOidcError, SignInWithOidcManager},
 };
 
 pub mod apple;
 pub mod google;
+pub mod oidc;
 
 pub struct SignInWithManager {
     google: SignInWithGoogleManager,
     apple: SignInWithAppleManager,
+    oidc: SignInWithOidcManager,
 }
 
 impl SignInWithManager {
     pub fn new(config: Arc<SimpleBackendConfig>, client: reqwest::Client) -> Self {
         Self {
             google: SignInWithGoogleManager::new(config.clone(), client.clone()),
-            apple: SignInWithAppleManager::new(config.clone(), client),
+            apple: SignInWithAppleManager::new(config.clone(), client.clone()),
+            oidc: SignInWithOidcManager::new(config, client),
         }
     }
 
@@ -40,4 +44,13 @@ impl SignInWithManager {
     ) -> Result<AppleAccountInfo, SignInWithAppleError> {
         self.apple.validate_apple_token(token, nonce).await
     }
+
+    pub async fn validate_oidc_token(
+        &self,
+        provider: &str,
+        token: String,
+        nonce: Vec<u8>,
+    ) -> Result<OidcAccountInfo, SignInWithOidcError> {
+        self.oidc.validate_oidc_token(provider, token, nonce).await
+    }
 }
@@ -0,0 +1,520 @@
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use base64::Engine;
+use error_stack::ResultExt;
+use headers::{CacheControl, HeaderMapExt};
+use jsonwebtoken::{
+    DecodingKey, Validation,
+    jwk::{Jwk, JwkSet},
+};
+use serde::Deserialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use simple_backend_config::{SimpleBackendConfig, file::SignInWithOidcConfig};
+use simple_backend_utils::{ContextExt, Result};
+use tokio::sync::Mutex;
+
+/// Public key cache time if the JWKS response does not have
+/// cache control header max age.
+const DEFAULT_KEY_CACHE_TIME: Duration = Duration::from_secs(60 * 60);
+/// Min time between public key downloads which are done because
+/// the requested key was not found.
+const MIN_KEY_REFRESH_INTERVAL: Duration = Duration::from_secs(60 * 5);
+
+#[derive(thiserror::Error, Debug)]
+pub enum SignInWithOidcError {
+    #[error("Token (from client) header parsing failed")]
+    InvalidTokenHeader,
+
+    #[error("Token from client was invalid")]
+    InvalidToken,
+
+    #[error("Token kid property not found from token received from client")]
+    MissingJwtKid,
+
+    #[error("Token does not contain sub claim")]
+    MissingSubject,
+
+    #[error("Token does not contain email claim")]
+    MissingEmail,
+
+    #[error("Downloading OpenID Connect discovery document failed")]
+    DiscoveryDocumentDownloadFailed,
+
+    #[error("Parsing OpenID Connect discovery document failed")]
+    DiscoveryDocumentParsingFailed,
+
+    #[error("OpenID Connect discovery document issuer does not match discovery URL")]
+    DiscoveryDocumentIssuerMismatch,
+
+    #[error("Couldn't download OpenID Connect provider public keys")]
+    PublicKeyDownloadFailed,
+
+    #[error("Parsing JWKS response cache control header failed")]
+    ParsingCacheControlHeader,
+
+    #[error("Max age related time calculation failed")]
+    CacheCalculation,
+
+    #[error("JWKS response didn't contain valid JwkSet")]
+    JwkSetParsingFailed,
+
+    #[error("Requested Jwk was not found")]
+    JwkNotFound,
+
+    #[error("Decoding key generation failed")]
+    DecodingKeyGenerationFailed,
+
+    #[error("OpenID Connect provider is not configured in server settings file")]
+    NotEnabled,
+}
+
+#[derive(Debug, Deserialize)]
+struct OidcDiscoveryDocument {
+    issuer: String,
+    jwks_uri: reqwest::Url,
+}
+
+pub struct OidcAccountInfo {
+    /// Provider name from server config
+    pub provider: String,
+    /// Value of ID token sub claim
+    pub id: String,
+    pub email: String,
+    pub email_verified: bool,
+}
+
+struct OidcProviderKeys {
+    issuer: String,
+    keys: JwkSet,
+    downloaded: Instant,
+    valid_until_this: Instant,
+}
+
+enum KeyStatus {
+    Found { issuer: String, key: Jwk },
+    KeyRefreshNeeded,
+}
+
+struct OidcProvider {
+    config: SignInWithOidcConfig,
+    /// Lock is per provider, so slow key download does not
+    /// block other providers.
+    keys: Mutex<Option<OidcProviderKeys>>,
+}
+
+pub struct SignInWithOidcManager {
+    client: reqwest::Client,
+    /// Provider name is the key
+    providers: HashMap<String, OidcProvider>,
+}
+
+impl SignInWithOidcManager {
+    pub fn new(config: Arc<SimpleBackendConfig>, client: reqwest::Client) -> Self {
+        Self::new_with_providers(config.sign_in_with_oidc_configs(), client)
+    }
+
+    fn new_with_providers(configs: &[SignInWithOidcConfig], client: reqwest::Client) -> Self {
+        let providers = configs
+            .iter()
+            .map(|config| {
+                let provider = OidcProvider {
+                    config: config.clone(),
+                    keys: Mutex::new(None),
+                };
+                (config.name.clone(), provider)
+            })
+            .collect();
+        Self { client, providers }
+    }
+
+    pub async fn validate_oidc_token(
+        &self,
+        provider: &str,
+        token: String,
+        nonce: Vec<u8>,
+    ) -> Result<OidcAccountInfo, SignInWithOidcError> {
+        let provider = self
+            .providers
+            .get(provider)
+            .ok_or(SignInWithOidcError::NotEnabled)?;
+        let oidc_config = &provider.config;
+
+        let not_validated_header = jsonwebtoken::decode_header(&token)
+            .change_context(SignInWithOidcError::InvalidTokenHeader)?;
+        let wanted_kid = not_validated_header
+            .kid
+            .ok_or(SignInWithOidcError::MissingJwtKid)?;
+
+        let (issuer, public_key) = self.get_public_key(provider, &wanted_kid).await?;
+
+        let key = DecodingKey::from_jwk(&public_key)
+            .change_context(SignInWithOidcError::DecodingKeyGenerationFailed)?;
+
+        let allowed_audiences: Vec<&str> = oidc_config
+            .client_ids
+            .iter()
+            .chain(&oidc_config.allowed_audiences)
+            .map(|v| v.as_str())
+            .collect();
+
+        let mut v = Validation::new(not_validated_header.alg);
+        v.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+        v.set_issuer(&[issuer]);
+        v.set_audience(&allowed_audiences);
+
+        let data = jsonwebtoken::decode::<HashMap<String, Value>>(&token, &key, &v)
+            .change_context(SignInWithOidcError::InvalidToken)?;
+        let claims = data.claims;
+
+        if let Some(azp) = claims.get("azp") {
+            let azp_valid = oidc_config
+                .client_ids
+                .iter()
+                .any(|id| Some(id.as_str()) == azp.as_str());
+            if !azp_valid {
+                return Err(SignInWithOidcError::InvalidToken.report());
+            }
+        }
+
+        let token_nonce = base64::engine::general_purpose::URL_SAFE.encode(Sha256::digest(nonce));
+        if claims.get("nonce").and_then(|v| v.as_str()) != Some(token_nonce.as_str()) {
+            return Err(SignInWithOidcError::InvalidToken.report());
+        }
+
+        let id = claims
+            .get("sub")
+            .and_then(|v| v.as_str())
+            .ok_or(SignInWithOidcError::MissingSubject)?
+            .to_string();
+
+        let email = claims
+            .get(oidc_config.email_claim())
+            .and_then(|v| v.as_str())
+            .ok_or(SignInWithOidcError::MissingEmail)?
+            .to_string();
+
+        let email_verified = match claims.get(oidc_config.email_verified_claim()) {
+            Some(Value::Bool(value)) => *value,
+            // Some providers use string instead of boolean
+            Some(Value::String(value)) => value == "true",
+            _ => oidc_config.assume_email_verified,
+        };
+
+        Ok(OidcAccountInfo {
+            provider: oidc_config.name.clone(),
+            id,
+            email,
+            email_verified,
+        })
+    }
+
+    async fn get_public_key(
+        &self,
+        provider: &OidcProvider,
+        wanted_kid: &str,
+    ) -> Result<(String, Jwk), SignInWithOidcError> {
+        let mut state = provider.keys.lock().await;
+        match Self::get_public_key_from_local_keys(state.as_ref(), wanted_kid)? {
+            KeyStatus::Found { issuer, key } => Ok((issuer, key)),
+            KeyStatus::KeyRefreshNeeded => {
+                let keys = self.download_public_keys(&provider.config).await?;
+                let key = keys
+                    .keys
+                    .find(wanted_kid)
+                    .ok_or(SignInWithOidcError::JwkNotFound)?
+                    .clone();
+                let issuer = keys.issuer.clone();
+                *state = Some(keys);
+                Ok((issuer, key))
+            }
+        }
+    }
+
+    fn get_public_key_from_local_keys(
+        keys: Option<&OidcProviderKeys>,
+        wanted_kid: &str,
+    ) -> Result<KeyStatus, SignInWithOidcError> {
+        let Some(keys) = keys else {
+            return Ok(KeyStatus::KeyRefreshNeeded);
+        };
+
+        let now = Instant::now();
+        if now >= keys.valid_until_this {
+            return Ok(KeyStatus::KeyRefreshNeeded);
+        }
+
+        match keys.keys.find(wanted_kid) {
+            Some(key) => Ok(KeyStatus::Found {
+                issuer: keys.issuer.clone(),
+                key: key.clone(),
+            }),
+            // Provider might have rotated the keys
+            None if now.duration_since(keys.downloaded) >= MIN_KEY_REFRESH_INTERVAL => {
+                Ok(KeyStatus::KeyRefreshNeeded)
+            }
+            None => Err(SignInWithOidcError::JwkNotFound.report()),
+        }
+    }
+
+    async fn download_public_keys(
+        &self,
+        oidc_config: &SignInWithOidcConfig,
+    ) -> Result<OidcProviderKeys, SignInWithOidcError> {
+        let discovery: OidcDiscoveryDocument = self
+            .client
+            .get(oidc_config.discovery_url.clone())
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .change_context(SignInWithOidcError::DiscoveryDocumentDownloadFailed)?
+            .json()
+            .await
+            .change_context(SignInWithOidcError::DiscoveryDocumentParsingFailed)?;
+
+        // Prevent discovery document from selecting issuer
+        // of some other provider.
+        let issuer_origin = reqwest::Url::parse(&discovery.issuer)
+            .change_context(SignInWithOidcError::DiscoveryDocumentParsingFailed)?
+            .origin();
+        if issuer_origin != oidc_config.discovery_url.origin() {
+            return Err(SignInWithOidcError::DiscoveryDocumentIssuerMismatch
+                .report()
+                .attach(format!(
+                    "Issuer: {}, discovery URL: {}",
+                    discovery.issuer, oidc_config.discovery_url
+                )));
+        }
+
+        let r = self
+            .client
+            .get(discovery.jwks_uri)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .change_context(SignInWithOidcError::PublicKeyDownloadFailed)?;
+
+        let max_age = r
+            .headers()
+            .typed_try_get::<CacheControl>()
+            .change_context(SignInWithOidcError::ParsingCacheControlHeader)?
+            .and_then(|v| v.max_age())
+            .unwrap_or(DEFAULT_KEY_CACHE_TIME);
+        let downloaded = Instant::now();
+        let valid_until_this = downloaded
+            .checked_add(max_age)
+            .ok_or(SignInWithOidcError::CacheCalculation)?;
+
+        let keys: JwkSet = r
+            .json()
+            .await
+            .change_context(SignInWithOidcError::JwkSetParsingFailed)?;
+
+        Ok(OidcProviderKeys {
+            issuer: discovery.issuer,
+            keys,
+            downloaded,
+            valid_until_this,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    use jsonwebtoken::{Algorithm, EncodingKey, Header};
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    const SECRET: &[u8] = b"oidc-mock-issuer-test-secret-0000";
+    const KID: &str = "test-key";
+    const CLIENT_ID: &str = "test-client";
+    const NONCE: &[u8] = b"nonce";
+
+    /// Mock OpenID Connect provider. Returns discovery URL and
+    /// JWKS download count.
+    async fn mock_issuer(issuer: Option<&str>) -> (reqwest::Url, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let origin = format!("http://{}", listener.local_addr().unwrap());
+        let discovery = serde_json::json!({
+            "issuer": issuer.unwrap_or(&origin),
+            "jwks_uri": format!("{origin}/jwks"),
+        })
+        .to_string();
+        let jwks = serde_json::json!({
+            "keys": [{
+                "kty": "oct",
+                "kid": KID,
+                "alg": "HS256",
+                "k": base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(SECRET),
+            }]
+        })
+        .to_string();
+        let jwks_downloads = Arc::new(AtomicUsize::new(0));
+        let jwks_downloads_for_server = jwks_downloads.clone();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut request = vec![];
+                let mut buffer = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    let n = stream.read(&mut buffer).await.unwrap();
+                    assert!(n > 0);
+                    request.extend_from_slice(&buffer[..n]);
+                }
+                let request = String::from_utf8_lossy(&request);
+                let body = if request.starts_with("GET /.well-known/openid-configuration ") {
+                    &discovery
+                } else if request.starts_with("GET /jwks ") {
+                    jwks_downloads_for_server.fetch_add(1, Ordering::Relaxed);
+                    &jwks
+                } else {
+                    panic!("Unexpected request {request}");
+                };
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+        let discovery_url = format!("{origin}/.well-known/openid-configuration")
+            .parse()
+            .unwrap();
+        (discovery_url, jwks_downloads)
+    }
+
+    fn manager(discovery_url: reqwest::Url) -> SignInWithOidcManager {
+        let config = SignInWithOidcConfig {
+            name: "test".to_string(),
+            discovery_url,
+            client_ids: vec![CLIENT_ID.to_string()],
+            allowed_audiences: vec![],
+            email_claim: None,
+            email_verified_claim: None,
+            assume_email_verified: false,
+        };
+        SignInWithOidcManager::new_with_providers(&[config], reqwest::Client::new())
+    }
+
+    fn claims(issuer: &str) -> serde_json::Value {
+        let exp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            + 60 * 60;
+        serde_json::json!({
+            "iss": issuer,
+            "aud": CLIENT_ID,
+            "sub": "user",
+            "exp": exp,
+            "nonce": base64::engine::general_purpose::URL_SAFE.encode(Sha256::digest(NONCE)),
+            "email": "user@example.com",
+            "email_verified": true,
+        })
+    }
+
+    fn token(claims: &serde_json::Value) -> String {
+        let mut header = Header::new(Algorithm::HS256);
+        header.kid = Some(KID.to_string());
+        jsonwebtoken::encode(&header, claims, &EncodingKey::from_secret(SECRET)).unwrap()
+    }
+
+    fn issuer(discovery_url: &reqwest::Url) -> String {
+        discovery_url.origin().ascii_serialization()
+    }
+
+    #[tokio::test]
+    async fn valid_token_is_accepted_and_keys_are_cached() {
+        let (discovery_url, jwks_downloads) = mock_issuer(None).await;
+        let token = token(&claims(&issuer(&discovery_url)));
+        let manager = manager(discovery_url);
+
+        for _ in 0..2 {
+            let info = manager
+                .validate_oidc_token("test", token.clone(), NONCE.to_vec())
+                .await
+                .unwrap();
+            assert_eq!(info.provider, "test");
+            assert_eq!(info.id, "user");
+            assert_eq!(info.email, "user@example.com");
+            assert!(info.email_verified);
+        }
+        assert_eq!(jwks_downloads.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn invalid_tokens_are_rejected() {
+        let (discovery_url, _) = mock_issuer(None).await;
+        let issuer = issuer(&discovery_url);
+        let manager = manager(discovery_url);
+
+        let wrong_nonce = manager
+            .validate_oidc_token("test", token(&claims(&issuer)), b"other".to_vec())
+            .await;
+        assert!(wrong_nonce.is_err());
+
+        let mut wrong_audience = claims(&issuer);
+        wrong_audience["aud"] = serde_json::json!("other-client");
+        let result = manager
+            .validate_oidc_token("test", token(&wrong_audience), NONCE.to_vec())
+            .await;
+        assert!(result.is_err());
+
+        let mut wrong_issuer = claims(&issuer);
+        wrong_issuer["iss"] = serde_json::json!("https://other.example.com");
+        let result = manager
+            .validate_oidc_token("test", token(&wrong_issuer), NONCE.to_vec())
+            .await;
+        assert!(result.is_err());
+
+        let mut header = Header::new(Algorithm::HS256);
+        header.kid = Some(KID.to_string());
+        let wrong_key = jsonwebtoken::encode(
+            &header,
+            &claims(&issuer),
+            &EncodingKey::from_secret(b"other-secret"),
+        )
+        .unwrap();
+        let result = manager
+            .validate_oidc_token("test", wrong_key, NONCE.to_vec())
+            .await;
+        assert!(result.is_err());
+
+        let result = manager
+            .validate_oidc_token("other", token(&claims(&issuer)), NONCE.to_vec())
+            .await;
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            SignInWithOidcError::NotEnabled
+        ));
+    }
+
+    #[tokio::test]
+    async fn discovery_issuer_must_match_discovery_url_origin() {
+        let (discovery_url, jwks_downloads) = mock_issuer(Some("https://other.example.com")).await;
+        let token = token(&claims("https://other.example.com"));
+        let manager = manager(discovery_url);
+
+        let result = manager
+            .validate_oidc_token("test", token, NONCE.to_vec())
+            .await;
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            SignInWithOidcError::DiscoveryDocumentIssuerMismatch
+        ));
+        assert_eq!(jwks_downloads.load(Ordering::Relaxed), 0);
+    }
+}
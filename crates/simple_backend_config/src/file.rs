@@ -25,6 +25,12 @@ pub const DEFAULT_CONFIG_FILE_TEXT: &str = r#"
 public_api = "127.0.0.1:3000"
 local_bot_api_port = 3001
 
+# Metrics in OpenMetrics text format from path /metrics. No TLS.
+# [socket.metrics_api]
+# address = "127.0.0.1:3002"
+# allow_all_ip_addresses = false
+# ip_allowlist = ["127.0.0.1"]
+
 # Use SQLite with default settings
 [database.sqlite]
 
@@ -51,6 +57,8 @@ local_bot_api_port = 3001
 
 # [tile_map]
 # tile_dir = "/map_tiles"
+# # Alternatively use MBTiles or PMTiles archive
+# tile_archive = "/map_tiles.pmtiles"
 
 # [sign_in_with.apple]
 # ios_bundle_id = "id"
@@ -63,6 +71,17 @@ local_bot_api_port = 3001
 # client_id_web = "id"
 # client_id_server = "id"
 
+# Generic OpenID Connect provider. The provider name is saved to the
+# database, so it should not be changed.
+# [[sign_in_with.oidc]]
+# name = "example"
+# discovery_url = "https://idp.example.com/.well-known/openid-configuration"
+# client_ids = ["id"]
+# allowed_audiences = [] # optional, client_ids are always allowed
+# email_claim = "email" # optional
+# email_verified_claim = "email_verified" # optional
+# assume_email_verified = false # optional, use if provider does not have email verified claim
+
 # [push_notifications.fcm]
 # service_account_key_path = "server_config/service_account_key.json"
 
@@ -77,15 +96,40 @@ local_bot_api_port = 3001
 # vapid_private_key_path = "server_config/vapid_key.pem"
 # sub = "mailto:admin@example.com"
 
+# [push_notifications.unified_push]
+# allow_http = false # optional, allow HTTP distributor endpoints for testing
+# allow_private_ip_addresses = false # optional, allow local distributor endpoints for testing
+
 # [email_sending]
 # smtp_server_address = "smtp.example.com"
 # use_starttls_instead_of_smtps = false # optional
-# username = "username"
-# password = "password"
+# username = "username" # optional, empty username and password disables authentication
+# password = "password" # optional
 # email_from_header = "Example <no-reply@example.com>"
 # send_limit_per_minute = 1, # optional, by default no limit
 # send_limit_per_day = 10,   # optional, by default no limit
 
+# Send emails using JSON HTTP API instead of SMTP. SMTP related
+# config must not be set when this is enabled. Values in the
+# body template are JSON string escaped. Available values are
+# "from", "to", "subject", "text", "html" and "inline_images".
+# The last one is a list of objects with fields "content_id",
+# "content_type" and "data_base64".
+# [email_sending.http_api]
+# url = "https://api.example.com/v1/send"
+# auth_header_name = "Authorization"
+# auth_header_value = "Bearer TODO"
+# message_id_json_pointer = "/id" # optional, by default message ID is not logged
+# body_template = """
+# {
+#   "from": "{{from}}",
+#   "to": ["{{to}}"],
+#   "subject": "{{subject}}"
+#   {{#if text}}, "text": "{{text}}"{{/if}}
+#   {{#if html}}, "html": "{{html}}"{{/if}}
+# }
+# """
+
 # Allow public API without TLS, for setups where TLS
 # termination is handled by a reverse proxy.
 # [tls.public_api]
@@ -110,6 +154,11 @@ local_bot_api_port = 3001
 
 # [image_processing]
 # jpeg_quality = 60 # optional
+# webp_enabled = true # optional
+# webp_quality = 60 # optional
+# avif_enabled = false # optional
+# avif_quality = 60 # optional
+# avif_speed = 8 # optional
 
 # [image_processing.seetaface]
 # model_file = "model.bin"
@@ -187,6 +236,7 @@ impl SimpleBackendConfigFile {
                 public_api: None,
                 local_bot_api_port: None,
                 debug_local_bot_api_ip: None,
+                metrics_api: None,
             },
             database: DatabaseConfig::sqlite(),
             push_notifications: PushNotificationConfig::default(),
@@ -235,6 +285,42 @@ impl SimpleBackendConfigFile {
             }
         }
 
+        if let Some(config) = &config.email_sending {
+            if config.smtp_server_address.is_some() && config.http_api.is_some() {
+                return Err(ConfigFileError::InvalidConfig.report().attach(
+                    "email_sending: both smtp_server_address and http_api are configured",
+                ));
+            }
+            if config.smtp_server_address.is_none() && config.http_api.is_none() {
+                return Err(ConfigFileError::InvalidConfig
+                    .report()
+                    .attach("email_sending: smtp_server_address or http_api must be configured"));
+            }
+        }
+
+        for (i, oidc) in config.sign_in_with.oidc.iter().enumerate() {
+            if oidc.name.is_empty() {
+                return Err(ConfigFileError::InvalidConfig
+                    .report()
+                    .attach("sign_in_with.oidc: provider name is empty"));
+            }
+            if oidc.client_ids.is_empty() {
+                return Err(ConfigFileError::InvalidConfig.report().attach(format!(
+                    "sign_in_with.oidc: provider '{}' does not have client IDs",
+                    oidc.name
+                )));
+            }
+            if config.sign_in_with.oidc[..i]
+                .iter()
+                .any(|v| v.name == oidc.name)
+            {
+                return Err(ConfigFileError::InvalidConfig.report().attach(format!(
+                    "sign_in_with.oidc: provider name '{}' is not unique",
+                    oidc.name
+                )));
+            }
+        }
+
         if config.database.sqlite.is_some() && config.database.postgres.is_some() {
             return Err(ConfigFileError::InvalidConfig
                 .report()
@@ -321,6 +407,8 @@ pub struct SocketConfig {
     ///
     /// Overrides the default localhost IP address.
     pub debug_local_bot_api_ip: Option<IpAddr>,
+    /// Metrics API for monitoring tools.
+    pub metrics_api: Option<MetricsApiConfig>,
 }
 
 impl SocketConfig {
@@ -329,6 +417,14 @@ impl SocketConfig {
     }
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MetricsApiConfig {
+    /// HTTP only
+    pub address: SocketAddr,
+    #[serde(flatten)]
+    pub access: IpAddressAccessConfig,
+}
+
 /// App manager config
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ManagerConfig {
@@ -388,13 +484,23 @@ pub struct ManagerTlsConfig {
 pub struct TileMapConfig {
     /// Directory for map tiles.
     /// Tiles must be stored in z/x/y.png format.
-    pub tile_dir: PathBuf,
+    pub tile_dir: Option<PathBuf>,
+    /// MBTiles or PMTiles archive for map tiles. The archive type is
+    /// detected from the file extension (`mbtiles` or `pmtiles`).
+    pub tile_archive: Option<PathBuf>,
+}
+
+impl TileMapConfig {
+    pub const MBTILES_FILE_EXTENSION: &str = "mbtiles";
+    pub const PMTILES_FILE_EXTENSION: &str = "pmtiles";
 }
 
 #[derive(Debug, Default, Deserialize, Serialize, Clone)]
 pub struct SignInWithConfig {
     pub apple: Option<SignInWithAppleConfig>,
     pub google: Option<SignInWithGoogleConfig>,
+    #[serde(default)]
+    pub oidc: Vec<SignInWithOidcConfig>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -416,6 +522,43 @@ pub struct SignInWithGoogleConfig {
     pub client_id_server: String,
 }
 
+/// Generic OpenID Connect provider config
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SignInWithOidcConfig {
+    /// Provider name which clients use to select the provider.
+    /// The name is saved to the database.
+    pub name: String,
+    /// URL for OpenID Connect discovery document
+    pub discovery_url: Url,
+    /// Client IDs which are allowed in ID token `aud` and `azp` fields.
+    pub client_ids: Vec<String>,
+    /// Additional values which are allowed in ID token `aud` field.
+    #[serde(default)]
+    pub allowed_audiences: Vec<String>,
+    /// ID token claim which contains the email address.
+    /// Default is `email`.
+    pub email_claim: Option<String>,
+    /// ID token claim which contains the email verification status.
+    /// Default is `email_verified`.
+    pub email_verified_claim: Option<String>,
+    /// Consider the email address verified even if the ID token does
+    /// not contain the email verification status.
+    #[serde(default)]
+    pub assume_email_verified: bool,
+}
+
+impl SignInWithOidcConfig {
+    pub fn email_claim(&self) -> &str {
+        self.email_claim.as_deref().unwrap_or("email")
+    }
+
+    pub fn email_verified_claim(&self) -> &str {
+        self.email_verified_claim
+            .as_deref()
+            .unwrap_or("email_verified")
+    }
+}
+
 /// Firebase Cloud Messaging config
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct FcmConfig {
@@ -455,23 +598,44 @@ pub struct WebPushConfig {
     pub debug_logging: bool,
 }
 
+/// UnifiedPush notification config
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+pub struct UnifiedPushConfig {
+    /// Allow distributor endpoint URLs which use HTTP instead of HTTPS.
+    /// This is useful when testing with a local ntfy server.
+    #[serde(default)]
+    pub allow_http: bool,
+    /// Allow distributor endpoints which resolve to loopback, private,
+    /// link-local, unique local or unspecified IP addresses.
+    /// This is useful when testing with a local ntfy server.
+    #[serde(default)]
+    pub allow_private_ip_addresses: bool,
+    #[serde(default)]
+    pub debug_logging: bool,
+}
+
 #[derive(Debug, Default, Deserialize, Serialize, Clone)]
 pub struct PushNotificationConfig {
     pub fcm: Option<FcmConfig>,
     pub apns: Option<ApnsConfig>,
     pub web: Option<WebPushConfig>,
+    pub unified_push: Option<UnifiedPushConfig>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct EmailSendingConfig {
     /// The SMTP server must have port 465 open for sending emails using
-    /// implicit TLS.
-    pub smtp_server_address: String,
+    /// implicit TLS. Required if `http_api` is not configured.
+    pub smtp_server_address: Option<String>,
     /// Use STARTTLS to start TLS connection on port 587 instead of implicit
     /// TLS.
     #[serde(default)]
     pub use_starttls_instead_of_smtps: bool,
+    /// SMTP authentication is disabled if both username and password
+    /// are empty.
+    #[serde(default)]
     pub username: String,
+    #[serde(default)]
     pub password: String,
     /// Email `From` header, for example `Example <no-reply@example.com>`
     pub email_from_header: EmailFromHeader,
@@ -479,6 +643,19 @@ pub struct EmailSendingConfig {
     pub send_limit_per_day: Option<NonZeroU32>,
     #[serde(default)]
     pub debug_logging: bool,
+    /// Send emails using JSON HTTP API instead of SMTP
+    pub http_api: Option<EmailHttpApiConfig>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct EmailHttpApiConfig {
+    pub url: Url,
+    pub auth_header_name: String,
+    pub auth_header_value: String,
+    /// Handlebars template for JSON request body
+    pub body_template: String,
+    /// JSON Pointer for finding message ID from response JSON
+    pub message_id_json_pointer: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -629,6 +806,18 @@ pub struct ImageProcessingStaticConfig {
     /// Jpeg quality value. Value is clamped between 1-100.
     /// Mozjpeg library recommends 60-80 values
     pub(crate) jpeg_quality: u8,
+    /// Create WebP variants in addition to JPEG variants.
+    pub(crate) webp_enabled: bool,
+    /// WebP quality value. Value is clamped between 0-100.
+    pub(crate) webp_quality: u8,
+    /// Create AVIF variants in addition to JPEG variants.
+    /// AVIF encoding is slow, so this is disabled by default.
+    pub(crate) avif_enabled: bool,
+    /// AVIF quality value. Value is clamped between 1-100.
+    pub(crate) avif_quality: u8,
+    /// AVIF encoding speed. Value is clamped between 1-10.
+    /// Higher value is faster but the file size is larger.
+    pub(crate) avif_speed: u8,
     pub seetaface: Option<SeetaFaceConfig>,
     pub nsfw_detection: Option<NsfwDetectionConfig>,
     /// Make sure to use higer value than the server process nice
@@ -640,6 +829,11 @@ impl Default for ImageProcessingStaticConfig {
     fn default() -> Self {
         Self {
             jpeg_quality: 60,
+            webp_enabled: true,
+            webp_quality: 60,
+            avif_enabled: false,
+            avif_quality: 60,
+            avif_speed: 8,
             seetaface: None,
             nsfw_detection: None,
             process_nice_value: None,
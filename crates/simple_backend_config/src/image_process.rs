@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use simple_backend_model::ImageProcessingDynamicConfig;
+use simple_backend_model::{ContentImageFormat, ImageProcessingDynamicConfig};
 
 use crate::file::ImageProcessingStaticConfig;
 
@@ -28,4 +28,28 @@ impl ImageProcessingConfig {
     pub fn jpeg_quality(&self) -> f32 {
         self.file.jpeg_quality as f32
     }
+
+    pub fn webp_quality(&self) -> f32 {
+        self.file.webp_quality as f32
+    }
+
+    pub fn avif_quality(&self) -> u8 {
+        self.file.avif_quality
+    }
+
+    pub fn avif_speed(&self) -> u8 {
+        self.file.avif_speed
+    }
+
+    /// Image formats which are created in addition to JPEG.
+    pub fn additional_formats(&self) -> Vec<ContentImageFormat> {
+        let mut formats = vec![];
+        if self.file.webp_enabled {
+            formats.push(ContentImageFormat::Webp);
+        }
+        if self.file.avif_enabled {
+            formats.push(ContentImageFormat::Avif);
+        }
+        formats
+    }
 }
@@ -29,11 +29,11 @@ use web_push::{PartialVapidSignatureBuilder, VapidSignatureBuilder};
 
 use self::file::{
     AppAttestationConfig, ManagerConfig, PublicApiTlsConfig, SignInWithGoogleConfig,
-    SimpleBackendConfigFile, SocketConfig,
+    SignInWithOidcConfig, SimpleBackendConfigFile, SocketConfig,
 };
 use crate::file::{
     ApnsConfig, DatabaseConfig, FcmConfig, ImageProcessingStaticConfig, LetsEncryptConfig,
-    WebPushConfig,
+    UnifiedPushConfig, WebPushConfig,
 };
 
 /// Config file debug mode status.
@@ -175,6 +175,18 @@ impl SimpleBackendConfig {
         self.file.sign_in_with.google.as_ref()
     }
 
+    pub fn sign_in_with_oidc_config(&self, provider: &str) -> Option<&SignInWithOidcConfig> {
+        self.file
+            .sign_in_with
+            .oidc
+            .iter()
+            .find(|v| v.name == provider)
+    }
+
+    pub fn sign_in_with_oidc_configs(&self) -> &[SignInWithOidcConfig] {
+        &self.file.sign_in_with.oidc
+    }
+
     pub fn fcm_config(&self) -> Option<&FcmConfig> {
         self.file.push_notifications.fcm.as_ref()
     }
@@ -195,6 +207,10 @@ impl SimpleBackendConfig {
             .zip(self.vapid_builder.as_ref().map(|v| &v.0))
     }
 
+    pub fn unified_push_config(&self) -> Option<&UnifiedPushConfig> {
+        self.file.push_notifications.unified_push.as_ref()
+    }
+
     pub fn manager_config(&self) -> Option<&ManagerConfig> {
         self.file.manager.as_ref()
     }
@@ -321,6 +337,29 @@ pub fn get_config(
             .attach("Web push notification config: sub must start with mailto: or https:");
     }
 
+    if let Some(config) = file_config.tile_map.as_ref() {
+        match (&config.tile_dir, &config.tile_archive) {
+            (Some(_), None) => (),
+            (None, Some(archive)) => {
+                if !archive.exists() {
+                    return Err(GetConfigError::InvalidConfiguration)
+                        .attach("Tile map archive does not exist");
+                }
+                let extension = archive.extension().and_then(|v| v.to_str());
+                if extension != Some(TileMapConfig::MBTILES_FILE_EXTENSION)
+                    && extension != Some(TileMapConfig::PMTILES_FILE_EXTENSION)
+                {
+                    return Err(GetConfigError::InvalidConfiguration)
+                        .attach("Tile map archive file extension must be mbtiles or pmtiles");
+                }
+            }
+            _ => {
+                return Err(GetConfigError::InvalidConfiguration)
+                    .attach("Tile map config: configure either tile_dir or tile_archive");
+            }
+        }
+    }
+
     let public_api_tls_config = match &file_config.tls.public_api {
         Some(PublicApiTlsConfig::Manual(config)) => Some(Arc::new(generate_server_config(
             config.key.as_path(),
@@ -35,6 +35,27 @@ impl DbReadHandle {
     pub fn diesel(&self) -> &DieselReadHandle {
         &self.diesel_read
     }
+
+    pub fn pool_status(&self) -> DbPoolStatus {
+        let status = self.diesel_read.pool().status();
+        DbPoolStatus {
+            max_size: status.max_size,
+            size: status.size,
+            available: status.available,
+            waiting: status.waiting,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DbPoolStatus {
+    pub max_size: usize,
+    /// Current connection count
+    pub size: usize,
+    /// Connections which are not in use
+    pub available: usize,
+    /// Tasks waiting for a connection
+    pub waiting: usize,
 }
 
 pub struct DbReadCloseHandle {
@@ -1,15 +1,19 @@
 use std::{
     fs::File,
     io::{self, BufReader, Read, Seek, SeekFrom},
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
 
 use error_stack::{IntoReport, ResultExt};
 use face_detection::FaceDetector;
-use image::{DynamicImage, EncodableLayout, ImageDecoder, ImageFormat, ImageReader};
+use image::{
+    DynamicImage, EncodableLayout, ExtendedColorType, ImageDecoder, ImageEncoder, ImageFormat,
+    ImageReader, codecs::avif::AvifEncoder,
+};
 use nsfw_detection::NsfwDetector;
 use serde::{Deserialize, Serialize};
 use simple_backend_config::image_process::ImageProcessingConfig;
+use simple_backend_model::ContentImageFormat;
 use simple_backend_utils::{Result, consts::MIB_IN_BYTES};
 
 mod face_detection;
@@ -89,6 +93,10 @@ pub enum ImageProcessMessage {
     },
 }
 
+/// Image formats in addition to JPEG are enabled from
+/// [ImageProcessingConfig::additional_formats]. Output files for those
+/// are created next to the JPEG output files. The file path is
+/// created using [ContentImageFormat::file_path_from_jpeg_path].
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ProcessImageCommand {
     /// Input image file.
@@ -240,19 +248,19 @@ fn handle_image(
 
     let nsfw_detected = nsfw_detector.detect_nsfw(high.to_rgba8())?;
 
-    encode_and_save_jpeg(config, &high, &command.output_high)?;
+    encode_and_save(config, &high, &command.output_high)?;
 
     let medium = resize_image_if_needed(&high, 854);
-    encode_and_save_jpeg(config, &medium, &command.output_medium)?;
+    encode_and_save(config, &medium, &command.output_medium)?;
 
     let low = resize_image_if_needed(&high, 640);
-    encode_and_save_jpeg(config, &low, &command.output_low)?;
+    encode_and_save(config, &low, &command.output_low)?;
 
     let lower = resize_image_if_needed(&high, 426);
-    encode_and_save_jpeg(config, &lower, &command.output_lower)?;
+    encode_and_save(config, &lower, &command.output_lower)?;
 
     let very_low = resize_image_if_needed(&high, 256);
-    encode_and_save_jpeg(config, &very_low, &command.output_very_low)?;
+    encode_and_save(config, &very_low, &command.output_very_low)?;
 
     let info = ImageProcessingInfo {
         face_detected,
@@ -262,10 +270,66 @@ fn handle_image(
     Ok(info)
 }
 
+/// Save JPEG image and additional formats if those are enabled.
+fn encode_and_save(
+    config: &ImageProcessingConfig,
+    img: &DynamicImage,
+    jpeg_output_path: &Path,
+) -> Result<(), ImageProcessError> {
+    encode_and_save_jpeg(config, img, jpeg_output_path)?;
+
+    for format in config.additional_formats() {
+        let data = match format {
+            ContentImageFormat::Jpeg => continue,
+            ContentImageFormat::Webp => encode_webp(config, img)?,
+            ContentImageFormat::Avif => encode_avif(config, img)?,
+        };
+        std::fs::write(format.file_path_from_jpeg_path(jpeg_output_path), data)
+            .change_context(ImageProcessError::FileWriting)?;
+    }
+
+    Ok(())
+}
+
+fn encode_webp(
+    config: &ImageProcessingConfig,
+    img: &DynamicImage,
+) -> Result<Vec<u8>, ImageProcessError> {
+    let quality = config.webp_quality().clamp(0.0, 100.0);
+    let rgb = img.to_rgb8();
+    let data = webp::Encoder::from_rgb(rgb.as_bytes(), img.width(), img.height())
+        .encode_simple(false, quality)
+        .map_err(|e| {
+            ImageProcessError::EncodingError
+                .into_report()
+                .attach(format!("WebP encoding failed: {e:?}"))
+        })?;
+    Ok(data.to_vec())
+}
+
+fn encode_avif(
+    config: &ImageProcessingConfig,
+    img: &DynamicImage,
+) -> Result<Vec<u8>, ImageProcessError> {
+    let quality = config.avif_quality().clamp(1, 100);
+    let speed = config.avif_speed().clamp(1, 10);
+    let rgb = img.to_rgb8();
+    let mut data = Vec::new();
+    AvifEncoder::new_with_speed_quality(&mut data, speed, quality)
+        .write_image(
+            rgb.as_bytes(),
+            img.width(),
+            img.height(),
+            ExtendedColorType::Rgb8,
+        )
+        .change_context(ImageProcessError::EncodingError)?;
+    Ok(data)
+}
+
 fn encode_and_save_jpeg(
     config: &ImageProcessingConfig,
     img: &DynamicImage,
-    output_path: &PathBuf,
+    output_path: &Path,
 ) -> Result<(), ImageProcessError> {
     let width = img.width();
     let height = img.height();
@@ -1,3 +1,5 @@
+use std::path::{Path, PathBuf};
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
@@ -54,3 +56,224 @@ impl ContentQualityVariant {
         ]
     }
 }
+
+/// Image format of processed image content.
+///
+/// JPEG is always available. Other formats are optional
+/// and stored next to the JPEG file using the JPEG file name
+/// with [ContentImageFormat::file_name_suffix] appended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum ContentImageFormat {
+    Jpeg,
+    Webp,
+    Avif,
+}
+
+impl ContentImageFormat {
+    pub fn file_name_suffix(&self) -> &'static str {
+        match self {
+            Self::Jpeg => "",
+            Self::Webp => ".webp",
+            Self::Avif => ".avif",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Jpeg => "image/jpeg",
+            Self::Webp => "image/webp",
+            Self::Avif => "image/avif",
+        }
+    }
+
+    /// Protocol value.
+    pub fn as_u8(&self) -> u8 {
+        match self {
+            Self::Jpeg => 0,
+            Self::Webp => 1,
+            Self::Avif => 2,
+        }
+    }
+
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Jpeg),
+            1 => Some(Self::Webp),
+            2 => Some(Self::Avif),
+            _ => None,
+        }
+    }
+
+    /// Parse format from query parameter value.
+    pub fn from_query_value(value: &str) -> Option<Self> {
+        match value {
+            "jpeg" => Some(Self::Jpeg),
+            "webp" => Some(Self::Webp),
+            "avif" => Some(Self::Avif),
+            _ => None,
+        }
+    }
+
+    /// Select preferred format from HTTP `Accept` header value.
+    ///
+    /// Only explicitly listed image MIME types are considered. AVIF is
+    /// preferred over WebP if the quality values are equal. Returns
+    /// [ContentImageFormat::Jpeg] if no other format is accepted.
+    pub fn from_accept_header(value: &str) -> Self {
+        let mut selected = (Self::Jpeg, 0.0);
+        for media_range in value.split(',') {
+            let mut parts = media_range.split(';').map(|v| v.trim());
+            let format = match parts.next() {
+                Some("image/avif") => Self::Avif,
+                Some("image/webp") => Self::Webp,
+                _ => continue,
+            };
+            let quality = parts
+                .filter_map(|v| v.strip_prefix("q="))
+                .find_map(|v| v.parse::<f32>().ok())
+                .unwrap_or(1.0);
+            let better = quality > selected.1
+                || (quality == selected.1 && format == Self::Avif && quality > 0.0);
+            if better {
+                selected = (format, quality);
+            }
+        }
+        selected.0
+    }
+
+    pub fn all_formats() -> [Self; 3] {
+        [Self::Jpeg, Self::Webp, Self::Avif]
+    }
+
+    /// Append [ContentImageFormat::file_name_suffix] to the JPEG file path.
+    pub fn file_path_from_jpeg_path(&self, jpeg_path: &Path) -> PathBuf {
+        let mut name = jpeg_path.as_os_str().to_os_string();
+        name.push(self.file_name_suffix());
+        PathBuf::from(name)
+    }
+}
+
+/// Processed image content file which is identified with
+/// quality and format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct ContentFileVariant {
+    pub quality: ContentQualityVariant,
+    pub format: ContentImageFormat,
+}
+
+impl ContentFileVariant {
+    pub fn new(quality: ContentQualityVariant, format: ContentImageFormat) -> Self {
+        Self { quality, format }
+    }
+
+    pub fn jpeg(quality: ContentQualityVariant) -> Self {
+        Self::new(quality, ContentImageFormat::Jpeg)
+    }
+
+    pub fn file_name_suffix(&self) -> String {
+        format!(
+            "{}{}",
+            self.quality.variant_suffix(),
+            self.format.file_name_suffix()
+        )
+    }
+
+    /// Protocol byte value. The lower 4 bits contain
+    /// [ContentQualityVariant::as_u8] and the higher 4 bits contain
+    /// [ContentImageFormat::as_u8], so JPEG variants have the same value
+    /// as [ContentQualityVariant].
+    pub fn as_u8(&self) -> u8 {
+        (self.format.as_u8() << 4) | self.quality.as_u8()
+    }
+
+    pub fn from_u8(value: u8) -> Option<Self> {
+        Some(Self {
+            quality: ContentQualityVariant::from_u8(value & 0x0F)?,
+            format: ContentImageFormat::from_u8(value >> 4)?,
+        })
+    }
+
+    /// All quality variants for the formats.
+    pub fn all_variants_for_formats(
+        formats: impl IntoIterator<Item = ContentImageFormat>,
+    ) -> Vec<Self> {
+        formats
+            .into_iter()
+            .flat_map(|format| {
+                ContentQualityVariant::all_variants()
+                    .into_iter()
+                    .map(move |quality| Self::new(quality, format))
+            })
+            .collect()
+    }
+
+    /// Split content file name to content ID text and variant.
+    pub fn parse_file_name(name: &str) -> Option<(&str, Self)> {
+        let (name, format) = ContentImageFormat::all_formats()
+            .into_iter()
+            .filter(|f| *f != ContentImageFormat::Jpeg)
+            .find_map(|f| name.strip_suffix(f.file_name_suffix()).map(|n| (n, f)))
+            .unwrap_or((name, ContentImageFormat::Jpeg));
+        let quality = ContentQualityVariant::all_variants()
+            .into_iter()
+            .find(|q| name.ends_with(q.variant_suffix()))?;
+        let content = name.strip_suffix(quality.variant_suffix())?;
+        if content.is_empty() || content.contains('.') {
+            return None;
+        }
+        Some((content, Self::new(quality, format)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accept_header_format_selection() {
+        assert_eq!(
+            ContentImageFormat::from_accept_header("image/avif,image/webp,*/*"),
+            ContentImageFormat::Avif
+        );
+        assert_eq!(
+            ContentImageFormat::from_accept_header("image/avif;q=0.5, image/webp"),
+            ContentImageFormat::Webp
+        );
+        assert_eq!(
+            ContentImageFormat::from_accept_header("image/webp;q=0"),
+            ContentImageFormat::Jpeg
+        );
+        assert_eq!(
+            ContentImageFormat::from_accept_header("*/*"),
+            ContentImageFormat::Jpeg
+        );
+    }
+
+    #[test]
+    fn file_variant_byte_roundtrip() {
+        for v in ContentFileVariant::all_variants_for_formats(ContentImageFormat::all_formats()) {
+            assert_eq!(ContentFileVariant::from_u8(v.as_u8()), Some(v));
+        }
+        assert_eq!(
+            ContentFileVariant::jpeg(ContentQualityVariant::High).as_u8(),
+            ContentQualityVariant::High.as_u8()
+        );
+    }
+
+    #[test]
+    fn file_name_parsing() {
+        assert_eq!(
+            ContentFileVariant::parse_file_name("abc6"),
+            Some(("abc", ContentFileVariant::jpeg(ContentQualityVariant::High)))
+        );
+        assert_eq!(
+            ContentFileVariant::parse_file_name("abc2.webp"),
+            Some((
+                "abc",
+                ContentFileVariant::new(ContentQualityVariant::VeryLow, ContentImageFormat::Webp)
+            ))
+        );
+        assert_eq!(ContentFileVariant::parse_file_name("abc6.sha256"), None);
+        assert_eq!(ContentFileVariant::parse_file_name("abc"), None);
+    }
+}
@@ -0,0 +1,49 @@
+use std::{fmt, sync::Arc};
+
+/// Image which is embedded to HTML email as inline attachment.
+/// HTML can refer to the image using URL "cid:{content_id}".
+#[derive(Clone)]
+pub struct EmailInlineImage {
+    pub content_id: String,
+    /// MIME type, for example "image/png"
+    pub content_type: String,
+    pub data: Arc<[u8]>,
+}
+
+impl fmt::Debug for EmailInlineImage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EmailInlineImage")
+            .field("content_id", &self.content_id)
+            .field("content_type", &self.content_type)
+            .field("data_len", &self.data.len())
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum EmailBody {
+    Text(String),
+    Html {
+        html: String,
+        inline_images: Vec<EmailInlineImage>,
+    },
+    /// Email with multipart/alternative content type
+    TextAndHtml {
+        text: String,
+        html: String,
+        inline_images: Vec<EmailInlineImage>,
+    },
+}
+
+impl EmailBody {
+    pub fn new(body: String, body_is_html: bool) -> Self {
+        if body_is_html {
+            Self::Html {
+                html: body,
+                inline_images: vec![],
+            }
+        } else {
+            Self::Text(body)
+        }
+    }
+}
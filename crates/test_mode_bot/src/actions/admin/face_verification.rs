@@ -99,6 +99,7 @@ impl AdminBotFaceVerificationLogic {
             &values_by_account.security_content.cid,
             Some(false),
             None,
+            None,
         )
         .await
         .change_context(TestError::ApiRequest)?
@@ -116,6 +117,7 @@ impl AdminBotFaceVerificationLogic {
                 &content_id.cid,
                 Some(false),
                 None,
+                None,
             )
             .await
             .change_context(TestError::ApiRequest)?
@@ -444,7 +444,12 @@ impl BotAction for ChangeBotAgeAndOtherSettings {
                     .unwrap_or(1);
 
                 let mut selected = 0;
-                for value in attribute.values.into_iter().take(max_selected) {
+                for value in attribute
+                    .values
+                    .unwrap_or_default()
+                    .into_iter()
+                    .take(max_selected)
+                {
                     selected |= value.id;
                 }
 
@@ -1,15 +1,29 @@
-use std::{fmt::Debug, time::Instant};
+use std::{
+    collections::BTreeMap,
+    fmt::Debug,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
 
 use api_client::{
-    apis::profile_api::{
-        get_profile, get_profile_from_database_debug_mode_benchmark,
-        post_profile_to_database_debug_mode_benchmark,
+    apis::{
+        chat_api::{
+            get_message_delivery_info, post_add_sender_acknowledgement,
+            post_delete_message_delivery_info, post_send_like, post_send_message,
+        },
+        profile_api::{
+            get_profile, get_profile_from_database_debug_mode_benchmark,
+            post_profile_to_database_debug_mode_benchmark,
+        },
+    },
+    models::{
+        AccountId, LimitedActionStatus, MessageDeliveryInfoIdList, MessageId, ProfileUpdate,
+        SendLike, SentMessageIdList,
     },
-    models::ProfileUpdate,
 };
 use async_trait::async_trait;
 use error_stack::ResultExt;
-use simple_backend_utils::Result;
+use simple_backend_utils::{Result, UuidBase64Url};
 use test_mode_bot::{
     BotState,
     actions::{
@@ -18,11 +32,23 @@ use test_mode_bot::{
     },
 };
 use test_mode_utils::client::TestError;
-use tracing::log::info;
+use tracing::log::{info, warn};
+use utils::encrypt::encrypt_data;
 
-use crate::utils::Counters;
+use super::user::acknowledge_pending_messages;
+use crate::utils::{Counters, Latencies, PerfCounterValues};
 
 static COUNTERS: Counters = Counters::new();
+static LATENCIES: Latencies = Latencies::new();
+static PREVIOUS_PERF_COUNTERS: Mutex<Option<PerfCounterValues>> = Mutex::new(None);
+/// Typing start message sending times. Key is sender task ID.
+/// The recipient removes the entry when it receives the typing start event,
+/// so each sent message is measured at most once.
+static TYPING_START_SENT: Mutex<BTreeMap<u32, Instant>> = Mutex::new(BTreeMap::new());
+
+/// Server ignores typing indicator messages which are sent
+/// more often than once per second (default client features config).
+const TYPING_INDICATOR_INTERVAL: Duration = Duration::from_millis(1100);
 
 #[derive(Debug)]
 pub struct GetProfile;
@@ -102,6 +128,175 @@ impl BotAction for PostProfileToDatabase {
     }
 }
 
+/// Send like to the other bot of a bot pair. If the other bot
+/// has already sent a like, then the bots will match.
+#[derive(Debug)]
+pub struct SendLikeToPair(pub AccountId);
+
+#[async_trait]
+impl BotAction for SendLikeToPair {
+    async fn excecute_impl(&self, state: &mut BotState) -> Result<(), TestError> {
+        let r = post_send_like(
+            &state.api(),
+            SendLike {
+                account_id: self.0.clone(),
+                allow_matching: Some(true),
+            },
+        )
+        .await
+        .change_context(TestError::ApiRequest)?;
+        if r.error_account_interaction_state_mismatch.is_some()
+            || r.status == Some(LimitedActionStatus::FailureLimitAlreadyReached)
+        {
+            return Err(TestError::ApiRequest.report())
+                .attach_opaque_with(|| format!("Sending like failed: {r:?}"));
+        }
+        Ok(())
+    }
+}
+
+/// Send message to the other bot of a bot pair and acknowledge
+/// received messages.
+#[derive(Debug)]
+pub struct ChatMessage {
+    pub recipient: AccountId,
+    pub recipient_public_key_id: i64,
+    pub recipient_public_key: Vec<u8>,
+}
+
+impl ChatMessage {
+    async fn delete_delivery_info(state: &BotState) -> Result<(), TestError> {
+        let delivery_info = get_message_delivery_info(&state.api())
+            .await
+            .change_context(TestError::ApiRequest)?;
+        let ids = delivery_info.info.iter().map(|msg| msg.id).collect();
+        post_delete_message_delivery_info(&state.api(), MessageDeliveryInfoIdList { ids })
+            .await
+            .change_context(TestError::ApiRequest)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl BotAction for ChatMessage {
+    async fn excecute_impl(&self, state: &mut BotState) -> Result<(), TestError> {
+        let keys = state.chat.keys.clone().ok_or(TestError::MissingValue)?;
+
+        let msg = "Hello!";
+        let mut message_bytes = vec![0]; // Text message
+        let len_u16 = msg.len() as u16;
+        message_bytes.extend_from_slice(&len_u16.to_le_bytes());
+        message_bytes.extend_from_slice(msg.as_bytes());
+        let encrypted_bytes = encrypt_data(
+            &keys.private,
+            self.recipient_public_key.clone(),
+            message_bytes,
+        )
+        .change_context(TestError::MessageEncryptionError)?;
+
+        loop {
+            let message_id = UuidBase64Url::new_random_id().to_string();
+            let start = Instant::now();
+            let r = post_send_message(
+                &state.api(),
+                keys.public_key_id,
+                &self.recipient.aid,
+                self.recipient_public_key_id,
+                &message_id,
+                encrypted_bytes.clone(),
+            )
+            .await
+            .change_context(TestError::ApiRequest)?;
+            let latency = start.elapsed();
+
+            if r.error_too_many_pending_delivery_infos_exists
+                .unwrap_or_default()
+            {
+                Self::delete_delivery_info(state).await?;
+                continue;
+            }
+
+            if r.error_too_many_recipient_acknowledgements_missing
+                .unwrap_or_default()
+            {
+                // Wait until the other bot acknowledges messages
+                acknowledge_pending_messages(state).await?;
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                continue;
+            }
+
+            if r.error.unwrap_or_default() {
+                return Err(TestError::ApiRequest.report())
+                    .attach_opaque_with(|| format!("Sending message failed: {r:?}"));
+            }
+
+            LATENCIES.record(latency);
+
+            post_add_sender_acknowledgement(
+                &state.api(),
+                SentMessageIdList {
+                    ids: vec![MessageId { id: message_id }],
+                },
+            )
+            .await
+            .change_context(TestError::ApiRequest)?;
+            break;
+        }
+
+        for _ in acknowledge_pending_messages(state).await? {
+            COUNTERS.inc_sub();
+        }
+
+        Ok(())
+    }
+}
+
+/// Send typing indicator to the other bot of a bot pair and
+/// receive typing indicators until next typing indicator can be sent.
+///
+/// Requires that events are enabled.
+#[derive(Debug)]
+pub struct TypingIndicator {
+    pub recipient: AccountId,
+    pub recipient_task_id: u32,
+}
+
+#[async_trait]
+impl BotAction for TypingIndicator {
+    async fn excecute_impl(&self, state: &mut BotState) -> Result<(), TestError> {
+        const TYPING_START: u8 = 120;
+
+        let recipient = UuidBase64Url::from_text(&self.recipient.aid)
+            .map_err(|e| TestError::MissingValue.report().attach(e))?;
+        let mut message = vec![TYPING_START];
+        message.extend_from_slice(recipient.as_bytes());
+
+        let next_typing_start = tokio::time::Instant::now() + TYPING_INDICATOR_INTERVAL;
+        if let Ok(mut sent) = TYPING_START_SENT.lock() {
+            sent.insert(state.task_id, Instant::now());
+        }
+        state.connections.send_client_message(message)?;
+
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep_until(next_typing_start) => return Ok(()),
+                event = state.connections.recv_event() => {
+                    if event?.typing_start.as_ref() == Some(&self.recipient.aid) {
+                        let sent = TYPING_START_SENT
+                            .lock()
+                            .ok()
+                            .and_then(|mut v| v.remove(&self.recipient_task_id));
+                        if let Some(sent) = sent {
+                            LATENCIES.record(sent.elapsed());
+                        }
+                        COUNTERS.inc_sub();
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct ActionsBeforeIteration;
 
@@ -128,7 +323,47 @@ impl BotAction for ActionsAfterIteration {
                 COUNTERS.reset_main(),
                 COUNTERS.reset_sub(),
             );
+            if let Some(latencies) = LATENCIES.reset() {
+                info!("Latency, {latencies}");
+            }
+            print_perf_counter_deltas(state).await;
         }
         Ok(())
     }
 }
+
+async fn print_perf_counter_deltas(state: &BotState) {
+    let Some(port) = state.config.benchmark_metrics_api_port() else {
+        return;
+    };
+
+    let response = state
+        .reqwest_client
+        .get(format!("http://127.0.0.1:{port}/metrics"))
+        .send()
+        .await;
+    let metrics = match response {
+        Ok(response) => response.text().await,
+        Err(e) => Err(e),
+    };
+    let current = match metrics {
+        Ok(metrics) => PerfCounterValues::parse(&metrics),
+        Err(e) => {
+            warn!("Reading server metrics failed: {e}");
+            return;
+        }
+    };
+
+    let Ok(mut previous) = PREVIOUS_PERF_COUNTERS.lock() else {
+        return;
+    };
+    if let Some(previous) = previous.as_ref() {
+        let deltas = current
+            .deltas(previous)
+            .into_iter()
+            .map(|(name, delta)| format!("{name}: {delta}"))
+            .collect::<Vec<_>>();
+        info!("Server perf counters, {}", deltas.join(", "));
+    }
+    *previous = Some(current);
+}
@@ -189,90 +189,7 @@ pub struct AnswerReceivedMessages;
 #[async_trait]
 impl BotAction for AnswerReceivedMessages {
     async fn excecute_impl(&self, state: &mut BotState) -> Result<(), TestError> {
-        let messages = get_pending_messages(&state.api())
-            .await
-            .change_context(TestError::ApiRequest)?
-            .bytes()
-            .await
-            .change_context(TestError::ApiRequest)?
-            .to_vec();
-
-        if messages.is_empty() {
-            return Ok(());
-        }
-
-        fn parse_account_id(d: &mut impl Iterator<Item = u8>) -> Option<AccountId> {
-            let id = d.by_ref().take(16).collect::<Vec<u8>>();
-            let id = TryInto::<[u8; 16]>::try_into(id).ok()?;
-            let id = UuidBase64Url::from_bytes(id);
-            let id = AccountId::new(id.to_string());
-            Some(id)
-        }
-
-        fn parse_message_id(d: &mut impl Iterator<Item = u8>) -> Option<MessageId> {
-            let id = d.by_ref().take(16).collect::<Vec<u8>>();
-            let id = TryInto::<[u8; 16]>::try_into(id).ok()?;
-            let id = UuidBase64Url::from_bytes(id);
-            let id = MessageId::new(id.to_string());
-            Some(id)
-        }
-
-        struct ParsedMessage {
-            sender: AccountId,
-            message_id: MessageId,
-            message_number: MessageNumber,
-        }
-
-        fn parse_signed_message_data(data: Vec<u8>) -> Option<ParsedMessage> {
-            let d = &mut data.iter().copied();
-            let _version = d.next()?;
-            let sender = parse_account_id(d)?;
-            let _ = parse_account_id(d)?;
-            let message_id = parse_message_id(d)?;
-            let _ = minimal_i64::parse_minimal_i64_from_iter(d)?;
-            let _ = minimal_i64::parse_minimal_i64_from_iter(d)?;
-            let message_number = minimal_i64::parse_minimal_i64_from_iter(d)?;
-
-            Some(ParsedMessage {
-                sender,
-                message_id,
-                message_number: MessageNumber { mn: message_number },
-            })
-        }
-
-        fn parse_messages(messages: &[u8]) -> Option<Vec<ParsedMessage>> {
-            let mut list_iterator = messages.iter().copied();
-            let mut pending_messages: Vec<ParsedMessage> = vec![];
-            while let Some(data_len) = minimal_i64::parse_minimal_i64_from_iter(&mut list_iterator)
-            {
-                let data_len = match TryInto::<usize>::try_into(data_len) {
-                    Ok(len) => len,
-                    Err(_) => break,
-                };
-                let data = list_iterator.by_ref().take(data_len).collect::<Vec<u8>>();
-                let data = unwrap_signed_binary_message(&data).ok()?;
-                pending_messages.push(parse_signed_message_data(data)?);
-            }
-
-            Some(pending_messages)
-        }
-
-        let pending_messages = parse_messages(&messages).ok_or(TestError::MissingValue)?;
-
-        let delete_list = PendingMessageAcknowledgementList {
-            ids: pending_messages
-                .iter()
-                .map(|msg| PendingMessageId {
-                    sender: msg.sender.clone(),
-                    id: msg.message_id.clone(),
-                })
-                .collect(),
-            delivery_failed: None,
-        };
-
-        post_add_recipient_acknowledgement(&state.api(), delete_list)
-            .await
-            .change_context(TestError::ApiRequest)?;
+        let pending_messages = acknowledge_pending_messages(state).await?;
 
         for msg in &pending_messages {
             let seen = SeenMessage {
@@ -294,6 +211,97 @@ impl BotAction for AnswerReceivedMessages {
     }
 }
 
+/// Download and acknowledge pending messages
+pub async fn acknowledge_pending_messages(
+    state: &BotState,
+) -> Result<Vec<ParsedMessage>, TestError> {
+    let messages = get_pending_messages(&state.api())
+        .await
+        .change_context(TestError::ApiRequest)?
+        .bytes()
+        .await
+        .change_context(TestError::ApiRequest)?
+        .to_vec();
+
+    if messages.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let pending_messages = parse_pending_messages(&messages).ok_or(TestError::MissingValue)?;
+
+    let delete_list = PendingMessageAcknowledgementList {
+        ids: pending_messages
+            .iter()
+            .map(|msg| PendingMessageId {
+                sender: msg.sender.clone(),
+                id: msg.message_id.clone(),
+            })
+            .collect(),
+        delivery_failed: None,
+    };
+
+    post_add_recipient_acknowledgement(&state.api(), delete_list)
+        .await
+        .change_context(TestError::ApiRequest)?;
+
+    Ok(pending_messages)
+}
+
+fn parse_account_id(d: &mut impl Iterator<Item = u8>) -> Option<AccountId> {
+    let id = d.by_ref().take(16).collect::<Vec<u8>>();
+    let id = TryInto::<[u8; 16]>::try_into(id).ok()?;
+    let id = UuidBase64Url::from_bytes(id);
+    let id = AccountId::new(id.to_string());
+    Some(id)
+}
+
+fn parse_message_id(d: &mut impl Iterator<Item = u8>) -> Option<MessageId> {
+    let id = d.by_ref().take(16).collect::<Vec<u8>>();
+    let id = TryInto::<[u8; 16]>::try_into(id).ok()?;
+    let id = UuidBase64Url::from_bytes(id);
+    let id = MessageId::new(id.to_string());
+    Some(id)
+}
+
+pub struct ParsedMessage {
+    pub sender: AccountId,
+    pub message_id: MessageId,
+    pub message_number: MessageNumber,
+}
+
+fn parse_signed_message_data(data: Vec<u8>) -> Option<ParsedMessage> {
+    let d = &mut data.iter().copied();
+    let _version = d.next()?;
+    let sender = parse_account_id(d)?;
+    let _ = parse_account_id(d)?;
+    let message_id = parse_message_id(d)?;
+    let _ = minimal_i64::parse_minimal_i64_from_iter(d)?;
+    let _ = minimal_i64::parse_minimal_i64_from_iter(d)?;
+    let message_number = minimal_i64::parse_minimal_i64_from_iter(d)?;
+
+    Some(ParsedMessage {
+        sender,
+        message_id,
+        message_number: MessageNumber { mn: message_number },
+    })
+}
+
+fn parse_pending_messages(messages: &[u8]) -> Option<Vec<ParsedMessage>> {
+    let mut list_iterator = messages.iter().copied();
+    let mut pending_messages: Vec<ParsedMessage> = vec![];
+    while let Some(data_len) = minimal_i64::parse_minimal_i64_from_iter(&mut list_iterator) {
+        let data_len = match TryInto::<usize>::try_into(data_len) {
+            Ok(len) => len,
+            Err(_) => break,
+        };
+        let data = list_iterator.by_ref().take(data_len).collect::<Vec<u8>>();
+        let data = unwrap_signed_binary_message(&data).ok()?;
+        pending_messages.push(parse_signed_message_data(data)?);
+    }
+
+    Some(pending_messages)
+}
+
 struct Retry;
 
 async fn send_message(
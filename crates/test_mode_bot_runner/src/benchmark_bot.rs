@@ -1,15 +1,21 @@
 use std::{
+    collections::BTreeMap,
     sync::{
-        Arc,
-        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+        atomic::{AtomicBool, AtomicU32, Ordering},
     },
     time::Duration,
 };
 
+use api_client::{
+    apis::chat_api::{get_latest_public_key_id, get_public_key},
+    models::AccountId,
+};
 use config::{
     args::{SelectedBenchmark, TestMode},
     bot_config_file::BotConfigFile,
 };
+use error_stack::ResultExt;
 use simple_backend_utils::Result;
 use test_mode_bot::{
     BotState,
@@ -30,6 +36,8 @@ use tokio::{
 use tracing::error;
 
 static BENCHMARK_GET_PROFILE_LIST_INDEX_READY: AtomicBool = AtomicBool::new(false);
+/// Bot pair benchmark account IDs. Key is task ID.
+static BENCHMARK_PAIR_ACCOUNTS: Mutex<BTreeMap<u32, AccountId>> = Mutex::new(BTreeMap::new());
 
 pub struct BenchmarkBot {
     state: BotState,
@@ -120,6 +128,8 @@ impl BenchmarkBot {
             SelectedBenchmark::PostProfileToDatabase => {
                 Self::benchmark_post_profile_to_database(state).await
             }
+            SelectedBenchmark::ChatMessages => Self::benchmark_chat_messages(state).await,
+            SelectedBenchmark::TypingIndicator => Self::benchmark_typing_indicator(state).await,
         }
     }
 
@@ -162,8 +172,6 @@ impl BenchmarkBot {
     }
 
     async fn benchmark_get_profile_list(state: &mut BotState) -> Result<(), TestError> {
-        use std::sync::atomic::{AtomicU32, Ordering};
-
         use test_mode_bot::{
             action_array,
             actions::{
@@ -268,4 +276,121 @@ impl BenchmarkBot {
             ActionsAfterIteration.excecute(state).await?;
         }
     }
+
+    /// Register bot and match it with the other bot of the bot pair.
+    /// Tasks 0 and 1 are the first pair, tasks 2 and 3 the second pair
+    /// and so on.
+    ///
+    /// Returns the account ID and task ID of the other bot.
+    async fn setup_bot_pair(state: &mut BotState) -> Result<(AccountId, u32), TestError> {
+        use crate::actions::{benchmark::SendLikeToPair, user::SetBotPublicKey};
+
+        static READY_COUNT: AtomicU32 = AtomicU32::new(0);
+        static LIKE_SENT_COUNT: AtomicU32 = AtomicU32::new(0);
+        static MATCH_COUNT: AtomicU32 = AtomicU32::new(0);
+
+        async fn wait_until(count: &AtomicU32, value: u32) {
+            while count.load(Ordering::Relaxed) < value {
+                tokio::time::sleep(Duration::from_millis(1)).await
+            }
+        }
+
+        RunActions(TO_NORMAL_STATE).excecute(state).await?;
+        SetBotPublicKey.excecute(state).await?;
+
+        let pair_count = state.config.tasks() / 2;
+        let pair_task_id = state.task_id ^ 1;
+        let first_in_pair = state.task_id % 2 == 0;
+
+        BENCHMARK_PAIR_ACCOUNTS
+            .lock()
+            .map_err(|_| TestError::MissingValue.report())?
+            .insert(state.task_id, state.account_id()?);
+        READY_COUNT.fetch_add(1, Ordering::Relaxed);
+        wait_until(&READY_COUNT, pair_count * 2).await;
+
+        let pair_account_id = BENCHMARK_PAIR_ACCOUNTS
+            .lock()
+            .map_err(|_| TestError::MissingValue.report())?
+            .get(&pair_task_id)
+            .cloned()
+            .ok_or(TestError::MissingValue.report())?;
+
+        if first_in_pair {
+            SendLikeToPair(pair_account_id.clone())
+                .excecute(state)
+                .await?;
+            LIKE_SENT_COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+        wait_until(&LIKE_SENT_COUNT, pair_count).await;
+
+        if !first_in_pair {
+            SendLikeToPair(pair_account_id.clone())
+                .excecute(state)
+                .await?;
+            MATCH_COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+        wait_until(&MATCH_COUNT, pair_count).await;
+
+        Ok((pair_account_id, pair_task_id))
+    }
+
+    async fn benchmark_chat_messages(state: &mut BotState) -> Result<(), TestError> {
+        use crate::actions::benchmark::{
+            ActionsAfterIteration, ActionsBeforeIteration, ChatMessage,
+        };
+
+        // Setup
+        let (recipient, _) = Self::setup_bot_pair(state).await?;
+
+        let recipient_public_key_id = get_latest_public_key_id(&state.api(), &recipient.aid)
+            .await
+            .change_context(TestError::ApiRequest)?
+            .id
+            .map(|v| v.id)
+            .ok_or(TestError::MissingValue.report())?;
+        let recipient_public_key =
+            get_public_key(&state.api(), &recipient.aid, recipient_public_key_id)
+                .await
+                .change_context(TestError::ApiRequest)?
+                .bytes()
+                .await
+                .change_context(TestError::ApiRequest)?
+                .to_vec();
+
+        let action = ChatMessage {
+            recipient,
+            recipient_public_key_id,
+            recipient_public_key,
+        };
+
+        // Benchmark loop
+        loop {
+            ActionsBeforeIteration.excecute(state).await?;
+            action.excecute(state).await?;
+            ActionsAfterIteration.excecute(state).await?;
+        }
+    }
+
+    async fn benchmark_typing_indicator(state: &mut BotState) -> Result<(), TestError> {
+        use crate::actions::benchmark::{
+            ActionsAfterIteration, ActionsBeforeIteration, TypingIndicator,
+        };
+
+        // Setup
+        let (recipient, recipient_task_id) = Self::setup_bot_pair(state).await?;
+        state.enable_events();
+
+        let action = TypingIndicator {
+            recipient,
+            recipient_task_id,
+        };
+
+        // Benchmark loop
+        loop {
+            ActionsBeforeIteration.excecute(state).await?;
+            action.excecute(state).await?;
+            ActionsAfterIteration.excecute(state).await?;
+        }
+    }
 }
@@ -1,6 +1,11 @@
 use std::{
-    fmt::Debug,
-    sync::atomic::{AtomicU64, Ordering},
+    collections::BTreeMap,
+    fmt::{Debug, Display},
+    sync::{
+        Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
 };
 
 /// Benchmark counters
@@ -36,3 +41,149 @@ impl Counters {
         self.sub.swap(0, Ordering::Relaxed)
     }
 }
+
+/// Benchmark latency samples
+#[derive(Default, Debug)]
+pub struct Latencies {
+    samples: Mutex<Vec<Duration>>,
+}
+
+impl Latencies {
+    pub const fn new() -> Self {
+        Self {
+            samples: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn record(&self, latency: Duration) {
+        if let Ok(mut samples) = self.samples.lock() {
+            samples.push(latency);
+        }
+    }
+
+    /// Returns `None` if there are no samples.
+    pub fn reset(&self) -> Option<LatencySummary> {
+        let mut samples = match self.samples.lock() {
+            Ok(mut samples) => std::mem::take(&mut *samples),
+            Err(_) => return None,
+        };
+        LatencySummary::new(&mut samples)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct LatencySummary {
+    pub count: usize,
+    pub p50: Duration,
+    pub p99: Duration,
+}
+
+impl LatencySummary {
+    fn new(samples: &mut [Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort_unstable();
+        let percentile = |p: usize| {
+            // Nearest-rank method
+            let rank = (samples.len() * p).div_ceil(100);
+            samples[rank.saturating_sub(1)]
+        };
+        Some(Self {
+            count: samples.len(),
+            p50: percentile(50),
+            p99: percentile(99),
+        })
+    }
+}
+
+impl Display for LatencySummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "samples: {}, p50: {:?}, p99: {:?}",
+            self.count, self.p50, self.p99
+        )
+    }
+}
+
+/// Server performance counter values from metrics API
+#[derive(Default, Debug)]
+pub struct PerfCounterValues {
+    values: BTreeMap<String, u64>,
+}
+
+impl PerfCounterValues {
+    const SAMPLE_NAME: &str = "perf_counter_total";
+
+    /// Parse `perf_counter` values from OpenMetrics text.
+    pub fn parse(metrics: &str) -> Self {
+        let mut values = BTreeMap::new();
+        for line in metrics.lines() {
+            let Some(labels_and_value) = line.strip_prefix(Self::SAMPLE_NAME) else {
+                continue;
+            };
+            let Some((labels, value)) = labels_and_value.rsplit_once(' ') else {
+                continue;
+            };
+            let Ok(value) = value.parse::<u64>() else {
+                continue;
+            };
+            let label = |name: &str| {
+                let start = format!("{name}=\"");
+                let value = &labels[labels.find(&start)? + start.len()..];
+                value.split_once('"').map(|(value, _)| value)
+            };
+            if let (Some(category), Some(counter)) = (label("category"), label("counter")) {
+                values.insert(format!("{category}/{counter}"), value);
+            }
+        }
+        Self { values }
+    }
+
+    /// Counters which have changed compared to previous values
+    pub fn deltas(&self, previous: &Self) -> Vec<(&str, u64)> {
+        self.values
+            .iter()
+            .filter_map(|(name, &value)| {
+                let previous = previous.values.get(name).copied().unwrap_or_default();
+                let delta = value.saturating_sub(previous);
+                (delta > 0).then_some((name.as_str(), delta))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latency_percentiles() {
+        let mut samples: Vec<Duration> = (1..=100).rev().map(Duration::from_millis).collect();
+        let summary = LatencySummary::new(&mut samples).unwrap();
+        assert_eq!(summary.count, 100);
+        assert_eq!(summary.p50, Duration::from_millis(50));
+        assert_eq!(summary.p99, Duration::from_millis(99));
+        assert_eq!(LatencySummary::new(&mut []), None);
+    }
+
+    #[test]
+    fn perf_counter_deltas() {
+        let previous = PerfCounterValues::parse(
+            "# TYPE perf_counter counter\n\
+             perf_counter_total{category=\"chat\",counter=\"post_send_message\"} 3\n\
+             perf_counter_total{category=\"chat\",counter=\"get_pending_messages\"} 1\n",
+        );
+        let current = PerfCounterValues::parse(
+            "perf_counter_total{category=\"chat\",counter=\"post_send_message\"} 10\n\
+             perf_counter_total{category=\"chat\",counter=\"get_pending_messages\"} 1\n\
+             perf_counter_total{category=\"profile\",counter=\"get_profile\"} 2\n\
+             # EOF\n",
+        );
+        assert_eq!(
+            current.deltas(&previous),
+            vec![("chat/post_send_message", 7), ("profile/get_profile", 2)]
+        );
+    }
+}
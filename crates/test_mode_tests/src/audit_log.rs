@@ -0,0 +1,115 @@
+use api_client::{
+    apis::{
+        account_api::{get_account_ban_time, get_account_deletion_request_state},
+        common_admin_api::post_get_admin_audit_log_page,
+    },
+    models::{AdminAuditLogAction, AdminAuditLogEntry, AdminAuditLogQuery, UnixTime},
+};
+use test_mode_test_utils::{Admin, prelude::*};
+
+async fn audit_log_page(
+    admin: &Admin,
+    query: AdminAuditLogQuery,
+) -> TestResult<Vec<AdminAuditLogEntry>> {
+    let page = post_get_admin_audit_log_page(&admin.api(), query).await?;
+    Ok(page.entries)
+}
+
+fn query_all(page: i64) -> AdminAuditLogQuery {
+    AdminAuditLogQuery::new(page, UnixTime::new(i64::MAX))
+}
+
+#[server_test]
+async fn admin_audit_log_page_filters(mut context: TestContext) -> TestResult {
+    let admin = context.new_admin().await?;
+    let first = context.new_account().await?;
+    let second = context.new_account().await?;
+
+    get_account_ban_time(&admin.api(), &first.account_id_string()).await?;
+    get_account_ban_time(&admin.api(), &second.account_id_string()).await?;
+    get_account_deletion_request_state(&admin.api(), &first.account_id_string()).await?;
+    // Own account access is not logged
+    get_account_ban_time(&first.api(), &first.account_id_string()).await?;
+
+    let entries = audit_log_page(
+        &admin,
+        AdminAuditLogQuery {
+            target_account: Some(first.account_id()),
+            ..query_all(0)
+        },
+    )
+    .await?;
+    assert_eq(2, entries.len())?;
+    // Newest first
+    assert_eq(
+        AdminAuditLogAction::GetAccountDeletionRequestState,
+        entries[0].action,
+    )?;
+    assert_eq(AdminAuditLogAction::GetAccountBanTime, entries[1].action)?;
+    assert_eq(admin.account_id(), entries[0].admin.clone())?;
+
+    let entries = audit_log_page(
+        &admin,
+        AdminAuditLogQuery {
+            action: Some(AdminAuditLogAction::GetAccountBanTime),
+            ..query_all(0)
+        },
+    )
+    .await?;
+    assert_eq(2, entries.len())?;
+    assert_eq(Some(second.account_id()), entries[0].target_account.clone())?;
+    assert_eq(Some(first.account_id()), entries[1].target_account.clone())?;
+
+    let entries = audit_log_page(
+        &admin,
+        AdminAuditLogQuery {
+            action: Some(AdminAuditLogAction::GetAccountBanTime),
+            target_account: Some(second.account_id()),
+            ..query_all(0)
+        },
+    )
+    .await?;
+    assert_eq(1, entries.len())?;
+
+    let entries = audit_log_page(
+        &admin,
+        AdminAuditLogQuery {
+            admin: Some(first.account_id()),
+            ..query_all(0)
+        },
+    )
+    .await?;
+    assert_eq(0, entries.len())?;
+
+    let entries = audit_log_page(
+        &admin,
+        AdminAuditLogQuery {
+            admin: Some(admin.account_id()),
+            ..query_all(0)
+        },
+    )
+    .await?;
+    assert_eq(3, entries.len())?;
+
+    let entries = audit_log_page(
+        &admin,
+        AdminAuditLogQuery {
+            start_position: UnixTime::new(0),
+            ..query_all(0)
+        },
+    )
+    .await?;
+    assert_eq(0, entries.len())?;
+
+    let entries = audit_log_page(
+        &admin,
+        AdminAuditLogQuery {
+            admin: Some(admin.account_id()),
+            ..query_all(1)
+        },
+    )
+    .await?;
+    assert_eq(0, entries.len())?;
+
+    Ok(())
+}
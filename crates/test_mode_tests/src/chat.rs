@@ -0,0 +1,198 @@
+use api_client::{
+    apis::{
+        Error,
+        chat_api::{
+            get_message_attachment, post_add_public_key, post_add_recipient_acknowledgement,
+            post_add_sender_acknowledgement, post_send_like, post_send_message,
+            post_upload_message_attachment,
+        },
+    },
+    models::{
+        MessageId, PendingMessageAcknowledgementList, PendingMessageId, SendLike, SentMessageIdList,
+    },
+};
+use simple_backend_utils::{UuidBase64Url, byte::ByteCount};
+use test_mode_test_utils::{Account, prelude::*};
+use utils::encrypt::generate_keys;
+
+const ATTACHMENT: &[u8] = &[1, 2, 3, 4];
+
+async fn add_public_key(account: &Account) -> TestResult<i64> {
+    let keys = generate_keys(account.account_id_string())
+        .map_err(|_| TestError::MessageEncryptionError.report())?;
+    let public_key_bytes = keys
+        .public_key_bytes()
+        .map_err(|_| TestError::MessageEncryptionError.report())?;
+    let result = post_add_public_key(&account.api(), public_key_bytes, Some(true)).await?;
+    let key_id = result.key_id.ok_or(TestError::MissingValue.report())?;
+    Ok(key_id.id)
+}
+
+async fn make_match(first: &Account, second: &Account) -> TestResult {
+    for (sender, target) in [(first, second), (second, first)] {
+        let like = SendLike {
+            account_id: target.account_id(),
+            allow_matching: Some(true),
+        };
+        let result = post_send_like(&sender.api(), like).await?;
+        assert(result.error_account_interaction_state_mismatch.is_none())?;
+    }
+    Ok(())
+}
+
+/// Create matching sender and recipient and send message from the sender
+/// to the recipient. Returns the message ID.
+async fn send_message_to_new_match(
+    context: &mut TestContext,
+) -> TestResult<(Account, Account, String)> {
+    let sender = context.new_account().await?;
+    let recipient = context.new_account().await?;
+    let sender_key = add_public_key(&sender).await?;
+    let recipient_key = add_public_key(&recipient).await?;
+    make_match(&sender, &recipient).await?;
+
+    let message_id = UuidBase64Url::new_random_id().to_string();
+    let result = post_send_message(
+        &sender.api(),
+        sender_key,
+        &recipient.account_id_string(),
+        recipient_key,
+        &message_id,
+        vec![0, 1, 2],
+    )
+    .await?;
+    assert(!result.error.unwrap_or_default())?;
+
+    Ok((sender, recipient, message_id))
+}
+
+async fn recipient_acknowledgement(
+    sender: &Account,
+    recipient: &Account,
+    message_id: &str,
+) -> TestResult {
+    post_add_recipient_acknowledgement(
+        &recipient.api(),
+        PendingMessageAcknowledgementList::new(vec![PendingMessageId::new(
+            MessageId::new(message_id.to_string()),
+            sender.account_id(),
+        )]),
+    )
+    .await?;
+    Ok(())
+}
+
+async fn sender_acknowledgement(sender: &Account, message_id: &str) -> TestResult {
+    post_add_sender_acknowledgement(
+        &sender.api(),
+        SentMessageIdList::new(vec![MessageId::new(message_id.to_string())]),
+    )
+    .await?;
+    Ok(())
+}
+
+async fn download_attachment(
+    sender: &Account,
+    recipient: &Account,
+    message_id: &str,
+) -> TestResult<Vec<u8>> {
+    let bytes = get_message_attachment(&recipient.api(), &sender.account_id_string(), message_id)
+        .await?
+        .bytes()
+        .await
+        .map_err(|_| TestError::ApiRequest.report())?;
+    Ok(bytes.to_vec())
+}
+
+#[server_test]
+async fn message_attachment_upload_download_and_acknowledge(
+    mut context: TestContext,
+) -> TestResult {
+    let (sender, recipient, message_id) = send_message_to_new_match(&mut context).await?;
+
+    let result =
+        post_upload_message_attachment(&sender.api(), &message_id, ATTACHMENT.to_vec()).await?;
+    assert(!result.error.unwrap_or_default())?;
+    sender_acknowledgement(&sender, &message_id).await?;
+
+    let attachment = download_attachment(&sender, &recipient, &message_id).await?;
+    assert_eq(ATTACHMENT.to_vec(), attachment)?;
+
+    recipient_acknowledgement(&sender, &recipient, &message_id).await?;
+
+    assert_failure(
+        get_message_attachment(&recipient.api(), &sender.account_id_string(), &message_id).await,
+    )?;
+
+    Ok(())
+}
+
+#[server_test]
+async fn message_attachment_is_available_until_sender_and_recipient_acknowledge_message(
+    mut context: TestContext,
+) -> TestResult {
+    let (sender, recipient, message_id) = send_message_to_new_match(&mut context).await?;
+
+    let result =
+        post_upload_message_attachment(&sender.api(), &message_id, ATTACHMENT.to_vec()).await?;
+    assert(!result.error.unwrap_or_default())?;
+
+    recipient_acknowledgement(&sender, &recipient, &message_id).await?;
+
+    let attachment = download_attachment(&sender, &recipient, &message_id).await?;
+    assert_eq(ATTACHMENT.to_vec(), attachment)?;
+
+    // Upload is not possible again because the message is acknowledged
+    let result =
+        post_upload_message_attachment(&sender.api(), &message_id, ATTACHMENT.to_vec()).await?;
+    assert(result.error_message_not_found.unwrap_or_default())?;
+
+    sender_acknowledgement(&sender, &message_id).await?;
+
+    assert_failure(
+        get_message_attachment(&recipient.api(), &sender.account_id_string(), &message_id).await,
+    )?;
+
+    Ok(())
+}
+
+#[server_test]
+async fn message_attachment_upload_fails_if_recipient_acknowledged_message_before_upload(
+    mut context: TestContext,
+) -> TestResult {
+    let (sender, recipient, message_id) = send_message_to_new_match(&mut context).await?;
+
+    recipient_acknowledgement(&sender, &recipient, &message_id).await?;
+
+    let result =
+        post_upload_message_attachment(&sender.api(), &message_id, ATTACHMENT.to_vec()).await?;
+    assert(result.error_message_not_found.unwrap_or_default())?;
+
+    assert_failure(
+        get_message_attachment(&recipient.api(), &sender.account_id_string(), &message_id).await,
+    )?;
+
+    Ok(())
+}
+
+fn set_attachment_max_size_to_1_mb(config: ServerConfigEditor) {
+    config.server.limits = Some(config::file::LimitsConfig {
+        chat: Some(config::file::ChatLimitsConfig {
+            message_attachment_max_bytes: ByteCount::from_megabytes(1),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+}
+
+#[server_test(modify_server_config_with = "set_attachment_max_size_to_1_mb")]
+async fn too_large_message_attachment_is_rejected(mut context: TestContext) -> TestResult {
+    let (sender, _, message_id) = send_message_to_new_match(&mut context).await?;
+
+    let attachment = vec![0; ByteCount::from_megabytes(1).bytes() as usize + 1];
+    let result = post_upload_message_attachment(&sender.api(), &message_id, attachment).await;
+    match result {
+        Err(Error::ResponseError(e)) => assert_eq(413, e.status.as_u16()),
+        _ => assert(false),
+    }
+}
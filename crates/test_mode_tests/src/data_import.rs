@@ -0,0 +1,124 @@
+use std::{
+    io::{Cursor, Read, Write},
+    time::Duration,
+};
+
+use api_client::{
+    apis::{
+        common_admin_api::post_import_account_data,
+        common_api::{get_data_export_archive, get_data_export_state, post_start_data_export},
+        profile_api::get_profile,
+    },
+    models::{DataExportStateType, DataExportType, PostStartDataExport},
+};
+use test_mode_test_utils::{Account, prelude::*};
+use zip::{ZipArchive, ZipWriter, write::SimpleFileOptions};
+
+const EXPORT_WAIT_TIMEOUT: Duration = Duration::from_secs(30);
+
+async fn export_user_data(account: &Account) -> TestResult<Vec<u8>> {
+    post_start_data_export(
+        &account.api(),
+        PostStartDataExport::new(DataExportType::User, account.account_id()),
+    )
+    .await?;
+
+    let name = tokio::time::timeout(EXPORT_WAIT_TIMEOUT, async {
+        loop {
+            let state = get_data_export_state(&account.api()).await?;
+            match state.state {
+                DataExportStateType::Done => {
+                    return state.name.ok_or(TestError::MissingValue.report());
+                }
+                DataExportStateType::Error => {
+                    return Err(TestError::AssertError("Data export failed".to_string()).report());
+                }
+                DataExportStateType::Empty | DataExportStateType::InProgress => {
+                    tokio::time::sleep(Duration::from_millis(100)).await
+                }
+            }
+        }
+    })
+    .await
+    .map_err(|_| TestError::AssertError("Data export timeout".to_string()).report())??;
+
+    let archive = get_data_export_archive(&account.api(), &name.name)
+        .await?
+        .bytes()
+        .await
+        .map_err(|_| TestError::ApiRequest.report())?;
+    Ok(archive.to_vec())
+}
+
+/// Remove email address from user data export archive, so that
+/// importing is possible when the exported account still exists.
+fn remove_email_address(archive: Vec<u8>) -> TestResult<Vec<u8>> {
+    let invalid = |_| TestError::InvalidValue.report();
+    let mut archive = ZipArchive::new(Cursor::new(archive)).map_err(invalid)?;
+    let mut writer = ZipWriter::new(Cursor::new(vec![]));
+    for i in 0..archive.len() {
+        let name = archive
+            .name_for_index(i)
+            .ok_or(TestError::MissingValue.report())?;
+        if name.ends_with("/user/account.json") {
+            let name = name.to_string();
+            let mut data = vec![];
+            archive
+                .by_index(i)
+                .map_err(invalid)?
+                .read_to_end(&mut data)
+                .map_err(|_| TestError::InvalidValue.report())?;
+            let mut account: serde_json::Value =
+                serde_json::from_slice(&data).map_err(|_| TestError::SerdeDeserialize.report())?;
+            account["email_address_state"]["email"] = serde_json::Value::Null;
+            let data =
+                serde_json::to_vec(&account).map_err(|_| TestError::InvalidValue.report())?;
+            writer
+                .start_file(name, SimpleFileOptions::default())
+                .map_err(invalid)?;
+            writer
+                .write_all(&data)
+                .map_err(|_| TestError::InvalidValue.report())?;
+        } else {
+            writer
+                .raw_copy_file(archive.by_index_raw(i).map_err(invalid)?)
+                .map_err(invalid)?;
+        }
+    }
+    Ok(writer.finish().map_err(invalid)?.into_inner())
+}
+
+#[server_test]
+async fn exported_account_data_can_be_imported(mut context: TestContext) -> TestResult {
+    let admin = context.new_admin().await?;
+    let account = context.new_account().await?;
+    let archive = export_user_data(&account).await?;
+
+    let result = post_import_account_data(&admin.api(), archive.clone()).await?;
+    assert(result.error_email_already_used.unwrap_or_default())?;
+
+    let result = post_import_account_data(&admin.api(), remove_email_address(archive)?).await?;
+    assert(!result.error.unwrap_or_default())?;
+    assert_eq(0, result.failed_image_count.unwrap_or_default())?;
+    let imported = result.aid.ok_or(TestError::MissingValue.report())?;
+    // The exported account still uses the account ID from the archive
+    assert(imported != account.account_id())?;
+
+    let profile = get_profile(&admin.api(), &imported.aid, None, None)
+        .await?
+        .profile
+        .ok_or(TestError::MissingValue.report())?;
+    assert_eq(Some("Test".to_string()), profile.name)?;
+
+    Ok(())
+}
+
+#[server_test]
+async fn invalid_data_import_archive_is_rejected(mut context: TestContext) -> TestResult {
+    let admin = context.new_admin().await?;
+
+    let result = post_import_account_data(&admin.api(), vec![1, 2, 3]).await?;
+    assert(result.error_invalid_archive.unwrap_or_default())?;
+
+    Ok(())
+}
@@ -5,6 +5,9 @@
 
 //! Common API tests
 
+mod audit_log;
+mod chat;
+mod data_import;
 mod report;
 
 pub fn call_this_to_make_sure_that_crate_is_linked() {}
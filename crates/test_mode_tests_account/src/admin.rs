@@ -1,5 +1,25 @@
-use api_client::apis::{account_admin_api::post_delete_account, account_api::get_account_state};
-use test_mode_test_utils::prelude::*;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use api_client::{
+    apis::{
+        account_admin_api::{post_delete_account, post_set_permissions},
+        account_api::{
+            get_account_state, post_confirm_totp_enrollment, post_disable_totp,
+            post_start_totp_enrollment, post_verify_totp,
+        },
+        common_admin_api::post_get_admin_audit_log_page,
+    },
+    models::{AdminAuditLogQuery, Permissions, TotpCode, UnixTime},
+};
+use test_mode_bot::{
+    action_array,
+    actions::{
+        account::{CompleteAccountSetup, SetInitialEmailAction},
+        media::{SendImageToSlot, SetContent},
+    },
+};
+use test_mode_test_utils::{Account, prelude::*};
+use utils::totp::{generate_totp_code, totp_secret_from_base32};
 
 fn disable_grant_admin_access(config: ServerConfigEditor) {
     config.server.grant_admin_access = None;
@@ -49,3 +69,123 @@ async fn normal_account_does_not_have_admin_rights(mut context: TestContext) ->
         Default::default(),
     )
 }
+
+fn require_admin_totp(config: ServerConfigEditor) {
+    config.server.admin_totp.required = true;
+    config.server.admin_totp.required_from_bots = true;
+}
+
+fn current_unix_time() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|v| v.as_secs() as i64)
+        .unwrap_or_default()
+}
+
+/// Account with admin email which has TOTP enabled before
+/// completing initial setup. Returns the account and TOTP secret.
+async fn new_admin_with_totp(context: &mut TestContext) -> TestResult<(Account, Vec<u8>)> {
+    let mut admin = Account::register_and_login(context, true).await?;
+    admin
+        .run_actions(action_array![
+            SetInitialEmailAction::admin(),
+            SendImageToSlot::slot(0),
+            SetContent {
+                security_content_slot_i: Some(0),
+                content_0_slot_i: Some(0),
+            },
+        ])
+        .await?;
+
+    let enrollment = post_start_totp_enrollment(&admin.api())
+        .await?
+        .enrollment
+        .flatten()
+        .ok_or(TestError::MissingValue.report())?;
+    let secret =
+        totp_secret_from_base32(&enrollment.secret).ok_or(TestError::MissingValue.report())?;
+    let result = post_confirm_totp_enrollment(
+        &admin.api(),
+        TotpCode::new(generate_totp_code(&secret, current_unix_time())),
+    )
+    .await?;
+    assert(!result.error_invalid_code.unwrap_or_default())?;
+
+    admin.run(CompleteAccountSetup).await?;
+    Ok((admin, secret))
+}
+
+#[server_test(modify_server_config_with = "require_admin_totp")]
+async fn admin_rights_are_not_granted_without_totp(mut context: TestContext) -> TestResult {
+    let admin = context.new_admin().await?;
+    assert_eq(
+        get_account_state(&admin.api()).await?.permissions,
+        Default::default(),
+    )?;
+
+    let result = post_start_totp_enrollment(&admin.api()).await?;
+    assert(result.enrollment.flatten().is_some())
+}
+
+#[server_test(modify_server_config_with = "require_admin_totp")]
+async fn admin_rights_are_granted_when_totp_is_enabled(mut context: TestContext) -> TestResult {
+    let (admin, _) = new_admin_with_totp(&mut context).await?;
+    let query = AdminAuditLogQuery::new(0, UnixTime::new(i64::MAX));
+
+    assert(get_account_state(&admin.api()).await?.permissions != Default::default())?;
+    post_get_admin_audit_log_page(&admin.api(), query).await?;
+
+    let result = post_start_totp_enrollment(&admin.api()).await?;
+    assert(result.error_already_enabled.unwrap_or_default())
+}
+
+#[server_test(modify_server_config_with = "require_admin_totp")]
+async fn invalid_totp_code_does_not_verify_login_session(mut context: TestContext) -> TestResult {
+    let (admin, secret) = new_admin_with_totp(&mut context).await?;
+
+    let result = post_verify_totp(
+        &admin.api(),
+        TotpCode::new(generate_totp_code(&secret, current_unix_time() - 60 * 60)),
+    )
+    .await?;
+    assert(result.error_invalid_code.unwrap_or_default())
+}
+
+#[server_test(modify_server_config_with = "require_admin_totp")]
+async fn admin_permissions_can_not_be_granted_without_totp(mut context: TestContext) -> TestResult {
+    let (admin, _) = new_admin_with_totp(&mut context).await?;
+    let account = context.new_account().await?;
+
+    let result = post_set_permissions(
+        &admin.api(),
+        &account.account_id_string(),
+        Permissions {
+            admin_view_permissions: Some(true),
+            ..Permissions::new()
+        },
+    )
+    .await?;
+    assert(result.error_totp_not_enabled.unwrap_or_default())?;
+    assert_eq(
+        get_account_state(&account.api()).await?.permissions,
+        Default::default(),
+    )
+}
+
+#[server_test(modify_server_config_with = "require_admin_totp")]
+async fn totp_enrollment_is_not_possible_with_admin_permissions(
+    mut context: TestContext,
+) -> TestResult {
+    let (admin, secret) = new_admin_with_totp(&mut context).await?;
+
+    let result = post_disable_totp(
+        &admin.api(),
+        TotpCode::new(generate_totp_code(&secret, current_unix_time() + 30)),
+    )
+    .await?;
+    assert(!result.error_invalid_code.unwrap_or_default())?;
+
+    let result = post_start_totp_enrollment(&admin.api()).await?;
+    assert(result.error_admin_permissions.unwrap_or_default())?;
+    assert(result.enrollment.flatten().is_none())
+}
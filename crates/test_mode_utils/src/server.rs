@@ -1,21 +1,28 @@
 use std::{
-    env, num::NonZeroU8, os::unix::process::CommandExt, path::PathBuf, process::Stdio, sync::Arc,
+    env,
+    net::{Ipv4Addr, SocketAddr},
+    num::NonZeroU8,
+    os::unix::process::CommandExt,
+    path::PathBuf,
+    process::Stdio,
+    sync::Arc,
 };
 
 use chrono::{Datelike, Timelike};
 use config::{
     args::{SelectedBenchmark, TestMode},
     file::{
-        ApiConfig, AutomaticProfileSearchConfig, ConfigFile, GrantAdminAccessConfig,
-        LocationConfig, ScheduledTasksConfig,
+        AdminTotpConfig, ApiConfig, AutomaticProfileSearchConfig, ConfigFile,
+        GrantAdminAccessConfig, LocationConfig, ScheduledTasksConfig,
     },
 };
 use nix::{sys::signal::Signal, unistd::Pid};
 use reqwest::Url;
 use server_data::index::info::LocationIndexInfoCreator;
 use simple_backend_config::file::{
-    DatabaseConfig, GeneralConfig, IpInfoConfig, PushNotificationConfig, SignInWithConfig,
-    SimpleBackendConfigFile, SocketConfig, TlsConfig, VideoCallingConfig,
+    DatabaseConfig, GeneralConfig, IpAddressAccessConfig, IpInfoConfig, MetricsApiConfig,
+    PushNotificationConfig, SignInWithConfig, SimpleBackendConfigFile, SocketConfig, TlsConfig,
+    VideoCallingConfig,
 };
 use tokio::{
     io::{AsyncBufReadExt, AsyncRead},
@@ -121,6 +128,7 @@ impl ServerManager {
 }
 
 fn new_config(config: &TestMode, bot_api_port: u16) -> (ConfigFile, SimpleBackendConfigFile) {
+    let metrics_api_port = config.benchmark_metrics_api_port();
     let config = ConfigFile {
         grant_admin_access: GrantAdminAccessConfig {
             debug_for_every_matching_new_account: false,
@@ -136,6 +144,7 @@ fn new_config(config: &TestMode, bot_api_port: u16) -> (ConfigFile, SimpleBacken
         automatic_profile_search: AutomaticProfileSearchConfig::default(),
         remote_bot_login: None,
         scheduled_tasks: ScheduledTasksConfig::default(),
+        admin_totp: AdminTotpConfig::default(),
         location: if let Some(SelectedBenchmark::GetProfileList) = config.selected_benchmark() {
             let mut location = DEFAULT_LOCATION_CONFIG_BENCHMARK;
             if let Some(index_cell_size) = config.overridden_index_cell_size() {
@@ -151,6 +160,7 @@ fn new_config(config: &TestMode, bot_api_port: u16) -> (ConfigFile, SimpleBacken
             DEFAULT_LOCATION_CONFIG
         }
         .into(),
+        location_regions: None,
         demo_accounts: None,
         limits: None,
         profile_name_allowlists: None,
@@ -169,6 +179,13 @@ fn new_config(config: &TestMode, bot_api_port: u16) -> (ConfigFile, SimpleBacken
             public_api: None,
             local_bot_api_port: Some(bot_api_port),
             debug_local_bot_api_ip: None,
+            metrics_api: metrics_api_port.map(|port| MetricsApiConfig {
+                address: SocketAddr::new(Ipv4Addr::LOCALHOST.into(), port),
+                access: IpAddressAccessConfig {
+                    ip_allowlist: vec![Ipv4Addr::LOCALHOST.into()],
+                    ..Default::default()
+                },
+            }),
         },
         push_notifications: PushNotificationConfig::default(),
         sign_in_with: SignInWithConfig::default(),
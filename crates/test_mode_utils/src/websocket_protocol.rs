@@ -15,6 +15,10 @@ pub struct EventToClient {
     pub content_processing_state_changed: Option<ContentProcessingStateChanged>,
     pub response_reset_profile_paging: Option<ResponseResetProfilePaging>,
     pub response_next_profile_page: Option<ResponseNextProfilePage>,
+    /// Account ID of the account which started typing
+    pub typing_start: Option<String>,
+    /// Backend signed PGP message containing `SignedMessageData`
+    pub pending_message: Option<Vec<u8>>,
     pub event: EventType,
 }
 
@@ -25,6 +29,8 @@ impl EventToClient {
             content_processing_state_changed: None,
             response_reset_profile_paging: None,
             response_next_profile_page: None,
+            typing_start: None,
+            pending_message: None,
             event,
         }
     }
@@ -160,6 +166,16 @@ fn convert_server_event_to_client_for_test_mode(
             });
             Some(event)
         }
+        EventToClientInternal::TypingStart(account_id) => {
+            let mut event = EventToClient::new(EventType::TypingStart);
+            event.typing_start = Some(account_id.to_string());
+            Some(event)
+        }
+        EventToClientInternal::PendingMessage(signed_message) => {
+            let mut event = EventToClient::new(EventType::PendingMessage);
+            event.pending_message = Some(signed_message);
+            Some(event)
+        }
         EventToClientInternal::AccountStateChanged
         | EventToClientInternal::EmailAddressStateChanged
         | EventToClientInternal::NewMessageReceived
@@ -178,11 +194,12 @@ fn convert_server_event_to_client_for_test_mode(
         | EventToClientInternal::DailyLikesLeftChanged
         | EventToClientInternal::ScheduledMaintenanceStatus(_)
         | EventToClientInternal::PushNotificationInfoChanged
-        | EventToClientInternal::TypingStart(_)
         | EventToClientInternal::TypingStop(_)
         | EventToClientInternal::OnlineStatusUpdated(_)
         | EventToClientInternal::MessageDeliveryInfoChanged
-        | EventToClientInternal::LatestSeenMessageChanged => None,
+        | EventToClientInternal::LatestSeenMessageChanged
+        | EventToClientInternal::ResponseSendMessage { .. }
+        | EventToClientInternal::ResponseAcknowledgeMessages { .. } => None,
     }
 }
 
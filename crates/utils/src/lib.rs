@@ -7,3 +7,4 @@ pub mod api;
 pub mod encrypt;
 pub mod minimal_i64;
 pub mod random_bytes;
+pub mod totp;
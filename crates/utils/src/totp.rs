@@ -0,0 +1,161 @@
+//! Time-based one-time passwords (RFC 6238)
+//!
+//! HMAC-SHA1, 30 second time step and 6 digit codes, which are the
+//! defaults for authenticator apps.
+
+use hmac::{Hmac, KeyInit, Mac};
+use rand::{RngCore, rngs::OsRng};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+/// RFC 4226 recommends 160 bit shared secrets.
+pub const TOTP_SECRET_LEN: usize = 20;
+const TIME_STEP_SECONDS: i64 = 30;
+const CODE_DIGITS: usize = 6;
+const CODE_MODULO: u32 = 1_000_000;
+/// Allow one time step clock difference between server and client.
+const ALLOWED_TIME_STEP_DIFFERENCE: i64 = 1;
+
+pub const RECOVERY_CODE_COUNT: usize = 10;
+const RECOVERY_CODE_CHARACTERS: usize = 10;
+
+pub fn generate_totp_secret() -> Vec<u8> {
+    let mut data = vec![0u8; TOTP_SECRET_LEN];
+    OsRng
+        .try_fill_bytes(&mut data)
+        .expect("Random generator failure");
+    data
+}
+
+/// Base32 without padding. Authenticator apps use this format.
+pub fn totp_secret_to_base32(secret: &[u8]) -> String {
+    data_encoding::BASE32_NOPAD.encode(secret)
+}
+
+pub fn totp_secret_from_base32(secret: &str) -> Option<Vec<u8>> {
+    data_encoding::BASE32_NOPAD.decode(secret.as_bytes()).ok()
+}
+
+/// Generate the code which authenticator apps would show.
+pub fn generate_totp_code(secret: &[u8], unix_time: i64) -> String {
+    format!(
+        "{:0width$}",
+        totp_code(secret, totp_time_step(unix_time)),
+        width = CODE_DIGITS
+    )
+}
+
+pub fn totp_time_step(unix_time: i64) -> i64 {
+    unix_time.div_euclid(TIME_STEP_SECONDS)
+}
+
+fn totp_code(secret: &[u8], time_step: i64) -> u32 {
+    let mut mac = Hmac::<Sha1>::new_from_slice(secret).expect("HMAC accepts keys of any length");
+    mac.update(&time_step.to_be_bytes());
+    let hash = mac.finalize().into_bytes();
+
+    let offset = (hash[hash.len() - 1] & 0x0F) as usize;
+    let value = u32::from_be_bytes([
+        hash[offset] & 0x7F,
+        hash[offset + 1],
+        hash[offset + 2],
+        hash[offset + 3],
+    ]);
+    value % CODE_MODULO
+}
+
+/// Returns time step of the matching code.
+///
+/// Codes from time steps equal to or older than `last_used_time_step`
+/// are rejected to prevent code reuse.
+pub fn verify_totp_code(
+    secret: &[u8],
+    code: &str,
+    unix_time: i64,
+    last_used_time_step: i64,
+) -> Option<i64> {
+    let code = code.trim();
+    if code.len() != CODE_DIGITS || !code.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let code: u32 = code.parse().ok()?;
+
+    let current = totp_time_step(unix_time);
+    (current - ALLOWED_TIME_STEP_DIFFERENCE..=current + ALLOWED_TIME_STEP_DIFFERENCE)
+        .filter(|&step| step > last_used_time_step)
+        .find(|&step| totp_code(secret, step) == code)
+}
+
+/// Generate recovery code with format "xxxxx-xxxxx".
+pub fn generate_recovery_code() -> String {
+    let mut data = [0u8; 8];
+    OsRng
+        .try_fill_bytes(&mut data)
+        .expect("Random generator failure");
+    let characters = data_encoding::BASE32_NOPAD
+        .encode(&data)
+        .to_ascii_lowercase();
+    let characters = &characters[..RECOVERY_CODE_CHARACTERS];
+    let (first, second) = characters.split_at(RECOVERY_CODE_CHARACTERS / 2);
+    format!("{first}-{second}")
+}
+
+/// SHA-256 hash of the recovery code. Separator, whitespace and
+/// character case differences are ignored.
+pub fn recovery_code_hash(code: &str) -> Vec<u8> {
+    let normalized: String = code
+        .chars()
+        .filter(|c| *c != '-' && !c.is_whitespace())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    Sha256::digest(normalized.as_bytes()).to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// RFC 6238 test secret for SHA1
+    const RFC_SECRET: &[u8] = b"12345678901234567890";
+
+    #[test]
+    fn rfc_6238_test_vectors() {
+        // RFC test vectors use 8 digits, so compare the last 6 digits.
+        assert_eq!(totp_code(RFC_SECRET, totp_time_step(59)), 287082);
+        assert_eq!(totp_code(RFC_SECRET, totp_time_step(1111111109)), 81804);
+        assert_eq!(totp_code(RFC_SECRET, totp_time_step(1234567890)), 5924);
+        assert_eq!(totp_code(RFC_SECRET, totp_time_step(2000000000)), 279037);
+    }
+
+    #[test]
+    fn verify_allows_clock_difference_and_prevents_reuse() {
+        let time = 1111111109;
+        let step = totp_time_step(time);
+        assert_eq!(verify_totp_code(RFC_SECRET, "081804", time, 0), Some(step));
+        assert_eq!(
+            verify_totp_code(RFC_SECRET, "081804", time + 30, 0),
+            Some(step)
+        );
+        assert_eq!(verify_totp_code(RFC_SECRET, "081804", time + 60, 0), None);
+        assert_eq!(verify_totp_code(RFC_SECRET, "081804", time, step), None);
+        assert_eq!(verify_totp_code(RFC_SECRET, "81804", time, 0), None);
+    }
+
+    #[test]
+    fn generated_code_is_verified() {
+        let time = 1111111109;
+        assert_eq!(generate_totp_code(RFC_SECRET, time), "081804");
+        let secret = totp_secret_from_base32(&totp_secret_to_base32(RFC_SECRET));
+        assert_eq!(secret.as_deref(), Some(RFC_SECRET));
+    }
+
+    #[test]
+    fn recovery_code_hash_ignores_formatting() {
+        let code = generate_recovery_code();
+        assert_eq!(code.len(), RECOVERY_CODE_CHARACTERS + 1);
+        assert_eq!(
+            recovery_code_hash(&code),
+            recovery_code_hash(&code.replace('-', " ").to_ascii_uppercase())
+        );
+    }
+}
@@ -114,6 +114,8 @@ CREATE TABLE IF NOT EXISTS account_permissions(
     admin_edit_profile_attributes_schema         BOOLEAN NOT NULL DEFAULT FALSE,
     admin_edit_profile_attributes_schema_visible_content BOOLEAN NOT NULL DEFAULT FALSE,
     admin_custom_email                           BOOLEAN NOT NULL DEFAULT FALSE,
+    admin_view_admin_audit_log                   BOOLEAN NOT NULL DEFAULT FALSE,
+    admin_import_account_data                    BOOLEAN NOT NULL DEFAULT FALSE,
     FOREIGN KEY (account_id)
         REFERENCES account_id (id)
             ON DELETE CASCADE
@@ -160,6 +162,7 @@ CREATE TABLE IF NOT EXISTS common_report(
     -- 1 = profile text
     -- 2 = profile content
     -- 3 = chat message
+    -- 4 = ban evasion
     -- Values from 64 to 127 are reserved for custom reports.
     report_type_number      SMALLINT            NOT NULL,
     creation_unix_time      BIGINT              NOT NULL,
@@ -185,6 +188,18 @@ CREATE TABLE IF NOT EXISTS common_report(
             ON UPDATE CASCADE
 );
 
+-- Report creator is the banned account and report target
+-- is the account which is possibly used for ban evasion.
+CREATE TABLE IF NOT EXISTS common_report_ban_evasion(
+    report_id               INTEGER PRIMARY KEY NOT NULL,
+    -- JSON
+    evidence_json           TEXT                NOT NULL,
+    FOREIGN KEY (report_id)
+        REFERENCES common_report (id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
+
 -- State specific to all components.
 CREATE TABLE IF NOT EXISTS common_state(
     account_id                    INTEGER PRIMARY KEY NOT NULL,
@@ -206,9 +221,14 @@ CREATE TABLE IF NOT EXISTS push_notification(
     account_id             INTEGER PRIMARY KEY NOT NULL,
     -- Bitflag value for pending push notification
     pending_flags          BIGINT              NOT NULL DEFAULT 0,
-    -- Push notification encryption key for APNs and FCM notifications
+    -- Push notification encryption key for APNs, FCM and UnifiedPush
+    -- notifications
     encryption_key         TEXT,
     device_token           TEXT                         UNIQUE,
+    -- Values:
+    -- 0 = Platform (FCM, APNs or web push depending on client type)
+    -- 1 = UnifiedPush (device_token is a distributor endpoint URL)
+    device_token_provider  SMALLINT            NOT NULL DEFAULT 0,
     -- Time when a token is saved. Not currently used for anything.
     -- Firebase docs recommend storing a timestamp with a token.
     device_token_unix_time BIGINT,
@@ -357,6 +377,10 @@ CREATE TABLE IF NOT EXISTS sign_in_with_info(
     account_id         INTEGER PRIMARY KEY NOT NULL,
     apple_account_id   TEXT                          UNIQUE,
     google_account_id  TEXT                          UNIQUE,
+    -- OpenID Connect provider name from server config
+    oidc_provider      TEXT,
+    oidc_account_id    TEXT,
+    UNIQUE (oidc_provider, oidc_account_id),
     FOREIGN KEY (account_id)
         REFERENCES account_id (id)
             ON DELETE CASCADE
@@ -483,6 +507,31 @@ CREATE TABLE IF NOT EXISTS account_email_login_limits(
             ON UPDATE CASCADE
 );
 
+-- Time-based one-time password (TOTP) second factor
+CREATE TABLE IF NOT EXISTS account_totp(
+    account_id              INTEGER PRIMARY KEY NOT NULL,
+    secret                  BLOB               NOT NULL,
+    -- False until enrollment is confirmed with a valid code
+    enabled                 BOOLEAN            NOT NULL DEFAULT FALSE,
+    -- Prevents using the same code twice
+    last_used_time_step     BIGINT             NOT NULL DEFAULT 0,
+    FOREIGN KEY (account_id)
+        REFERENCES account_id (id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS account_totp_recovery_code(
+    account_id              BIGINT             NOT NULL,
+    -- SHA-256 hash of the recovery code
+    code_hash               BLOB               NOT NULL,
+    PRIMARY KEY (account_id, code_hash),
+    FOREIGN KEY (account_id)
+        REFERENCES account_id (id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
+
 CREATE TABLE IF NOT EXISTS email_registration_limits(
     -- 0 = email registration limits
     row_type                    INTEGER PRIMARY KEY NOT NULL,
@@ -779,6 +828,9 @@ CREATE TABLE IF NOT EXISTS profile_attributes_filter_settings(
     attribute_id    SMALLINT            NOT NULL,
     filter_accept_missing_attribute BOOLEAN NOT NULL DEFAULT FALSE,
     filter_use_logical_operator_and BOOLEAN NOT NULL DEFAULT FALSE,
+    -- Numeric attribute range filter values
+    filter_min      BIGINT,
+    filter_max      BIGINT,
     PRIMARY KEY (account_id, attribute_id),
     FOREIGN KEY (account_id)
         REFERENCES account_id (id)
@@ -1015,6 +1067,8 @@ CREATE TABLE IF NOT EXISTS media_content(
     moderation_moderator_account_id     BIGINT,
     usage_start_unix_time  BIGINT,
     usage_end_unix_time    BIGINT,
+    -- SHA-256 of the uploaded file before image processing
+    raw_content_sha256     BLOB,
     FOREIGN KEY (account_id)
         REFERENCES account_id (id)
             ON DELETE CASCADE
@@ -1260,6 +1314,20 @@ CREATE TABLE IF NOT EXISTS pending_messages(
             ON UPDATE CASCADE
 );
 
+-- End-to-end encrypted attachment for a pending message.
+-- The attachment is removed when the message recipient acknowledges
+-- the message or when the pending message is removed.
+CREATE TABLE IF NOT EXISTS pending_message_attachment(
+    pending_message_id      BIGINT PRIMARY KEY NOT NULL,
+    upload_unix_time        BIGINT NOT NULL,
+    -- Encrypted attachment bytes.
+    attachment_bytes        BLOB  NOT NULL,
+    FOREIGN KEY (pending_message_id)
+        REFERENCES pending_messages (id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
+
 CREATE TABLE IF NOT EXISTS message_delivery_info(
     id                      INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
     -- The account which sent the message.
@@ -1463,6 +1531,20 @@ CREATE TABLE IF NOT EXISTS history_ip_country_statistics(
             ON UPDATE CASCADE
 );
 
+-- Admin actions which modify data or server state
+CREATE TABLE IF NOT EXISTS history_admin_audit_log(
+    id             INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    unix_time      BIGINT   NOT NULL,
+    admin_account  BLOB     NOT NULL,
+    -- AdminAuditLogAction
+    action         SMALLINT NOT NULL,
+    target_account BLOB,
+    target_content BLOB,
+    -- JSON
+    parameters     TEXT,
+    ip_address     BLOB     NOT NULL
+);
+
 ---------- History tables for server component account ----------
 
 CREATE TABLE IF NOT EXISTS history_client_version_statistics_version_number(